@@ -0,0 +1,770 @@
+# English (default) locale for ZXP Manager.
+# Syntax is a Fluent subset: `key = value`, `{ $arg }` placeholders, `#` comments.
+
+## Sidebar
+drop-zone-title = Drop ZXP files or extension folders here
+drop-zone-subtitle = or click to browse
+drop-zone-unavailable = Drag and drop is unavailable here - browse or paste a path
+paste-path-placeholder = Paste a .zxp path and press Enter
+browse-files = Browse Files
+open-inventory = Open folder as inventory...
+export-plugin-list = Export plugin list...
+link-dev-folder = Link dev folder...
+link-dev-folder-hint = Load an unpacked extension you are working on straight from its folder - no packaging, changes show up when the panel reloads
+install-folder = Install folder...
+install-folder-hint = Install an unpacked extension folder with the same checks as a ZXP - a copy goes into the extensions folder
+inspect-zxp = Open ZXP...
+inspect-zxp-hint = List a ZXP's files, manifest and signature without installing or writing anything
+backup-all = Backup all
+backup-all-hint = Export every third-party plugin as ZXP files plus a manifest, for moving to another machine
+restore-set = Restore set
+restore-set-hint = Install a backed-up plugin set from its folder
+backup-all-title = Choose where to save the plugin set
+restore-set-title = Choose a plugin set folder
+recent-installs = Recent
+recent-clear = Clear
+install-queue-title = Install queue
+install-queue-clear = Clear finished
+install-queue-remove = Remove from the queue
+install-queue-waiting = Waiting
+install-queue-extracting = Extracting
+install-queue-extracting-progress = Extracting { $done }/{ $total }
+install-queue-done = Done
+install-queue-failed = Failed
+setting-cep-path = CEP Extensions Path
+setting-user-path = User Extensions Path
+setting-language = Language
+setting-theme = Theme
+theme-dark = Dark
+theme-high-contrast = High contrast
+setting-motion = Animations
+motion-system = Follow system setting
+motion-reduced = Reduced
+motion-full = Always on
+setting-log-level = Log level
+setting-cep-preferences = CEP preferences (restart host apps to apply)
+setting-double-click = Double-click action
+setting-quick-actions = Row quick actions
+setting-columns = Plugin list columns
+setting-toast-position = Notification position
+toast-position-status-bar = Above the status bar
+toast-position-top-right = Top right corner
+toast-position-top-center = Top center
+setting-toast-duration = Notification duration
+toast-type-success = Success
+toast-type-info = Info
+toast-type-error = Error
+toast-duration-seconds = { $seconds } seconds
+toast-duration-sticky = Until dismissed
+setting-check-updates = Check for updates on startup
+setting-telemetry = Send anonymous usage counts
+setting-telemetry-hint = Once per launch: how often the app was started and installs succeeded or failed, plus app version, OS and architecture. No plugin names, paths or identifiers.
+setting-telemetry-preview = Show what is sent
+setting-telemetry-preview-hide = Hide preview
+setting-inspect-before-install = Inspect before install
+setting-scan-scripts = Scan scripts before install
+setting-scan-scripts-hint = Look through the panel's JS and JSX for eval, shell commands, obfuscated code and calls to raw IP addresses, and show a risk summary before installing
+setting-clear-quarantine = Clear macOS quarantine flags after installing
+setting-clear-quarantine-hint = Downloaded packages can pass the quarantine flag on to their files, which stops bundled binaries and Node helpers from running
+setting-tray-icon = Show a tray icon
+setting-tray-icon-hint = Install and rescan from the tray menu. From the next start, closing the window keeps the app running in the tray.
+setting-system-notifications = System notifications in the background
+setting-system-notifications-hint = When installs, batch jobs or the update check finish while the window is in the background, the system shows a notification too
+
+## Plugins panel
+column-plugin = Plugin
+column-bundle-id = Bundle ID
+column-version = Version
+column-size = Size
+column-installed = Installed
+size-on-disk = On disk: { $size }
+column-remove = Remove
+column-import = Import
+badge-native = native
+badge-installed = installed
+badge-dev = dev
+badge-dev-hint = Linked working folder - removing it only removes the link
+badge-legacy = legacy
+badge-legacy-hint = Left by Extension Manager or a CS-era ServiceManager - current host apps don't load it. Import copies it into the CEP extensions folder.
+badge-missing-host = no host app
+badge-missing-host-hint = Targets { $hosts }, which is not installed on this machine
+badge-runtime = needs CEP { $required }
+badge-runtime-hint = Requires CEP { $required }, your { $host } only ships CEP { $available } - the panel will not load
+badge-duplicate = Duplicate
+badge-duplicate-hint = Installed { $count } times - host apps load only one copy, and the panel may appear twice
+badge-background = Background
+badge-background-hint = Every extension in this bundle is invisible - it runs in the background and never appears in Window > Extensions
+duplicates-banner = { $count } plugins are installed in more than one folder.
+duplicates-resolve = Remove duplicates, keep newest
+missing-roots-banner = These extensions folders don't exist yet, so host apps have nothing to load from them:
+missing-roots-create = Create it now
+missing-roots-dismiss = Not now
+missing-roots-admin-hint = The all-users folder needs administrator rights
+loading-plugins = Loading plugins...
+disabled-plugins-title = Disabled: { $count }
+broken-extensions-title = Broken: { $count }
+broken-extensions-hint = Folders in the extensions folders that aren't readable extensions. Hosts may still trip over them.
+broken-extension-open = Open folder
+
+## Status bar
+status-plugins-installed = Plugins installed: { $count }
+status-plugins-cached = Plugins installed: { $count } (cached, scanning...)
+status-loading = Loading...
+status-disk-usage = { $size } on disk
+status-scan-progress = Scanning: { $processed }/{ $total } folders processed
+status-scan-cancel = Cancel
+status-scan-cancel-hint = Stop the scan and keep showing the previous list
+update-available = Update available: v{ $version }
+
+## Messages
+toast-plugin-installed = Installed { $name } { $version }
+toast-plugin-disabled = Disabled { $name } - host apps no longer load it
+toast-plugin-enabled = Enabled { $name } - restart host apps to load it
+toast-disable-failed = Failed to disable { $name }: { $error }
+toast-enable-failed = Failed to enable { $name }: { $error }
+toast-plugin-removed = Plugin removed successfully!
+toast-install-failed = Installation failed: { $error }
+toast-remove-failed = Failed to remove plugin: { $error }
+toast-dismiss = Dismiss
+toast-dismiss-all = Dismiss all
+toast-paste-nothing = No .zxp file or path found in the clipboard
+toast-paste-url-unsupported = Web links can't be installed directly - download the .zxp first
+# An error whose own text is already translated
+toast-error = { $error }
+
+## Scan changes
+scan-changes-detected = Extensions changed externally: { $summary }
+scan-added = { $count } added
+scan-removed = { $count } removed
+scan-version-changed = { $count } version changed
+scan-cancelled = Scan cancelled - the list shows the previous result
+rescan = Rescan extensions
+
+## Navigation
+nav-plugins = Plugins
+nav-overview = Overview
+nav-diagnostics = Diagnostics
+nav-compliance = Compliance
+nav-troubleshoot = Troubleshoot
+nav-signing = Sign & package
+nav-release = Prepare release
+nav-debug-ports = Remote debugging
+nav-logs = Logs
+nav-error-codes = Error codes
+
+## Diagnostics
+diagnostics-intro = What host apps will load: extensions from all roots, which copy wins on ID collisions, and the resulting Window > Extensions menu.
+diagnostics-export = Export diagnostics
+diagnostics-export-hint = Saves the app log, plugin list, CSXS preferences, host apps and OS version in one ZIP to attach to a bug report.
+diagnostics-menu-title = Window > Extensions menu
+diagnostics-menu-empty = No extension adds a menu entry.
+diagnostics-duplicate-menu = Appears { $count } times in the menu
+diagnostics-collisions-title = ID collisions
+diagnostics-collisions-empty = No extension ID is installed more than once.
+diagnostics-wins = Loaded copy:
+diagnostics-ambiguous = Same root - the loaded copy depends on directory order:
+diagnostics-ignored = Ignored copies:
+diagnostics-hidden-title = Without a menu entry
+diagnostics-hidden-empty = Every loaded extension has a menu entry.
+diagnostics-hidden-reason = No menu label for UI type { $type } - invisible or opened by another panel
+diagnostics-hosts-title = Installed host apps
+diagnostics-hosts-empty = No Adobe host app was found.
+diagnostics-host-reset = Reset extension cache ({ $count })
+diagnostics-host-reset-hint = Removes this app's CEP cache and extension logs. Other apps are not affected.
+diagnostics-debug-launch = Debug launch
+diagnostics-debug-launch-hint = Starts the app with factory preferences in a temporary sandbox and verbose CEP logging. Your real preferences are not touched.
+diagnostics-restart-cep = Restart CEP
+diagnostics-restart-cep-hint = Quits the CEP engine processes that run panels. Blank or frozen panels load again when reopened.
+diagnostics-orphans-title = Data left by removed extensions
+diagnostics-orphans-empty = Every CEP cache, log and storage entry belongs to an installed extension.
+diagnostics-orphans-hint = These extensions are no longer installed, but their CEP cache, logs and stored settings remain. Untick anything you want to keep.
+diagnostics-orphans-entries = { $count } entries
+diagnostics-orphans-clean = Clean up selected ({ $size })
+root-system = system
+root-user = user
+
+## Shortcuts
+search-placeholder = Search plugins
+host-filter-all = All host apps
+tag-filter-all = All tags
+favorite-add = Pin to the top
+favorite-remove = Unpin
+expanded-expand = Show more
+expanded-collapse = Show less
+expanded-hosts = Host apps
+group-by-vendor = Group by vendor
+vendor-unknown = No vendor
+vendor-subtotal = { $count } · { $size }
+shortcut-sheet-title = Keyboard shortcuts
+shortcut-sheet-hint = Press ? or Esc to close
+shortcut-open-picker = Install a ZXP file
+shortcut-rescan = Rescan plugins
+shortcut-remove-selected = Remove the selected plugin
+shortcut-focus-search = Search plugins
+shortcut-paste-install = Install ZXP files from the clipboard
+shortcut-show-shortcuts = Show this list
+shortcut-command-palette = Open the command palette
+confirm-remove-title = Remove plugin
+confirm-remove-body = Remove { $name }? Its folder will be deleted from disk.
+confirm-host-reset-title = Reset the extension cache of { $app }?
+confirm-host-reset-body = Quit { $app } first - a running app keeps using and rewriting its cache. Panels lose what they stored locally, such as sign-ins and panel settings, and rebuild their cache on the next launch.
+
+## Context menu
+menu-reveal-finder = Reveal in Finder
+menu-reveal-explorer = Show in Explorer
+menu-copy-bundle-id = Copy bundle ID
+menu-copy-version = Copy version
+menu-copy-path = Copy path
+menu-open-manifest = Open manifest.xml
+menu-export-zxp = Export as ZXP...
+menu-resolve-duplicate = Remove duplicate, keep newest version
+menu-remove = Remove
+menu-disable = Disable
+menu-enable = Enable
+menu-remove-as-admin = Remove as administrator...
+menu-remove-deep-clean = Remove with settings and cache...
+menu-import = Import
+menu-unlink-dev = Unlink dev folder
+item-bundle-id = Bundle ID
+item-version = Version
+item-path = Path
+toast-copied = { $item } copied to clipboard
+toast-plugin-exported = Plugin exported successfully!
+toast-export-failed = Export failed: { $error }
+toast-plugin-list-exported = Exported a list of { $count } plugins
+toast-compliance-exported = Compliance report exported
+toast-diagnostics-exported = Diagnostics saved to { $path }
+toast-history-exported = Exported { $count } history entries
+toast-backup-done = Backed up { $count } plugins
+toast-backup-failed = Backup failed: { $error }
+toast-restore-done = Restored { $installed } plugins, { $skipped } already installed.
+toast-restore-partial = Restored { $installed } plugins, { $skipped } already installed. Failed: { $names }
+toast-restore-failed = Restore failed: { $error }
+
+## Details drawer
+menu-details = Details
+details-close = Close
+details-copy = Copy
+details-bundle-id = Bundle ID
+details-size-on-disk = Size on disk
+details-extensions = Extensions
+details-ui-type = UI type
+ui-kind-panel = Panel
+ui-kind-modal-dialog = Modal dialog
+ui-kind-modeless = Modeless window
+ui-kind-invisible = Invisible - runs in the background
+details-menu = Menu label
+details-main-path = Main path
+details-script-path = Script path
+details-auto-visible = Opens automatically
+details-start-on = Starts on events
+details-cef-parameters = CEF command line
+details-none = None
+details-yes = Yes
+details-no = No
+details-notes = Notes and tags
+details-notes-placeholder = Notes for yourself, e.g. where the license key is
+license-key-title = License key
+license-key-hint = Kept in the system keychain, not in the app's files.
+license-key-placeholder = Paste the license key
+license-key-add = Add license key
+license-key-save = Save
+license-key-cancel = Cancel
+license-key-show = Show
+license-key-hide = Hide
+license-key-edit = Change
+license-key-remove = Remove
+item-license-key = License key
+panel-settings-title = Panel settings
+panel-settings-hint = Settings the panel keeps outside its folder (web storage in the CEP cache, its Application Support folder). Back them up before reinstalling.
+panel-settings-backed-up = Backed up on { $date }
+panel-settings-backup = Backup settings
+panel-settings-restore = Restore settings
+details-tag-placeholder = Add a tag and press Enter
+details-tag-remove = Remove tag
+manifest-edit-title = Manifest
+manifest-edit-open = Edit manifest...
+manifest-edit-restore = Restore original
+manifest-edit-restore-hint = Put back the manifest as it was before the first edit
+manifest-edit-signed = This package is signed. Editing the manifest breaks the signature, so hosts load it only with PlayerDebugMode on.
+manifest-edit-name = Display name
+manifest-edit-host = { $host } versions
+manifest-edit-any-version = Any version
+manifest-edit-range-hint = A version such as 24.0 or a range such as [22.0,99.9]. Widening the range lets an older panel load in a newer host.
+manifest-edit-save = Save manifest
+manifest-edit-cancel = Cancel
+manifest-edit-name-empty = The display name can't be empty
+manifest-edit-bad-version = "{ $value }" is not a version like 1.2.0
+manifest-edit-bad-range = "{ $value }" is not a version or a range like [22.0,99.9]
+toast-manifest-saved = Manifest saved - the original is kept as manifest.xml.original
+toast-manifest-restored = Original manifest restored
+toast-license-saved = License key saved to the keychain
+toast-license-removed = License key removed
+toast-license-failed = Could not save the license key: { $error }
+toast-settings-nothing = { $name } keeps no settings outside its folder
+toast-settings-backed-up = Backed up { $count } settings locations of { $name }
+toast-settings-backup-failed = Settings backup failed: { $error }
+toast-settings-restored = Restored { $count } settings locations of { $name }
+toast-settings-restore-failed = Restoring settings failed: { $error }
+confirm-settings-restore-title = Restore panel settings?
+confirm-settings-restore-body = The current settings of { $name } are replaced with the backup from { $date }.
+toast-manifest-save-failed = Failed to update the manifest: { $error }
+version-history-title = Previous versions
+version-history-none = No earlier versions archived yet - upgrades keep the version they replace
+version-history-revert-previous = Revert to previous version
+version-history-revert = Revert to this version
+toast-version-reverted = { $name } reverted to { $version }
+toast-version-revert-failed = Failed to revert { $name }: { $error }
+cef-enable-nodejs = Node.js APIs are available in the panel
+cef-mixed-context = Node.js and the browser share one JavaScript context
+cef-remote-debugging = Remote debugging port is opened
+cef-relaxed-security = Browser security restrictions are relaxed
+cef-media-stream = Camera and microphone access is allowed
+cef-no-app-cache = Application cache is disabled
+
+## Maintenance
+setting-maintenance = Scheduled maintenance
+maintenance-enabled = Run daily while the app is open
+maintenance-task-backup = Back up installed plugins
+maintenance-task-cache-cleanup = Clear CEP cache
+maintenance-backup-done = Scheduled backup saved { $count } plugins
+maintenance-cache-cleanup-done = Scheduled cleanup removed { $count } cache entries
+maintenance-task-failed = Scheduled maintenance failed: { $error }
+setting-hot-folder = Hot folder
+hot-folder-enabled = Install ZXPs dropped into this folder
+hot-folder-choose = Choose folder...
+hot-folder-hint = Every .zxp that appears in the folder is validated and installed without asking, e.g. from a synced studio folder
+hot-folder-archive = Then move them to "Installed"
+hot-folder-delete = Then delete them
+hot-folder-installed = Hot folder installed { $names }
+hot-folder-failed = Hot folder couldn't install { $names } - moved to "{ $folder }"
+
+## Inventory
+inventory-browsing = Browsing { $count } plugins read-only from:
+inventory-close = Back to installed
+toast-inventory-opened = Found { $count } plugins in folder
+toast-inventory-failed = Failed to open folder: { $error }
+toast-plugin-imported = Plugin imported successfully!
+toast-dev-linked = Dev folder linked - reload the panel in the host app to pick up changes
+toast-dev-link-failed = Couldn't link the folder: { $error }
+toast-dev-unlinked = Dev folder unlinked, your files were not touched
+toast-dev-unlink-failed = Couldn't unlink the folder: { $error }
+toast-import-failed = Import failed: { $error }
+toast-fix-failed = Fix failed: { $error }
+toast-host-reset-done = Extension cache of { $app } reset ({ $count } entries removed)
+toast-host-reset-failed = Cache reset failed: { $error }
+toast-debug-launch-started = Started { $app } with a clean preferences sandbox and CEP debug logging: { $path }
+toast-debug-launch-started-plain = Started { $app } with a clean preferences sandbox: { $path }. CEP's log level stays as it is - on Windows it's a registry setting the sandbox can't keep separate
+toast-debug-launch-failed = Couldn't start { $app } for debugging: { $error }
+toast-orphans-found = Removed extensions left { $count } cache and storage entries ({ $size }) - clean them up in Diagnostics
+toast-orphans-removed = Removed { $count } orphaned entries, { $size } freed
+toast-duplicates-removed = Removed { $count } duplicate copies
+toast-duplicate-failed = Could not remove { $path }: { $error }
+toast-root-created = Created { $path }
+toast-root-create-failed = Could not create { $path }: { $error }
+toast-certificate-created = Certificate created
+toast-certificate-failed = Could not create the certificate: { $error }
+toast-signed = Signed package saved to { $path }
+toast-release-ready = Release saved to { $path }
+toast-release-scaffolded = Manifest created: { $path }
+toast-release-failed = Failed to prepare the release: { $error }
+toast-debug-file-saved = Saved { $count } debug ports
+toast-debug-file-removed = Removed the .debug file
+toast-debug-file-failed = Failed to update the .debug file: { $error }
+toast-cep-flag-saved = { $flag } updated for CSXS { $version }. Restart the host app to apply it.
+toast-cep-flag-failed = Failed to set { $flag }: { $error }
+toast-debug-mode-on = PlayerDebugMode turned on for { $count } CSXS versions. Restart the host app to apply it.
+toast-debug-mode-off = PlayerDebugMode turned off for { $count } CSXS versions. Restart the host app to apply it.
+toast-sign-failed = Signing failed: { $error }
+
+## Activity
+activity-title = Activity
+activity-clear = Clear
+activity-empty = Nothing has happened yet
+activity-keep = Keep history across sessions
+activity-from = From
+activity-to = To
+activity-export = Export...
+
+## Logs
+logs-level-all = All levels
+logs-level-warn = Warnings and errors
+logs-level-error = Errors only
+logs-search-placeholder = Filter log lines...
+logs-empty = No matching log lines
+logs-open-folder = Open log folder
+log-level-error = Errors
+log-level-warn = Warnings
+log-level-info = Info
+log-level-debug = Debug (verbose)
+cep-preferences-unsupported = Only available on macOS and Windows
+cep-preferences-loading = Reading preferences...
+cep-preferences-none = No CSXS preference domains found
+cep-debug-mode = PlayerDebugMode (load unsigned extensions)
+cep-log-level = LogLevel
+cep-log-unset = Not set
+cep-log-off = 0 - Off
+cep-log-error = 1 - Error
+cep-log-warn = 2 - Warning
+cep-log-info = 3 - Info
+cep-log-debug = 4 - Debug
+cep-log-trace = 5 - Trace
+cep-log-all = 6 - All
+
+## Compliance
+compliance-intro = Plugins checked against the allow and deny lists in { $path }. Installs the policy denies are blocked.
+compliance-no-policy = No policy is installed - every plugin is allowed.
+compliance-invalid-policy = The policy file could not be read, so nothing is enforced. Ask your administrator to fix it.
+compliance-all-allowed = All installed plugins comply with the policy.
+compliance-flagged = Flagged for removal: { $count }
+compliance-required = Required but missing or outdated: { $count }
+compliance-missing = Required by policy but not installed
+compliance-outdated = Installed { $installed }, policy requires { $required } or newer
+compliance-export = Export report...
+policy-denied-id = Bundle ID matches the deny list entry { $pattern }
+policy-denied-publisher = Signed by denied publisher { $publisher }
+policy-not-allowed = Not on the allow list
+
+## Troubleshooting
+troubleshoot-panel-title = Panel not appearing in host app
+troubleshoot-panel-intro = Walks through what a host app checks before it shows a panel under Window > Extensions.
+troubleshoot-install-title = Install fails with permission error
+troubleshoot-install-intro = Checks the all-users extensions folder that installs extract into.
+troubleshoot-choose-plugin = Choose a plugin...
+troubleshoot-run = Run checks
+troubleshoot-running = Checking...
+check-manifest = Manifest
+check-manifest-pass = CSXS/manifest.xml is present and readable.
+check-manifest-fail = The manifest can't be read: { $error }
+check-hosts = Host app
+check-hosts-pass = At least one app the plugin targets is installed.
+check-hosts-missing = Targets { $hosts }, which is not installed on this machine.
+check-hosts-none-listed = The manifest lists no host apps, so no app will load it.
+check-runtime = CEP runtime
+check-runtime-pass = The installed host apps ship a CEP version the plugin supports.
+check-signature = Signature
+check-signature-signed = The plugin was installed from a signed package.
+check-signature-debug-on = Unsigned, but debug mode allows unsigned panels.
+check-signature-debug-off = Unsigned and debug mode is off - host apps silently refuse to load it.
+check-duplicates = Duplicate IDs
+check-duplicates-pass = No other copy overrides this plugin.
+check-duplicates-shadowed = Another copy with the same ID loads instead: { $path }
+check-menu = Menu entry
+check-menu-pass = The plugin adds an entry to Window > Extensions.
+check-menu-hidden = No menu entry - it runs invisibly or is opened by another panel.
+check-cache = CEP cache
+check-cache-empty = The CEP cache is empty.
+check-cache-present = { $count } cached extensions - a stale cache can keep showing an old build.
+check-install-folder = Extensions folder
+check-install-folder-pass = The all-users extensions folder is writable.
+check-install-folder-missing = { $path } doesn't exist yet and can't be created without admin rights.
+check-install-folder-readonly = { $path } is not writable for your user account.
+check-plugin-owners = Plugin folders
+check-plugin-owners-pass = Every third-party plugin folder belongs to you.
+check-plugin-owners-warn = { $count } plugin folders belong to another user, e.g. { $path } - updating them fails.
+check-free-space = Free space
+check-free-space-pass = { $available } free.
+check-free-space-warn = Only { $available } free - larger plugins will not fit.
+check-free-space-unknown = Free space could not be determined.
+check-policy = Admin policy
+check-policy-missing = No admin policy restricts installs.
+check-policy-loaded = An admin policy is in place - see Compliance for what it blocks.
+check-policy-invalid = The admin policy file is unreadable - ask your administrator.
+fix-enable-debug-mode = Enable debug mode
+fix-clear-cache = Clear cache
+fix-remove-copy = Remove other copy
+fix-take-ownership = Fix permissions
+fix-debug-mode-done = Debug mode enabled - restart the host app
+fix-cache-done = CEP cache cleared
+fix-copy-removed = Duplicate copy removed
+fix-ownership-done = Permissions fixed
+
+## Sign & package
+signing-intro = Package an extension folder into a signed ZXP for testing or distribution. Uses Adobe's ZXPSignCmd.
+signing-tool-found = ZXPSignCmd: { $path }
+signing-tool-missing = ZXPSignCmd not found. Download it from Adobe's CEP resources and locate it here.
+signing-locate-tool = Locate ZXPSignCmd...
+signing-certificate-title = New self-signed certificate
+signing-country = Country code
+signing-state = State or region
+signing-organization = Organization
+signing-common-name = Publisher name
+signing-password = Certificate password
+signing-create-certificate = Create certificate...
+signing-package-title = Package and sign
+signing-source = Extension folder
+signing-certificate = Certificate (.p12)
+signing-none-chosen = Nothing chosen
+signing-choose = Choose...
+signing-timestamp = Timestamp the signature, so the package stays valid after the certificate expires
+signing-tsa-url = Timestamp server
+signing-sign = Sign & package...
+signing-working = Working...
+
+## Release wizard
+release-intro = Turns a CEP panel project into a distributable package: checks the project, signs it, test-installs the result and writes an update-feed entry next to it.
+release-project-title = Project
+release-project = Project folder
+release-no-manifest = This folder has no CSXS/manifest.xml yet. Enter an ID and name to create one.
+release-bundle-id = Bundle ID
+release-name = Name
+release-create-manifest = Create manifest
+release-signing-title = Signing
+release-download-url = Download URL (folder the ZXP will be uploaded to)
+release-prepare = Prepare release
+release-steps-title = Steps
+release-step-validate = Validate
+release-step-sign = Sign
+release-step-test-install = Test install
+release-step-feed = Feed entry
+release-step-running = Running...
+release-validate-errors = Validation found { $count } errors
+release-validate-done = Passed with { $count } warnings
+release-test-install-done = Installed cleanly as version { $version }
+release-feed-title = Feed entry
+release-copy-feed = Copy
+
+## Remote debugging
+debug-ports-intro = A .debug file in the extension folder opens a Chrome DevTools port for each panel and host app; with PlayerDebugMode enabled, browse to localhost:<port> while the panel is open.
+debug-ports-extension = Extension
+debug-ports-choose = Choose an extension...
+debug-ports-no-targets = The manifest lists no extensions or host apps to generate ports for.
+debug-ports-none = No debug ports yet.
+debug-ports-generate = Generate ports
+debug-ports-save = Save .debug file
+debug-ports-remove-file = Remove .debug file
+debug-ports-remove-entry = Remove
+debug-ports-conflicts = Port conflicts
+debug-ports-conflict = Port { $port } is used by:
+
+## Overview
+overview-total-plugins = Extensions installed
+overview-total-size = Used on disk
+overview-largest = Largest extensions
+overview-show-plugin = Show in the plugin list
+overview-by-host = By host app
+overview-by-vendor = By vendor
+overview-plugins = { $count } extensions
+overview-unknown = Unknown
+overview-nothing = No extensions installed
+
+## Install validation
+validation-title = Problems found in this ZXP
+validation-body = { $file } has { $errors } errors and { $warnings } warnings. The panel may not load if you install it.
+validation-install-anyway = Install anyway
+validation-cancel = Cancel
+validation-more = ...and { $count } more
+validation-unreadable-entry = Archive entry { $index } cannot be read
+validation-unsigned = The package is not signed
+validation-manifest-missing = CSXS/manifest.xml is missing
+validation-manifest-invalid = CSXS/manifest.xml cannot be parsed
+validation-no-extensions = The manifest declares no extensions
+validation-main-undeclared = { $extension } has no MainPath
+validation-path-missing = { $extension }: { $path } is not in the package
+validation-path-outside = { $extension }: { $path } points outside the extension folder
+validation-absolute-path = { $extension }: { $path } is an absolute path
+validation-absolute-entry = Entry with an absolute path: { $path }
+validation-traversal-entry = Entry escaping the extension folder: { $path }
+validation-symlink = Symbolic link: { $path }
+validation-executable = Executable file: { $path }
+validation-compression = Unusually high compression, possibly a zip bomb: { $path }
+
+## Script scan
+scan-title = Risky code found in this extension
+scan-body = { $file }: { $scripts } scripts scanned, { $high } high-risk and { $medium } medium-risk findings. These are text matches - read the lines before trusting or rejecting the panel.
+scan-finding = { $risk } - { $location } ({ $count }x)
+scan-risk-eval = Evaluates code built at runtime
+scan-risk-remote-eval = Evaluates code in a script that downloads data
+scan-risk-shell = Runs shell commands
+scan-risk-obfuscated = Obfuscated or encoded code
+scan-risk-raw-ip = Network call to a raw IP address
+scan-risk-compiled = Compiled ExtendScript that cannot be read
+toast-scan-clean = No red flags in { $count } scripts
+
+## Administrator rights
+elevation-install-title = Administrator rights needed
+elevation-install-body = Installing for all users writes to /Library/Application Support/Adobe/CEP/extensions, which needs an administrator password. You can install for your account only instead.
+elevation-install-admin = Install as administrator
+elevation-install-user = Install for me only
+elevation-cancel = Cancel
+elevation-remove-title = Administrator rights needed
+elevation-remove-body = This plugin belongs to another user or to the system. Remove it with administrator rights?
+permission-install-body = Your account can't write to { $path }, so the install would fail partway through. Choose another way to install, or cancel.
+permission-install-missing-body = The all-users extensions folder { $root } doesn't exist yet, and your account can't create it in { $path }. Choose another way to install, or cancel.
+permission-remove-body = Your account can't delete from { $path }, so the removal would fail partway through. Choose another way to remove it, or cancel.
+permission-remove-admin = Remove as administrator
+permission-open-folder = Open folder
+toast-permission-folder-opened = Change the permissions of { $path } so your account can write to it, then try again.
+
+## Running host apps
+running-hosts-title = Adobe apps are running
+running-hosts-install-body = { $apps } may load { $name } only half-way while it is being installed. Quit the apps first, or continue and restart them afterwards.
+running-hosts-remove-body = { $apps } may still have { $name } loaded. Quit the apps first, or continue and restart them afterwards.
+running-hosts-settings-body = { $apps } may still have { $name } open, and it would overwrite the restored settings. Quit the apps first, or continue anyway.
+running-hosts-continue = Continue anyway
+running-hosts-cancel = Cancel
+toast-restart-hosts = Restart { $apps } to load the change
+confirm-cep-restart-title = Restart CEP?
+confirm-cep-restart-body = { $count } CEP engine processes are running: { $apps }. Open panels close and load again when they are reopened. Unsaved panel state is lost.
+cep-engine-other-host = other apps
+toast-cep-none-running = No CEP engine is running
+toast-cep-restarted = Stopped { $count } CEP engine processes: { $apps }. Reopen the panels to load them again.
+toast-cep-restart-partial = Stopped { $count } of { $total } CEP engine processes - the rest may belong to another user
+
+## Crash reports
+crash-report-title = ZXP Manager quit unexpectedly
+crash-report-body = A crash report was saved. Attaching it to a bug report helps get the problem fixed.
+crash-report-open = Open report
+crash-report-copy = Copy report
+crash-report-close = Close
+crash-report-item = Crash report
+
+## Deep clean
+confirm-deep-clean-title = Remove with settings and cache
+confirm-deep-clean-body = Remove { $name } together with everything it left outside its folder? Saved panel settings cannot be restored afterwards unless you backed them up.
+confirm-deep-clean-found = Also deleted: { $count } entries, { $size }
+confirm-deep-clean-more = ...and { $count } more
+confirm-deep-clean-none = No settings, cache or logs were found outside the extension folder - only the folder is deleted.
+toast-leftovers-removed = Removed { $count } settings, cache and log entries, { $size } freed
+
+## Downgrade
+confirm-downgrade-title = Install an older version?
+confirm-downgrade-body = { $name } { $installed } is installed. Replace it with the older { $version }?
+
+## ZXP inspector
+inspector-title = Contents of { $file }
+inspector-name = Name
+inspector-bundle-id = Bundle ID
+inspector-hosts = Host apps
+inspector-extensions = Extensions
+inspector-signature = Signature
+inspector-contents = Contents
+inspector-none = None listed
+inspector-signed-by = Signed by { $signer }
+inspector-signer-unreadable = Signed, but the certificate could not be read
+inspector-unsigned = Not signed - the publisher can't be verified
+inspector-totals = { $count } files, { $size } unpacked ({ $compressed } in the archive)
+inspector-blocked = The install policy would refuse this package: { $reason }
+inspector-unsafe-entries = { $count } entries point outside the extension folder - installing would be refused
+inspector-link = link
+inspector-executable = executable
+inspector-folder-files = { $count } files
+inspector-close = Close
+
+## Compare with installed
+compare-button = Compare
+compare-hint = Show which files this ZXP adds, changes or removes compared to the installed copy
+compare-title = Installed { $installed } compared with { $incoming }
+compare-installed-at = Installed copy: { $path }
+compare-summary = { $added } added, { $changed } changed, { $removed } only in the installed copy, { $unchanged } unchanged
+compare-identical = Every file is identical - installing changes nothing.
+compare-only-signature = Only the signature differs - the extension's own files are the same.
+compare-added = Added
+compare-changed = Changed
+compare-removed = Removed
+
+## Install preview
+install-preview-title = Inspect { $file }
+install-preview-destination = Destination: { $path }
+install-preview-totals = Files: { $count }, { $size } extracted
+install-preview-overwrites = Existing files replaced: { $count }
+install-preview-existing-folder = The destination folder already exists; no files in it are replaced
+install-preview-replaced = replaced
+install-preview-queued = Waiting for inspection: { $count }
+install-preview-copy = Copy
+install-preview-hash-failed = The checksum could not be computed
+install-preview-expected-placeholder = Paste the published SHA-256 to verify
+install-preview-expected-invalid = Not a SHA-256 checksum
+install-preview-hash-match = Matches
+install-preview-hash-mismatch = Does not match - this is not the published file
+install-preview-cancel = Cancel
+install-preview-install = Install
+
+
+## Session restore
+resume-installs-title = Resume unfinished installs?
+resume-installs-body = Installs still queued when ZXP Manager last closed: { $count }
+resume-installs-more = ...and { $count } more
+resume-installs-resume = Resume
+resume-installs-discard = Discard
+
+## Install dates
+age-today = Today
+age-yesterday = Yesterday
+age-days = { $count } days ago
+age-weeks = { $count } weeks ago
+age-months = { $count } months ago
+age-year = A year ago
+age-years = { $count } years ago
+
+## Tray
+tray-plugin-count = { $count } plugins installed
+tray-scanning = Scanning...
+tray-install = Install ZXP...
+tray-rescan = Rescan
+tray-show = Open ZXP Manager
+tray-quit = Quit
+notification-update-available = ZXP Manager { $version } is available
+
+## Command palette
+palette-placeholder = Type a command or plugin name...
+palette-no-matches = No matching commands or plugins
+palette-hint = Up/Down to choose, Enter to run, Esc to close
+palette-install = Install a ZXP file...
+palette-install-folder = Install an extension folder...
+palette-inspect-zxp = Inspect a ZXP file without installing...
+palette-rescan = Rescan plugins
+palette-export-list = Export the plugin list...
+palette-export-diagnostics = Export diagnostics
+palette-toggle-debug-mode = Toggle PlayerDebugMode for all CSXS versions
+palette-restart-cep = Restart CEP engines
+palette-show-shortcuts = Show keyboard shortcuts
+palette-go-to = Go to { $view }
+
+## Error codes
+error-codes-intro = Error messages end with a code like (ZXPM-1044). Quote it when contacting a plugin vendor or filing a bug.
+error-code-1001 = Extensions folder not found - nothing is installed for this user yet, or the CEP folder was moved
+error-code-1002 = Permission denied while scanning - check ownership of the CEP extensions folder
+error-code-1003 = Plugin folder has no CSXS/manifest.xml - the package is incomplete or not a CEP extension
+error-code-1004 = manifest.xml could not be parsed - the vendor shipped malformed XML
+error-code-1040 = A file dialog was cancelled - never shown as an error
+error-code-1041 = The chosen file does not end in .zxp
+error-code-1042 = The file or plugin folder no longer exists at the given path
+error-code-1043 = Permission denied while installing or removing - the folder belongs to another user or an admin
+error-code-1044 = The file is not a valid ZIP archive - the download is corrupt or incomplete
+error-code-1045 = Extraction failed part way - usually a full disk or an unwritable extensions folder
+error-code-1046 = Creating a ZXP archive failed - check the export destination
+error-code-1047 = The system file manager, default app or browser could not be opened
+error-code-1048 = A plugin with the same folder name is already installed - remove it first
+error-code-1049 = The folder has no readable plugin-set.json
+error-code-1050 = Not enough free disk space for the install, import or backup
+error-code-1051 = The admin policy does not permit this plugin or its publisher
+error-code-1052 = ZXPSignCmd could not be found or started
+error-code-1053 = ZXPSignCmd reported an error - wrong certificate password, unreachable timestamp server or an invalid folder
+error-code-1054 = Files written during an install did not match the package - a failing disk, a full volume or antivirus software changing files; retrying usually fixes it
+error-code-1055 = The system keychain or credential manager refused to read or store a license key - it may be locked, or access was denied
+
+## Errors
+error-dialog-cancelled = File dialog was cancelled
+error-invalid-extension = { $path } is not a ZXP file or CEP extension
+error-file-not-found = Not found: { $path }
+error-permission-denied = Permission denied writing { $path }
+error-permission-denied-reading = Permission denied reading { $path }
+error-hint-user-folder = try installing to the user folder
+error-invalid-zip = { $path } is not a valid ZXP archive - the download may be corrupt or incomplete
+error-extract-failed = Failed to write { $path }: { $error }
+error-directory-not-found = Folder not found: { $path }
+error-manifest-not-found = Manifest not found: { $path }
+error-invalid-manifest = Invalid manifest: { $reason }
+error-archive-failed = Failed to write archive { $path }: { $error }
+error-open-failed = Couldn't open { $target }
+error-clipboard-failed = Could not access the clipboard
+error-no-log-folder = No log folder is available on this system
+error-already-installed = { $path } already exists - remove it first
+error-invalid-plugin-set = { $path } does not contain a valid plugin set
+error-insufficient-space = Not enough disk space: { $required } needed, { $available } available
+error-blocked-by-policy = Blocked by policy: { $reason }
+error-signing-tool-missing = ZXPSignCmd was not found
+error-signing-failed = ZXPSignCmd failed: { $output }
+error-corrupted-install = Installation corrupted, retry: { $count } files in { $path } don't match the package ({ $files })
+error-keychain-failed = Could not access the keychain: { $error }
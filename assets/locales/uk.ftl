@@ -0,0 +1,769 @@
+# Ukrainian locale for ZXP Manager.
+
+## Sidebar
+drop-zone-title = Перетягніть ZXP-файли або теки розширень сюди
+drop-zone-subtitle = або натисніть, щоб вибрати
+drop-zone-unavailable = Перетягування тут недоступне - виберіть файл або вставте шлях
+paste-path-placeholder = Вставте шлях до .zxp і натисніть Enter
+browse-files = Вибрати файли
+open-inventory = Відкрити теку як інвентар...
+export-plugin-list = Експортувати список плагінів...
+link-dev-folder = Підключити теку розробки...
+link-dev-folder-hint = Завантажувати розширення, над яким ви працюєте, прямо з його теки - без пакування, зміни видно після перезавантаження панелі
+install-folder = Встановити теку...
+install-folder-hint = Встановити нерозпаковану теку розширення з тими ж перевірками, що й ZXP - копія потрапить у теку розширень
+inspect-zxp = Відкрити ZXP...
+inspect-zxp-hint = Переглянути файли, маніфест і підпис ZXP без встановлення і без запису на диск
+backup-all = Зберегти всі
+backup-all-hint = Експортувати всі сторонні плагіни як ZXP-файли з маніфестом для перенесення на інший комп'ютер
+restore-set = Відновити набір
+restore-set-hint = Встановити збережений набір плагінів з його теки
+backup-all-title = Виберіть, куди зберегти набір плагінів
+restore-set-title = Виберіть теку набору плагінів
+recent-installs = Нещодавні
+recent-clear = Очистити
+install-queue-title = Черга встановлення
+install-queue-clear = Прибрати завершені
+install-queue-remove = Прибрати з черги
+install-queue-waiting = Очікує
+install-queue-extracting = Розпакування
+install-queue-extracting-progress = Розпакування { $done }/{ $total }
+install-queue-done = Готово
+install-queue-failed = Помилка
+setting-cep-path = Шлях до розширень CEP
+setting-user-path = Шлях до розширень користувача
+setting-language = Мова
+setting-theme = Тема
+theme-dark = Темна
+theme-high-contrast = Висока контрастність
+setting-motion = Анімації
+motion-system = Як у системі
+motion-reduced = Зменшені
+motion-full = Завжди увімкнені
+setting-log-level = Рівень журналу
+setting-cep-preferences = Налаштування CEP (перезапустіть програми, щоб застосувати)
+setting-double-click = Дія подвійного кліку
+setting-quick-actions = Швидкі дії в рядку
+setting-columns = Стовпці списку плагінів
+setting-toast-position = Розташування сповіщень
+toast-position-status-bar = Над рядком стану
+toast-position-top-right = Правий верхній кут
+toast-position-top-center = Зверху по центру
+setting-toast-duration = Тривалість сповіщень
+toast-type-success = Успіх
+toast-type-info = Інформація
+toast-type-error = Помилка
+toast-duration-seconds = { $seconds } с
+toast-duration-sticky = До закриття
+setting-check-updates = Перевіряти оновлення під час запуску
+setting-telemetry = Надсилати анонімну статистику використання
+setting-telemetry-hint = Раз за запуск: скільки разів програму запускали та скільки встановлень вдалося чи не вдалося, а також версія програми, ОС і архітектура. Без назв плагінів, шляхів чи ідентифікаторів.
+setting-telemetry-preview = Показати, що надсилається
+setting-telemetry-preview-hide = Сховати попередній перегляд
+setting-inspect-before-install = Переглядати перед встановленням
+setting-scan-scripts = Перевіряти скрипти перед встановленням
+setting-scan-scripts-hint = Шукати в JS і JSX панелі eval, виклики командного рядка, обфускований код і звернення до IP-адрес напряму та показувати підсумок ризиків перед встановленням
+setting-clear-quarantine = Знімати карантинні позначки macOS після встановлення
+setting-clear-quarantine-hint = Завантажені пакети можуть передавати позначку карантину своїм файлам, через що вбудовані програми та помічники Node не запускаються
+setting-tray-icon = Показувати значок у треї
+setting-tray-icon-hint = Встановлюйте й пересканьовуйте з меню в треї. З наступного запуску закриття вікна залишає програму працювати в треї.
+setting-system-notifications = Системні сповіщення у фоні
+setting-system-notifications-hint = Коли встановлення, пакетні завдання чи перевірка оновлень завершуються, поки вікно у фоні, система теж показує сповіщення
+
+## Plugins panel
+column-plugin = Плагін
+column-bundle-id = ID пакета
+column-version = Версія
+column-size = Розмір
+column-installed = Встановлено
+size-on-disk = На диску: { $size }
+column-remove = Видалити
+column-import = Імпорт
+badge-native = вбудований
+badge-installed = встановлений
+badge-dev = розробка
+badge-dev-hint = Підключена робоча тека - видалення прибирає лише посилання
+badge-legacy = застаріле
+badge-legacy-hint = Залишене Extension Manager або ServiceManager часів CS - сучасні програми його не завантажують. Імпорт копіює його до теки розширень CEP.
+badge-missing-host = немає програми
+badge-missing-host-hint = Призначено для { $hosts }, але на цьому компʼютері не встановлено
+badge-runtime = потрібен CEP { $required }
+badge-runtime-hint = Потрібен CEP { $required }, а { $host } містить лише CEP { $available } - панель не завантажиться
+badge-duplicate = Дублікат
+badge-duplicate-hint = Копій: { $count } - програми завантажують лише одну копію, а панель може з'являтися двічі
+badge-background = Фоновий
+badge-background-hint = Усі розширення цього пакета невидимі - вони працюють у фоні й ніколи не з'являються в меню Window > Extensions
+duplicates-banner = Плагінів, встановлених у кількох теках: { $count }.
+duplicates-resolve = Видалити дублікати, лишити найновіші
+missing-roots-banner = Цих тек розширень ще немає, тож хост-програмам нічого з них завантажувати:
+missing-roots-create = Створити зараз
+missing-roots-dismiss = Не зараз
+missing-roots-admin-hint = Для теки всіх користувачів потрібні права адміністратора
+loading-plugins = Завантаження плагінів...
+disabled-plugins-title = Вимкнені: { $count }
+broken-extensions-title = Пошкоджені: { $count }
+broken-extensions-hint = Теки в теках розширень, які не вдається прочитати як розширення. Програми можуть на них спотикатися.
+broken-extension-open = Відкрити теку
+
+## Status bar
+status-plugins-installed = Встановлено плагінів: { $count }
+status-plugins-cached = Встановлено плагінів: { $count } (з кешу, сканування...)
+status-loading = Завантаження...
+status-disk-usage = { $size } на диску
+status-scan-progress = Сканування: оброблено { $processed }/{ $total } тек
+status-scan-cancel = Скасувати
+status-scan-cancel-hint = Зупинити сканування й залишити попередній список
+update-available = Доступне оновлення: v{ $version }
+
+## Messages
+toast-plugin-installed = Встановлено { $name } { $version }
+toast-plugin-disabled = { $name } вимкнено - програми більше його не завантажують
+toast-plugin-enabled = { $name } увімкнено - перезапустіть програми, щоб завантажити його
+toast-disable-failed = Не вдалося вимкнути { $name }: { $error }
+toast-enable-failed = Не вдалося увімкнути { $name }: { $error }
+toast-plugin-removed = Плагін успішно видалено!
+toast-install-failed = Помилка встановлення: { $error }
+toast-remove-failed = Не вдалося видалити плагін: { $error }
+toast-dismiss = Закрити
+toast-dismiss-all = Закрити всі
+toast-paste-nothing = У буфері обміну немає файлу чи шляху .zxp
+toast-paste-url-unsupported = Вебпосилання не можна встановити напряму - спершу завантажте .zxp
+# An error whose own text is already translated
+toast-error = { $error }
+
+## Scan changes
+scan-changes-detected = Розширення змінено ззовні: { $summary }
+scan-added = додано: { $count }
+scan-removed = видалено: { $count }
+scan-version-changed = змінено версію: { $count }
+scan-cancelled = Сканування скасовано - показано попередній результат
+rescan = Пересканувати розширення
+
+## Navigation
+nav-plugins = Плагіни
+nav-overview = Огляд
+nav-diagnostics = Діагностика
+nav-compliance = Відповідність
+nav-troubleshoot = Діагностика проблем
+nav-signing = Підпис і пакування
+nav-release = Підготовка релізу
+nav-debug-ports = Віддалене налагодження
+nav-logs = Журнал
+nav-error-codes = Коди помилок
+
+## Diagnostics
+diagnostics-intro = Що завантажать програми Adobe: розширення з усіх тек, яка копія перемагає при збігу ID, і підсумкове меню Window > Extensions.
+diagnostics-export = Експортувати діагностику
+diagnostics-export-hint = Зберігає журнал програми, список плагінів, налаштування CSXS, програми-хости й версію ОС в одному ZIP, щоб додати його до звіту про помилку.
+diagnostics-menu-title = Меню Window > Extensions
+diagnostics-menu-empty = Жодне розширення не додає пункт меню.
+diagnostics-duplicate-menu = Зустрічається в меню { $count } рази
+diagnostics-collisions-title = Збіги ID
+diagnostics-collisions-empty = Жоден ID розширення не встановлено двічі.
+diagnostics-wins = Завантажена копія:
+diagnostics-ambiguous = Та сама тека - завантажена копія залежить від порядку файлів:
+diagnostics-ignored = Ігноровані копії:
+diagnostics-hidden-title = Без пункту меню
+diagnostics-hidden-empty = Кожне завантажене розширення має пункт меню.
+diagnostics-hidden-reason = Немає назви меню для типу UI { $type } - невидиме або відкривається іншою панеллю
+diagnostics-hosts-title = Встановлені програми Adobe
+diagnostics-hosts-empty = Не знайдено жодної програми Adobe.
+diagnostics-host-reset = Скинути кеш розширень ({ $count })
+diagnostics-host-reset-hint = Видаляє кеш CEP і журнали розширень цієї програми. Інших програм це не стосується.
+diagnostics-debug-launch = Запуск для налагодження
+diagnostics-debug-launch-hint = Запускає застосунок із заводськими налаштуваннями в тимчасовій пісочниці та докладним журналом CEP. Ваші справжні налаштування не змінюються.
+diagnostics-restart-cep = Перезапустити CEP
+diagnostics-restart-cep-hint = Завершує процеси рушія CEP, у яких працюють панелі. Порожні чи завислі панелі знову завантажаться після повторного відкриття.
+diagnostics-orphans-title = Дані видалених розширень
+diagnostics-orphans-empty = Усі записи кешу, журналів і сховища CEP належать встановленим розширенням.
+diagnostics-orphans-hint = Ці розширення вже не встановлено, але їхній кеш, журнали CEP і збережені налаштування лишилися. Зніміть позначку з того, що хочете зберегти.
+diagnostics-orphans-entries = Записів: { $count }
+diagnostics-orphans-clean = Очистити вибране ({ $size })
+root-system = системне
+root-user = користувача
+
+## Shortcuts
+search-placeholder = Пошук плагінів
+host-filter-all = Усі програми
+tag-filter-all = Усі теги
+favorite-add = Закріпити вгорі
+favorite-remove = Відкріпити
+expanded-expand = Показати більше
+expanded-collapse = Показати менше
+expanded-hosts = Програми
+group-by-vendor = Групувати за розробником
+vendor-unknown = Без розробника
+vendor-subtotal = { $count } · { $size }
+shortcut-sheet-title = Клавіатурні скорочення
+shortcut-sheet-hint = Натисніть ? або Esc, щоб закрити
+shortcut-open-picker = Встановити файл ZXP
+shortcut-rescan = Пересканувати плагіни
+shortcut-remove-selected = Видалити вибраний плагін
+shortcut-focus-search = Пошук плагінів
+shortcut-paste-install = Встановити ZXP з буфера обміну
+shortcut-show-shortcuts = Показати цей список
+shortcut-command-palette = Відкрити палітру команд
+confirm-remove-title = Видалити плагін
+confirm-remove-body = Видалити { $name }? Його теку буде видалено з диска.
+confirm-host-reset-title = Скинути кеш розширень { $app }?
+confirm-host-reset-body = Спершу закрийте { $app } - запущена програма продовжує використовувати й перезаписувати кеш. Панелі втратять збережені локально дані, як-от входи й налаштування, і відновлять кеш під час наступного запуску.
+
+## Context menu
+menu-reveal-finder = Показати у Finder
+menu-reveal-explorer = Показати в Провіднику
+menu-copy-bundle-id = Копіювати ID пакета
+menu-copy-version = Копіювати версію
+menu-copy-path = Копіювати шлях
+menu-open-manifest = Відкрити manifest.xml
+menu-export-zxp = Експортувати як ZXP...
+menu-resolve-duplicate = Видалити дублікат, лишити найновішу версію
+menu-remove = Видалити
+menu-disable = Вимкнути
+menu-enable = Увімкнути
+menu-remove-as-admin = Видалити як адміністратор...
+menu-remove-deep-clean = Видалити з налаштуваннями та кешем...
+menu-import = Імпортувати
+menu-unlink-dev = Відʼєднати теку розробки
+item-bundle-id = ID пакета
+item-version = Версія
+item-path = Шлях
+toast-copied = { $item } скопійовано в буфер обміну
+toast-plugin-exported = Плагін успішно експортовано!
+toast-export-failed = Помилка експорту: { $error }
+toast-plugin-list-exported = Експортовано список плагінів: { $count }
+toast-compliance-exported = Звіт про відповідність експортовано
+toast-diagnostics-exported = Діагностику збережено в { $path }
+toast-history-exported = Експортовано записів історії: { $count }
+toast-backup-done = Збережено плагінів: { $count }
+toast-backup-failed = Помилка резервного копіювання: { $error }
+toast-restore-done = Відновлено плагінів: { $installed }, уже встановлено: { $skipped }.
+toast-restore-partial = Відновлено плагінів: { $installed }, уже встановлено: { $skipped }. Не вдалося: { $names }
+toast-restore-failed = Помилка відновлення: { $error }
+
+## Details drawer
+menu-details = Деталі
+details-close = Закрити
+details-copy = Копіювати
+details-bundle-id = ID пакета
+details-size-on-disk = Розмір на диску
+details-extensions = Розширення
+details-ui-type = Тип UI
+ui-kind-panel = Панель
+ui-kind-modal-dialog = Модальне вікно
+ui-kind-modeless = Немодальне вікно
+ui-kind-invisible = Невидиме - працює у фоні
+details-menu = Назва в меню
+details-main-path = Головний файл
+details-script-path = Файл скрипту
+details-auto-visible = Відкривається автоматично
+details-start-on = Запускається за подіями
+details-cef-parameters = Командний рядок CEF
+details-none = Немає
+details-yes = Так
+details-no = Ні
+details-notes = Нотатки й теги
+details-notes-placeholder = Нотатки для себе, наприклад, де лежить ліцензійний ключ
+license-key-title = Ліцензійний ключ
+license-key-hint = Зберігається в системній зв'язці ключів, а не у файлах програми.
+license-key-placeholder = Вставте ліцензійний ключ
+license-key-add = Додати ліцензійний ключ
+license-key-save = Зберегти
+license-key-cancel = Скасувати
+license-key-show = Показати
+license-key-hide = Сховати
+license-key-edit = Змінити
+license-key-remove = Видалити
+item-license-key = Ліцензійний ключ
+panel-settings-title = Налаштування панелі
+panel-settings-hint = Налаштування, які панель зберігає поза своєю текою (веб-сховище в кеші CEP, її тека Application Support). Створіть резервну копію перед перевстановленням.
+panel-settings-backed-up = Резервна копія від { $date }
+panel-settings-backup = Зберегти налаштування
+panel-settings-restore = Відновити налаштування
+details-tag-placeholder = Додайте тег і натисніть Enter
+details-tag-remove = Прибрати тег
+manifest-edit-title = Маніфест
+manifest-edit-open = Редагувати маніфест...
+manifest-edit-restore = Відновити оригінал
+manifest-edit-restore-hint = Повернути маніфест у стан до першого редагування
+manifest-edit-signed = Цей пакет підписано. Редагування маніфесту порушує підпис, тож програми завантажать його лише з увімкненим PlayerDebugMode.
+manifest-edit-name = Назва
+manifest-edit-host = Версії { $host }
+manifest-edit-any-version = Будь-яка версія
+manifest-edit-range-hint = Версія, наприклад 24.0, або діапазон, наприклад [22.0,99.9]. Ширший діапазон дозволяє старішій панелі завантажуватися в новішій програмі.
+manifest-edit-save = Зберегти маніфест
+manifest-edit-cancel = Скасувати
+manifest-edit-name-empty = Назва не може бути порожньою
+manifest-edit-bad-version = "{ $value }" - не версія на кшталт 1.2.0
+manifest-edit-bad-range = "{ $value }" - не версія і не діапазон на кшталт [22.0,99.9]
+toast-manifest-saved = Маніфест збережено - оригінал залишено як manifest.xml.original
+toast-manifest-restored = Оригінальний маніфест відновлено
+toast-license-saved = Ліцензійний ключ збережено у зв'язці ключів
+toast-license-removed = Ліцензійний ключ видалено
+toast-license-failed = Не вдалося зберегти ліцензійний ключ: { $error }
+toast-settings-nothing = { $name } не зберігає налаштувань поза своєю текою
+toast-settings-backed-up = Збережено місць з налаштуваннями { $name }: { $count }
+toast-settings-backup-failed = Не вдалося зберегти налаштування: { $error }
+toast-settings-restored = Відновлено місць з налаштуваннями { $name }: { $count }
+toast-settings-restore-failed = Не вдалося відновити налаштування: { $error }
+confirm-settings-restore-title = Відновити налаштування панелі?
+confirm-settings-restore-body = Поточні налаштування { $name } буде замінено резервною копією від { $date }.
+toast-manifest-save-failed = Не вдалося оновити маніфест: { $error }
+version-history-title = Попередні версії
+version-history-none = Ще немає збережених версій - оновлення зберігають версію, яку замінюють
+version-history-revert-previous = Повернути попередню версію
+version-history-revert = Повернути цю версію
+toast-version-reverted = { $name } повернуто до версії { $version }
+toast-version-revert-failed = Не вдалося повернути { $name }: { $error }
+cef-enable-nodejs = У панелі доступні API Node.js
+cef-mixed-context = Node.js і браузер мають спільний контекст JavaScript
+cef-remote-debugging = Відкрито порт віддаленого налагодження
+cef-relaxed-security = Обмеження безпеки браузера послаблено
+cef-media-stream = Дозволено доступ до камери та мікрофона
+cef-no-app-cache = Кеш застосунку вимкнено
+
+## Maintenance
+setting-maintenance = Планове обслуговування
+maintenance-enabled = Щодня, поки застосунок відкритий
+maintenance-task-backup = Резервна копія встановлених плагінів
+maintenance-task-cache-cleanup = Очистити кеш CEP
+maintenance-backup-done = Планова резервна копія зберегла плагінів: { $count }
+maintenance-cache-cleanup-done = Планове очищення видалило записів кешу: { $count }
+maintenance-task-failed = Помилка планового обслуговування: { $error }
+setting-hot-folder = Тека автовстановлення
+hot-folder-enabled = Встановлювати ZXP, що зʼявляються в цій теці
+hot-folder-choose = Вибрати теку...
+hot-folder-hint = Кожен .zxp, що зʼявиться в теці, перевіряється і встановлюється без запитань, наприклад із синхронізованої теки студії
+hot-folder-archive = Потім переносити в "Installed"
+hot-folder-delete = Потім видаляти
+hot-folder-installed = Тека автовстановлення: встановлено { $names }
+hot-folder-failed = Тека автовстановлення: не вдалося встановити { $names } - перенесено в "{ $folder }"
+
+## Inventory
+inventory-browsing = Перегляд лише для читання, плагінів: { $count }. Тека:
+inventory-close = Назад до встановлених
+toast-inventory-opened = У теці знайдено плагінів: { $count }
+toast-inventory-failed = Не вдалося відкрити теку: { $error }
+toast-plugin-imported = Плагін успішно імпортовано!
+toast-dev-linked = Теку розробки підключено - перезавантажте панель у програмі, щоб побачити зміни
+toast-dev-link-failed = Не вдалося підключити теку: { $error }
+toast-dev-unlinked = Теку розробки відʼєднано, ваші файли не змінено
+toast-dev-unlink-failed = Не вдалося відʼєднати теку: { $error }
+toast-import-failed = Помилка імпорту: { $error }
+toast-fix-failed = Не вдалося виправити: { $error }
+toast-host-reset-done = Кеш розширень { $app } скинуто (видалено записів: { $count })
+toast-host-reset-failed = Не вдалося скинути кеш: { $error }
+toast-debug-launch-started = { $app } запущено з чистою пісочницею налаштувань і журналом налагодження CEP: { $path }
+toast-debug-launch-started-plain = { $app } запущено з чистою пісочницею налаштувань: { $path }. Рівень журналу CEP не змінено - у Windows це параметр реєстру, який пісочниця не може відокремити
+toast-debug-launch-failed = Не вдалося запустити { $app } для налагодження: { $error }
+toast-orphans-found = Видалені розширення залишили записів кешу й сховища: { $count } ({ $size }) - їх можна очистити в Діагностиці
+toast-orphans-removed = Видалено залишених записів: { $count }, звільнено { $size }
+toast-duplicates-removed = Видалено дублікатів: { $count }
+toast-duplicate-failed = Не вдалося видалити { $path }: { $error }
+toast-root-created = Створено { $path }
+toast-root-create-failed = Не вдалося створити { $path }: { $error }
+toast-certificate-created = Сертифікат створено
+toast-certificate-failed = Не вдалося створити сертифікат: { $error }
+toast-signed = Підписаний пакет збережено: { $path }
+toast-release-ready = Реліз збережено: { $path }
+toast-release-scaffolded = Маніфест створено: { $path }
+toast-release-failed = Не вдалося підготувати реліз: { $error }
+toast-debug-file-saved = Збережено портів налагодження: { $count }
+toast-debug-file-removed = Файл .debug видалено
+toast-debug-file-failed = Не вдалося оновити файл .debug: { $error }
+toast-cep-flag-saved = { $flag } оновлено для CSXS { $version }. Перезапустіть програму, щоб застосувати.
+toast-cep-flag-failed = Не вдалося встановити { $flag }: { $error }
+toast-debug-mode-on = PlayerDebugMode увімкнено для версій CSXS: { $count }. Перезапустіть програму, щоб застосувати.
+toast-debug-mode-off = PlayerDebugMode вимкнено для версій CSXS: { $count }. Перезапустіть програму, щоб застосувати.
+toast-sign-failed = Не вдалося підписати: { $error }
+
+## Activity
+activity-title = Активність
+activity-clear = Очистити
+activity-empty = Поки що нічого не відбулося
+activity-keep = Зберігати історію між сеансами
+activity-from = З
+activity-to = По
+activity-export = Експортувати...
+
+## Logs
+logs-level-all = Усі рівні
+logs-level-warn = Попередження та помилки
+logs-level-error = Лише помилки
+logs-search-placeholder = Фільтрувати рядки журналу...
+logs-empty = Немає відповідних рядків
+logs-open-folder = Відкрити теку журналів
+log-level-error = Помилки
+log-level-warn = Попередження
+log-level-info = Інформація
+log-level-debug = Налагодження (детально)
+cep-preferences-unsupported = Доступно лише на macOS і Windows
+cep-preferences-loading = Читання налаштувань...
+cep-preferences-none = Доменів налаштувань CSXS не знайдено
+cep-debug-mode = PlayerDebugMode (завантажувати непідписані розширення)
+cep-log-level = LogLevel
+cep-log-unset = Не задано
+cep-log-off = 0 - Вимкнено
+cep-log-error = 1 - Помилки
+cep-log-warn = 2 - Попередження
+cep-log-info = 3 - Інформація
+cep-log-debug = 4 - Налагодження
+cep-log-trace = 5 - Трасування
+cep-log-all = 6 - Усе
+
+## Compliance
+compliance-intro = Плагіни перевіряються за списками дозволу та заборони з { $path }. Встановлення заборонених плагінів блокується.
+compliance-no-policy = Політику не встановлено - усі плагіни дозволені.
+compliance-invalid-policy = Не вдалося прочитати файл політики, тому нічого не застосовується. Зверніться до адміністратора.
+compliance-all-allowed = Усі встановлені плагіни відповідають політиці.
+compliance-flagged = Позначено для видалення: { $count }
+compliance-required = Обовʼязкові, але відсутні чи застарілі: { $count }
+compliance-missing = Вимагається політикою, але не встановлено
+compliance-outdated = Встановлено { $installed }, політика вимагає { $required } або новішу
+compliance-export = Експортувати звіт...
+policy-denied-id = ID пакета збігається із записом списку заборони { $pattern }
+policy-denied-publisher = Підписано забороненим видавцем { $publisher }
+policy-not-allowed = Немає у списку дозволених
+
+## Troubleshooting
+troubleshoot-panel-title = Панель не з'являється в програмі
+troubleshoot-panel-intro = Покроково перевіряє все, що програма Adobe перевіряє, перш ніж показати панель у Window > Extensions.
+troubleshoot-install-title = Встановлення завершується помилкою доступу
+troubleshoot-install-intro = Перевіряє спільну теку розширень, у яку розпаковуються встановлення.
+troubleshoot-choose-plugin = Виберіть плагін...
+troubleshoot-run = Запустити перевірку
+troubleshoot-running = Перевірка...
+check-manifest = Маніфест
+check-manifest-pass = CSXS/manifest.xml на місці й читається.
+check-manifest-fail = Не вдається прочитати маніфест: { $error }
+check-hosts = Програма
+check-hosts-pass = Встановлено принаймні одну програму, для якої призначено плагін.
+check-hosts-missing = Призначено для { $hosts }, але на цьому компʼютері не встановлено.
+check-hosts-none-listed = У маніфесті не вказано жодної програми, тож жодна його не завантажить.
+check-runtime = Середовище CEP
+check-runtime-pass = Встановлені програми містять версію CEP, яку підтримує плагін.
+check-signature = Підпис
+check-signature-signed = Плагін встановлено з підписаного пакета.
+check-signature-debug-on = Без підпису, але режим налагодження дозволяє непідписані панелі.
+check-signature-debug-off = Без підпису, а режим налагодження вимкнено - програми мовчки відмовляються його завантажувати.
+check-duplicates = Дублікати ID
+check-duplicates-pass = Жодна інша копія не перекриває цей плагін.
+check-duplicates-shadowed = Замість нього завантажується інша копія з тим самим ID: { $path }
+check-menu = Пункт меню
+check-menu-pass = Плагін додає пункт у Window > Extensions.
+check-menu-hidden = Немає пункту меню - працює невидимо або відкривається іншою панеллю.
+check-cache = Кеш CEP
+check-cache-empty = Кеш CEP порожній.
+check-cache-present = Розширень у кеші: { $count } - застарілий кеш може показувати стару збірку.
+check-install-folder = Тека розширень
+check-install-folder-pass = Спільна тека розширень доступна для запису.
+check-install-folder-missing = { $path } ще не існує, і її не можна створити без прав адміністратора.
+check-install-folder-readonly = { $path } недоступна для запису вашому обліковому запису.
+check-plugin-owners = Теки плагінів
+check-plugin-owners-pass = Усі теки сторонніх плагінів належать вам.
+check-plugin-owners-warn = Тек плагінів, що належать іншому користувачу: { $count }, наприклад { $path } - їх оновлення не вдасться.
+check-free-space = Вільне місце
+check-free-space-pass = Вільно { $available }.
+check-free-space-warn = Вільно лише { $available } - більші плагіни не вмістяться.
+check-free-space-unknown = Не вдалося визначити вільне місце.
+check-policy = Політика адміністратора
+check-policy-missing = Жодна політика не обмежує встановлення.
+check-policy-loaded = Діє політика адміністратора - дивіться у «Відповідність», що вона блокує.
+check-policy-invalid = Файл політики не читається - зверніться до адміністратора.
+fix-enable-debug-mode = Увімкнути налагодження
+fix-clear-cache = Очистити кеш
+fix-remove-copy = Видалити іншу копію
+fix-take-ownership = Виправити доступ
+fix-debug-mode-done = Режим налагодження увімкнено - перезапустіть програму
+fix-cache-done = Кеш CEP очищено
+fix-copy-removed = Дублікат видалено
+fix-ownership-done = Доступ виправлено
+
+## Sign & package
+signing-intro = Пакує теку розширення в підписаний ZXP для тестування чи поширення. Використовує ZXPSignCmd від Adobe.
+signing-tool-found = ZXPSignCmd: { $path }
+signing-tool-missing = ZXPSignCmd не знайдено. Завантажте його з ресурсів CEP від Adobe і вкажіть тут.
+signing-locate-tool = Вказати ZXPSignCmd...
+signing-certificate-title = Новий самопідписаний сертифікат
+signing-country = Код країни
+signing-state = Область чи регіон
+signing-organization = Організація
+signing-common-name = Назва видавця
+signing-password = Пароль сертифіката
+signing-create-certificate = Створити сертифікат...
+signing-package-title = Пакування й підпис
+signing-source = Тека розширення
+signing-certificate = Сертифікат (.p12)
+signing-none-chosen = Нічого не вибрано
+signing-choose = Вибрати...
+signing-timestamp = Додати позначку часу, щоб пакет лишався дійсним після завершення строку сертифіката
+signing-tsa-url = Сервер позначок часу
+signing-sign = Підписати й запакувати...
+signing-working = Виконується...
+
+## Release wizard
+release-intro = Перетворює проєкт панелі CEP на пакет для розповсюдження: перевіряє проєкт, підписує його, пробно встановлює результат і записує поруч запис для каналу оновлень.
+release-project-title = Проєкт
+release-project = Тека проєкту
+release-no-manifest = У цій теці ще немає CSXS/manifest.xml. Введіть ідентифікатор і назву, щоб створити його.
+release-bundle-id = Ідентифікатор пакета
+release-name = Назва
+release-create-manifest = Створити маніфест
+release-signing-title = Підпис
+release-download-url = URL завантаження (тека, куди буде вивантажено ZXP)
+release-prepare = Підготувати реліз
+release-steps-title = Кроки
+release-step-validate = Перевірка
+release-step-sign = Підпис
+release-step-test-install = Пробне встановлення
+release-step-feed = Запис каналу
+release-step-running = Виконується...
+release-validate-errors = Перевірка знайшла помилок: { $count }
+release-validate-done = Пройдено, попереджень: { $count }
+release-test-install-done = Встановлено без помилок як версію { $version }
+release-feed-title = Запис каналу
+release-copy-feed = Копіювати
+
+## Віддалене налагодження
+debug-ports-intro = Файл .debug у теці розширення відкриває порт Chrome DevTools для кожної панелі й програми; з увімкненим PlayerDebugMode відкрийте localhost:<port>, поки панель відкрита.
+debug-ports-extension = Розширення
+debug-ports-choose = Оберіть розширення...
+debug-ports-no-targets = У маніфесті немає розширень чи програм, для яких можна створити порти.
+debug-ports-none = Портів налагодження ще немає.
+debug-ports-generate = Створити порти
+debug-ports-save = Зберегти файл .debug
+debug-ports-remove-file = Видалити файл .debug
+debug-ports-remove-entry = Видалити
+debug-ports-conflicts = Конфлікти портів
+debug-ports-conflict = Порт { $port } використовують:
+
+## Overview
+overview-total-plugins = Встановлено розширень
+overview-total-size = Займають на диску
+overview-largest = Найбільші розширення
+overview-show-plugin = Показати у списку плагінів
+overview-by-host = За програмою
+overview-by-vendor = За розробником
+overview-plugins = Розширень: { $count }
+overview-unknown = Невідомо
+overview-nothing = Немає встановлених розширень
+
+## Install validation
+validation-title = У цьому ZXP знайдено проблеми
+validation-body = { $file }: помилок - { $errors }, попереджень - { $warnings }. Після встановлення панель може не завантажитися.
+validation-install-anyway = Усе одно встановити
+validation-cancel = Скасувати
+validation-more = ...і ще { $count }
+validation-unreadable-entry = Не вдалося прочитати запис архіву { $index }
+validation-unsigned = Пакет не підписано
+validation-manifest-missing = Відсутній CSXS/manifest.xml
+validation-manifest-invalid = Не вдалося розібрати CSXS/manifest.xml
+validation-no-extensions = Маніфест не оголошує жодного розширення
+validation-main-undeclared = { $extension } не має MainPath
+validation-path-missing = { $extension }: { $path } немає в пакеті
+validation-path-outside = { $extension }: { $path } вказує за межі теки розширення
+validation-absolute-path = { $extension }: { $path } - абсолютний шлях
+validation-absolute-entry = Запис з абсолютним шляхом: { $path }
+validation-traversal-entry = Запис виходить за межі теки розширення: { $path }
+validation-symlink = Символічне посилання: { $path }
+validation-executable = Виконуваний файл: { $path }
+validation-compression = Надто високе стиснення, можливо zip-бомба: { $path }
+
+## Script scan
+scan-title = У цьому розширенні знайдено ризикований код
+scan-body = { $file }: перевірено скриптів - { $scripts }, високий ризик - { $high }, середній - { $medium }. Це текстові збіги - прочитайте рядки, перш ніж довіряти панелі чи відхиляти її.
+scan-finding = { $risk } - { $location } ({ $count }x)
+scan-risk-eval = Виконує код, складений під час роботи
+scan-risk-remote-eval = Виконує код у скрипті, що завантажує дані
+scan-risk-shell = Запускає команди оболонки
+scan-risk-obfuscated = Обфускований або закодований код
+scan-risk-raw-ip = Мережевий запит на IP-адресу напряму
+scan-risk-compiled = Скомпільований ExtendScript, який неможливо прочитати
+toast-scan-clean = Жодних підозрілих місць у скриптах: { $count }
+
+## Administrator rights
+elevation-install-title = Потрібні права адміністратора
+elevation-install-body = Встановлення для всіх користувачів записує в /Library/Application Support/Adobe/CEP/extensions, для чого потрібен пароль адміністратора. Натомість можна встановити лише для вашого облікового запису.
+elevation-install-admin = Встановити як адміністратор
+elevation-install-user = Встановити лише для мене
+elevation-cancel = Скасувати
+elevation-remove-title = Потрібні права адміністратора
+elevation-remove-body = Цей плагін належить іншому користувачеві або системі. Видалити його з правами адміністратора?
+permission-install-body = Ваш обліковий запис не може записувати в { $path }, тож встановлення перерветься на півдорозі. Оберіть інший спосіб встановлення або скасуйте.
+permission-install-missing-body = Теки розширень для всіх користувачів { $root } ще немає, і ваш обліковий запис не може створити її в { $path }. Оберіть інший спосіб встановлення або скасуйте.
+permission-remove-body = Ваш обліковий запис не може видаляти з { $path }, тож видалення перерветься на півдорозі. Оберіть інший спосіб видалення або скасуйте.
+permission-remove-admin = Видалити як адміністратор
+permission-open-folder = Відкрити теку
+toast-permission-folder-opened = Змініть дозволи { $path }, щоб ваш обліковий запис міг туди записувати, і спробуйте ще раз.
+
+## Running host apps
+running-hosts-title = Програми Adobe запущені
+running-hosts-install-body = { $apps } можуть завантажити { $name } лише частково під час встановлення. Спершу закрийте програми або продовжте й перезапустіть їх потім.
+running-hosts-remove-body = { $apps } можуть досі тримати { $name } завантаженим. Спершу закрийте програми або продовжте й перезапустіть їх потім.
+running-hosts-settings-body = { $apps } можуть досі тримати { $name } відкритою, і вона перезапише відновлені налаштування. Спершу закрийте програми або все одно продовжте.
+running-hosts-continue = Все одно продовжити
+running-hosts-cancel = Скасувати
+toast-restart-hosts = Перезапустіть { $apps }, щоб застосувати зміни
+confirm-cep-restart-title = Перезапустити CEP?
+confirm-cep-restart-body = Запущено процесів рушія CEP: { $count } ({ $apps }). Відкриті панелі закриються й завантажаться знову після повторного відкриття. Незбережений стан панелей буде втрачено.
+cep-engine-other-host = інші програми
+toast-cep-none-running = Жоден рушій CEP не запущено
+toast-cep-restarted = Зупинено процесів рушія CEP: { $count } ({ $apps }). Відкрийте панелі знову, щоб завантажити їх.
+toast-cep-restart-partial = Зупинено { $count } з { $total } процесів рушія CEP - решта може належати іншому користувачу
+
+## Crash reports
+crash-report-title = ZXP Manager несподівано завершив роботу
+crash-report-body = Звіт про збій збережено. Додайте його до звіту про помилку, щоб її швидше виправили.
+crash-report-open = Відкрити звіт
+crash-report-copy = Копіювати звіт
+crash-report-close = Закрити
+crash-report-item = Звіт про збій
+
+## Deep clean
+confirm-deep-clean-title = Видалити з налаштуваннями та кешем
+confirm-deep-clean-body = Видалити { $name } разом з усім, що лишилося поза його текою? Збережені налаштування панелі потім не відновити, якщо ви не створили резервну копію.
+confirm-deep-clean-found = Також буде видалено записів: { $count }, { $size }
+confirm-deep-clean-more = ...і ще { $count }
+confirm-deep-clean-none = Поза текою розширення не знайдено налаштувань, кешу чи журналів - буде видалено лише теку.
+toast-leftovers-removed = Видалено записів налаштувань, кешу та журналів: { $count }, звільнено { $size }
+
+## Downgrade
+confirm-downgrade-title = Встановити старішу версію?
+confirm-downgrade-body = Встановлено { $name } { $installed }. Замінити його старішою версією { $version }?
+
+## ZXP inspector
+inspector-title = Вміст { $file }
+inspector-name = Назва
+inspector-bundle-id = Bundle ID
+inspector-hosts = Програми
+inspector-extensions = Розширення
+inspector-signature = Підпис
+inspector-contents = Вміст
+inspector-none = Не вказано
+inspector-signed-by = Підписано: { $signer }
+inspector-signer-unreadable = Підписано, але сертифікат не вдалося прочитати
+inspector-unsigned = Не підписано - видавця неможливо перевірити
+inspector-totals = Файлів: { $count }, { $size } після розпакування ({ $compressed } в архіві)
+inspector-blocked = Політика встановлення відхилить цей пакет: { $reason }
+inspector-unsafe-entries = Записів, що вказують за межі теки розширення: { $count } - встановлення буде відхилено
+inspector-link = посилання
+inspector-executable = виконуваний
+inspector-folder-files = Файлів: { $count }
+inspector-close = Закрити
+
+## Compare with installed
+compare-button = Порівняти
+compare-hint = Показати, які файли цей ZXP додає, змінює чи прибирає порівняно зі встановленою копією
+compare-title = Встановлена { $installed } порівняно з { $incoming }
+compare-installed-at = Встановлена копія: { $path }
+compare-summary = Додано: { $added }, змінено: { $changed }, лише у встановленій копії: { $removed }, без змін: { $unchanged }
+compare-identical = Усі файли однакові - встановлення нічого не змінить.
+compare-only-signature = Відрізняється лише підпис - файли самого розширення ті самі.
+compare-added = Додано
+compare-changed = Змінено
+compare-removed = Прибрано
+
+## Install preview
+install-preview-title = Перегляд { $file }
+install-preview-destination = Призначення: { $path }
+install-preview-totals = Файлів: { $count }, { $size } після розпакування
+install-preview-overwrites = Буде замінено наявних файлів: { $count }
+install-preview-existing-folder = Тека призначення вже існує; жоден файл у ній не буде замінено
+install-preview-replaced = замінюється
+install-preview-queued = Очікують перегляду: { $count }
+install-preview-copy = Копіювати
+install-preview-hash-failed = Не вдалося обчислити контрольну суму
+install-preview-expected-placeholder = Вставте опубліковану SHA-256 для перевірки
+install-preview-expected-invalid = Це не контрольна сума SHA-256
+install-preview-hash-match = Збігається
+install-preview-hash-mismatch = Не збігається - це не опублікований файл
+install-preview-cancel = Скасувати
+install-preview-install = Встановити
+
+
+## Session restore
+resume-installs-title = Продовжити незавершені встановлення?
+resume-installs-body = Встановлень у черзі на момент закриття ZXP Manager: { $count }
+resume-installs-more = ...і ще { $count }
+resume-installs-resume = Продовжити
+resume-installs-discard = Відкинути
+
+## Install dates
+age-today = Сьогодні
+age-yesterday = Учора
+age-days = { $count } дн. тому
+age-weeks = { $count } тиж. тому
+age-months = { $count } міс. тому
+age-year = Рік тому
+age-years = { $count } р. тому
+
+## Tray
+tray-plugin-count = Встановлено плагінів: { $count }
+tray-scanning = Сканування...
+tray-install = Встановити ZXP...
+tray-rescan = Пересканувати
+tray-show = Відкрити ZXP Manager
+tray-quit = Вийти
+notification-update-available = Доступна версія ZXP Manager { $version }
+
+## Command palette
+palette-placeholder = Введіть команду або назву плагіна...
+palette-no-matches = Немає відповідних команд чи плагінів
+palette-hint = Вгору/Вниз - вибір, Enter - виконати, Esc - закрити
+palette-install = Встановити файл ZXP...
+palette-install-folder = Встановити теку розширення...
+palette-inspect-zxp = Переглянути ZXP без встановлення...
+palette-rescan = Пересканувати плагіни
+palette-export-list = Експортувати список плагінів...
+palette-export-diagnostics = Експортувати діагностику
+palette-toggle-debug-mode = Перемкнути PlayerDebugMode для всіх версій CSXS
+palette-restart-cep = Перезапустити рушії CEP
+palette-show-shortcuts = Показати клавіатурні скорочення
+palette-go-to = Перейти: { $view }
+
+## Error codes
+error-codes-intro = Повідомлення про помилки закінчуються кодом на зразок (ZXPM-1044). Вкажіть його, звертаючись до постачальника плагіна чи повідомляючи про ваду.
+error-code-1001 = Теку розширень не знайдено - для цього користувача ще нічого не встановлено або теку CEP переміщено
+error-code-1002 = Немає доступу під час сканування - перевірте власника теки розширень CEP
+error-code-1003 = У теці плагіна немає CSXS/manifest.xml - пакет неповний або це не розширення CEP
+error-code-1004 = Не вдалося розібрати manifest.xml - постачальник надав некоректний XML
+error-code-1040 = Діалог вибору файлу скасовано - ніколи не показується як помилка
+error-code-1041 = Вибраний файл не має розширення .zxp
+error-code-1042 = Файл або тека плагіна більше не існує за вказаним шляхом
+error-code-1043 = Немає доступу під час встановлення чи видалення - тека належить іншому користувачу або адміністратору
+error-code-1044 = Файл не є коректним ZIP-архівом - завантаження пошкоджене або неповне
+error-code-1045 = Розпакування перервалося - зазвичай через повний диск або недоступну для запису теку розширень
+error-code-1046 = Не вдалося створити ZXP-архів - перевірте місце збереження
+error-code-1047 = Не вдалося відкрити файловий менеджер, типову програму або браузер
+error-code-1048 = Плагін з такою самою назвою теки вже встановлено - спершу видаліть його
+error-code-1049 = У теці немає коректного plugin-set.json
+error-code-1050 = Недостатньо вільного місця для встановлення, імпорту чи резервної копії
+error-code-1051 = Політика адміністратора не дозволяє цей плагін або його видавця
+error-code-1052 = Не вдалося знайти або запустити ZXPSignCmd
+error-code-1053 = ZXPSignCmd повідомив про помилку - неправильний пароль сертифіката, недоступний сервер позначок часу або некоректна тека
+error-code-1054 = Записані під час встановлення файли не збігаються з пакетом - несправний диск, переповнений том або антивірус, що змінює файли; зазвичай допомагає повторна спроба
+error-code-1055 = Системна зв'язка ключів або диспетчер облікових даних відмовилися прочитати чи зберегти ліцензійний ключ - можливо, сховище заблоковане або доступ заборонено
+
+## Errors
+error-dialog-cancelled = Вибір файлу скасовано
+error-invalid-extension = { $path } не є ZXP-файлом або розширенням CEP
+error-file-not-found = Не знайдено: { $path }
+error-permission-denied = Немає дозволу на запис у { $path }
+error-permission-denied-reading = Немає дозволу на читання { $path }
+error-hint-user-folder = спробуйте встановити в теку користувача
+error-invalid-zip = { $path } не є дійсним ZXP-архівом - можливо, завантаження пошкоджене або неповне
+error-extract-failed = Не вдалося записати { $path }: { $error }
+error-directory-not-found = Теку не знайдено: { $path }
+error-manifest-not-found = Маніфест не знайдено: { $path }
+error-invalid-manifest = Недійсний маніфест: { $reason }
+error-archive-failed = Не вдалося записати архів { $path }: { $error }
+error-open-failed = Не вдалося відкрити { $target }
+error-clipboard-failed = Немає доступу до буфера обміну
+error-no-log-folder = Тека журналів недоступна в цій системі
+error-already-installed = { $path } вже існує - спершу видаліть його
+error-invalid-plugin-set = { $path } не містить дійсного набору плагінів
+error-insufficient-space = Недостатньо місця на диску: потрібно { $required }, доступно { $available }
+error-blocked-by-policy = Заблоковано політикою: { $reason }
+error-signing-tool-missing = ZXPSignCmd не знайдено
+error-signing-failed = Помилка ZXPSignCmd: { $output }
+error-corrupted-install = Встановлення пошкоджено, спробуйте ще раз: { $count } файлів у { $path } не збігаються з пакетом ({ $files })
+error-keychain-failed = Не вдалося отримати доступ до зв'язки ключів: { $error }
@@ -0,0 +1,125 @@
+// Benchmarks for the scan and install hot paths - run with `cargo bench`
+// The app is a binary crate, so the modules under test are compiled straight into this target
+// (unused items and the modules' own test imports are expected here)
+
+#[allow(dead_code, unused_imports)]
+#[path = "../src/i18n.rs"]
+mod i18n;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/background.rs"]
+mod background;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/data_operations.rs"]
+mod data_operations;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/manifest.rs"]
+mod manifest;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/version.rs"]
+mod version;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/policy.rs"]
+mod policy;
+#[allow(dead_code, unused_imports)]
+#[path = "../src/file_operations.rs"]
+mod file_operations;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::fs;
+use std::hint::black_box;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+const FILES_PER_EXTENSION: usize = 20;
+const FILE_SIZE: usize = 4 * 1024;
+
+fn bench_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("zxpm-bench-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+// One folder per extension, laid out the way hosts expect: CSXS/manifest.xml plus assets
+fn write_synthetic_extension(root: &Path, index: usize) {
+    let folder = root.join(format!("com.bench.extension{}", index));
+    fs::create_dir_all(folder.join("CSXS")).unwrap();
+    fs::create_dir_all(folder.join("js")).unwrap();
+
+    let manifest = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ExtensionManifest ExtensionBundleId="com.bench.extension{0}" ExtensionBundleVersion="1.0.{0}" ExtensionBundleName="Bench {0}">
+  <ExtensionList><Extension Id="com.bench.extension{0}.panel" Version="1.0.{0}" /></ExtensionList>
+</ExtensionManifest>"#,
+        index
+    );
+    fs::write(folder.join("CSXS").join("manifest.xml"), manifest).unwrap();
+
+    for file in 0..FILES_PER_EXTENSION {
+        fs::write(folder.join("js").join(format!("module{}.js", file)), vec![b'x'; FILE_SIZE]).unwrap();
+    }
+}
+
+fn write_synthetic_zxp(path: &Path, files: usize) {
+    let mut writer = ZipWriter::new(fs::File::create(path).unwrap());
+    writer.start_file("CSXS/manifest.xml", SimpleFileOptions::default()).unwrap();
+    writer.write_all(br#"<ExtensionManifest ExtensionBundleId="com.bench.zxp" ExtensionBundleVersion="1.0"/>"#).unwrap();
+
+    for file in 0..files {
+        writer.start_file(format!("js/module{}.js", file), SimpleFileOptions::default()).unwrap();
+        writer.write_all(&vec![b'x'; FILE_SIZE]).unwrap();
+    }
+    writer.finish().unwrap();
+}
+
+fn bench_scan(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scan_extensions_root");
+
+    for count in [10, 100, 500] {
+        let root = bench_dir(&format!("scan-{}", count));
+        (0..count).for_each(|index| write_synthetic_extension(&root, index));
+
+        group.bench_with_input(BenchmarkId::from_parameter(count), &root, |b, root| {
+            b.iter(|| data_operations::scan_extensions_root(black_box(root)).unwrap())
+        });
+        let _ = fs::remove_dir_all(&root);
+    }
+    group.finish();
+}
+
+fn bench_folder_size(c: &mut Criterion) {
+    let root = bench_dir("size");
+    (0..50).for_each(|index| write_synthetic_extension(&root, index));
+
+    c.bench_function("calculate_folder_size", |b| {
+        b.iter(|| data_operations::calculate_folder_size(black_box(&root)))
+    });
+    let _ = fs::remove_dir_all(&root);
+}
+
+fn bench_extraction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("extract_archive");
+    group.sample_size(20);
+
+    for files in [50, 500] {
+        let dir = bench_dir(&format!("extract-{}", files));
+        let zxp_path = dir.join("bench.zxp");
+        write_synthetic_zxp(&zxp_path, files);
+
+        group.bench_with_input(BenchmarkId::from_parameter(files), &zxp_path, |b, zxp_path| {
+            b.iter(|| {
+                let target = dir.join("target");
+                let mut archive = ZipArchive::new(fs::File::open(zxp_path).unwrap()).unwrap();
+                file_operations::extract_archive(&mut archive, &target).unwrap();
+                fs::remove_dir_all(&target).unwrap();
+            })
+        });
+        let _ = fs::remove_dir_all(&dir);
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_scan, bench_folder_size, bench_extraction);
+criterion_main!(benches);
@@ -0,0 +1,1714 @@
+use dioxus::prelude::*;
+use rfd::{MessageButtons, MessageDialog, MessageDialogResult, MessageLevel};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use crate::background::{
+    export_plugin_as_zxp, import_plugin_folder, inspect_zxp, install_folder, install_folder_into, install_zxp, install_zxp_into, link_dev_folder,
+    preview_install, remove_plugin, revert_to_version, run_blocking, unlink_dev_folder,
+};
+use crate::file_operations::{
+    disable_plugin, enable_plugin, open_in_default_app, remove_orphaned_cache, reset_host_cache, reveal_in_file_manager, select_certificate_destination, select_certificate_file,
+    select_export_destination, select_extension_folder, select_hot_folder, select_inventory_folder, select_report_destination,
+    select_bundle_destination, select_plugin_set_folder, select_signing_tool, select_zxp_file, source_host_list, source_plugin_info,
+    zxp_paths_from_text, compare_zxp_with_folder, install_destination, unwritable_folder, ArchivedVersion, FileOperationError, InstallComparison, InstallPreview, InstalledPlugin,
+    OrphanedEntry, ZxpInspection,
+};
+use crate::i18n::{t, t_args};
+use crate::install_queue::{QueueState, claim_worker, enqueue, forget, release_worker, remove_waiting, set_state, take_next};
+use crate::message::{HISTORY, history_in_range, mark_plugin_as_newly_installed, render_history, show_error, show_info, show_success, trigger_refresh};
+use crate::clock::LocalTime;
+use crate::cep_preferences::{CepFlag, preferences_supported, read_all_preferences, write_flag};
+use crate::debug_launch::launch_debug_session;
+use crate::debug_ports::{DebugEntry, write_debug_file};
+use crate::data_operations::{
+    CEP_EXTENSIONS_PATH, ExtensionRoot, HostRequirement, Plugin, PluginType, SYSTEM_EXTENSIONS_PATH, format_size,
+    parse_host_list,
+};
+use crate::elevation::{create_extension_root, elevation_available, install_folder_elevated, install_zxp_elevated, remove_plugin_elevated};
+use crate::host_detection::{
+    EngineProcess, HostApp, cep_engine_processes, host_code_aliases, host_display_name, running_targeted_hosts, stop_cep_engines,
+};
+use crate::license_keys::save_license;
+use crate::logging::log_dir;
+use crate::manifest_editor::{self, ManifestEdit};
+use crate::notifications::notify_when_away;
+use crate::compliance::{build_compliance_report, render_compliance_report};
+use crate::plugin_data::{backup_plugin_data, find_plugin_leftovers, plugin_data_ids, read_backup_info, restore_plugin_data, unshared_data_ids};
+use crate::plugin_list::{ListFormat, render_plugin_list};
+use crate::policy::load_policy;
+use crate::plugin_set::{backup_plugin_set, default_set_folder_name, restore_plugin_set};
+use crate::session::{finish_install_tracking, track_install};
+use crate::settings::{last_directory, remember_directory, remember_recent_install, update_settings, DialogKind, SETTINGS};
+use crate::release::{
+    ReleaseRequest, ReleaseStep, StepOutcome, feed_entry, read_project, release_file_name, scaffold_project, test_install, validate_project,
+    write_feed_entry,
+};
+use crate::script_scan::{RiskLevel, ScanReport, scan_folder, scan_zxp};
+use crate::support_bundle::{BundleContents, write_support_bundle};
+use crate::telemetry::{self, TelemetryEvent};
+use crate::signing::{CertificateRequest, SignRequest, create_self_signed_certificate, find_zxpsigncmd, sign_extension};
+use crate::validation::{Severity, ValidationReport, validate_folder, validate_zxp};
+use crate::version::compare_versions;
+use crate::plugin_store::{
+    DISABLED_PLUGINS, Duplicate, HOST_APPS, INVENTORY, MISSING_ROOTS_DISMISSED, ORPHANED_CACHE, PLUGINS, PLUGIN_TARGETS, SELECTED_PLUGIN, detect_orphaned_cache,
+    expect_change, open_inventory,
+};
+
+// UI-level operations shared by buttons and keyboard shortcuts
+
+// Installs waiting for approval while "Inspect before install" is on, first one shown by InstallPreviewDialog
+pub static INSTALL_PREVIEWS: GlobalSignal<Vec<InstallPreview>> = Signal::global(Vec::new);
+
+// The ZXP open in the inspector, which only reads it
+pub static ZXP_INSPECTION: GlobalSignal<Option<ZxpInspection>> = Signal::global(|| None);
+
+// Files a ZXP would add, change or remove in its installed copy, shown over the preview or inspector
+pub static INSTALL_COMPARISON: GlobalSignal<Option<InstallComparison>> = Signal::global(|| None);
+
+pub fn install_from_picker() {
+    spawn(async move {
+        match pick_with_memory(DialogKind::InstallZxp, select_zxp_file) {
+            Ok(zxp_path) => install_from_path(zxp_path),
+            Err(e) => {
+                log::info!("File selection cancelled or failed: {}", e);
+                // Don't show cancellation as error - it's user choice
+            }
+        }
+    });
+}
+
+// Unpacked extensions, for developers who ship a folder instead of a ZXP
+pub fn install_folder_from_picker() {
+    spawn(async move {
+        match pick_with_memory(DialogKind::Signing, select_extension_folder) {
+            Ok(folder) => install_from_path(folder),
+            Err(e) => log::info!("Folder selection cancelled or failed: {}", e),
+        }
+    });
+}
+
+// Shared by the pickers, dropped files and folders, and pasted paths; folders install unpacked
+pub fn install_from_path(zxp_path: PathBuf) {
+    queue_install(zxp_path, SETTINGS.peek().inspect_before_install);
+}
+
+// Files the OS opened with the app always wait in the preview - a double-click is not a decision to install
+pub fn install_opened_file(zxp_path: PathBuf) {
+    log::info!("Opened with the app: {:?}", zxp_path);
+    queue_install(zxp_path, true);
+}
+
+// Installs the preview the dialog is showing
+pub fn approve_install_preview() {
+    let preview = take_install_preview();
+    if let Some(preview) = preview {
+        spawn(async move {
+            install_now(preview.zxp_path.clone()).await;
+            finish_install_tracking(&preview.zxp_path);
+        });
+    }
+}
+
+pub fn dismiss_install_preview() {
+    let preview = take_install_preview();
+    if let Some(preview) = preview {
+        log::info!("Install of {:?} cancelled after inspecting it", preview.zxp_path);
+        finish_install_tracking(&preview.zxp_path);
+    }
+}
+
+// "Open ZXP...": contents, manifest and signature of a package, with nothing written anywhere
+pub fn inspect_zxp_from_picker() {
+    spawn(async move {
+        let zxp_path = match pick_with_memory(DialogKind::InstallZxp, select_zxp_file) {
+            Ok(zxp_path) => zxp_path,
+            Err(e) => {
+                log::info!("File selection cancelled or failed: {}", e);
+                return;
+            }
+        };
+        match inspect_zxp(zxp_path).await {
+            Ok(inspection) => *ZXP_INSPECTION.write() = Some(inspection),
+            Err(e) => {
+                log::error!("Failed to inspect ZXP: {}", e);
+                show_error("toast-error", &[("error", &e.to_string())]);
+            }
+        }
+    });
+}
+
+// Hands the inspected package to the normal install path, with all its checks
+pub fn install_inspected_zxp() {
+    let inspection = ZXP_INSPECTION.write().take();
+    if let Some(inspection) = inspection {
+        install_from_path(inspection.zxp_path);
+    }
+}
+
+pub fn close_zxp_inspection() {
+    *ZXP_INSPECTION.write() = None;
+}
+
+// Where a bundle is installed, the newest copy if several roots have one; legacy folders don't count
+pub fn installed_copy(bundle_id: &str) -> Option<PathBuf> {
+    PLUGINS.peek().as_ref().and_then(|plugins| {
+        plugins
+            .iter()
+            .filter(|plugin| plugin.bundle_id == bundle_id && plugin.plugin_type != PluginType::Legacy)
+            .max_by(|a, b| compare_versions(&a.version, &b.version))
+            .map(|plugin| plugin.path.clone())
+    })
+}
+
+pub fn compare_with_installed(zxp_path: PathBuf, installed: PathBuf) {
+    spawn(async move {
+        match run_blocking(move || compare_zxp_with_folder(&zxp_path, &installed)).await {
+            Ok(comparison) => *INSTALL_COMPARISON.write() = Some(comparison),
+            Err(e) => {
+                log::error!("Failed to compare with the installed copy: {}", e);
+                show_error("toast-error", &[("error", &e.to_string())]);
+            }
+        }
+    });
+}
+
+pub fn close_install_comparison() {
+    *INSTALL_COMPARISON.write() = None;
+}
+
+// How one install from the queue ended
+enum InstallOutcome {
+    Installed,
+    Failed(String),
+    Cancelled,
+    Previewed, // Left waiting in the preview dialog, which takes over from here
+}
+
+fn queue_install(zxp_path: PathBuf, inspect: bool) {
+    // Tracked until it finishes, so an install cut off by quitting is offered again on next launch
+    track_install(&zxp_path);
+    enqueue(zxp_path, inspect, false);
+    if !claim_worker() {
+        return; // The running worker picks it up
+    }
+    // Not tied to the component that queued it - a closing palette must not stop the queue
+    spawn_forever(async move {
+        while let Some(item) = take_next() {
+            match validate_and_install(item.path.clone(), item.inspect).await {
+                InstallOutcome::Installed => set_state(item.id, QueueState::Done),
+                InstallOutcome::Failed(error) => set_state(item.id, QueueState::Failed(error)),
+                InstallOutcome::Cancelled => forget(item.id),
+                InstallOutcome::Previewed => {
+                    forget(item.id);
+                    continue; // Still tracked until the preview is approved or dismissed
+                }
+            }
+            finish_install_tracking(&item.path);
+        }
+        release_worker();
+    });
+}
+
+// Before it runs, a queued install can be taken out again
+pub fn remove_queued_install(id: u64) {
+    if let Some(item) = remove_waiting(id) {
+        log::info!("Removed {:?} from the install queue", item.path);
+        if !item.hot_folder {
+            finish_install_tracking(&item.path);
+        }
+    }
+}
+
+async fn validate_and_install(zxp_path: PathBuf, inspect: bool) -> InstallOutcome {
+    if !confirm_validation(&zxp_path).await {
+        log::info!("Install of {:?} cancelled after validation", zxp_path);
+        return InstallOutcome::Cancelled;
+    }
+    if SETTINGS.peek().scan_scripts && !confirm_script_scan(&zxp_path).await {
+        log::info!("Install of {:?} cancelled after the script scan", zxp_path);
+        return InstallOutcome::Cancelled;
+    }
+    let source = zxp_path.clone();
+    if let Ok(hosts) = run_blocking(move || source_host_list(&source)).await
+        && !confirm_hosts_closed(hosts, &file_label(&zxp_path), "running-hosts-install-body").await
+    {
+        log::info!("Install of {:?} cancelled while its host apps run", zxp_path);
+        return InstallOutcome::Cancelled;
+    }
+    if !confirm_downgrade(&zxp_path).await {
+        log::info!("Install of {:?} cancelled to keep the newer installed version", zxp_path);
+        return InstallOutcome::Cancelled;
+    }
+    // The preview reads the archive; a folder's files are already there to look at
+    if inspect && !zxp_path.is_dir() {
+        return match preview_install(zxp_path).await {
+            Ok(preview) => {
+                INSTALL_PREVIEWS.write().push(preview); // Dropping several files queues them
+                InstallOutcome::Previewed
+            }
+            Err(e) => InstallOutcome::Failed(report_install_failure(e)),
+        };
+    }
+    install_now(zxp_path).await
+}
+
+async fn install_now(zxp_path: PathBuf) -> InstallOutcome {
+    // A folder that refuses writes is caught here, with ways around it, instead of halfway through extraction
+    let source = zxp_path.clone();
+    let refused = run_blocking(move || {
+        install_destination(&source, Path::new(SYSTEM_EXTENSIONS_PATH)).ok().and_then(|destination| unwritable_folder(&destination))
+    })
+    .await;
+    if let Some(folder) = refused {
+        // A fresh machine has no all-users root yet - say so rather than blaming a folder the user never saw
+        let key = if Path::new(SYSTEM_EXTENSIONS_PATH).is_dir() { "permission-install-body" } else { "permission-install-missing-body" };
+        let body = t_args(key, &[("path", &folder.display().to_string()), ("root", SYSTEM_EXTENSIONS_PATH)]);
+        return match ask_permission_remedy(body, true) {
+            Some(PermissionRemedy::OpenFolder) => {
+                open_for_permissions(&folder);
+                InstallOutcome::Cancelled
+            }
+            Some(remedy) => install_with_remedy(zxp_path, remedy).await,
+            None => {
+                log::info!("Install of {:?} cancelled at the permission check", zxp_path);
+                InstallOutcome::Cancelled
+            }
+        };
+    }
+
+    log::info!("Installing from path: {:?}", zxp_path);
+    let result = if zxp_path.is_dir() { install_folder(zxp_path.clone()).await } else { install_zxp(zxp_path.clone()).await };
+    match result {
+        Ok(installed) => {
+            finish_install(&zxp_path, installed);
+            InstallOutcome::Installed
+        }
+        Err(FileOperationError::PermissionDenied(_)) => retry_install_with_permission(zxp_path).await,
+        Err(e) => InstallOutcome::Failed(report_install_failure(e)),
+    }
+}
+
+fn move_plugin_with_feedback(
+    plugin: Plugin,
+    move_plugin: fn(&Path) -> Result<PathBuf, FileOperationError>,
+    success_key: &'static str,
+    failure_key: &'static str,
+) {
+    spawn(async move {
+        let plugin_path = plugin.path.clone();
+        match run_blocking(move || move_plugin(&plugin_path)).await {
+            Ok(moved) => {
+                expect_change(plugin.path.clone());
+                expect_change(moved);
+                show_success(success_key, &[("name", &plugin.name)]);
+                trigger_refresh();
+            }
+            Err(e) => {
+                let error_msg = show_error(failure_key, &[("name", &plugin.name), ("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+fn take_install_preview() -> Option<InstallPreview> {
+    let mut previews = INSTALL_PREVIEWS.write();
+    (!previews.is_empty()).then(|| previews.remove(0))
+}
+
+// Copied files win over text, so both Finder copies and paths pasted from chat work
+pub fn install_from_clipboard() {
+    let mut clipboard = match arboard::Clipboard::new() {
+        Ok(clipboard) => clipboard,
+        Err(e) => {
+            log::error!("Failed to open clipboard: {}", e);
+            show_error("error-clipboard-failed", &[]);
+            return;
+        }
+    };
+
+    let copied_files = clipboard.get().file_list().unwrap_or_default();
+    let paths = if copied_files.is_empty() {
+        let text = clipboard.get_text().unwrap_or_default();
+        if text.trim_start().starts_with("http://") || text.trim_start().starts_with("https://") {
+            show_error("toast-paste-url-unsupported", &[]);
+            return;
+        }
+        zxp_paths_from_text(&text)
+    } else {
+        copied_files.into_iter().filter(|path| path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("zxp"))).collect()
+    };
+
+    if paths.is_empty() {
+        show_info("toast-paste-nothing", &[]);
+        return;
+    }
+    for path in paths {
+        install_from_path(path);
+    }
+}
+
+pub fn remove_plugin_with_feedback(plugin_path: PathBuf) {
+    spawn(async move {
+        if !confirm_hosts_closed(plugin_hosts(&plugin_path), &plugin_label(&plugin_path), "running-hosts-remove-body").await {
+            log::info!("Removal of {:?} cancelled while its host apps run", plugin_path);
+            return;
+        }
+        log::info!("Starting plugin removal for: {:?}", plugin_path);
+        let result = match remove_with_preflight(&plugin_path).await {
+            None => {
+                log::info!("Removal of {:?} cancelled at the permission check", plugin_path);
+                return;
+            }
+            Some(Err(FileOperationError::PermissionDenied(_))) if elevation_available() && confirm_elevated_removal() => {
+                let path = plugin_path.clone();
+                run_blocking(move || remove_plugin_elevated(&path)).await
+            }
+            Some(result) => result,
+        };
+        report_removal(plugin_path, result);
+    });
+}
+
+// For plugins the current user can't delete - the system password prompt is the confirmation
+pub fn remove_plugin_as_admin(plugin_path: PathBuf) {
+    spawn(async move {
+        if !confirm_hosts_closed(plugin_hosts(&plugin_path), &plugin_label(&plugin_path), "running-hosts-remove-body").await {
+            log::info!("Removal of {:?} cancelled while its host apps run", plugin_path);
+            return;
+        }
+        let path = plugin_path.clone();
+        let result = run_blocking(move || remove_plugin_elevated(&path)).await;
+        report_removal(plugin_path, result);
+    });
+}
+
+// Removal that also deletes the panel's settings, CEP cache and engine logs, after showing what goes
+pub fn remove_plugin_deep_clean(plugin_path: PathBuf) {
+    spawn(async move {
+        let Some(plugin) = PLUGINS.peek().as_ref().and_then(|plugins| plugins.iter().find(|plugin| plugin.path == plugin_path).cloned()) else {
+            return;
+        };
+        if !confirm_hosts_closed(plugin_hosts(&plugin.path), &plugin.name, "running-hosts-remove-body").await {
+            log::info!("Deep clean of {:?} cancelled while its host apps run", plugin.path);
+            return;
+        }
+        let others: Vec<Plugin> = PLUGINS.peek().iter().flatten().chain(DISABLED_PLUGINS.peek().iter()).cloned().collect();
+        let ids = unshared_data_ids(&plugin, &others);
+        let leftovers = run_blocking(move || find_plugin_leftovers(&ids)).await;
+        if !confirm_deep_clean(&plugin.name, &leftovers) {
+            return;
+        }
+
+        log::info!("Starting deep clean removal for {:?} with {} leftovers", plugin.path, leftovers.len());
+        let Some(result) = remove_with_preflight(&plugin.path).await else {
+            log::info!("Deep clean of {:?} cancelled at the permission check", plugin.path);
+            return;
+        };
+        let removed = result.is_ok();
+        report_removal(plugin.path, result);
+        if removed && !leftovers.is_empty() {
+            let (count, freed) = run_blocking(move || remove_orphaned_cache(&leftovers)).await;
+            show_success("toast-leftovers-removed", &[("count", &count.to_string()), ("size", &format_size(freed))]);
+            detect_orphaned_cache();
+        }
+    });
+}
+
+// Toggling moves the folder between its extensions root and the disabled folder next to it
+pub fn disable_plugin_with_feedback(plugin: Plugin) {
+    move_plugin_with_feedback(plugin, disable_plugin, "toast-plugin-disabled", "toast-disable-failed");
+}
+
+pub fn enable_plugin_with_feedback(plugin: Plugin) {
+    move_plugin_with_feedback(plugin, enable_plugin, "toast-plugin-enabled", "toast-enable-failed");
+}
+
+// Removes the older copies and keeps the newest version of each duplicated bundle ID
+pub fn resolve_duplicates(duplicates: Vec<Duplicate>) {
+    spawn(async move {
+        // Every copy of a bundle ID targets the same hosts - ask once for the whole batch
+        let hosts: Vec<HostRequirement> = duplicates.iter().flat_map(|duplicate| plugin_hosts(&duplicate.keep)).collect();
+        let names: Vec<String> = duplicates.iter().map(|duplicate| plugin_label(&duplicate.keep)).collect();
+        if !confirm_hosts_closed(hosts.clone(), &names.join(", "), "running-hosts-remove-body").await {
+            log::info!("Duplicate cleanup cancelled while host apps run");
+            return;
+        }
+        let mut removed = 0;
+        for path in duplicates.iter().flat_map(|duplicate| &duplicate.remove) {
+            match remove_plugin(path.clone()).await {
+                Ok(_) => {
+                    removed += 1;
+                    expect_change(path.clone());
+                    if SELECTED_PLUGIN.peek().as_ref() == Some(path) {
+                        *SELECTED_PLUGIN.write() = None;
+                    }
+                }
+                Err(e) => {
+                    let error_msg = show_error("toast-duplicate-failed", &[("path", &path.display().to_string()), ("error", &e.to_string())]);
+                    log::error!("{}", error_msg);
+                }
+            }
+        }
+
+        if removed > 0 {
+            remind_restart(hosts);
+            let message = show_success("toast-duplicates-removed", &[("count", &removed.to_string())]);
+            notify_when_away(&message);
+            trigger_refresh();
+        }
+    });
+}
+
+// "Create it now" on the missing folders banner; the all-users root goes through the password prompt
+pub fn create_missing_roots(roots: Vec<ExtensionRoot>) {
+    spawn(async move {
+        let mut created = false;
+        for root in roots {
+            match run_blocking(move || create_extension_root(root)).await {
+                Ok(path) => {
+                    created = true;
+                    show_success("toast-root-created", &[("path", &path.display().to_string())]);
+                }
+                Err(e) => {
+                    let path = root.path().map(|path| path.display().to_string()).unwrap_or_default();
+                    let error_msg = show_error("toast-root-create-failed", &[("path", &path), ("error", &e.to_string())]);
+                    log::error!("{}", error_msg);
+                }
+            }
+        }
+        if created {
+            trigger_refresh();
+        }
+    });
+}
+
+// Hidden for the rest of the session; installs still create the folders they need
+pub fn dismiss_missing_roots() {
+    *MISSING_ROOTS_DISMISSED.write() = true;
+}
+
+// Keyboard removal asks first, since there's no explicit button click behind it
+pub fn remove_selected_plugin() {
+    let Some(selected_path) = SELECTED_PLUGIN() else {
+        return;
+    };
+    let Some(plugin) = PLUGINS
+        .read()
+        .as_ref()
+        .and_then(|plugins| plugins.iter().find(|plugin| plugin.path == selected_path).cloned())
+    else {
+        return;
+    };
+
+    if !plugin.can_remove {
+        let error = FileOperationError::PermissionDenied(plugin.path.clone());
+        show_error("toast-remove-failed", &[("error", &error.to_string())]);
+        return;
+    }
+
+    let confirmed = MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(t("confirm-remove-title"))
+        .set_description(t_args("confirm-remove-body", &[("name", &plugin.name)]))
+        .set_buttons(MessageButtons::YesNo)
+        .show();
+
+    if confirmed == MessageDialogResult::Yes {
+        *SELECTED_PLUGIN.write() = None;
+        remove_plugin_with_feedback(plugin.path);
+    }
+}
+
+// Support's "reset the extension cache" for one app - confirms first since panels lose stored state
+pub fn reset_host_app_cache(app: HostApp) {
+    let confirmed = MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(t_args("confirm-host-reset-title", &[("app", &app.name)]))
+        .set_description(t_args("confirm-host-reset-body", &[("app", &app.name)]))
+        .set_buttons(MessageButtons::YesNo)
+        .show();
+    if confirmed != MessageDialogResult::Yes {
+        return;
+    }
+
+    match reset_host_cache(&host_code_aliases(app.code)) {
+        Ok(removed) => {
+            show_success("toast-host-reset-done", &[("app", &app.name), ("count", &removed.to_string())]);
+            trigger_refresh();
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-host-reset-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+        }
+    }
+}
+
+// Troubleshooting's "restart CEP": blank or frozen panels usually come back once their engine is killed
+pub fn restart_cep_engines() {
+    spawn(async move {
+        let running = run_blocking(cep_engine_processes).await;
+        if running.is_empty() {
+            show_info("toast-cep-none-running", &[]);
+            return;
+        }
+
+        let confirmed = MessageDialog::new()
+            .set_level(MessageLevel::Warning)
+            .set_title(t("confirm-cep-restart-title"))
+            .set_description(t_args(
+                "confirm-cep-restart-body",
+                &[("count", &running.len().to_string()), ("apps", &engine_hosts(&running))],
+            ))
+            .set_buttons(MessageButtons::YesNo)
+            .show();
+        if confirmed != MessageDialogResult::Yes {
+            return;
+        }
+
+        let requested = running.len();
+        let stopped = run_blocking(move || stop_cep_engines(&running)).await;
+        log::info!("Stopped {} of {} CEP engine processes", stopped.len(), requested);
+        if stopped.len() < requested {
+            show_error(
+                "toast-cep-restart-partial",
+                &[("count", &stopped.len().to_string()), ("total", &requested.to_string())],
+            );
+        } else {
+            show_success(
+                "toast-cep-restarted",
+                &[("count", &stopped.len().to_string()), ("apps", &engine_hosts(&stopped))],
+            );
+        }
+    });
+}
+
+// Fresh preferences sandbox per launch, so a panel issue can be reproduced against factory settings
+pub fn launch_debug_session_with_feedback(app: HostApp) {
+    spawn(async move {
+        let host = app.clone();
+        match run_blocking(move || launch_debug_session(&host)).await {
+            Ok(launch) => {
+                let key = if launch.debug_logging { "toast-debug-launch-started" } else { "toast-debug-launch-started-plain" };
+                show_success(key, &[("app", &app.name), ("path", &launch.sandbox.display().to_string())]);
+            }
+            Err(e) => {
+                let error_msg = show_error("toast-debug-launch-failed", &[("app", &app.name), ("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+// Entries the user unticked in Diagnostics stay on disk
+pub fn clean_orphaned_cache(kept: HashSet<PathBuf>) {
+    // Detected again first, so a panel installed since the last scan keeps its cache
+    detect_orphaned_cache();
+    let orphans: Vec<OrphanedEntry> = ORPHANED_CACHE.peek().iter().filter(|orphan| !kept.contains(&orphan.path)).cloned().collect();
+    let (removed, freed) = remove_orphaned_cache(&orphans);
+    show_success("toast-orphans-removed", &[("count", &removed.to_string()), ("size", &format_size(freed))]);
+    detect_orphaned_cache();
+}
+
+// Sign & package tool - dialogs share one remembered directory, work usually happens in one project folder
+pub fn pick_extension_folder() -> Option<PathBuf> {
+    pick_with_memory(DialogKind::Signing, select_extension_folder).ok()
+}
+
+pub fn choose_hot_folder() {
+    if let Ok(folder) = pick_with_memory(DialogKind::HotFolder, select_hot_folder) {
+        log::info!("Hot folder set to {:?}", folder);
+        update_settings(|settings| {
+            settings.hot_folder.folder = Some(folder);
+            settings.hot_folder.enabled = true;
+        });
+    }
+}
+
+pub fn pick_certificate() -> Option<PathBuf> {
+    pick_with_memory(DialogKind::Signing, select_certificate_file).ok()
+}
+
+pub fn locate_signing_tool() {
+    if let Ok(tool) = select_signing_tool(None) {
+        log::info!("Using signing tool {:?}", tool);
+        update_settings(|settings| settings.zxpsigncmd_path = Some(tool));
+    }
+}
+
+// Returns the new certificate so the form can use it right away
+pub async fn create_certificate(request: CertificateRequest) -> Option<PathBuf> {
+    let tool = signing_tool()?;
+    let output = pick_with_memory(DialogKind::Signing, select_certificate_destination).ok()?;
+
+    let result = {
+        let output = output.clone();
+        tokio::task::spawn_blocking(move || create_self_signed_certificate(&tool, &request, &output)).await
+    };
+    match result {
+        Ok(Ok(())) => {
+            show_success("toast-certificate-created", &[]);
+            Some(output)
+        }
+        Ok(Err(e)) => {
+            show_error("toast-certificate-failed", &[("error", &e.to_string())]);
+            None
+        }
+        Err(e) => {
+            log::error!("Certificate task failed: {}", e);
+            None
+        }
+    }
+}
+
+pub async fn sign_and_package(source: PathBuf, certificate: PathBuf, password: String, tsa_url: Option<String>) {
+    let Some(tool) = signing_tool() else {
+        return;
+    };
+    let default_name = format!("{}.zxp", source.file_name().map(|name| name.to_string_lossy()).unwrap_or_default());
+    let Ok(output) = pick_with_memory(DialogKind::Signing, |start_dir| select_export_destination(&default_name, start_dir)) else {
+        return;
+    };
+
+    let request = SignRequest { source, certificate, password, output: output.clone(), tsa_url };
+    match tokio::task::spawn_blocking(move || sign_extension(&tool, &request)).await {
+        Ok(Ok(())) => {
+            show_success("toast-signed", &[("path", &output.display().to_string())]);
+        }
+        Ok(Err(e)) => {
+            let error_msg = show_error("toast-sign-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+        }
+        Err(e) => log::error!("Signing task failed: {}", e),
+    }
+}
+
+// For project folders without a manifest yet; true once the folder can be released
+pub async fn scaffold_release_project(folder: PathBuf, bundle_id: String, name: String) -> bool {
+    match run_blocking(move || scaffold_project(&folder, &bundle_id, &name)).await {
+        Ok(manifest_path) => {
+            show_success("toast-release-scaffolded", &[("path", &manifest_path.display().to_string())]);
+            true
+        }
+        Err(e) => {
+            show_error("toast-release-failed", &[("error", &e.to_string())]);
+            false
+        }
+    }
+}
+
+// Runs the release steps in order and stops at the first failure; returns the feed entry JSON
+pub async fn prepare_release(request: ReleaseRequest, mut progress: Signal<Vec<(ReleaseStep, StepOutcome)>>) -> Option<String> {
+    let tool = signing_tool()?;
+    let source = request.source.clone();
+    let info = match run_blocking(move || read_project(&source)).await {
+        Ok(info) => info,
+        Err(e) => {
+            show_error("toast-release-failed", &[("error", &e.to_string())]);
+            return None;
+        }
+    };
+    let default_name = release_file_name(&info);
+    let output = pick_with_memory(DialogKind::Signing, |start_dir| select_export_destination(&default_name, start_dir)).ok()?;
+
+    progress.set(Vec::new());
+    let mut report = move |step: ReleaseStep, outcome: StepOutcome| {
+        let mut steps = progress.write();
+        steps.retain(|(existing, _)| *existing != step);
+        steps.push((step, outcome));
+    };
+    let fail = |e: FileOperationError| StepOutcome::Failed(e.to_string());
+
+    report(ReleaseStep::Validate, StepOutcome::Running);
+    let source = request.source.clone();
+    let outcome = match run_blocking(move || validate_project(&source)).await {
+        Ok(checked) if checked.count(Severity::Error) > 0 => {
+            StepOutcome::Failed(t_args("release-validate-errors", &[("count", &checked.count(Severity::Error).to_string())]))
+        }
+        Ok(checked) => StepOutcome::Done(t_args("release-validate-done", &[("count", &checked.count(Severity::Warning).to_string())])),
+        Err(e) => fail(e),
+    };
+    let failed = matches!(outcome, StepOutcome::Failed(_));
+    report(ReleaseStep::Validate, outcome);
+    if failed {
+        return None;
+    }
+
+    report(ReleaseStep::Sign, StepOutcome::Running);
+    let sign = SignRequest {
+        source: request.source.clone(),
+        certificate: request.certificate,
+        password: request.password,
+        output: output.clone(),
+        tsa_url: request.tsa_url,
+    };
+    if let Err(e) = run_blocking(move || sign_extension(&tool, &sign)).await {
+        report(ReleaseStep::Sign, fail(e));
+        return None;
+    }
+    report(ReleaseStep::Sign, StepOutcome::Done(output.display().to_string()));
+
+    report(ReleaseStep::TestInstall, StepOutcome::Running);
+    let (zxp_path, expected) = (output.clone(), info.clone());
+    if let Err(e) = run_blocking(move || test_install(&zxp_path, &expected)).await {
+        report(ReleaseStep::TestInstall, fail(e));
+        return None;
+    }
+    report(ReleaseStep::TestInstall, StepOutcome::Done(t_args("release-test-install-done", &[("version", &info.version)])));
+
+    report(ReleaseStep::FeedEntry, StepOutcome::Running);
+    let download_url = request.download_url;
+    let zxp_path = output.clone();
+    let written = run_blocking(move || {
+        let entry = feed_entry(&info, &zxp_path, &download_url)?;
+        write_feed_entry(&entry, &zxp_path).map(|path| (entry, path))
+    })
+    .await;
+    match written {
+        Ok((entry, path)) => {
+            report(ReleaseStep::FeedEntry, StepOutcome::Done(path.display().to_string()));
+            show_success("toast-release-ready", &[("path", &output.display().to_string())]);
+            Some(serde_json::to_string_pretty(&entry).unwrap_or_default())
+        }
+        Err(e) => {
+            report(ReleaseStep::FeedEntry, fail(e));
+            None
+        }
+    }
+}
+
+fn signing_tool() -> Option<PathBuf> {
+    let tool = find_zxpsigncmd(SETTINGS.read().zxpsigncmd_path.as_deref());
+    if tool.is_none() {
+        show_error("toast-error", &[("error", &FileOperationError::SigningToolMissing.to_string())]);
+    }
+    tool
+}
+
+// `item` is the already-translated name of what was copied, used in the toast
+pub fn copy_to_clipboard(text: String, item: &str) {
+    let result = arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(text));
+
+    match result {
+        Ok(_) => {
+            show_success("toast-copied", &[("item", item)]);
+        }
+        Err(e) => {
+            log::error!("Failed to copy to clipboard: {}", e);
+            show_error("error-clipboard-failed", &[]);
+        }
+    }
+}
+
+pub fn reveal_with_feedback(path: &Path) {
+    if let Err(e) = reveal_in_file_manager(path) {
+        log::error!("Failed to reveal {:?}: {}", path, e);
+        show_error("toast-error", &[("error", &e.to_string())]);
+    }
+}
+
+// Host apps read CEP flags at launch, so the toast says a restart is needed
+pub async fn set_cep_flag(version: u32, flag: CepFlag, value: String) -> bool {
+    match run_blocking(move || write_flag(version, flag, &value)).await {
+        Ok(()) => {
+            show_success("toast-cep-flag-saved", &[("flag", flag.key()), ("version", &version.to_string())]);
+            true
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-cep-flag-failed", &[("flag", flag.key()), ("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+            false
+        }
+    }
+}
+
+// PlayerDebugMode for every CSXS domain at once: on if any is off, otherwise off
+pub fn toggle_debug_mode() {
+    if !preferences_supported() {
+        show_error("cep-preferences-unsupported", &[]);
+        return;
+    }
+    spawn(async move {
+        let result = run_blocking(|| {
+            let domains = read_all_preferences();
+            let enable = domains.iter().any(|domain| !domain.debug_mode);
+            let value = if enable { "1" } else { "0" };
+            domains
+                .iter()
+                .try_for_each(|domain| write_flag(domain.version, CepFlag::PlayerDebugMode, value))
+                .map(|_| (enable, domains.len()))
+        })
+        .await;
+
+        match result {
+            Ok((_, 0)) => {
+                show_info("cep-preferences-none", &[]);
+            }
+            Ok((enable, count)) => {
+                let key = if enable { "toast-debug-mode-on" } else { "toast-debug-mode-off" };
+                show_success(key, &[("count", &count.to_string())]);
+            }
+            Err(e) => {
+                let error_msg = show_error("toast-cep-flag-failed", &[("flag", CepFlag::PlayerDebugMode.key()), ("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+// Writes the remote debugging ports; no entries removes the .debug file
+pub async fn save_debug_entries(plugin_path: PathBuf, entries: Vec<DebugEntry>) -> bool {
+    let count = entries.len();
+    match run_blocking(move || write_debug_file(&plugin_path, &entries)).await {
+        Ok(()) if count == 0 => {
+            show_success("toast-debug-file-removed", &[]);
+            true
+        }
+        Ok(()) => {
+            show_success("toast-debug-file-saved", &[("count", &count.to_string())]);
+            true
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-debug-file-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+            false
+        }
+    }
+}
+
+// True when the manifest was written; the rescan picks up the new name and version
+pub async fn save_manifest_edit(plugin_path: PathBuf, edit: ManifestEdit) -> bool {
+    let path = plugin_path.clone();
+    match run_blocking(move || manifest_editor::save_manifest_edit(&path, &edit)).await {
+        Ok(()) => {
+            expect_change(plugin_path);
+            show_success("toast-manifest-saved", &[]);
+            trigger_refresh();
+            true
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-manifest-save-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+            false
+        }
+    }
+}
+
+// An empty key removes the saved one
+pub async fn save_license_key(bundle_id: String, key: String) -> bool {
+    let removed = key.trim().is_empty();
+    match run_blocking(move || save_license(&bundle_id, &key)).await {
+        Ok(()) => {
+            show_success(if removed { "toast-license-removed" } else { "toast-license-saved" }, &[]);
+            true
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-license-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+            false
+        }
+    }
+}
+
+// Panel settings outside the extension folder, kept so a reinstall or cache reset doesn't lose them
+pub async fn backup_plugin_settings(plugin: Plugin) -> bool {
+    let (bundle_id, ids) = (plugin.bundle_id.clone(), plugin_data_ids(&plugin));
+    match run_blocking(move || backup_plugin_data(&bundle_id, &ids)).await {
+        Ok(0) => {
+            show_info("toast-settings-nothing", &[("name", &plugin.name)]);
+            false
+        }
+        Ok(count) => {
+            show_success("toast-settings-backed-up", &[("name", &plugin.name), ("count", &count.to_string())]);
+            true
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-settings-backup-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+            false
+        }
+    }
+}
+
+pub async fn restore_plugin_settings(plugin: Plugin) -> bool {
+    let Some(info) = read_backup_info(&plugin.bundle_id) else {
+        return false;
+    };
+    // A running panel holds its storage open and would write over the restored files
+    if !confirm_hosts_closed(plugin_hosts(&plugin.path), &plugin.name, "running-hosts-settings-body").await {
+        return false;
+    }
+    let confirmed = MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(t("confirm-settings-restore-title"))
+        .set_description(t_args(
+            "confirm-settings-restore-body",
+            &[("name", &plugin.name), ("date", &LocalTime::from_unix(info.created_at).date_label())],
+        ))
+        .set_buttons(MessageButtons::YesNo)
+        .show();
+    if confirmed != MessageDialogResult::Yes {
+        return false;
+    }
+
+    let bundle_id = plugin.bundle_id.clone();
+    match run_blocking(move || restore_plugin_data(&bundle_id)).await {
+        Ok(count) => {
+            show_success("toast-settings-restored", &[("name", &plugin.name), ("count", &count.to_string())]);
+            true
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-settings-restore-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+            false
+        }
+    }
+}
+
+pub async fn restore_original_manifest(plugin_path: PathBuf) -> bool {
+    let path = plugin_path.clone();
+    match run_blocking(move || manifest_editor::restore_original_manifest(&path)).await {
+        Ok(()) => {
+            expect_change(plugin_path);
+            show_success("toast-manifest-restored", &[]);
+            trigger_refresh();
+            true
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-manifest-save-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+            false
+        }
+    }
+}
+
+pub async fn revert_plugin_version(plugin: Plugin, archived: ArchivedVersion) -> bool {
+    match revert_to_version(plugin.path.clone(), archived.path).await {
+        Ok(installed) => {
+            expect_change(installed.path);
+            let args = [("name", plugin.name.as_str()), ("version", archived.version.as_str())];
+            show_success("toast-version-reverted", &args);
+            trigger_refresh();
+            true
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-version-revert-failed", &[("name", &plugin.name), ("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+            false
+        }
+    }
+}
+
+// A dangling dev link has no folder to open - its parent is shown instead
+pub fn open_folder_with_feedback(path: &Path) {
+    if !path.is_dir() {
+        return reveal_with_feedback(path);
+    }
+    if let Err(e) = open_in_default_app(path) {
+        log::error!("Failed to open {:?}: {}", path, e);
+        show_error("toast-error", &[("error", &e.to_string())]);
+    }
+}
+
+pub fn open_manifest_with_feedback(plugin_path: &Path) {
+    let manifest_path = plugin_path.join("CSXS").join("manifest.xml");
+    if let Err(e) = open_in_default_app(&manifest_path) {
+        log::error!("Failed to open {:?}: {}", manifest_path, e);
+        show_error("toast-error", &[("error", &e.to_string())]);
+    }
+}
+
+pub fn export_plugin_with_feedback(plugin: Plugin) {
+    spawn(async move {
+        let default_name = format!("{}-{}.zxp", plugin.bundle_id, plugin.version);
+        let result = match pick_with_memory(DialogKind::ExportZxp, |start_dir| select_export_destination(&default_name, start_dir)) {
+            Ok(destination) => export_plugin_as_zxp(plugin.path, destination).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(_) => {
+                show_success("toast-plugin-exported", &[]);
+            }
+            Err(FileOperationError::DialogCancelled) => {} // User choice, not an error
+            Err(e) => {
+                let error_msg = show_error("toast-export-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+pub fn export_plugin_list() {
+    // Export whatever the panel shows - an open inventory folder or the installed plugins
+    let plugins = match INVENTORY.read().as_ref() {
+        Some(inventory) => inventory.plugins.clone(),
+        None => PLUGINS.read().clone().unwrap_or_default(),
+    };
+
+    spawn(async move {
+        let result = pick_with_memory(DialogKind::ExportList, |start_dir| {
+            select_report_destination("Export Plugin List", "plugins.json", start_dir)
+        }).and_then(|destination| {
+            let content = render_plugin_list(&plugins, ListFormat::from_path(&destination));
+            std::fs::write(&destination, content).map_err(|e| FileOperationError::archive(&destination, e))
+        });
+
+        match result {
+            Ok(_) => {
+                show_success("toast-plugin-list-exported", &[("count", &plugins.len().to_string())]);
+            }
+            Err(FileOperationError::DialogCancelled) => {}
+            Err(e) => {
+                let error_msg = show_error("toast-export-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+pub fn export_compliance_report() {
+    let plugins = PLUGINS.read().clone().unwrap_or_default();
+
+    spawn(async move {
+        let result = pick_with_memory(DialogKind::ExportList, |start_dir| {
+            select_report_destination("Export Compliance Report", "compliance.json", start_dir)
+        })
+        .and_then(|destination| {
+            let report = build_compliance_report(&load_policy(), &plugins);
+            let content = render_compliance_report(&report, ListFormat::from_path(&destination));
+            std::fs::write(&destination, content).map_err(|e| FileOperationError::archive(&destination, e))
+        });
+
+        match result {
+            Ok(_) => {
+                show_success("toast-compliance-exported", &[]);
+            }
+            Err(FileOperationError::DialogCancelled) => {}
+            Err(e) => {
+                let error_msg = show_error("toast-export-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+// Logs, plugin list, CSXS flags, host apps and OS info in one ZIP for bug reports
+pub fn export_diagnostics() {
+    let plugins = PLUGINS.read().clone().unwrap_or_default();
+    let host_apps = HOST_APPS.read().clone();
+
+    spawn(async move {
+        let destination = match pick_with_memory(DialogKind::ExportList, |start_dir| {
+            select_bundle_destination("zxp-manager-diagnostics.zip", start_dir)
+        }) {
+            Ok(destination) => destination,
+            Err(FileOperationError::DialogCancelled) => return,
+            Err(e) => {
+                show_error("toast-export-failed", &[("error", &e.to_string())]);
+                return;
+            }
+        };
+
+        let target = destination.clone();
+        let result = run_blocking(move || {
+            write_support_bundle(&target, &BundleContents { plugins: &plugins, host_apps: &host_apps })
+        })
+        .await;
+        match result {
+            Ok(_) => {
+                show_success("toast-diagnostics-exported", &[("path", &destination.display().to_string())]);
+            }
+            Err(e) => {
+                let error_msg = show_error("toast-export-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+// Bounds are Unix seconds, `until` exclusive - for attaching change records to incident reports
+pub fn export_history(from: Option<i64>, until: Option<i64>) {
+    let entries = history_in_range(&HISTORY.read(), from, until);
+
+    spawn(async move {
+        let result = pick_with_memory(DialogKind::ExportList, |start_dir| {
+            select_report_destination("Export Activity History", "history.csv", start_dir)
+        })
+        .and_then(|destination| {
+            let content = render_history(&entries, ListFormat::from_path(&destination));
+            std::fs::write(&destination, content).map_err(|e| FileOperationError::archive(&destination, e))
+        });
+
+        match result {
+            Ok(_) => {
+                show_success("toast-history-exported", &[("count", &entries.len().to_string())]);
+            }
+            Err(FileOperationError::DialogCancelled) => {}
+            Err(e) => {
+                let error_msg = show_error("toast-export-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+pub fn backup_all_plugins() {
+    let plugins = PLUGINS.read().clone().unwrap_or_default();
+
+    spawn(async move {
+        let result = match pick_with_memory(DialogKind::PluginSet, |start_dir| {
+            select_plugin_set_folder(&t("backup-all-title"), start_dir)
+        }) {
+            Ok(parent) => run_blocking(move || backup_plugin_set(&plugins, &parent.join(default_set_folder_name()))).await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(set) => {
+                let message = show_success("toast-backup-done", &[("count", &set.plugins.len().to_string())]);
+                notify_when_away(&message);
+            }
+            Err(FileOperationError::DialogCancelled) => {}
+            Err(e) => {
+                let error_msg = show_error("toast-backup-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+                notify_when_away(&error_msg);
+            }
+        }
+    });
+}
+
+pub fn restore_plugin_set_from_picker() {
+    let installed = PLUGINS.read().clone().unwrap_or_default();
+
+    spawn(async move {
+        let result = match pick_with_memory(DialogKind::PluginSet, |start_dir| {
+            select_plugin_set_folder(&t("restore-set-title"), start_dir)
+        }) {
+            Ok(set_dir) => run_blocking(move || restore_plugin_set(&set_dir, &installed)).await,
+            Err(e) => Err(e),
+        };
+
+        let report = match result {
+            Ok(report) => report,
+            Err(FileOperationError::DialogCancelled) => return,
+            Err(e) => {
+                let error_msg = show_error("toast-restore-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+                return;
+            }
+        };
+
+        for installed_path in &report.installed {
+            expect_change(installed_path.clone());
+        }
+        if !report.installed.is_empty() {
+            trigger_refresh();
+        }
+
+        let installed = report.installed.len().to_string();
+        let skipped = report.skipped.to_string();
+        let message = if report.failed.is_empty() {
+            show_success("toast-restore-done", &[("installed", &installed), ("skipped", &skipped)])
+        } else {
+            let names: Vec<&str> = report.failed.iter().map(|(name, _)| name.as_str()).collect();
+            show_error("toast-restore-partial", &[("installed", &installed), ("skipped", &skipped), ("names", &names.join(", "))])
+        };
+        notify_when_away(&message);
+    });
+}
+
+pub fn open_inventory_from_picker() {
+    spawn(async move {
+        let Ok(folder) = pick_with_memory(DialogKind::OpenInventory, select_inventory_folder) else {
+            return; // Cancelled - user choice
+        };
+
+        match open_inventory(folder.clone()).await {
+            Ok(count) => {
+                show_info("toast-inventory-opened", &[("count", &count.to_string())]);
+            }
+            Err(e) => {
+                log::error!("Failed to open inventory {:?}: {}", folder, e);
+                show_error("toast-inventory-failed", &[("error", &e.to_string())]);
+            }
+        }
+    });
+}
+
+pub fn import_plugin_with_feedback(plugin_path: PathBuf) {
+    spawn(async move {
+        match import_plugin_folder(plugin_path).await {
+            Ok(installed_path) => {
+                expect_change(installed_path.clone());
+                mark_plugin_as_newly_installed(installed_path);
+                show_success("toast-plugin-imported", &[]);
+                trigger_refresh();
+            }
+            Err(e) => {
+                let error_msg = show_error("toast-import-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+// Same project folders as Sign & package, so both share a remembered directory
+pub fn link_dev_folder_from_picker() {
+    spawn(async move {
+        let Ok(source) = pick_with_memory(DialogKind::Signing, select_extension_folder) else {
+            return; // Cancelled - user choice
+        };
+
+        match link_dev_folder(source).await {
+            Ok(link) => {
+                expect_change(link.clone());
+                mark_plugin_as_newly_installed(link);
+                show_success("toast-dev-linked", &[]);
+                trigger_refresh();
+            }
+            Err(e) => {
+                let error_msg = show_error("toast-dev-link-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+pub fn unlink_dev_folder_with_feedback(link: PathBuf) {
+    spawn(async move {
+        match unlink_dev_folder(link.clone()).await {
+            Ok(()) => {
+                expect_change(link);
+                show_success("toast-dev-unlinked", &[]);
+                trigger_refresh();
+            }
+            Err(e) => {
+                let error_msg = show_error("toast-dev-unlink-failed", &[("error", &e.to_string())]);
+                log::error!("{}", error_msg);
+            }
+        }
+    });
+}
+
+pub fn open_log_folder() {
+    let Some(dir) = log_dir() else {
+        show_error("error-no-log-folder", &[]);
+        return;
+    };
+
+    // The folder only exists once something was logged to a file
+    let result = std::fs::create_dir_all(&dir)
+        .map_err(|e| FileOperationError::extract(&dir, e))
+        .and_then(|_| open_in_default_app(&dir));
+
+    if let Err(e) = result {
+        log::error!("Failed to open log folder {:?}: {}", dir, e);
+        show_error("toast-error", &[("error", &e.to_string())]);
+    }
+}
+
+// Opens a dialog where this kind of dialog was last used and remembers the new choice
+// Lints the archive before anything is extracted; findings are shown with an "Install anyway" choice
+async fn confirm_validation(zxp_path: &Path) -> bool {
+    let path = zxp_path.to_path_buf();
+    let Ok(report) = run_blocking(move || if path.is_dir() { validate_folder(&path) } else { validate_zxp(&path) }).await else {
+        return true; // Unreadable archives get install_zxp's usual error
+    };
+    if report.is_clean() {
+        return true;
+    }
+    log::warn!(
+        "Validation of {:?}: {} errors, {} warnings",
+        zxp_path,
+        report.count(Severity::Error),
+        report.count(Severity::Warning)
+    );
+
+    let file_name = zxp_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let install_anyway = t("validation-install-anyway");
+    let result = MessageDialog::new()
+        .set_level(if report.count(Severity::Error) > 0 { MessageLevel::Error } else { MessageLevel::Warning })
+        .set_title(t("validation-title"))
+        .set_description(validation_summary(&report, &file_name))
+        .set_buttons(MessageButtons::OkCancelCustom(install_anyway.clone(), t("validation-cancel")))
+        .show();
+    result == MessageDialogResult::Ok || result == MessageDialogResult::Custom(install_anyway)
+}
+
+fn validation_summary(report: &ValidationReport, file_name: &str) -> String {
+    const SHOWN_FINDINGS: usize = 12; // Keeps the dialog on screen for archives with hundreds of bad entries
+
+    let mut lines = vec![t_args(
+        "validation-body",
+        &[
+            ("file", file_name),
+            ("errors", &report.count(Severity::Error).to_string()),
+            ("warnings", &report.count(Severity::Warning).to_string()),
+        ],
+    )];
+    // Errors first - they are the ones that stop the panel from loading
+    let ordered = [Severity::Error, Severity::Warning]
+        .into_iter()
+        .flat_map(|severity| report.findings.iter().filter(move |finding| finding.severity == severity));
+    for finding in ordered.take(SHOWN_FINDINGS) {
+        let args: Vec<(&str, &str)> = finding.args.iter().map(|(name, value)| (*name, value.as_str())).collect();
+        let marker = if finding.severity == Severity::Error { "✖" } else { "⚠" };
+        lines.push(format!("{} {}", marker, t_args(finding.message_key, &args)));
+    }
+    if report.findings.len() > SHOWN_FINDINGS {
+        lines.push(t_args("validation-more", &[("count", &(report.findings.len() - SHOWN_FINDINGS).to_string())]));
+    }
+    lines.join("\n")
+}
+
+// Risky calls in the scripts, with an "Install anyway" choice; a clean scan only gets a toast
+async fn confirm_script_scan(zxp_path: &Path) -> bool {
+    let path = zxp_path.to_path_buf();
+    let Ok(report) = run_blocking(move || if path.is_dir() { scan_folder(&path) } else { scan_zxp(&path) }).await else {
+        return true; // Unreadable archives get install_zxp's usual error
+    };
+    if report.is_clean() {
+        show_info("toast-scan-clean", &[("count", &report.files_scanned.to_string())]);
+        return true;
+    }
+    log::warn!(
+        "Script scan of {:?}: {} high and {} medium risk findings",
+        zxp_path,
+        report.count(RiskLevel::High),
+        report.count(RiskLevel::Medium)
+    );
+
+    let file_name = zxp_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let install_anyway = t("validation-install-anyway");
+    let result = MessageDialog::new()
+        .set_level(if report.level() == Some(RiskLevel::High) { MessageLevel::Error } else { MessageLevel::Warning })
+        .set_title(t("scan-title"))
+        .set_description(script_scan_summary(&report, &file_name))
+        .set_buttons(MessageButtons::OkCancelCustom(install_anyway.clone(), t("validation-cancel")))
+        .show();
+    result == MessageDialogResult::Ok || result == MessageDialogResult::Custom(install_anyway)
+}
+
+fn script_scan_summary(report: &ScanReport, file_name: &str) -> String {
+    const SHOWN_FINDINGS: usize = 12;
+
+    let mut lines = vec![t_args(
+        "scan-body",
+        &[
+            ("file", file_name),
+            ("scripts", &report.files_scanned.to_string()),
+            ("high", &report.count(RiskLevel::High).to_string()),
+            ("medium", &report.count(RiskLevel::Medium).to_string()),
+        ],
+    )];
+    let ordered = [RiskLevel::High, RiskLevel::Medium]
+        .into_iter()
+        .flat_map(|level| report.findings.iter().filter(move |finding| finding.risk.level() == level));
+    for finding in ordered.take(SHOWN_FINDINGS) {
+        let location = match finding.line {
+            0 => finding.file.clone(),
+            line => format!("{}:{}", finding.file, line),
+        };
+        let marker = if finding.risk.level() == RiskLevel::High { "✖" } else { "⚠" };
+        let args = [("risk", t(finding.risk.label_key())), ("location", location), ("count", finding.occurrences.to_string())];
+        let args: Vec<(&str, &str)> = args.iter().map(|(name, value)| (*name, value.as_str())).collect();
+        lines.push(format!("{} {}", marker, t_args("scan-finding", &args)));
+    }
+    if report.findings.len() > SHOWN_FINDINGS {
+        lines.push(t_args("validation-more", &[("count", &(report.findings.len() - SHOWN_FINDINGS).to_string())]));
+    }
+    lines.join("\n")
+}
+
+fn finish_install(zxp_path: &Path, installed: InstalledPlugin) {
+    log::info!("ZXP installation successful: {} {}", installed.info.bundle_id, installed.info.version);
+    telemetry::record(TelemetryEvent::InstallSucceeded);
+    remember_recent_install(zxp_path);
+    expect_change(installed.path.clone());
+    remind_restart(parse_host_list(&installed.path.join("CSXS").join("manifest.xml")).unwrap_or_default());
+    mark_plugin_as_newly_installed(installed.path);
+    let message = show_success("toast-plugin-installed", &[("name", &installed.info.name), ("version", &installed.info.version)]);
+    notify_when_away(&message);
+    trigger_refresh();
+}
+
+// Returns the error for the install queue's row
+fn report_install_failure(e: FileOperationError) -> String {
+    telemetry::record(TelemetryEvent::InstallFailed);
+    let error_msg = show_error("toast-install-failed", &[("error", &e.to_string())]);
+    log::error!("{}", error_msg);
+    notify_when_away(&error_msg);
+    e.to_string()
+}
+
+// The all-users folder needs admin rights - retry elevated, or install for the current user only
+// Ways past a folder the current user can't write to
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum PermissionRemedy {
+    Elevate,
+    UserRoot, // Installs only: the current user's extensions folder
+    OpenFolder,
+}
+
+// Permission errors the preflight couldn't foresee, e.g. a read-only file inside the installed copy
+async fn retry_install_with_permission(zxp_path: PathBuf) -> InstallOutcome {
+    match ask_permission_remedy(t("elevation-install-body"), true) {
+        Some(PermissionRemedy::OpenFolder) => {
+            open_for_permissions(Path::new(SYSTEM_EXTENSIONS_PATH));
+            InstallOutcome::Cancelled
+        }
+        Some(remedy) => install_with_remedy(zxp_path, remedy).await,
+        None => {
+            log::info!("Install of {:?} cancelled at the permission prompt", zxp_path);
+            InstallOutcome::Cancelled
+        }
+    }
+}
+
+// rfd dialogs fit three buttons, one of them Cancel: installs offer administrator rights where the
+// OS can prompt for them and opening the folder where it can't; removals offer both
+fn ask_permission_remedy(body: String, install: bool) -> Option<PermissionRemedy> {
+    let as_admin = t(if install { "elevation-install-admin" } else { "permission-remove-admin" });
+    let for_me = t("elevation-install-user");
+    let open_folder = t("permission-open-folder");
+    let buttons = match (install, elevation_available()) {
+        (true, true) => MessageButtons::YesNoCancelCustom(as_admin.clone(), for_me.clone(), t("elevation-cancel")),
+        (true, false) => MessageButtons::YesNoCancelCustom(for_me.clone(), open_folder.clone(), t("elevation-cancel")),
+        (false, true) => MessageButtons::YesNoCancelCustom(as_admin.clone(), open_folder.clone(), t("elevation-cancel")),
+        (false, false) => MessageButtons::OkCancelCustom(open_folder.clone(), t("elevation-cancel")),
+    };
+    let choice = MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(t("elevation-install-title"))
+        .set_description(body)
+        .set_buttons(buttons)
+        .show();
+
+    match choice {
+        MessageDialogResult::Custom(label) if label == as_admin => Some(PermissionRemedy::Elevate),
+        MessageDialogResult::Custom(label) if label == for_me => Some(PermissionRemedy::UserRoot),
+        MessageDialogResult::Custom(label) if label == open_folder => Some(PermissionRemedy::OpenFolder),
+        _ => None,
+    }
+}
+
+async fn install_with_remedy(zxp_path: PathBuf, remedy: PermissionRemedy) -> InstallOutcome {
+    let result = match remedy {
+        PermissionRemedy::Elevate => {
+            let path = zxp_path.clone();
+            run_blocking(move || if path.is_dir() { install_folder_elevated(&path) } else { install_zxp_elevated(&path) }).await
+        }
+        PermissionRemedy::UserRoot => match ExtensionRoot::User.path() {
+            Some(root) if zxp_path.is_dir() => install_folder_into(zxp_path.clone(), root).await,
+            Some(root) => install_zxp_into(zxp_path.clone(), root).await,
+            None => Err(FileOperationError::FileNotFound(PathBuf::from(CEP_EXTENSIONS_PATH))),
+        },
+        PermissionRemedy::OpenFolder => return InstallOutcome::Cancelled,
+    };
+    match result {
+        Ok(installed) => {
+            finish_install(&zxp_path, installed);
+            InstallOutcome::Installed
+        }
+        Err(e) => InstallOutcome::Failed(report_install_failure(e)),
+    }
+}
+
+// Removes the plugin unless its folder refuses writes; then the user picks a way around it, and
+// None means they cancelled
+async fn remove_with_preflight(plugin_path: &Path) -> Option<Result<(), FileOperationError>> {
+    let target = plugin_path.to_path_buf();
+    let Some(folder) = run_blocking(move || unwritable_folder(&target)).await else {
+        return Some(remove_plugin(plugin_path.to_path_buf()).await);
+    };
+    let body = t_args("permission-remove-body", &[("path", &folder.display().to_string())]);
+    match ask_permission_remedy(body, false) {
+        Some(PermissionRemedy::Elevate) => {
+            let path = plugin_path.to_path_buf();
+            Some(run_blocking(move || remove_plugin_elevated(&path)).await)
+        }
+        Some(PermissionRemedy::OpenFolder) => {
+            open_for_permissions(&folder);
+            None
+        }
+        _ => None,
+    }
+}
+
+// Shows the folder so its owner or sharing settings can be changed; the user retries afterwards
+fn open_for_permissions(folder: &Path) {
+    let existing = folder.ancestors().find(|dir| dir.is_dir()).unwrap_or(folder);
+    match open_in_default_app(existing) {
+        Ok(()) => {
+            show_info("toast-permission-folder-opened", &[("path", &existing.display().to_string())]);
+        }
+        Err(e) => {
+            show_error("toast-error", &[("error", &e.to_string())]);
+        }
+    }
+}
+
+fn confirm_elevated_removal() -> bool {
+    MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(t("elevation-remove-title"))
+        .set_description(t("elevation-remove-body"))
+        .set_buttons(MessageButtons::YesNo)
+        .show()
+        == MessageDialogResult::Yes
+}
+
+// Preview of every leftover the deep clean deletes; true to go ahead
+fn confirm_deep_clean(name: &str, leftovers: &[OrphanedEntry]) -> bool {
+    const SHOWN_LEFTOVERS: usize = 12;
+    let preview = if leftovers.is_empty() {
+        t("confirm-deep-clean-none")
+    } else {
+        let mut lines: Vec<String> = leftovers
+            .iter()
+            .take(SHOWN_LEFTOVERS)
+            .map(|entry| format!("{} ({})", entry.path.display(), format_size(entry.size)))
+            .collect();
+        if leftovers.len() > SHOWN_LEFTOVERS {
+            lines.push(t_args("confirm-deep-clean-more", &[("count", &(leftovers.len() - SHOWN_LEFTOVERS).to_string())]));
+        }
+        let total: u64 = leftovers.iter().map(|entry| entry.size).sum();
+        format!("{}\n\n{}", t_args("confirm-deep-clean-found", &[("count", &leftovers.len().to_string()), ("size", &format_size(total))]), lines.join("\n"))
+    };
+    MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(t("confirm-deep-clean-title"))
+        .set_description(format!("{}\n\n{}", t_args("confirm-deep-clean-body", &[("name", name)]), preview))
+        .set_buttons(MessageButtons::YesNo)
+        .show()
+        == MessageDialogResult::Yes
+}
+
+fn report_removal(plugin_path: PathBuf, result: Result<(), FileOperationError>) {
+    match result {
+        Ok(_) => {
+            log::info!("Plugin removed successfully: {:?}", plugin_path);
+            remind_restart(plugin_hosts(&plugin_path)); // Targets stay known until the rescan
+            expect_change(plugin_path);
+            show_success("toast-plugin-removed", &[]);
+            trigger_refresh();
+        }
+        Err(e) => {
+            let error_msg = show_error("toast-remove-failed", &[("error", &e.to_string())]);
+            log::error!("{}", error_msg);
+        }
+    }
+}
+
+// Installing over a newer copy is usually a stale download; true when nothing newer is installed
+async fn confirm_downgrade(source: &Path) -> bool {
+    let path = source.to_path_buf();
+    let Ok(incoming) = run_blocking(move || source_plugin_info(&path)).await else {
+        return true; // Validation already reported unreadable manifests
+    };
+    let installed = PLUGINS.peek().as_ref().and_then(|plugins| {
+        plugins
+            .iter()
+            .filter(|plugin| plugin.bundle_id == incoming.bundle_id && plugin.plugin_type != PluginType::Legacy)
+            .map(|plugin| plugin.version.clone())
+            .max_by(|a, b| compare_versions(a, b))
+    });
+    let Some(installed) = installed.filter(|installed| compare_versions(&incoming.version, installed) == Ordering::Less) else {
+        return true;
+    };
+    log::warn!("{} {} would replace the newer {}", incoming.bundle_id, incoming.version, installed);
+
+    MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(t("confirm-downgrade-title"))
+        .set_description(t_args(
+            "confirm-downgrade-body",
+            &[("name", &incoming.name), ("installed", &installed), ("version", &incoming.version)],
+        ))
+        .set_buttons(MessageButtons::YesNo)
+        .show()
+        == MessageDialogResult::Yes
+}
+
+// Running host apps would keep the old files loaded or pick up a half-copied folder; true to go ahead
+async fn confirm_hosts_closed(hosts: Vec<HostRequirement>, name: &str, body_key: &'static str) -> bool {
+    let running = run_blocking(move || running_targeted_hosts(&hosts)).await;
+    if running.is_empty() {
+        return true;
+    }
+    let apps = host_names(&running);
+    log::warn!("{} running while changing {}", apps, name);
+
+    let proceed = t("running-hosts-continue");
+    let result = MessageDialog::new()
+        .set_level(MessageLevel::Warning)
+        .set_title(t("running-hosts-title"))
+        .set_description(t_args(body_key, &[("apps", &apps), ("name", name)]))
+        .set_buttons(MessageButtons::OkCancelCustom(proceed.clone(), t("running-hosts-cancel")))
+        .show();
+    result == MessageDialogResult::Ok || result == MessageDialogResult::Custom(proceed)
+}
+
+// After an install or removal, for the hosts still running that only see it after a restart
+fn remind_restart(hosts: Vec<HostRequirement>) {
+    spawn(async move {
+        let running = run_blocking(move || running_targeted_hosts(&hosts)).await;
+        if !running.is_empty() {
+            show_info("toast-restart-hosts", &[("apps", &host_names(&running))]);
+        }
+    });
+}
+
+fn host_names(codes: &[&str]) -> String {
+    codes.iter().map(|code| host_display_name(code)).collect::<Vec<_>>().join(", ")
+}
+
+// "Photoshop (3), other apps (1)" - engines whose host can't be told count as other apps
+fn engine_hosts(processes: &[EngineProcess]) -> String {
+    let mut counts: Vec<(Option<&str>, usize)> = Vec::new();
+    for process in processes {
+        match counts.iter_mut().find(|(host, _)| *host == process.host) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((process.host, 1)),
+        }
+    }
+    counts.sort_by_key(|(host, _)| host.is_none()); // Named apps first
+    counts
+        .into_iter()
+        .map(|(host, count)| {
+            let name = host.map(|code| host_display_name(code).to_string()).unwrap_or_else(|| t("cep-engine-other-host"));
+            format!("{} ({})", name, count)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Names panel storage can go by: the bundle ID and each extension ID in the manifest
+fn plugin_hosts(plugin_path: &Path) -> Vec<HostRequirement> {
+    PLUGIN_TARGETS.peek().get(plugin_path).map(|targets| targets.hosts.clone()).unwrap_or_default()
+}
+
+fn plugin_label(plugin_path: &Path) -> String {
+    PLUGINS
+        .peek()
+        .as_ref()
+        .and_then(|plugins| plugins.iter().find(|plugin| plugin.path == plugin_path).map(|plugin| plugin.name.clone()))
+        .unwrap_or_else(|| file_label(plugin_path))
+}
+
+fn file_label(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+fn pick_with_memory(
+    kind: DialogKind,
+    pick: impl FnOnce(Option<PathBuf>) -> Result<PathBuf, FileOperationError>,
+) -> Result<PathBuf, FileOperationError> {
+    let chosen = pick(last_directory(kind))?;
+    remember_directory(kind, &chosen);
+    Ok(chosen)
+}
@@ -0,0 +1,79 @@
+use std::path::PathBuf;
+
+use crate::file_operations::{self, FileOperationError, InstallPreview, InstalledPlugin, ZxpInspection};
+
+// Async facade over the synchronous filesystem work. Installs, removals and size walks can take
+// seconds for large extensions, so they run on tokio's blocking pool instead of the UI runtime.
+
+// Runs `task` on a worker thread; a panic in it resumes here, as if it had run inline
+pub async fn run_blocking<T: Send + 'static>(task: impl FnOnce() -> T + Send + 'static) -> T {
+    match tokio::task::spawn_blocking(task).await {
+        Ok(value) => value,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => panic!("Blocking task was cancelled: {}", e), // Only happens while the runtime shuts down
+    }
+}
+
+pub async fn install_zxp(zxp_path: PathBuf) -> Result<InstalledPlugin, FileOperationError> {
+    run_blocking(move || file_operations::install_zxp(&zxp_path)).await
+}
+
+pub async fn install_zxp_into(zxp_path: PathBuf, root: PathBuf) -> Result<InstalledPlugin, FileOperationError> {
+    run_blocking(move || file_operations::install_zxp_into(&zxp_path, &root)).await
+}
+
+pub async fn install_folder(source: PathBuf) -> Result<InstalledPlugin, FileOperationError> {
+    run_blocking(move || file_operations::install_folder(&source)).await
+}
+
+pub async fn install_folder_into(source: PathBuf, root: PathBuf) -> Result<InstalledPlugin, FileOperationError> {
+    run_blocking(move || file_operations::install_folder_into(&source, &root)).await
+}
+
+pub async fn revert_to_version(plugin_path: PathBuf, archived: PathBuf) -> Result<InstalledPlugin, FileOperationError> {
+    run_blocking(move || file_operations::revert_to_version(&plugin_path, &archived)).await
+}
+
+pub async fn preview_install(zxp_path: PathBuf) -> Result<InstallPreview, FileOperationError> {
+    run_blocking(move || file_operations::preview_install(&zxp_path)).await
+}
+
+pub async fn inspect_zxp(zxp_path: PathBuf) -> Result<ZxpInspection, FileOperationError> {
+    run_blocking(move || file_operations::inspect_zxp(&zxp_path)).await
+}
+
+pub async fn remove_plugin(plugin_path: PathBuf) -> Result<(), FileOperationError> {
+    run_blocking(move || file_operations::remove_plugin(&plugin_path)).await
+}
+
+pub async fn import_plugin_folder(plugin_path: PathBuf) -> Result<PathBuf, FileOperationError> {
+    run_blocking(move || file_operations::import_plugin_folder(&plugin_path)).await
+}
+
+pub async fn link_dev_folder(source: PathBuf) -> Result<PathBuf, FileOperationError> {
+    run_blocking(move || file_operations::link_dev_folder(&source)).await
+}
+
+pub async fn unlink_dev_folder(link: PathBuf) -> Result<(), FileOperationError> {
+    run_blocking(move || file_operations::unlink_dev_folder(&link)).await
+}
+
+pub async fn export_plugin_as_zxp(plugin_path: PathBuf, destination: PathBuf) -> Result<(), FileOperationError> {
+    run_blocking(move || file_operations::export_plugin_as_zxp(&plugin_path, &destination)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_blocking_returns_and_propagates_panics() {
+        let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        assert_eq!(runtime.block_on(run_blocking(|| 40 + 2)), 42);
+
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            runtime.block_on(run_blocking(|| panic!("boom")))
+        }));
+        assert!(panicked.is_err());
+    }
+}
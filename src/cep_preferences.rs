@@ -0,0 +1,206 @@
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::file_operations::FileOperationError;
+
+// Per-version CEP flags in the com.adobe.CSXS.N preference domains: plists read and written
+// with `defaults` on macOS, HKCU\Software\Adobe\CSXS.N values on Windows
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CepFlag {
+    PlayerDebugMode, // Loads unsigned extensions
+    LogLevel,        // CEP and CEF log verbosity, 0-6
+}
+
+impl CepFlag {
+    pub fn key(&self) -> &'static str {
+        match self {
+            CepFlag::PlayerDebugMode => "PlayerDebugMode",
+            CepFlag::LogLevel => "LogLevel",
+        }
+    }
+}
+
+// LogLevel values as CEP defines them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CepLogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+    All,
+}
+
+impl CepLogLevel {
+    pub const ALL: [CepLogLevel; 7] = [
+        CepLogLevel::Off,
+        CepLogLevel::Error,
+        CepLogLevel::Warn,
+        CepLogLevel::Info,
+        CepLogLevel::Debug,
+        CepLogLevel::Trace,
+        CepLogLevel::All,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            CepLogLevel::Off => "0",
+            CepLogLevel::Error => "1",
+            CepLogLevel::Warn => "2",
+            CepLogLevel::Info => "3",
+            CepLogLevel::Debug => "4",
+            CepLogLevel::Trace => "5",
+            CepLogLevel::All => "6",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<CepLogLevel> {
+        CepLogLevel::ALL.into_iter().find(|level| level.code() == code)
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            CepLogLevel::Off => "cep-log-off",
+            CepLogLevel::Error => "cep-log-error",
+            CepLogLevel::Warn => "cep-log-warn",
+            CepLogLevel::Info => "cep-log-info",
+            CepLogLevel::Debug => "cep-log-debug",
+            CepLogLevel::Trace => "cep-log-trace",
+            CepLogLevel::All => "cep-log-all",
+        }
+    }
+}
+
+// The flags of one com.adobe.CSXS.N domain; unset flags are None
+#[derive(Debug, Clone, PartialEq)]
+pub struct CepPreferences {
+    pub version: u32,
+    pub debug_mode: bool,
+    pub log_level: Option<CepLogLevel>,
+}
+
+pub fn preferences_supported() -> bool {
+    cfg!(any(target_os = "macos", target_os = "windows"))
+}
+
+// Every CSXS version that has a preference domain, oldest first
+pub fn detect_csxs_versions() -> Vec<u32> {
+    let mut versions: Vec<u32> = if cfg!(target_os = "windows") {
+        Command::new("reg")
+            .args(["query", "HKCU\\Software\\Adobe"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| {
+                String::from_utf8_lossy(&output.stdout)
+                    .lines()
+                    .filter_map(|line| line.trim().rsplit('\\').next().and_then(csxs_version))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        let folder = dirs::home_dir().unwrap_or_default().join("Library/Preferences");
+        fs::read_dir(folder)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.file_name().to_string_lossy().to_string();
+                name.strip_prefix("com.adobe.").and_then(|rest| rest.strip_suffix(".plist")).and_then(csxs_version)
+            })
+            .collect()
+    };
+    versions.sort_unstable();
+    versions.dedup();
+    versions
+}
+
+pub fn read_preferences(version: u32) -> CepPreferences {
+    CepPreferences {
+        version,
+        debug_mode: read_flag(version, CepFlag::PlayerDebugMode).as_deref() == Some("1"),
+        log_level: read_flag(version, CepFlag::LogLevel).as_deref().and_then(CepLogLevel::from_code),
+    }
+}
+
+pub fn read_all_preferences() -> Vec<CepPreferences> {
+    detect_csxs_versions().into_iter().map(read_preferences).collect()
+}
+
+pub fn read_flag(version: u32, flag: CepFlag) -> Option<String> {
+    let output = if cfg!(target_os = "windows") {
+        Command::new("reg").args(["query", &registry_key(version), "/v", flag.key()]).output()
+    } else {
+        Command::new("defaults").args(["read", &domain(version), flag.key()]).output()
+    };
+    output
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| flag_value(&String::from_utf8_lossy(&output.stdout)))
+}
+
+pub fn write_flag(version: u32, flag: CepFlag, value: &str) -> Result<(), FileOperationError> {
+    let status = if cfg!(target_os = "windows") {
+        Command::new("reg")
+            .args(["add", &registry_key(version), "/v", flag.key(), "/t", "REG_SZ", "/d", value, "/f"])
+            .status()
+    } else {
+        Command::new("defaults").args(["write", &domain(version), flag.key(), value]).status()
+    };
+    match status {
+        Ok(status) if status.success() => {
+            log::info!("Set {}={} for CSXS {}", flag.key(), value, version);
+            Ok(())
+        }
+        _ => Err(FileOperationError::PermissionDenied(preferences_location(version))),
+    }
+}
+
+pub fn registry_key(version: u32) -> String {
+    format!("HKCU\\Software\\Adobe\\CSXS.{}", version)
+}
+
+// Where the flags are stored, for error messages
+pub fn preferences_location(version: u32) -> PathBuf {
+    if cfg!(target_os = "windows") {
+        PathBuf::from(registry_key(version))
+    } else {
+        dirs::home_dir().unwrap_or_default().join("Library/Preferences").join(format!("{}.plist", domain(version)))
+    }
+}
+
+// Helper functions
+fn domain(version: u32) -> String {
+    format!("com.adobe.CSXS.{}", version)
+}
+
+// "CSXS.11" -> 11
+fn csxs_version(name: &str) -> Option<u32> {
+    name.strip_prefix("CSXS.").and_then(|version| version.parse().ok())
+}
+
+// `defaults read` prints just the value, `reg query` ends its line with it
+fn flag_value(output: &str) -> Option<String> {
+    output.split_whitespace().last().map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_output_and_domain_names() {
+        assert_eq!(flag_value("1\n").as_deref(), Some("1"));
+        assert_eq!(flag_value("0\n").as_deref(), Some("0"));
+        assert_eq!(
+            flag_value("\r\nHKEY_CURRENT_USER\\Software\\Adobe\\CSXS.11\r\n    PlayerDebugMode    REG_SZ    1\r\n").as_deref(),
+            Some("1")
+        );
+        assert_eq!(flag_value(""), None);
+        assert_eq!(csxs_version("CSXS.12"), Some(12));
+        assert_eq!(csxs_version("CSXS.Extensions"), None);
+    }
+}
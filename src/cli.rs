@@ -0,0 +1,245 @@
+use std::path::{Path, PathBuf};
+
+use crate::compliance::{build_compliance_report, render_compliance_report};
+use crate::data_operations::{Plugin, scan_cep_plugins};
+use crate::file_operations::{install_zxp, remove_plugin};
+use crate::plugin_list::{ListFormat, render_plugin_list};
+use crate::policy::load_policy;
+use crate::settings::load_settings;
+use crate::update_check::CURRENT_VERSION;
+
+const USAGE: &str = "Usage:
+  zxp-manager                      Open the window
+  zxp-manager <file.zxp>…          Open the window with the files waiting for approval to install
+  zxp-manager list [--format F]    List installed extensions as text (tab-separated), json or csv
+  zxp-manager compliance [-f F]    Check extensions against the admin policy, exit 1 if not compliant
+  zxp-manager install <file.zxp>…  Install one or more ZXP files
+  zxp-manager remove <id|path>…    Remove extensions by bundle ID or folder path
+  zxp-manager help                 Show this help
+  zxp-manager version              Show the version";
+
+// Exit codes for scripts
+const EXIT_OK: i32 = 0;
+const EXIT_FAILED: i32 = 1;
+const EXIT_USAGE: i32 = 2;
+
+// Returns None when the GUI should start, otherwise the process exit code
+pub fn run(args: &[String]) -> Option<i32> {
+    let (command, rest) = match args.split_first() {
+        None => return None,
+        Some((first, _)) if first.starts_with("-psn") => return None, // Finder launch argument on older macOS
+        Some(_) if launch_files(args).is_some() => return None, // Opened through a file association
+        Some((first, rest)) => (first.as_str(), rest),
+    };
+
+    // Settings decide the language of error messages
+    load_settings();
+
+    let exit_code = match command {
+        "list" | "ls" => list(rest),
+        "compliance" => compliance(rest),
+        "install" | "i" => install(rest),
+        "remove" | "rm" => remove(rest),
+        "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            EXIT_OK
+        }
+        "version" | "--version" | "-V" => {
+            println!("zxp-manager {}", CURRENT_VERSION);
+            EXIT_OK
+        }
+        unknown => {
+            eprintln!("Unknown command: {}\n\n{}", unknown, USAGE);
+            EXIT_USAGE
+        }
+    };
+
+    Some(exit_code)
+}
+
+// `zxp-manager Panel.zxp`, the way Explorer and Linux file managers open an associated file. Only
+// when every argument is an existing .zxp, so a mistyped subcommand still gets the usage text
+pub fn launch_files(args: &[String]) -> Option<Vec<PathBuf>> {
+    let files: Vec<PathBuf> = args.iter().map(PathBuf::from).collect();
+    let all_zxp = files.iter().all(|file| {
+        file.is_file() && file.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("zxp"))
+    });
+    (!files.is_empty() && all_zxp).then_some(files)
+}
+
+fn list(options: &[String]) -> i32 {
+    let format = match parse_list_format(options) {
+        Ok(format) => format,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+
+    match scan_cep_plugins() {
+        Ok(plugins) => {
+            print!("{}", render_plugin_list(&plugins, format));
+            EXIT_OK
+        }
+        Err(e) => {
+            eprintln!("Failed to scan extensions: {}", e);
+            EXIT_FAILED
+        }
+    }
+}
+
+fn compliance(options: &[String]) -> i32 {
+    let format = match parse_list_format(options) {
+        Ok(format) => format,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+
+    match scan_cep_plugins() {
+        Ok(plugins) => {
+            let report = build_compliance_report(&load_policy(), &plugins);
+            print!("{}", render_compliance_report(&report, format));
+            if report.compliant { EXIT_OK } else { EXIT_FAILED }
+        }
+        Err(e) => {
+            eprintln!("Failed to scan extensions: {}", e);
+            EXIT_FAILED
+        }
+    }
+}
+
+fn install(files: &[String]) -> i32 {
+    if files.is_empty() {
+        eprintln!("install needs at least one .zxp file\n\n{}", USAGE);
+        return EXIT_USAGE;
+    }
+
+    let mut exit_code = EXIT_OK;
+    for file in files {
+        match install_zxp(Path::new(file)) {
+            Ok(installed) => println!(
+                "Installed {} {} from {} to {}",
+                installed.info.name,
+                installed.info.version,
+                file,
+                installed.path.display()
+            ),
+            Err(e) => {
+                eprintln!("Failed to install {}: {}", file, e);
+                exit_code = EXIT_FAILED;
+            }
+        }
+    }
+    exit_code
+}
+
+fn remove(targets: &[String]) -> i32 {
+    if targets.is_empty() {
+        eprintln!("remove needs at least one bundle ID or path\n\n{}", USAGE);
+        return EXIT_USAGE;
+    }
+
+    let plugins = match scan_cep_plugins() {
+        Ok(plugins) => plugins,
+        Err(e) => {
+            eprintln!("Failed to scan extensions: {}", e);
+            return EXIT_FAILED;
+        }
+    };
+
+    let mut exit_code = EXIT_OK;
+    for target in targets {
+        let matches = find_plugins(&plugins, target);
+        let plugin = match matches.as_slice() {
+            [plugin] => plugin,
+            [] => {
+                eprintln!("No installed extension matches {}", target);
+                exit_code = EXIT_FAILED;
+                continue;
+            }
+            several => {
+                // Same bundle ID in more than one root - make the caller pick a folder
+                eprintln!("{} is installed in several places, remove by path instead:", target);
+                for plugin in several {
+                    eprintln!("  {}", plugin.path.display());
+                }
+                exit_code = EXIT_FAILED;
+                continue;
+            }
+        };
+
+        match remove_plugin(&plugin.path) {
+            Ok(_) => println!("Removed {} from {}", plugin.bundle_id, plugin.path.display()),
+            Err(e) => {
+                eprintln!("Failed to remove {}: {}", plugin.bundle_id, e);
+                exit_code = EXIT_FAILED;
+            }
+        }
+    }
+    exit_code
+}
+
+// Helper functions
+fn parse_list_format(options: &[String]) -> Result<ListFormat, String> {
+    let value = match options {
+        [] => return Ok(ListFormat::Text),
+        [flag, value] if flag == "--format" || flag == "-f" => value.as_str(),
+        [flag] if flag.starts_with("--format=") => &flag["--format=".len()..],
+        [flag] if flag == "--format" || flag == "-f" => return Err("--format needs a value: text, json or csv".to_string()),
+        _ => return Err(format!("Unexpected arguments: {}", options.join(" "))),
+    };
+    ListFormat::from_name(value).ok_or_else(|| format!("Unknown format {}, expected text, json or csv", value))
+}
+
+fn find_plugins<'a>(plugins: &'a [Plugin], target: &str) -> Vec<&'a Plugin> {
+    let target_path = PathBuf::from(target);
+    plugins
+        .iter()
+        .filter(|plugin| plugin.bundle_id == target || plugin.path == target_path)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plugin(id: &str, path: &str) -> Plugin {
+        Plugin { path: PathBuf::from(path), ..Plugin::for_test(id, "1.0") }
+    }
+
+    #[test]
+    fn test_find_plugins_by_id_or_path() {
+        let plugins = vec![plugin("com.a", "/system/com.a"), plugin("com.a", "/user/com.a"), plugin("com.b", "/user/com.b")];
+
+        assert_eq!(find_plugins(&plugins, "com.a").len(), 2);
+        assert_eq!(find_plugins(&plugins, "/user/com.a"), vec![&plugins[1]]);
+        assert!(find_plugins(&plugins, "com.c").is_empty());
+    }
+
+    #[test]
+    fn test_parse_list_format() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(parse_list_format(&[]), Ok(ListFormat::Text));
+        assert_eq!(parse_list_format(&args(&["--format", "json"])), Ok(ListFormat::Json));
+        assert_eq!(parse_list_format(&args(&["--format=CSV"])), Ok(ListFormat::Csv));
+        assert!(parse_list_format(&args(&["--format", "xml"])).is_err());
+        assert!(parse_list_format(&args(&["--format"])).is_err());
+    }
+
+    #[test]
+    fn test_no_arguments_starts_gui() {
+        assert_eq!(run(&[]), None);
+        assert_eq!(run(&["-psn_0_12345".to_string()]), None);
+
+        let zxp = std::env::temp_dir().join(format!("zxpm-launch-{}.ZXP", std::process::id()));
+        std::fs::write(&zxp, b"").unwrap();
+        let opened = vec![zxp.to_string_lossy().to_string()];
+        assert_eq!(launch_files(&opened), Some(vec![zxp.clone()]));
+        assert_eq!(run(&opened), None);
+        assert_eq!(launch_files(&[opened[0].clone(), "lits".to_string()]), None);
+        std::fs::remove_file(&zxp).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::i18n::{t, t_args};
+
+// Local wall-clock time via libc - the app only needs calendar fields, not a date library
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalTime {
+    pub year: i32,
+    pub month: u8, // 1-12
+    pub day: u8,   // 1-31
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl LocalTime {
+    pub fn now() -> LocalTime {
+        LocalTime::from_unix(unix_now())
+    }
+
+    pub fn from_unix(timestamp: i64) -> LocalTime {
+        let timestamp = timestamp as libc::time_t;
+        let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+        unsafe { libc::localtime_r(&timestamp, &mut tm) };
+
+        LocalTime {
+            year: tm.tm_year + 1900,
+            month: (tm.tm_mon + 1) as u8,
+            day: tm.tm_mday as u8,
+            hour: tm.tm_hour as u8,
+            minute: tm.tm_min as u8,
+            second: tm.tm_sec as u8,
+        }
+    }
+
+    pub fn date(&self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    // "2025-04-10"
+    pub fn date_label(&self) -> String {
+        format!("{}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    // "14:03:22"
+    pub fn time_label(&self) -> String {
+        format!("{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+// Unix time of local midnight at the start of the given day - days past the month's end roll over, as in mktime
+pub fn local_midnight((year, month, day): (i32, u8, u8)) -> i64 {
+    let mut tm: libc::tm = unsafe { std::mem::zeroed() };
+    tm.tm_year = year - 1900;
+    tm.tm_mon = month as i32 - 1;
+    tm.tm_mday = day as i32;
+    tm.tm_isdst = -1; // Let the C library work out daylight saving time
+    unsafe { libc::mktime(&mut tm) as i64 }
+}
+
+// Parses "2025-04-10", the format of date_label and of HTML date inputs
+pub fn parse_date_label(label: &str) -> Option<(i32, u8, u8)> {
+    let mut parts = label.trim().splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month = parts.next()?.parse().ok().filter(|month| (1..=12).contains(month))?;
+    let day = parts.next()?.parse().ok().filter(|day| (1..=31).contains(day))?;
+    Some((year, month, day))
+}
+
+// "Today", "Yesterday", "3 days ago" - counted in local calendar days, so last night is yesterday
+pub fn relative_date_label(timestamp: i64, now: i64) -> String {
+    let midnight = |time: i64| local_midnight(LocalTime::from_unix(time).date());
+    // Rounded, since a day with a daylight saving change is 23 or 25 hours long
+    let days = (midnight(now) - midnight(timestamp) + 43_200).div_euclid(86_400);
+    let count = |value: i64| value.to_string();
+
+    match days {
+        ..=0 => t("age-today"), // Also for clocks slightly ahead of this one
+        1 => t("age-yesterday"),
+        2..=13 => t_args("age-days", &[("count", &count(days))]),
+        14..=59 => t_args("age-weeks", &[("count", &count(days / 7))]),
+        60..=364 => t_args("age-months", &[("count", &count(days / 30))]),
+        365..=729 => t("age-year"),
+        _ => t_args("age-years", &[("count", &count(days / 365))]),
+    }
+}
+
+pub fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_date_label() {
+        let now = local_midnight((2025, 4, 10)) + 10 * 3600; // 10:00
+        let hours_ago = |hours: i64| now - hours * 3600;
+
+        assert_eq!(relative_date_label(hours_ago(1), now), "Today");
+        assert_eq!(relative_date_label(hours_ago(11), now), "Yesterday"); // 23:00 the day before
+        assert_eq!(relative_date_label(hours_ago(3 * 24), now), "3 days ago");
+        assert_eq!(relative_date_label(hours_ago(20 * 24), now), "2 weeks ago");
+        assert_eq!(relative_date_label(hours_ago(400 * 24), now), "A year ago");
+        assert_eq!(relative_date_label(now + 60, now), "Today");
+    }
+}
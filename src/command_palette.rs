@@ -0,0 +1,168 @@
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+use crate::actions::{
+    export_diagnostics, export_plugin_list, inspect_zxp_from_picker, install_folder_from_picker, install_from_picker, restart_cep_engines,
+    toggle_debug_mode,
+};
+use crate::data_operations::Plugin;
+use crate::i18n::{t, t_args};
+use crate::message::trigger_refresh;
+use crate::navigation::{View, show_view};
+use crate::plugin_store::SELECTED_PLUGIN;
+use crate::shortcuts::{SHORTCUT_SHEET_OPEN, Shortcut, ShortcutAction};
+
+// Cmd/Ctrl+K palette: the app's actions, views and installed plugins in one list filtered by a
+// fuzzy match, so everything is a few keystrokes away
+
+// Rows shown at once; typing narrows the rest down
+const MAX_RESULTS: usize = 12;
+
+pub static COMMAND_PALETTE_OPEN: GlobalSignal<bool> = Signal::global(|| false);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteCommand {
+    Install,
+    InstallFolder,
+    InspectZxp,
+    Rescan,
+    ExportList,
+    ExportDiagnostics,
+    ToggleDebugMode,
+    RestartCep,
+    ShowShortcuts,
+    ShowView(View),
+    JumpToPlugin(PathBuf),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteEntry {
+    pub command: PaletteCommand,
+    pub label: String,
+    pub detail: String, // Shortcut for actions, bundle ID for plugins
+}
+
+pub fn toggle_command_palette() {
+    let open = COMMAND_PALETTE_OPEN();
+    *COMMAND_PALETTE_OPEN.write() = !open;
+}
+
+// Actions first, then views and plugins; with a query, best matches first
+pub fn palette_entries(query: &str, plugins: &[Plugin]) -> Vec<PaletteEntry> {
+    let actions = [
+        (PaletteCommand::Install, "palette-install", Some(ShortcutAction::OpenPicker)),
+        (PaletteCommand::InstallFolder, "palette-install-folder", None),
+        (PaletteCommand::InspectZxp, "palette-inspect-zxp", None),
+        (PaletteCommand::Rescan, "palette-rescan", Some(ShortcutAction::Rescan)),
+        (PaletteCommand::ExportList, "palette-export-list", None),
+        (PaletteCommand::ExportDiagnostics, "palette-export-diagnostics", None),
+        (PaletteCommand::ToggleDebugMode, "palette-toggle-debug-mode", None),
+        (PaletteCommand::RestartCep, "palette-restart-cep", None),
+        (PaletteCommand::ShowShortcuts, "palette-show-shortcuts", Some(ShortcutAction::ShowShortcuts)),
+    ];
+    let mut entries: Vec<PaletteEntry> = actions
+        .into_iter()
+        .map(|(command, key, shortcut)| PaletteEntry {
+            command,
+            label: t(key),
+            detail: shortcut.and_then(Shortcut::for_action).map(|shortcut| shortcut.display()).unwrap_or_default(),
+        })
+        .collect();
+    entries.extend(View::ALL.into_iter().map(|view| PaletteEntry {
+        command: PaletteCommand::ShowView(view),
+        label: t_args("palette-go-to", &[("view", &t(view.label_key()))]),
+        detail: String::new(),
+    }));
+
+    let query = query.trim();
+    if query.is_empty() {
+        entries.truncate(MAX_RESULTS);
+        return entries; // Plugins only show up once something is typed
+    }
+    entries.extend(plugins.iter().map(|plugin| PaletteEntry {
+        command: PaletteCommand::JumpToPlugin(plugin.path.clone()),
+        label: format!("{} {}", plugin.name, plugin.version),
+        detail: plugin.bundle_id.clone(),
+    }));
+
+    let mut scored: Vec<(u32, PaletteEntry)> = entries
+        .into_iter()
+        .filter_map(|entry| {
+            let score = fuzzy_score(query, &entry.label).max(fuzzy_score(query, &entry.detail))?;
+            Some((score, entry))
+        })
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score)); // Stable, so ties keep the order above
+    scored.into_iter().take(MAX_RESULTS).map(|(_, entry)| entry).collect()
+}
+
+// None unless every query character appears in order; consecutive runs and word starts score higher
+pub fn fuzzy_score(query: &str, text: &str) -> Option<u32> {
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let mut score = 0;
+    let mut position = 0;
+    let mut previous_match: Option<usize> = None;
+
+    for wanted in query.to_lowercase().chars().filter(|c| !c.is_whitespace()) {
+        let index = position + text[position..].iter().position(|c| *c == wanted)?;
+        score += 1;
+        if previous_match.is_some_and(|previous| previous + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+        previous_match = Some(index);
+        position = index + 1;
+    }
+    Some(score)
+}
+
+pub fn run_command(command: PaletteCommand) {
+    *COMMAND_PALETTE_OPEN.write() = false;
+    log::debug!("Command palette: {:?}", command);
+
+    match command {
+        PaletteCommand::Install => install_from_picker(),
+        PaletteCommand::InstallFolder => install_folder_from_picker(),
+        PaletteCommand::InspectZxp => inspect_zxp_from_picker(),
+        PaletteCommand::Rescan => trigger_refresh(),
+        PaletteCommand::ExportList => export_plugin_list(),
+        PaletteCommand::ExportDiagnostics => export_diagnostics(),
+        PaletteCommand::ToggleDebugMode => toggle_debug_mode(),
+        PaletteCommand::RestartCep => restart_cep_engines(),
+        PaletteCommand::ShowShortcuts => *SHORTCUT_SHEET_OPEN.write() = true,
+        PaletteCommand::ShowView(view) => show_view(view),
+        PaletteCommand::JumpToPlugin(path) => jump_to_plugin(path),
+    }
+}
+
+// Helper functions
+fn jump_to_plugin(path: PathBuf) {
+    show_view(View::Plugins);
+    *SELECTED_PLUGIN.write() = Some(path);
+
+    // The row may only mount on the next frame after switching views
+    let _ = document::eval(
+        r#"(function scrollToSelected(attempts) {
+            const row = document.querySelector(".plugin-card.selected");
+            if (row) { row.scrollIntoView({ block: "nearest" }); }
+            else if (attempts > 0) { requestAnimationFrame(() => scrollToSelected(attempts - 1)); }
+        })(10);"#,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fuzzy_score_prefers_runs_and_word_starts() {
+        assert_eq!(fuzzy_score("xyz", "Rescan plugins"), None);
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+        let word_start = fuzzy_score("rp", "Rescan plugins").unwrap();
+        let scattered = fuzzy_score("rp", "Error report").unwrap();
+        assert!(word_start > scattered);
+        assert!(fuzzy_score("resc", "Rescan plugins").unwrap() > fuzzy_score("rscn", "Rescan plugins").unwrap());
+    }
+}
@@ -0,0 +1,184 @@
+use serde::Serialize;
+
+use crate::clock::LocalTime;
+use crate::data_operations::Plugin;
+use crate::plugin_list::{ListFormat, csv_field};
+use crate::policy::{DenyReason, POLICY_PATH, PolicyStatus, find_requirement_gaps, find_violations};
+
+// Machine-readable report for fleet aggregation - field values are stable English codes, not UI text
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComplianceReport {
+    pub machine: String,
+    pub generated: String, // Local "YYYY-MM-DD HH:MM:SS"
+    pub policy_path: &'static str,
+    pub policy: &'static str, // missing, loaded or invalid
+    pub compliant: bool,
+    pub entries: Vec<ComplianceEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComplianceEntry {
+    pub status: ComplianceStatus,
+    pub bundle_id: String,
+    pub name: String,
+    pub version: String, // Installed version, empty when missing
+    pub detail: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComplianceStatus {
+    Allowed,
+    Denied,   // Installed but not permitted - should be removed
+    Missing,  // Required but not installed
+    Outdated, // Required and installed, but older than the minimum version
+}
+
+impl ComplianceStatus {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ComplianceStatus::Allowed => "allowed",
+            ComplianceStatus::Denied => "denied",
+            ComplianceStatus::Missing => "missing",
+            ComplianceStatus::Outdated => "outdated",
+        }
+    }
+}
+
+const CSV_HEADER: [&str; 8] = ["machine", "generated", "status", "bundle_id", "name", "version", "detail", "path"];
+
+pub fn build_compliance_report(status: &PolicyStatus, plugins: &[Plugin]) -> ComplianceReport {
+    let now = LocalTime::now();
+    let mut entries = Vec::new();
+
+    if let PolicyStatus::Loaded(policy) = status {
+        let violations = find_violations(policy, plugins);
+        for plugin in plugins {
+            let violation = violations.iter().find(|violation| violation.plugin.path == plugin.path);
+            entries.push(ComplianceEntry {
+                status: if violation.is_some() { ComplianceStatus::Denied } else { ComplianceStatus::Allowed },
+                bundle_id: plugin.bundle_id.clone(),
+                name: plugin.name.clone(),
+                version: plugin.version.clone(),
+                detail: violation.map(|violation| deny_code(&violation.reason)).unwrap_or_default(),
+                path: plugin.path.display().to_string(),
+            });
+        }
+
+        for gap in find_requirement_gaps(policy, plugins) {
+            let min_version = gap.requirement.min_version.clone().unwrap_or_default();
+            entries.push(ComplianceEntry {
+                status: if gap.installed_version.is_some() { ComplianceStatus::Outdated } else { ComplianceStatus::Missing },
+                name: gap.requirement.bundle_id.clone(),
+                bundle_id: gap.requirement.bundle_id,
+                version: gap.installed_version.unwrap_or_default(),
+                detail: if min_version.is_empty() { "required".to_string() } else { format!("required >= {}", min_version) },
+                path: String::new(),
+            });
+        }
+    }
+
+    ComplianceReport {
+        machine: machine_name(),
+        generated: format!("{} {}", now.date_label(), now.time_label()),
+        policy_path: POLICY_PATH,
+        policy: match status {
+            PolicyStatus::Missing => "missing",
+            PolicyStatus::Loaded(_) => "loaded",
+            PolicyStatus::Invalid => "invalid",
+        },
+        // An unreadable policy can't be verified, so it never counts as compliant
+        compliant: !matches!(status, PolicyStatus::Invalid)
+            && entries.iter().all(|entry| entry.status == ComplianceStatus::Allowed),
+        entries,
+    }
+}
+
+pub fn render_compliance_report(report: &ComplianceReport, format: ListFormat) -> String {
+    match format {
+        ListFormat::Text => {
+            let mut text = format!(
+                "{} - policy {} ({}) - {}\n",
+                report.machine,
+                report.policy,
+                report.policy_path,
+                if report.compliant { "compliant" } else { "NOT compliant" }
+            );
+            for entry in &report.entries {
+                text.push_str(&format!("{}\t{}\t{}\t{}\n", entry.status.code(), entry.bundle_id, entry.version, entry.detail));
+            }
+            text
+        }
+        ListFormat::Json => {
+            let mut json = serde_json::to_string_pretty(report).unwrap_or_default();
+            json.push('\n');
+            json
+        }
+        ListFormat::Csv => {
+            // One row per entry, each carrying the machine so files from many machines can be concatenated
+            let mut csv = CSV_HEADER.join(",") + "\n";
+            for entry in &report.entries {
+                let fields = [
+                    report.machine.as_str(),
+                    report.generated.as_str(),
+                    entry.status.code(),
+                    entry.bundle_id.as_str(),
+                    entry.name.as_str(),
+                    entry.version.as_str(),
+                    entry.detail.as_str(),
+                    entry.path.as_str(),
+                ];
+                let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+                csv.push_str(&row.join(","));
+                csv.push('\n');
+            }
+            csv
+        }
+    }
+}
+
+// Helper functions
+fn deny_code(reason: &DenyReason) -> String {
+    match reason {
+        DenyReason::DeniedId(pattern) => format!("deny-list {}", pattern),
+        DenyReason::DeniedPublisher(publisher) => format!("denied-publisher {}", publisher),
+        DenyReason::NotAllowed => "not-on-allow-list".to_string(),
+    }
+}
+
+fn machine_name() -> String {
+    let mut buffer = [0u8; 256];
+    let result = unsafe { libc::gethostname(buffer.as_mut_ptr() as *mut libc::c_char, buffer.len()) };
+    if result != 0 {
+        return "unknown".to_string();
+    }
+    let length = buffer.iter().position(|&byte| byte == 0).unwrap_or(buffer.len());
+    String::from_utf8_lossy(&buffer[..length]).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::{Policy, RequiredPlugin};
+
+    #[test]
+    fn test_report_flags_denied_and_missing() {
+        let policy = Policy {
+            deny: vec!["com.bad.*".to_string()],
+            required: vec![RequiredPlugin { bundle_id: "com.vendor.sync".to_string(), min_version: None }],
+            ..Default::default()
+        };
+        let plugins = vec![Plugin::for_test("com.vendor.tools", "1.0"), Plugin::for_test("com.bad.panel", "1.0")];
+
+        let report = build_compliance_report(&PolicyStatus::Loaded(policy), &plugins);
+        let statuses: Vec<_> = report.entries.iter().map(|entry| entry.status).collect();
+        assert_eq!(statuses, vec![ComplianceStatus::Allowed, ComplianceStatus::Denied, ComplianceStatus::Missing]);
+        assert!(!report.compliant);
+
+        let csv = render_compliance_report(&report, ListFormat::Csv);
+        assert_eq!(csv.lines().count(), 4);
+        assert!(build_compliance_report(&PolicyStatus::Missing, &plugins).compliant);
+        assert!(!build_compliance_report(&PolicyStatus::Invalid, &plugins).compliant);
+    }
+}
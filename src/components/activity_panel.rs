@@ -0,0 +1,104 @@
+use crate::actions::export_history;
+use crate::clock::{LocalTime, local_midnight, parse_date_label};
+use crate::i18n::t;
+use crate::message::{HISTORY, clear_history};
+use crate::settings::{SETTINGS, set_keep_history};
+use crate::shortcuts::is_text_editing_key;
+use dioxus::prelude::*;
+
+// Popover above the status bar listing every message, newest first
+#[component]
+pub fn ActivityPanel(on_close: EventHandler<()>) -> Element {
+    let keep_history = SETTINGS.read().keep_history;
+    let history = HISTORY.read();
+    let today = LocalTime::now().date();
+    let mut from_date = use_signal(String::new); // Blank bounds leave that end of the range open
+    let mut to_date = use_signal(String::new);
+
+    rsx! {
+        div { class: "activity-overlay", onclick: move |_| on_close.call(()) }
+        div { class: "activity-panel",
+            div { class: "activity-header",
+                div { class: "activity-title", {t("activity-title")} }
+                button {
+                    class: "activity-clear",
+                    disabled: history.is_empty(),
+                    onclick: move |_| clear_history(),
+                    {t("activity-clear")}
+                }
+            }
+            div { class: "activity-list",
+                if history.is_empty() {
+                    div { class: "activity-empty", {t("activity-empty")} }
+                }
+                for (index, entry) in history.iter().enumerate().rev() {
+                    div {
+                        key: "{index}",
+                        class: "activity-entry",
+                        "data-type": "{entry.msg_type:?}",
+                        div { class: "activity-time", {time_label(entry.timestamp, today)} }
+                        div { class: "activity-content", "{entry.content}" }
+                    }
+                }
+            }
+            div { class: "activity-export",
+                label { class: "activity-date",
+                    {t("activity-from")}
+                    input {
+                        r#type: "date",
+                        value: "{from_date}",
+                        oninput: move |event| from_date.set(event.value()),
+                        onkeydown: move |event| {
+                            if is_text_editing_key(&event) {
+                                event.stop_propagation();
+                            }
+                        },
+                    }
+                }
+                label { class: "activity-date",
+                    {t("activity-to")}
+                    input {
+                        r#type: "date",
+                        value: "{to_date}",
+                        oninput: move |event| to_date.set(event.value()),
+                        onkeydown: move |event| {
+                            if is_text_editing_key(&event) {
+                                event.stop_propagation();
+                            }
+                        },
+                    }
+                }
+                button {
+                    class: "activity-clear",
+                    disabled: history.is_empty(),
+                    onclick: move |_| {
+                        let from = parse_date_label(&from_date()).map(local_midnight);
+                        // The end date is inclusive, so the range stops at the following midnight
+                        let until = parse_date_label(&to_date()).map(|(year, month, day)| local_midnight((year, month, day + 1)));
+                        export_history(from, until);
+                    },
+                    {t("activity-export")}
+                }
+            }
+            label { class: "activity-keep",
+                input {
+                    r#type: "checkbox",
+                    checked: keep_history,
+                    onchange: move |event| set_keep_history(event.checked()),
+                }
+                {t("activity-keep")}
+            }
+        }
+    }
+}
+
+// Helper functions
+// Entries from earlier days (kept across sessions) also show their date
+fn time_label(timestamp: i64, today: (i32, u8, u8)) -> String {
+    let time = LocalTime::from_unix(timestamp);
+    if time.date() == today {
+        time.time_label()
+    } else {
+        format!("{} {}", time.date_label(), time.time_label())
+    }
+}
@@ -0,0 +1,78 @@
+use crate::command_palette::{COMMAND_PALETTE_OPEN, palette_entries, run_command};
+use crate::i18n::t;
+use crate::plugin_store::PLUGINS;
+use crate::settings::SETTINGS;
+use crate::shortcuts::is_text_editing_key;
+use dioxus::prelude::*;
+
+// Search box over every command and plugin; arrow keys move the highlight, Enter runs it
+#[component]
+pub fn CommandPalette() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let mut query = use_signal(String::new);
+    let mut highlighted = use_signal(|| 0_usize);
+    let entries = palette_entries(&query.read(), PLUGINS.read().as_deref().unwrap_or_default());
+    let no_matches = entries.is_empty();
+    let last = entries.len().saturating_sub(1);
+    let current = highlighted().min(last);
+    let commands: Vec<_> = entries.iter().map(|entry| entry.command.clone()).collect();
+
+    rsx! {
+        div { class: "command-palette-overlay", onclick: move |_| *COMMAND_PALETTE_OPEN.write() = false }
+        div { class: "command-palette",
+            input {
+                class: "command-palette-input",
+                r#type: "text",
+                placeholder: t("palette-placeholder"),
+                value: "{query}",
+                onmounted: move |event| async move {
+                    let _ = event.set_focus(true).await;
+                },
+                oninput: move |event| {
+                    query.set(event.value());
+                    highlighted.set(0);
+                },
+                onkeydown: move |event: KeyboardEvent| match event.key() {
+                    Key::ArrowDown => {
+                        event.prevent_default();
+                        highlighted.set((current + 1).min(last));
+                    }
+                    Key::ArrowUp => {
+                        event.prevent_default();
+                        highlighted.set(current.saturating_sub(1));
+                    }
+                    Key::Enter => {
+                        if let Some(command) = commands.get(current) {
+                            run_command(command.clone());
+                        }
+                    }
+                    Key::Escape => *COMMAND_PALETTE_OPEN.write() = false,
+                    // Cmd/Ctrl+K reaches the root handler, which closes the palette
+                    _ if is_text_editing_key(&event) => event.stop_propagation(),
+                    _ => {}
+                },
+            }
+            div { class: "command-palette-list",
+                for (index, entry) in entries.into_iter().enumerate() {
+                    div {
+                        key: "{index}-{entry.label}",
+                        class: if index == current { "command-palette-item active" } else { "command-palette-item" },
+                        onmouseenter: move |_| highlighted.set(index),
+                        onclick: {
+                            let command = entry.command.clone();
+                            move |_| run_command(command.clone())
+                        },
+                        span { class: "command-palette-label", "{entry.label}" }
+                        if !entry.detail.is_empty() {
+                            span { class: "command-palette-detail", "{entry.detail}" }
+                        }
+                    }
+                }
+            }
+            if no_matches {
+                div { class: "command-palette-empty", {t("palette-no-matches")} }
+            }
+            div { class: "command-palette-hint", {t("palette-hint")} }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use crate::actions::{export_compliance_report, remove_plugin_with_feedback};
+use crate::i18n::{t, t_args};
+use crate::plugin_store::PLUGINS;
+use crate::policy::{
+    POLICY_PATH, PolicyStatus, PolicyViolation, RequirementGap, find_requirement_gaps, find_violations, load_policy,
+};
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+
+#[component]
+fn ViolationRow(violation: PolicyViolation) -> Element {
+    let plugin = violation.plugin;
+
+    rsx! {
+        div { class: "compliance-row",
+            div { class: "compliance-info",
+                div { class: "compliance-name", "{plugin.name}" }
+                div { class: "compliance-meta",
+                    "{plugin.bundle_id} {plugin.version}"
+                    if let Some(publisher) = &violation.publisher {
+                        " · {publisher}"
+                    }
+                }
+                div { class: "compliance-reason", {violation.reason.to_string()} }
+            }
+            button {
+                class: "compliance-remove",
+                disabled: !plugin.can_remove,
+                onclick: move |_| remove_plugin_with_feedback(plugin.path.clone()),
+                {t("menu-remove")}
+            }
+        }
+    }
+}
+
+#[component]
+fn GapRow(gap: RequirementGap) -> Element {
+    let min_version = gap.requirement.min_version.clone().unwrap_or_default();
+    let reason = match &gap.installed_version {
+        Some(installed) => t_args("compliance-outdated", &[("installed", installed), ("required", &min_version)]),
+        None => t("compliance-missing"),
+    };
+
+    rsx! {
+        div { class: "compliance-row compliance-gap",
+            div { class: "compliance-info",
+                div { class: "compliance-name", "{gap.requirement.bundle_id}" }
+                div { class: "compliance-reason", {reason} }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn CompliancePanel() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    let compliance = use_resource(move || {
+        let plugins = PLUGINS().unwrap_or_default(); // Re-check after every scan
+        async move {
+            let status = load_policy();
+            let (violations, gaps) = match &status {
+                PolicyStatus::Loaded(policy) => (find_violations(policy, &plugins), find_requirement_gaps(policy, &plugins)),
+                PolicyStatus::Missing | PolicyStatus::Invalid => (Vec::new(), Vec::new()),
+            };
+            (status, violations, gaps)
+        }
+    });
+
+    rsx! {
+        div { class: "section compliance-panel",
+            div { class: "compliance-header",
+                div { class: "compliance-intro", {t_args("compliance-intro", &[("path", POLICY_PATH)])} }
+                button {
+                    class: "compliance-export",
+                    onclick: move |_| export_compliance_report(),
+                    {t("compliance-export")}
+                }
+            }
+            match &*compliance.read() {
+                None => rsx! { div { class: "loading-message", {t("loading-plugins")} } },
+                Some((PolicyStatus::Missing, _, _)) => rsx! { div { class: "compliance-empty", {t("compliance-no-policy")} } },
+                Some((PolicyStatus::Invalid, _, _)) => rsx! { div { class: "compliance-error", {t("compliance-invalid-policy")} } },
+                Some((PolicyStatus::Loaded(_), violations, gaps)) => rsx! {
+                    if violations.is_empty() && gaps.is_empty() {
+                        div { class: "compliance-empty", {t("compliance-all-allowed")} }
+                    }
+                    if !violations.is_empty() {
+                        h3 { {t_args("compliance-flagged", &[("count", &violations.len().to_string())])} }
+                        for violation in violations.iter() {
+                            ViolationRow { key: "{violation.plugin.path.display()}", violation: violation.clone() }
+                        }
+                    }
+                    if !gaps.is_empty() {
+                        h3 { {t_args("compliance-required", &[("count", &gaps.len().to_string())])} }
+                        for gap in gaps.iter() {
+                            GapRow { key: "{gap.requirement.bundle_id}", gap: gap.clone() }
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
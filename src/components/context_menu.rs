@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+
+#[derive(Clone, PartialEq)]
+pub struct ContextMenuItem {
+    pub label: String,
+    pub danger: bool,
+    pub disabled: bool,
+    pub on_select: Callback<()>,
+}
+
+impl ContextMenuItem {
+    pub fn new(label: String, on_select: impl FnMut(()) + 'static) -> Self {
+        Self { label, danger: false, disabled: false, on_select: Callback::new(on_select) }
+    }
+
+    pub fn danger(mut self) -> Self {
+        self.danger = true;
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+// Floating menu at a window position; any click outside closes it
+#[component]
+pub fn ContextMenu(x: f64, y: f64, items: Vec<ContextMenuItem>, on_close: EventHandler<()>) -> Element {
+    rsx! {
+        div {
+            class: "context-menu-overlay",
+            onclick: move |_| on_close.call(()),
+            oncontextmenu: move |event| {
+                event.prevent_default();
+                on_close.call(());
+            },
+            div {
+                class: "context-menu",
+                style: "left: {x}px; top: {y}px;",
+                onclick: move |event| event.stop_propagation(),
+                for (index, item) in items.into_iter().enumerate() {
+                    button {
+                        key: "{index}",
+                        class: if item.danger { "context-menu-item danger" } else { "context-menu-item" },
+                        disabled: item.disabled,
+                        onclick: move |_| {
+                            on_close.call(());
+                            item.on_select.call(());
+                        },
+                        "{item.label}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use crate::actions::save_debug_entries;
+use crate::background::run_blocking;
+use crate::data_operations::parse_extension_entries;
+use crate::debug_ports::{DebugEntry, collect_debug_entries, find_port_conflicts, generate_entries, read_debug_file};
+use crate::host_detection::host_display_name;
+use crate::i18n::{t, t_args};
+use crate::message::show_error;
+use crate::plugin_store::{PLUGINS, PLUGIN_TARGETS};
+use crate::settings::SETTINGS;
+use crate::shortcuts::is_text_editing_key;
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+#[component]
+pub fn DebugPortsPanel() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    let mut plugins = PLUGINS().unwrap_or_default();
+    plugins.sort_by_key(|plugin| plugin.name.to_lowercase());
+    let mut selected = use_signal(|| None::<PathBuf>);
+    let mut entries = use_signal(Vec::<DebugEntry>::new);
+    let mut saved = use_signal(|| 0u32);
+    let mut busy = use_signal(|| false);
+
+    // Every plugin's saved entries, for ports already taken elsewhere
+    let saved_entries = use_resource(move || {
+        let plugins = PLUGINS().unwrap_or_default();
+        let _ = saved(); // Re-read after each save
+        async move { run_blocking(move || collect_debug_entries(&plugins)).await }
+    });
+
+    let selected_path = selected();
+    let (extension_ids, hosts) = match &selected_path {
+        Some(path) => {
+            let ids: Vec<String> = parse_extension_entries(&path.join("CSXS").join("manifest.xml"))
+                .map(|extensions| extensions.into_iter().map(|extension| extension.id).collect())
+                .unwrap_or_default();
+            let hosts: Vec<String> = PLUGIN_TARGETS
+                .read()
+                .get(path)
+                .map(|targets| targets.hosts.iter().map(|host| host.name.clone()).collect())
+                .unwrap_or_default();
+            (ids, hosts)
+        }
+        None => (Vec::new(), Vec::new()),
+    };
+
+    // Saved entries of the other plugins plus the selected plugin's unsaved edits
+    let mut combined: Vec<(PathBuf, DebugEntry)> = saved_entries
+        .read()
+        .iter()
+        .flatten()
+        .filter(|(path, _)| Some(path) != selected_path.as_ref())
+        .cloned()
+        .collect();
+    if let Some(path) = &selected_path {
+        combined.extend(entries.read().iter().map(|entry| (path.clone(), entry.clone())));
+    }
+    let conflicts = find_port_conflicts(&combined);
+    let conflicting: HashSet<u16> = conflicts.iter().map(|conflict| conflict.port).collect();
+    let used_elsewhere: HashSet<u16> =
+        combined.iter().filter(|(path, _)| Some(path) != selected_path.as_ref()).map(|(_, entry)| entry.port).collect();
+    let plugin_name = |path: &PathBuf| {
+        plugins.iter().find(|plugin| &plugin.path == path).map(|plugin| plugin.name.clone()).unwrap_or_default()
+    };
+    let can_generate = !extension_ids.is_empty() && !hosts.is_empty();
+
+    rsx! {
+        div { class: "section debug-ports-panel",
+            div { class: "signing-intro", {t("debug-ports-intro")} }
+
+            div { class: "signing-section",
+                label { class: "signing-field",
+                    span { class: "signing-label", {t("debug-ports-extension")} }
+                    select {
+                        class: "setting-select",
+                        onchange: move |event| {
+                            let path = Some(PathBuf::from(event.value())).filter(|path| !path.as_os_str().is_empty());
+                            let loaded = match &path {
+                                Some(path) => read_debug_file(path).unwrap_or_else(|e| {
+                                    show_error("toast-debug-file-failed", &[("error", &e.to_string())]);
+                                    Vec::new()
+                                }),
+                                None => Vec::new(),
+                            };
+                            entries.set(loaded);
+                            selected.set(path);
+                        },
+                        option { value: "", selected: selected_path.is_none(), {t("debug-ports-choose")} }
+                        for plugin in plugins.iter() {
+                            option {
+                                value: "{plugin.path.display()}",
+                                selected: selected_path.as_ref() == Some(&plugin.path),
+                                "{plugin.name}"
+                            }
+                        }
+                    }
+                }
+
+                if let Some(path) = selected_path.clone() {
+                    if !can_generate {
+                        div { class: "signing-warning", {t("debug-ports-no-targets")} }
+                    }
+                    if entries.read().is_empty() {
+                        div { class: "debug-ports-empty", {t("debug-ports-none")} }
+                    } else {
+                        div { class: "debug-ports-table",
+                            for (index, entry) in entries.read().iter().enumerate() {
+                                div {
+                                    key: "{entry.extension_id}-{entry.host}",
+                                    class: if conflicting.contains(&entry.port) { "debug-ports-row conflict" } else { "debug-ports-row" },
+                                    span { class: "debug-ports-id", "{entry.extension_id}" }
+                                    span { class: "debug-ports-host", {host_display_name(&entry.host).to_string()} }
+                                    input {
+                                        class: "signing-input debug-ports-port",
+                                        r#type: "number",
+                                        min: "1024",
+                                        max: "65535",
+                                        value: "{entry.port}",
+                                        oninput: move |event| {
+                                            if let Ok(port) = event.value().parse::<u16>() {
+                                                entries.write()[index].port = port;
+                                            }
+                                        },
+                                        onkeydown: move |event| {
+                                            if is_text_editing_key(&event) {
+                                                event.stop_propagation();
+                                            }
+                                        },
+                                    }
+                                    button {
+                                        class: "signing-button",
+                                        onclick: move |_| {
+                                            entries.write().remove(index);
+                                        },
+                                        {t("debug-ports-remove-entry")}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div { class: "debug-ports-actions",
+                        button {
+                            class: "signing-button",
+                            disabled: !can_generate,
+                            onclick: move |_| {
+                                let generated = generate_entries(&entries.read(), &extension_ids, &hosts, &used_elsewhere);
+                                entries.set(generated);
+                            },
+                            {t("debug-ports-generate")}
+                        }
+                        button {
+                            class: "signing-button primary",
+                            disabled: busy() || !conflicts.is_empty(),
+                            onclick: {
+                                let path = path.clone();
+                                move |_| {
+                                    let path = path.clone();
+                                    spawn(async move {
+                                        busy.set(true);
+                                        if save_debug_entries(path, entries.cloned()).await {
+                                            saved += 1;
+                                        }
+                                        busy.set(false);
+                                    });
+                                }
+                            },
+                            {t("debug-ports-save")}
+                        }
+                        button {
+                            class: "signing-button",
+                            disabled: busy(),
+                            onclick: move |_| {
+                                let path = path.clone();
+                                spawn(async move {
+                                    busy.set(true);
+                                    if save_debug_entries(path, Vec::new()).await {
+                                        entries.set(Vec::new());
+                                        saved += 1;
+                                    }
+                                    busy.set(false);
+                                });
+                            },
+                            {t("debug-ports-remove-file")}
+                        }
+                    }
+                }
+            }
+
+            if !conflicts.is_empty() {
+                div { class: "signing-section",
+                    h3 { {t("debug-ports-conflicts")} }
+                    for conflict in conflicts.iter() {
+                        div { key: "{conflict.port}", class: "debug-ports-conflict",
+                            {t_args("debug-ports-conflict", &[("port", &conflict.port.to_string())])}
+                            for (path, entry) in conflict.users.iter() {
+                                div { class: "debug-ports-conflict-user",
+                                    "{plugin_name(path)} · {entry.extension_id} · {host_display_name(&entry.host)}"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,550 @@
+use crate::actions::{
+    backup_plugin_settings, copy_to_clipboard, restore_original_manifest, restore_plugin_settings, revert_plugin_version, save_license_key,
+    save_manifest_edit,
+};
+use crate::background::run_blocking;
+use crate::clock::LocalTime;
+use crate::data_operations::{ExtensionEntry, Plugin, UiKind, parse_extension_entries};
+use crate::file_operations::archived_versions;
+use crate::host_detection::host_display_name;
+use crate::i18n::{t, t_args};
+use crate::license_keys::{mask_license, read_license};
+use crate::plugin_data::read_backup_info;
+use crate::manifest_editor::{self, ManifestEdit, edit_problems, has_backup, read_manifest_edit};
+use crate::message::show_error;
+use crate::plugin_notes::{PLUGIN_NOTES, add_tag, remove_tag, set_notes};
+use crate::shortcuts::is_text_editing_key;
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+// Rows with a `copy_item` get a copy button; the item name is used in the toast
+#[component]
+fn DetailRow(label: String, value: String, #[props(default)] copy_item: Option<String>) -> Element {
+    rsx! {
+        div { class: "detail-row",
+            div { class: "detail-label", "{label}" }
+            div { class: "detail-value-line",
+                div { class: "detail-value", "{value}" }
+                if let Some(item) = copy_item {
+                    button {
+                        class: "detail-copy",
+                        title: t("details-copy"),
+                        onclick: move |_| copy_to_clipboard(value.clone(), &item),
+                        span { dangerous_inner_html: include_str!("../../assets/icons/copy.svg") }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CefParameters(parameters: Vec<String>) -> Element {
+    rsx! {
+        div { class: "detail-row",
+            div { class: "detail-label", {t("details-cef-parameters")} }
+            if parameters.is_empty() {
+                div { class: "detail-value", {t("details-none")} }
+            }
+            for parameter in parameters {
+                div { key: "{parameter}", class: "detail-parameter",
+                    code { "{parameter}" }
+                    if let Some(key) = describe_cef_parameter(&parameter) {
+                        span { class: "detail-hint", {t(key)} }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ExtensionDetails(entry: ExtensionEntry) -> Element {
+    let none = t("details-none");
+    let auto_visible = match entry.auto_visible {
+        Some(true) => t("details-yes"),
+        Some(false) => t("details-no"),
+        None => none.clone(),
+    };
+    let start_on = if entry.start_on.is_empty() { none.clone() } else { entry.start_on.join(", ") };
+    // Invisible names the manifest's own value, e.g. Custom, or an unknown type
+    let ui_type = match (entry.ui_kind(), &entry.ui_type) {
+        (UiKind::Invisible, Some(raw)) => format!("{} ({})", t(UiKind::Invisible.label_key()), raw),
+        (kind, _) => t(kind.label_key()),
+    };
+
+    rsx! {
+        div { class: "detail-extension",
+            div { class: "detail-extension-id", "{entry.id}" }
+            DetailRow { label: t("details-ui-type"), value: ui_type }
+            DetailRow { label: t("details-menu"), value: entry.menu.clone().unwrap_or(none.clone()) }
+            DetailRow { label: t("details-main-path"), value: entry.main_path.clone().unwrap_or(none.clone()) }
+            if let Some(script_path) = entry.script_path.clone() {
+                DetailRow { label: t("details-script-path"), value: script_path }
+            }
+            DetailRow { label: t("details-auto-visible"), value: auto_visible }
+            DetailRow { label: t("details-start-on"), value: start_on }
+            CefParameters { parameters: entry.cef_parameters.clone() }
+        }
+    }
+}
+
+// The user's own notes and tags, saved as soon as the field loses focus or a tag is added
+#[component]
+fn NotesEditor(bundle_id: String) -> Element {
+    let note = PLUGIN_NOTES.read().get(&bundle_id).cloned().unwrap_or_default();
+    let mut new_tag = use_signal(String::new);
+
+    rsx! {
+        textarea {
+            class: "details-notes",
+            placeholder: t("details-notes-placeholder"),
+            value: "{note.notes}",
+            onchange: {
+                let bundle_id = bundle_id.clone();
+                move |event: Event<FormData>| set_notes(&bundle_id, event.value())
+            },
+            onkeydown: move |event| {
+                if is_text_editing_key(&event) {
+                    event.stop_propagation();
+                }
+            },
+        }
+        div { class: "details-tags",
+            for tag in note.tags {
+                span { key: "{tag}", class: "plugin-tag",
+                    "{tag}"
+                    button {
+                        class: "plugin-tag-remove",
+                        title: t("details-tag-remove"),
+                        onclick: {
+                            let (bundle_id, tag) = (bundle_id.clone(), tag.clone());
+                            move |_| remove_tag(&bundle_id, &tag)
+                        },
+                        "×"
+                    }
+                }
+            }
+            input {
+                class: "details-tag-input",
+                r#type: "text",
+                placeholder: t("details-tag-placeholder"),
+                value: "{new_tag}",
+                oninput: move |event| new_tag.set(event.value()),
+                onkeydown: move |event| {
+                    if is_text_editing_key(&event) {
+                        event.stop_propagation();
+                    }
+                    if event.key() == Key::Enter {
+                        add_tag(&bundle_id, &new_tag());
+                        new_tag.set(String::new());
+                    }
+                },
+            }
+        }
+    }
+}
+
+// Backup and restore of what the panel stores outside its folder
+#[component]
+fn PanelSettings(plugin: Plugin) -> Element {
+    let mut busy = use_signal(|| false);
+    let mut backup_generation = use_signal(|| 0); // Bumped after a backup to re-read its date
+    let bundle_id = plugin.bundle_id.clone();
+    let backup = use_memo(move || {
+        let _ = backup_generation();
+        read_backup_info(&bundle_id)
+    });
+
+    rsx! {
+        div { class: "detail-hint",
+            match backup() {
+                Some(info) => t_args("panel-settings-backed-up", &[("date", &LocalTime::from_unix(info.created_at).date_label())]),
+                None => t("panel-settings-hint"),
+            }
+        }
+        div { class: "manifest-edit-actions",
+            button {
+                class: "signing-button",
+                disabled: busy(),
+                onclick: {
+                    let plugin = plugin.clone();
+                    move |_| {
+                        let plugin = plugin.clone();
+                        spawn(async move {
+                            busy.set(true);
+                            if backup_plugin_settings(plugin).await {
+                                backup_generation += 1;
+                            }
+                            busy.set(false);
+                        });
+                    }
+                },
+                {t("panel-settings-backup")}
+            }
+            button {
+                class: "signing-button",
+                disabled: busy() || backup().is_none(),
+                onclick: move |_| {
+                    let plugin = plugin.clone();
+                    spawn(async move {
+                        busy.set(true);
+                        restore_plugin_settings(plugin).await;
+                        busy.set(false);
+                    });
+                },
+                {t("panel-settings-restore")}
+            }
+        }
+    }
+}
+
+// Masked until shown; read off the UI thread since the keychain may ask for permission first
+#[component]
+fn LicenseKey(bundle_id: String) -> Element {
+    let mut revealed = use_signal(|| false);
+    let mut draft = use_signal(|| None::<String>); // Some while editing
+    let mut busy = use_signal(|| false);
+    let id = bundle_id.clone();
+    let mut saved = use_resource(move || {
+        let id = id.clone();
+        async move { run_blocking(move || read_license(&id)).await.map_err(|e| e.to_string()) }
+    });
+    let stop_editing_keys = move |event: Event<KeyboardData>| {
+        if is_text_editing_key(&event) {
+            event.stop_propagation();
+        }
+    };
+    let save = move |bundle_id: String, key: String| {
+        spawn(async move {
+            busy.set(true);
+            if save_license_key(bundle_id, key).await {
+                draft.set(None);
+                saved.restart();
+            }
+            busy.set(false);
+        });
+    };
+
+    if let Some(key) = draft() {
+        return rsx! {
+            input {
+                class: "signing-input license-key-input",
+                r#type: "text",
+                placeholder: t("license-key-placeholder"),
+                value: "{key}",
+                oninput: move |event| draft.set(Some(event.value())),
+                onkeydown: stop_editing_keys,
+            }
+            div { class: "manifest-edit-actions",
+                button {
+                    class: "signing-button primary",
+                    disabled: busy() || key.trim().is_empty(),
+                    onclick: {
+                        let (bundle_id, key) = (bundle_id.clone(), key.clone());
+                        move |_| save(bundle_id.clone(), key.clone())
+                    },
+                    {t("license-key-save")}
+                }
+                button { class: "signing-button", onclick: move |_| draft.set(None), {t("license-key-cancel")} }
+            }
+        };
+    }
+
+    let current = saved.read().clone();
+    rsx! {
+        match current {
+            None => rsx! {},
+            Some(Err(e)) => rsx! { div { class: "detail-hint", "{e}" } },
+            Some(Ok(None)) => rsx! {
+                div { class: "detail-hint", {t("license-key-hint")} }
+                div { class: "manifest-edit-actions",
+                    button { class: "signing-button", onclick: move |_| draft.set(Some(String::new())), {t("license-key-add")} }
+                }
+            },
+            Some(Ok(Some(key))) => rsx! {
+                div { class: "detail-value-line",
+                    div { class: "detail-value license-key", if revealed() { "{key}" } else { {mask_license(&key)} } }
+                    button {
+                        class: "detail-copy",
+                        title: t("details-copy"),
+                        onclick: {
+                            let key = key.clone();
+                            move |_| copy_to_clipboard(key.clone(), &t("item-license-key"))
+                        },
+                        span { dangerous_inner_html: include_str!("../../assets/icons/copy.svg") }
+                    }
+                }
+                div { class: "manifest-edit-actions",
+                    button {
+                        class: "signing-button",
+                        onclick: move |_| revealed.toggle(),
+                        if revealed() { {t("license-key-hide")} } else { {t("license-key-show")} }
+                    }
+                    button {
+                        class: "signing-button",
+                        onclick: {
+                            let key = key.clone();
+                            move |_| draft.set(Some(key.clone()))
+                        },
+                        {t("license-key-edit")}
+                    }
+                    button {
+                        class: "signing-button",
+                        disabled: busy(),
+                        onclick: move |_| save(bundle_id.clone(), String::new()),
+                        {t("license-key-remove")}
+                    }
+                }
+            },
+        }
+    }
+}
+
+// Display name, version and host ranges, written back into CSXS/manifest.xml on save
+#[component]
+fn ManifestEditor(plugin_path: PathBuf) -> Element {
+    let mut draft = use_signal(|| None::<ManifestEdit>);
+    let mut busy = use_signal(|| false);
+    let signed = plugin_path.join("META-INF").join("signatures.xml").is_file();
+    let backed_up = has_backup(&plugin_path);
+
+    let Some(edit) = draft() else {
+        let path = plugin_path.clone();
+        return rsx! {
+            div { class: "manifest-edit-actions",
+                button {
+                    class: "signing-button",
+                    onclick: move |_| match read_manifest_edit(&path) {
+                        Ok(edit) => draft.set(Some(edit)),
+                        Err(e) => {
+                            show_error("toast-error", &[("error", &e.to_string())]);
+                        }
+                    },
+                    {t("manifest-edit-open")}
+                }
+                if backed_up {
+                    button {
+                        class: "signing-button",
+                        title: t("manifest-edit-restore-hint"),
+                        disabled: busy(),
+                        onclick: move |_| {
+                            let path = plugin_path.clone();
+                            spawn(async move {
+                                busy.set(true);
+                                restore_original_manifest(path).await;
+                                busy.set(false);
+                            });
+                        },
+                        {t("manifest-edit-restore")}
+                    }
+                }
+            }
+        };
+    };
+    let problems = edit_problems(&edit);
+    let stop_editing_keys = move |event: Event<KeyboardData>| {
+        if is_text_editing_key(&event) {
+            event.stop_propagation();
+        }
+    };
+
+    rsx! {
+        div { class: "manifest-editor",
+            if signed {
+                div { class: "signing-warning", {t("manifest-edit-signed")} }
+            }
+            label { class: "signing-field",
+                span { class: "signing-label", {t("manifest-edit-name")} }
+                input {
+                    class: "signing-input",
+                    r#type: "text",
+                    value: "{edit.name}",
+                    oninput: move |event| {
+                        if let Some(draft) = draft.write().as_mut() {
+                            draft.name = event.value();
+                        }
+                    },
+                    onkeydown: stop_editing_keys,
+                }
+            }
+            label { class: "signing-field",
+                span { class: "signing-label", {t("column-version")} }
+                input {
+                    class: "signing-input",
+                    r#type: "text",
+                    value: "{edit.version}",
+                    oninput: move |event| {
+                        if let Some(draft) = draft.write().as_mut() {
+                            draft.version = event.value();
+                        }
+                    },
+                    onkeydown: stop_editing_keys,
+                }
+            }
+            for (index, host) in edit.hosts.iter().enumerate() {
+                label { key: "{host.name}", class: "signing-field",
+                    span { class: "signing-label", {t_args("manifest-edit-host", &[("host", host_display_name(&host.name))])} }
+                    input {
+                        class: "signing-input",
+                        r#type: "text",
+                        placeholder: t("manifest-edit-any-version"),
+                        value: host.version.clone().unwrap_or_default(),
+                        oninput: move |event| {
+                            if let Some(draft) = draft.write().as_mut() {
+                                draft.hosts[index].version = Some(event.value()).filter(|version| !version.trim().is_empty());
+                            }
+                        },
+                        onkeydown: stop_editing_keys,
+                    }
+                }
+            }
+            div { class: "detail-hint", {t("manifest-edit-range-hint")} }
+            for (key, value) in problems.iter() {
+                div { class: "signing-warning", {t_args(key, &[("value", value)])} }
+            }
+            div { class: "manifest-edit-actions",
+                button {
+                    class: "signing-button primary",
+                    disabled: busy() || !problems.is_empty(),
+                    onclick: {
+                        let path = plugin_path.clone();
+                        move |_| {
+                            let (path, edit) = (path.clone(), edit.clone());
+                            spawn(async move {
+                                busy.set(true);
+                                if save_manifest_edit(path, edit).await {
+                                    draft.set(None);
+                                }
+                                busy.set(false);
+                            });
+                        }
+                    },
+                    {t("manifest-edit-save")}
+                }
+                button {
+                    class: "signing-button",
+                    onclick: move |_| draft.set(None),
+                    {t("manifest-edit-cancel")}
+                }
+            }
+        }
+    }
+}
+
+// Versions replaced by upgrades, newest first; reverting archives the current one in turn
+#[component]
+fn VersionHistory(plugin: Plugin) -> Element {
+    let mut reverted = use_signal(|| 0u32);
+    let mut busy = use_signal(|| false);
+    let versions = use_memo(use_reactive!(|plugin| {
+        let _ = reverted(); // Re-read after a revert archived the replaced version
+        archived_versions(&plugin.bundle_id)
+    }));
+
+    rsx! {
+        if versions.read().is_empty() {
+            div { class: "detail-value", {t("version-history-none")} }
+        }
+        for (index, archived) in versions().into_iter().enumerate() {
+            div { key: "{archived.path.display()}", class: "version-history-row",
+                span { class: "version-history-version", "{archived.version}" }
+                span { class: "version-history-date",
+                    {archived.archived_at.map(|timestamp| LocalTime::from_unix(timestamp).date_label()).unwrap_or_default()}
+                }
+                button {
+                    class: "signing-button",
+                    disabled: busy(),
+                    onclick: {
+                        let (plugin, archived) = (plugin.clone(), archived.clone());
+                        move |_| {
+                            let (plugin, archived) = (plugin.clone(), archived.clone());
+                            spawn(async move {
+                                busy.set(true);
+                                if revert_plugin_version(plugin, archived).await {
+                                    reverted += 1;
+                                }
+                                busy.set(false);
+                            });
+                        }
+                    },
+                    if index == 0 { {t("version-history-revert-previous")} } else { {t("version-history-revert")} }
+                }
+            }
+        }
+    }
+}
+
+// Side drawer with everything known about one plugin
+#[component]
+pub fn DetailsDrawer(plugin: Plugin, #[props(default)] read_only: bool, on_close: EventHandler<()>) -> Element {
+    let manifest_path = plugin.path.join("CSXS").join("manifest.xml");
+    let entries = use_memo(use_reactive!(|manifest_path| {
+        parse_extension_entries(&manifest_path).unwrap_or_else(|e| {
+            log::warn!("Failed to read dispatch info from {:?}: {}", manifest_path, e);
+            Vec::new()
+        })
+    }));
+
+    rsx! {
+        div { class: "details-drawer",
+            div { class: "details-header",
+                div { class: "details-title", "{plugin.name}" }
+                button {
+                    class: "details-close",
+                    title: t("details-close"),
+                    onclick: move |_| on_close.call(()),
+                    "×"
+                }
+            }
+            DetailRow { label: t("details-bundle-id"), value: plugin.bundle_id.clone(), copy_item: t("item-bundle-id") }
+            DetailRow { label: t("column-version"), value: plugin.version.clone(), copy_item: t("item-version") }
+            DetailRow { label: t("column-size"), value: plugin.size.clone() }
+            DetailRow { label: t("details-size-on-disk"), value: plugin.disk_size.clone() }
+            DetailRow { label: t("item-path"), value: plugin.path.display().to_string(), copy_item: t("item-path") }
+
+            h3 { class: "details-section-title", {t("details-notes")} }
+            NotesEditor { key: "{plugin.bundle_id}", bundle_id: plugin.bundle_id.clone() }
+
+            if !read_only {
+                h3 { class: "details-section-title", {t("license-key-title")} }
+                LicenseKey { key: "{plugin.bundle_id}", bundle_id: plugin.bundle_id.clone() }
+            }
+
+            if !read_only {
+                h3 { class: "details-section-title", {t("panel-settings-title")} }
+                PanelSettings { key: "{plugin.path.display()}", plugin: plugin.clone() }
+            }
+
+            if !read_only && manifest_editor::manifest_path(&plugin.path).is_file() {
+                h3 { class: "details-section-title", {t("manifest-edit-title")} }
+                ManifestEditor { key: "{plugin.path.display()}", plugin_path: plugin.path.clone() }
+            }
+
+            if !read_only {
+                h3 { class: "details-section-title", {t("version-history-title")} }
+                VersionHistory { plugin: plugin.clone() }
+            }
+
+            h3 { class: "details-section-title", {t("details-extensions")} }
+            for entry in entries() {
+                ExtensionDetails { key: "{entry.id}", entry: entry.clone() }
+            }
+        }
+    }
+}
+
+// Helper functions
+// Translation key explaining a well-known CEF command-line flag
+fn describe_cef_parameter(parameter: &str) -> Option<&'static str> {
+    let flag = parameter.split('=').next().unwrap_or(parameter);
+    match flag {
+        "--enable-nodejs" => Some("cef-enable-nodejs"),
+        "--mixed-context" => Some("cef-mixed-context"),
+        "--remote-debugging-port" => Some("cef-remote-debugging"),
+        "--disable-web-security" | "--allow-file-access" | "--allow-file-access-from-files" => Some("cef-relaxed-security"),
+        "--enable-media-stream" => Some("cef-media-stream"),
+        "--disable-application-cache" => Some("cef-no-app-cache"),
+        _ => None,
+    }
+}
@@ -0,0 +1,266 @@
+use crate::actions::{
+    clean_orphaned_cache, export_diagnostics, launch_debug_session_with_feedback, reset_host_app_cache, restart_cep_engines,
+};
+use crate::data_operations::{ExtensionRoot, format_size};
+use crate::diagnostics::{DiagnosticsReport, ExtensionCopy, build_report, collect_extension_copies};
+use crate::file_operations::{OrphanedEntry, host_cache_entries};
+use crate::host_detection::host_code_aliases;
+use crate::i18n::{t, t_args};
+use crate::message::REFRESH_TRIGGER;
+use crate::plugin_store::{HOST_APPS, ORPHANED_CACHE};
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+#[component]
+fn RootBadge(root: ExtensionRoot) -> Element {
+    rsx! {
+        span {
+            class: if matches!(root, ExtensionRoot::User) { "badge-root-user" } else { "badge-root-system" },
+            if matches!(root, ExtensionRoot::User) { {t("root-user")} } else { {t("root-system")} }
+        }
+    }
+}
+
+#[component]
+fn CopyLine(copy: ExtensionCopy) -> Element {
+    rsx! {
+        div { class: "diagnostics-copy",
+            RootBadge { root: copy.root }
+            span { class: "diagnostics-path", "{copy.folder.display()}" }
+            span { class: "diagnostics-version", "{copy.bundle_version}" }
+        }
+    }
+}
+
+#[component]
+fn MenuSection(report: DiagnosticsReport) -> Element {
+    rsx! {
+        div { class: "diagnostics-section",
+            h3 { {t("diagnostics-menu-title")} }
+            if report.menu_entries.is_empty() {
+                div { class: "diagnostics-empty", {t("diagnostics-menu-empty")} }
+            }
+            for menu in report.menu_entries {
+                div {
+                    key: "{menu.label}",
+                    class: if menu.extension_ids.len() > 1 { "diagnostics-row diagnostics-warning" } else { "diagnostics-row" },
+                    div { class: "diagnostics-label", "{menu.label}" }
+                    div { class: "diagnostics-ids",
+                        for id in menu.extension_ids.iter() {
+                            div { key: "{id}", "{id}" }
+                        }
+                    }
+                    if menu.extension_ids.len() > 1 {
+                        div { class: "diagnostics-note",
+                            {t_args("diagnostics-duplicate-menu", &[("count", &menu.extension_ids.len().to_string())])}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn CollisionSection(report: DiagnosticsReport) -> Element {
+    let collisions: Vec<_> = report.collisions().cloned().collect();
+
+    rsx! {
+        div { class: "diagnostics-section",
+            h3 { {t("diagnostics-collisions-title")} }
+            if collisions.is_empty() {
+                div { class: "diagnostics-empty", {t("diagnostics-collisions-empty")} }
+            }
+            for resolution in collisions {
+                div {
+                    key: "{resolution.id}",
+                    class: "diagnostics-row diagnostics-warning",
+                    div { class: "diagnostics-label", "{resolution.id}" }
+                    div { class: "diagnostics-note",
+                        if resolution.is_ambiguous() { {t("diagnostics-ambiguous")} } else { {t("diagnostics-wins")} }
+                    }
+                    CopyLine { copy: resolution.winner.clone() }
+                    div { class: "diagnostics-note", {t("diagnostics-ignored")} }
+                    for copy in resolution.shadowed {
+                        CopyLine { key: "{copy.folder.display()}", copy: copy.clone() }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn HiddenSection(report: DiagnosticsReport) -> Element {
+    rsx! {
+        div { class: "diagnostics-section",
+            h3 { {t("diagnostics-hidden-title")} }
+            if report.hidden.is_empty() {
+                div { class: "diagnostics-empty", {t("diagnostics-hidden-empty")} }
+            }
+            for copy in report.hidden {
+                div {
+                    key: "{copy.entry.id}",
+                    class: "diagnostics-row",
+                    div { class: "diagnostics-label", "{copy.entry.id}" }
+                    div { class: "diagnostics-note",
+                        {t_args("diagnostics-hidden-reason", &[("type", copy.entry.ui_type.as_deref().unwrap_or("-"))])}
+                    }
+                    CopyLine { copy: copy.clone() }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn HostAppsSection() -> Element {
+    let apps = HOST_APPS();
+
+    rsx! {
+        div { class: "diagnostics-section",
+            h3 { {t("diagnostics-hosts-title")} }
+            if apps.is_empty() {
+                div { class: "diagnostics-empty", {t("diagnostics-hosts-empty")} }
+            } else {
+                button {
+                    class: "diagnostics-host-reset",
+                    title: t("diagnostics-restart-cep-hint"),
+                    onclick: move |_| restart_cep_engines(),
+                    {t("diagnostics-restart-cep")}
+                }
+            }
+            for app in apps {
+                div {
+                    key: "{app.path.display()}",
+                    class: "diagnostics-copy",
+                    span { class: "diagnostics-label", "{app.name}" }
+                    span { class: "diagnostics-version", {app.version.clone().unwrap_or_default()} }
+                    span { class: "diagnostics-path", "{app.code}" }
+                    if let Some(cep) = app.cep_version {
+                        span { class: "diagnostics-version", "CEP {cep}" }
+                    }
+                    button {
+                        class: "diagnostics-host-reset",
+                        title: t("diagnostics-host-reset-hint"),
+                        onclick: {
+                            let app = app.clone();
+                            move |_| reset_host_app_cache(app.clone())
+                        },
+                        {t_args("diagnostics-host-reset", &[("count", &host_cache_entries(&host_code_aliases(app.code)).to_string())])}
+                    }
+                    button {
+                        class: "diagnostics-host-reset",
+                        title: t("diagnostics-debug-launch-hint"),
+                        onclick: {
+                            let app = app.clone();
+                            move |_| launch_debug_session_with_feedback(app.clone())
+                        },
+                        {t("diagnostics-debug-launch")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn OrphanedCacheSection() -> Element {
+    let orphans = ORPHANED_CACHE();
+    // Unticked entries; new detections start ticked
+    let mut kept = use_signal(HashSet::<PathBuf>::new);
+    let selected: Vec<&OrphanedEntry> = orphans.iter().filter(|orphan| !kept.read().contains(&orphan.path)).collect();
+    let total: u64 = selected.iter().map(|orphan| orphan.size).sum();
+
+    // One group per extension - a removed panel usually leaves entries for several hosts, logs and storage
+    let mut extensions: Vec<(String, Vec<OrphanedEntry>)> = Vec::new();
+    for orphan in &orphans {
+        match extensions.last_mut() {
+            Some((id, entries)) if *id == orphan.extension_id => entries.push(orphan.clone()),
+            _ => extensions.push((orphan.extension_id.clone(), vec![orphan.clone()])),
+        }
+    }
+
+    rsx! {
+        div { class: "diagnostics-section",
+            h3 { {t("diagnostics-orphans-title")} }
+            if orphans.is_empty() {
+                div { class: "diagnostics-empty", {t("diagnostics-orphans-empty")} }
+            } else {
+                div { class: "diagnostics-note", {t("diagnostics-orphans-hint")} }
+            }
+            for (id, entries) in extensions {
+                div { key: "{id}", class: "diagnostics-orphan",
+                    div { class: "diagnostics-copy",
+                        span { class: "diagnostics-label", "{id}" }
+                        span { class: "diagnostics-path", {t_args("diagnostics-orphans-entries", &[("count", &entries.len().to_string())])} }
+                        span { class: "diagnostics-version", {format_size(entries.iter().map(|entry| entry.size).sum())} }
+                    }
+                    for entry in entries {
+                        label {
+                            key: "{entry.path.display()}",
+                            class: "diagnostics-copy diagnostics-orphan-entry",
+                            input {
+                                r#type: "checkbox",
+                                checked: !kept.read().contains(&entry.path),
+                                onchange: {
+                                    let path = entry.path.clone();
+                                    move |event: FormEvent| {
+                                        if event.checked() {
+                                            kept.write().remove(&path);
+                                        } else {
+                                            kept.write().insert(path.clone());
+                                        }
+                                    }
+                                },
+                            }
+                            span { class: "diagnostics-path", "{entry.path.display()}" }
+                            span { class: "diagnostics-version", {format_size(entry.size)} }
+                        }
+                    }
+                }
+            }
+            if !orphans.is_empty() {
+                button {
+                    class: "diagnostics-host-reset",
+                    disabled: selected.is_empty(),
+                    onclick: move |_| clean_orphaned_cache(kept.peek().clone()),
+                    {t_args("diagnostics-orphans-clean", &[("size", &format_size(total))])}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn DiagnosticsPanel() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    let report = use_resource(move || {
+        let _ = REFRESH_TRIGGER(); // Recompute whenever the plugin list is refreshed
+        async move { build_report(collect_extension_copies()) }
+    });
+
+    rsx! {
+        div { class: "section diagnostics-panel",
+            div { class: "diagnostics-intro", {t("diagnostics-intro")} }
+            button {
+                class: "diagnostics-host-reset",
+                title: t("diagnostics-export-hint"),
+                onclick: move |_| export_diagnostics(),
+                {t("diagnostics-export")}
+            }
+            if let Some(report) = &*report.read() {
+                MenuSection { report: report.clone() }
+                CollisionSection { report: report.clone() }
+                HiddenSection { report: report.clone() }
+                HostAppsSection {}
+                OrphanedCacheSection {}
+            } else {
+                div { class: "loading-message", {t("loading-plugins")} }
+            }
+        }
+    }
+}
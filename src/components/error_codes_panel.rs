@@ -0,0 +1,22 @@
+use crate::error_codes::{ERROR_CODES, description_key};
+use crate::i18n::t;
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+
+// Reference for the codes in error toasts, so users can quote them and vendors can look them up
+#[component]
+pub fn ErrorCodesPanel() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    rsx! {
+        div { class: "section error-codes-panel",
+            div { class: "error-codes-intro", {t("error-codes-intro")} }
+            for code in ERROR_CODES {
+                div { key: "{code}", class: "error-code-row",
+                    span { class: "error-code", "{code}" }
+                    span { class: "error-code-description", {t(&description_key(code))} }
+                }
+            }
+        }
+    }
+}
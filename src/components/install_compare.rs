@@ -0,0 +1,83 @@
+use crate::actions::{INSTALL_COMPARISON, close_install_comparison};
+use crate::data_operations::format_size;
+use crate::file_operations::{ChangeKind, FileChange};
+use crate::i18n::{t, t_args};
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+
+// "Compare": files an incoming ZXP adds, changes or drops relative to the installed copy
+#[component]
+pub fn InstallCompareDialog() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let comparison = INSTALL_COMPARISON.read();
+    let Some(comparison) = comparison.as_ref() else {
+        return rsx! {};
+    };
+    let summary = t_args(
+        "compare-summary",
+        &[
+            ("added", &comparison.count(ChangeKind::Added).to_string()),
+            ("changed", &comparison.count(ChangeKind::Changed).to_string()),
+            ("removed", &comparison.count(ChangeKind::Removed).to_string()),
+            ("unchanged", &comparison.unchanged.to_string()),
+        ],
+    );
+    let verdict = if comparison.changes.is_empty() {
+        Some(t("compare-identical"))
+    } else if comparison.changes_only_packaging() {
+        Some(t("compare-only-signature"))
+    } else {
+        None
+    };
+
+    rsx! {
+        div { class: "install-compare-overlay", onclick: move |_| close_install_comparison() }
+        div { class: "install-preview install-compare",
+            div { class: "install-preview-title",
+                {t_args(
+                    "compare-title",
+                    &[("installed", &comparison.installed_version), ("incoming", &comparison.incoming_version)],
+                )}
+            }
+            div { class: "install-preview-summary",
+                div { {t_args("compare-installed-at", &[("path", &comparison.installed_path.display().to_string())])} }
+                div { "{summary}" }
+                if let Some(verdict) = verdict {
+                    div { class: "install-preview-warning", "{verdict}" }
+                }
+            }
+            if !comparison.changes.is_empty() {
+                div { class: "install-preview-files",
+                    for change in comparison.changes.iter() {
+                        ChangeRow { key: "{change.path.display()}", change: change.clone() }
+                    }
+                }
+            }
+            div { class: "install-preview-actions",
+                button { class: "install-preview-button", onclick: move |_| close_install_comparison(), {t("inspector-close")} }
+            }
+        }
+    }
+}
+
+#[component]
+fn ChangeRow(change: FileChange) -> Element {
+    let (class, label_key) = match change.kind {
+        ChangeKind::Added => ("install-compare-kind added", "compare-added"),
+        ChangeKind::Changed => ("install-compare-kind changed", "compare-changed"),
+        ChangeKind::Removed => ("install-compare-kind removed", "compare-removed"),
+    };
+    let sizes = match (change.installed_size, change.incoming_size) {
+        (Some(before), Some(after)) => format!("{} → {}", format_size(before), format_size(after)),
+        (Some(size), None) | (None, Some(size)) => format_size(size),
+        (None, None) => String::new(),
+    };
+
+    rsx! {
+        div { class: if change.is_packaging() { "install-preview-file install-compare-packaging" } else { "install-preview-file" },
+            span { class: "{class}", {t(label_key)} }
+            span { class: "install-preview-path", title: "{change.path.display()}", "{change.path.display()}" }
+            span { class: "install-preview-size", "{sizes}" }
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::actions::{INSTALL_PREVIEWS, approve_install_preview, compare_with_installed, copy_to_clipboard, dismiss_install_preview};
+use crate::data_operations::format_size;
+use crate::file_operations::parse_checksum;
+use crate::i18n::{t, t_args};
+use crate::settings::SETTINGS;
+use crate::shortcuts::is_text_editing_key;
+use dioxus::prelude::*;
+
+// "Inspect before install": every file the ZXP would write, approved or cancelled by the user
+#[component]
+pub fn InstallPreviewDialog() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let mut expected = use_signal(String::new); // Checksum pasted by the user, cleared for the next preview
+    let previews = INSTALL_PREVIEWS.read();
+    let Some(preview) = previews.first() else {
+        return rsx! {};
+    };
+    let overwrites = preview.overwrite_count();
+    let queued = previews.len() - 1;
+    let file_name = preview.zxp_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let expected_hash = parse_checksum(&expected.read());
+    let mismatch = expected_hash.is_some() && expected_hash != preview.sha256;
+
+    rsx! {
+        div {
+            class: "install-preview-overlay",
+            onclick: move |_| {
+                expected.set(String::new());
+                dismiss_install_preview();
+            },
+        }
+        div { class: "install-preview",
+            div { class: "install-preview-title", {t_args("install-preview-title", &[("file", &file_name)])} }
+            div { class: "install-preview-summary",
+                div { {t_args("install-preview-destination", &[("path", &preview.destination.display().to_string())])} }
+                div {
+                    {t_args(
+                        "install-preview-totals",
+                        &[("count", &preview.files.len().to_string()), ("size", &format_size(preview.total_size()))],
+                    )}
+                }
+                if overwrites > 0 {
+                    div { class: "install-preview-warning",
+                        {t_args("install-preview-overwrites", &[("count", &overwrites.to_string())])}
+                    }
+                } else if preview.destination.exists() {
+                    div { {t("install-preview-existing-folder")} }
+                }
+            }
+            div { class: "install-preview-checksum",
+                span { class: "install-preview-checksum-label", "SHA-256" }
+                match preview.sha256.clone() {
+                    Some(sha256) => rsx! {
+                        span { class: "install-preview-hash", "{sha256}" }
+                        button {
+                            class: "install-preview-button",
+                            onclick: move |_| copy_to_clipboard(sha256.clone(), "SHA-256"),
+                            {t("install-preview-copy")}
+                        }
+                    },
+                    None => rsx! { span { class: "install-preview-warning", {t("install-preview-hash-failed")} } },
+                }
+            }
+            div { class: "install-preview-checksum",
+                input {
+                    class: "install-preview-expected",
+                    r#type: "text",
+                    placeholder: t("install-preview-expected-placeholder"),
+                    value: "{expected}",
+                    oninput: move |event| expected.set(event.value()),
+                    onkeydown: move |event| {
+                        if is_text_editing_key(&event) {
+                            event.stop_propagation();
+                        }
+                    },
+                }
+                if !expected.read().trim().is_empty() {
+                    if expected_hash.is_none() {
+                        span { class: "install-preview-warning", {t("install-preview-expected-invalid")} }
+                    } else if mismatch {
+                        span { class: "install-preview-mismatch", {t("install-preview-hash-mismatch")} }
+                    } else {
+                        span { class: "install-preview-match", {t("install-preview-hash-match")} }
+                    }
+                }
+            }
+            div { class: "install-preview-files",
+                for file in preview.files.iter() {
+                    div {
+                        key: "{file.path.display()}",
+                        class: if file.overwrites { "install-preview-file overwrites" } else { "install-preview-file" },
+                        span { class: "install-preview-path", "{file.path.display()}" }
+                        if file.overwrites {
+                            span { class: "install-preview-badge", {t("install-preview-replaced")} }
+                        }
+                        span { class: "install-preview-size", {format_size(file.size)} }
+                    }
+                }
+            }
+            if queued > 0 {
+                div { class: "install-preview-queued", {t_args("install-preview-queued", &[("count", &queued.to_string())])} }
+            }
+            div { class: "install-preview-actions",
+                // What an install writes over - the folder the comparison is against
+                if preview.destination.join("CSXS").join("manifest.xml").is_file() {
+                    button {
+                        class: "install-preview-button",
+                        title: t("compare-hint"),
+                        onclick: {
+                            let (zxp_path, destination) = (preview.zxp_path.clone(), preview.destination.clone());
+                            move |_| compare_with_installed(zxp_path.clone(), destination.clone())
+                        },
+                        {t("compare-button")}
+                    }
+                }
+                button {
+                    class: "install-preview-button",
+                    onclick: move |_| {
+                        expected.set(String::new());
+                        dismiss_install_preview();
+                    },
+                    {t("install-preview-cancel")}
+                }
+                button {
+                    class: "install-preview-button primary",
+                    disabled: mismatch, // A package that isn't the one the vendor published never installs from here
+                    onclick: move |_| {
+                        expected.set(String::new());
+                        approve_install_preview();
+                    },
+                    {t("install-preview-install")}
+                }
+            }
+        }
+    }
+}
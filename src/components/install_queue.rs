@@ -0,0 +1,88 @@
+use crate::actions::remove_queued_install;
+use crate::file_operations::extraction_progress;
+use crate::i18n::{t, t_args};
+use crate::install_queue::{INSTALL_QUEUE, QueueState, QueuedInstall, clear_finished};
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+use std::time::Duration;
+
+// How often the entry counter is read from the extracting thread
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+// Sidebar list of the installs in line, shown while several are queued, one is still waiting or a
+// single large ZXP is still extracting
+#[component]
+pub fn InstallQueue() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    // Extraction counts entries on a worker thread, so poll like the status bar polls the scan
+    let mut progress = use_signal(|| None::<(usize, usize)>);
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(PROGRESS_INTERVAL).await;
+            let counts = extraction_progress();
+            if counts != *progress.peek() {
+                progress.set(counts);
+            }
+        }
+    });
+
+    let queue = INSTALL_QUEUE();
+    let waiting = queue.iter().any(|item| item.state == QueueState::Waiting);
+    if queue.len() < 2 && !waiting && progress().is_none() {
+        return rsx! {};
+    }
+    let finished = queue.iter().any(QueuedInstall::is_finished);
+
+    rsx! {
+        div { class: "install-queue",
+            div { class: "install-queue-header",
+                span { class: "setting-label", {t("install-queue-title")} }
+                if finished {
+                    button {
+                        class: "install-queue-clear",
+                        onclick: move |_| clear_finished(),
+                        {t("install-queue-clear")}
+                    }
+                }
+            }
+            for item in queue {
+                div {
+                    key: "{item.id}",
+                    class: "install-queue-item",
+                    title: "{item.path.display()}",
+                    span { class: "install-queue-name", {item.file_name()} }
+                    QueueStateLabel { state: item.state.clone(), progress: progress() }
+                    if item.state == QueueState::Waiting {
+                        button {
+                            class: "install-queue-remove",
+                            title: t("install-queue-remove"),
+                            onclick: move |_| remove_queued_install(item.id),
+                            "×"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn QueueStateLabel(state: QueueState, progress: Option<(usize, usize)>) -> Element {
+    rsx! {
+        match state {
+            QueueState::Waiting => rsx! { span { class: "install-queue-state", {t("install-queue-waiting")} } },
+            // Only one item extracts at a time, so the running extraction is this one's
+            QueueState::Extracting => match progress {
+                Some((done, total)) => rsx! {
+                    span { class: "install-queue-state active",
+                        {t_args("install-queue-extracting-progress", &[("done", &done.to_string()), ("total", &total.to_string())])}
+                    }
+                },
+                None => rsx! { span { class: "install-queue-state active", {t("install-queue-extracting")} } },
+            },
+            QueueState::Done => rsx! { span { class: "install-queue-state done", {t("install-queue-done")} } },
+            QueueState::Failed(error) => rsx! { span { class: "install-queue-state failed", title: error, {t("install-queue-failed")} } },
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::actions::open_log_folder;
+use crate::clock::LocalTime;
+use crate::i18n::t;
+use crate::logging::{LogLine, generation, recent_lines};
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+use log::Level;
+use std::time::Duration;
+
+// Levels offered in the filter; each shows itself and everything more severe
+const LEVEL_FILTERS: [(Level, &str); 3] = [
+    (Level::Trace, "logs-level-all"),
+    (Level::Warn, "logs-level-warn"),
+    (Level::Error, "logs-level-error"),
+];
+
+#[component]
+fn LogRow(line: LogLine) -> Element {
+    let time = LocalTime::from_unix(line.timestamp).time_label();
+
+    rsx! {
+        div { class: "log-row", "data-level": "{line.level}",
+            span { class: "log-time", "{time}" }
+            span { class: "log-level", "{line.level}" }
+            span { class: "log-target", "{line.target}" }
+            span { class: "log-message", "{line.message}" }
+        }
+    }
+}
+
+#[component]
+pub fn LogViewer() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let mut lines = use_signal(recent_lines);
+    let mut min_level = use_signal(|| Level::Trace);
+    let mut search = use_signal(String::new);
+
+    // Log records can come from any thread, so poll the buffer instead of signalling from the logger
+    use_future(move || async move {
+        let mut seen = generation();
+        loop {
+            tokio::time::sleep(Duration::from_millis(500)).await;
+            if generation() != seen {
+                seen = generation();
+                lines.set(recent_lines());
+            }
+        }
+    });
+
+    let query = search.read().to_lowercase();
+    let visible: Vec<LogLine> = lines
+        .read()
+        .iter()
+        .rev() // Newest first
+        .filter(|line| line.level <= min_level())
+        .filter(|line| query.is_empty() || line.message.to_lowercase().contains(&query) || line.target.contains(&query))
+        .cloned()
+        .collect();
+
+    rsx! {
+        div { class: "section log-viewer",
+            div { class: "log-toolbar",
+                select {
+                    class: "log-filter",
+                    onchange: move |event| {
+                        if let Ok(level) = event.value().parse::<Level>() {
+                            min_level.set(level);
+                        }
+                    },
+                    for (level, key) in LEVEL_FILTERS {
+                        option { value: "{level}", selected: level == min_level(), {t(key)} }
+                    }
+                }
+                input {
+                    class: "log-search",
+                    r#type: "search",
+                    placeholder: t("logs-search-placeholder"),
+                    value: "{search}",
+                    oninput: move |event| search.set(event.value()),
+                    onkeydown: move |event| event.stop_propagation(),
+                }
+                button {
+                    class: "log-open-folder",
+                    onclick: move |_| open_log_folder(),
+                    {t("logs-open-folder")}
+                }
+            }
+            div { class: "log-list",
+                if visible.is_empty() {
+                    div { class: "log-empty", {t("logs-empty")} }
+                }
+                for (index, line) in visible.into_iter().enumerate() {
+                    LogRow { key: "{index}", line }
+                }
+            }
+        }
+    }
+}
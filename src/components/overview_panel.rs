@@ -0,0 +1,78 @@
+use crate::data_operations::format_size;
+use crate::i18n::{t, t_args};
+use crate::navigation::{View, show_view};
+use crate::overview::{Breakdown, build_overview};
+use crate::plugin_store::{PLUGINS, PLUGIN_TARGETS, SELECTED_PLUGIN};
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+
+#[component]
+fn BreakdownList(title_key: &'static str, groups: Vec<Breakdown>) -> Element {
+    rsx! {
+        div { class: "overview-card",
+            h3 { {t(title_key)} }
+            if groups.is_empty() {
+                div { class: "overview-empty", {t("overview-nothing")} }
+            }
+            for group in groups {
+                div { key: "{group.label}", class: "overview-row",
+                    span { class: "overview-label",
+                        if group.label.is_empty() { {t("overview-unknown")} } else { "{group.label}" }
+                    }
+                    span { class: "overview-value", {t_args("overview-plugins", &[("count", &group.count.to_string())])} }
+                    span { class: "overview-value", {format_size(group.disk_bytes)} }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn OverviewPanel() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let overview = build_overview(&PLUGINS().unwrap_or_default(), &PLUGIN_TARGETS.read());
+
+    rsx! {
+        div { class: "section overview-panel",
+            div { class: "overview-totals",
+                div { class: "overview-total",
+                    span { class: "overview-number", "{overview.plugin_count}" }
+                    span { class: "overview-caption", {t("overview-total-plugins")} }
+                }
+                div { class: "overview-total",
+                    span { class: "overview-number", {format_size(overview.disk_bytes)} }
+                    span { class: "overview-caption", {t("overview-total-size")} }
+                }
+            }
+
+            div { class: "overview-card",
+                h3 { {t("overview-largest")} }
+                if overview.largest.is_empty() {
+                    div { class: "overview-empty", {t("overview-nothing")} }
+                }
+                for plugin in overview.largest {
+                    div {
+                        key: "{plugin.path.display()}",
+                        class: "overview-row clickable",
+                        title: t("overview-show-plugin"),
+                        onclick: {
+                            let path = plugin.path.clone();
+                            move |_| {
+                                *SELECTED_PLUGIN.write() = Some(path.clone());
+                                show_view(View::Plugins);
+                            }
+                        },
+                        span { class: "overview-label", "{plugin.name}" }
+                        span { class: "overview-value", "{plugin.version}" }
+                        span { class: "overview-value", "{plugin.disk_size}" }
+                    }
+                }
+            }
+
+            div { class: "overview-columns",
+                BreakdownList { title_key: "overview-by-host", groups: overview.by_host }
+                BreakdownList { title_key: "overview-by-vendor", groups: overview.by_vendor }
+            }
+        }
+    }
+}
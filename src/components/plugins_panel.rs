@@ -1,30 +1,154 @@
-use crate::data_operations::{Plugin, PluginType};
-use crate::file_operations::remove_plugin;
-use crate::message::{
-    LAST_INSTALLED_PLUGIN, REFRESH_TRIGGER, clear_newly_installed_plugin, show_error, show_success,
-    trigger_refresh,
+use crate::actions::{
+    copy_to_clipboard, create_missing_roots, disable_plugin_with_feedback, dismiss_missing_roots, enable_plugin_with_feedback, export_plugin_with_feedback,
+    import_plugin_with_feedback, open_folder_with_feedback, open_manifest_with_feedback, remove_plugin_as_admin, remove_plugin_deep_clean,
+    remove_plugin_with_feedback, resolve_duplicates, reveal_with_feedback, unlink_dev_folder_with_feedback,
 };
+use crate::clock::{LocalTime, relative_date_label, unix_now};
+use crate::components::context_menu::{ContextMenu, ContextMenuItem};
+use crate::components::details_drawer::DetailsDrawer;
+use crate::data_operations::{BrokenExtension, ExtensionRoot, Plugin, PluginType, UiKind, format_size};
+use crate::elevation::elevation_available;
+use crate::i18n::{t, t_args};
+use crate::message::{LAST_INSTALLED_PLUGIN, clear_newly_installed_plugin};
+use crate::host_detection::{
+    RuntimeShortfall, host_display_name, listed_host_codes, runtime_shortfall, targets_host, targets_only_missing_hosts,
+};
+use crate::plugin_icons::{avatar_hue, avatar_initial};
+use crate::plugin_notes::{PLUGIN_NOTES, all_tags, has_tag, is_favorite, toggle_favorite};
+use crate::plugin_store::{
+    BROKEN_EXTENSIONS, DISABLED_PLUGINS, Duplicate, HOST_APPS, INVENTORY, MISSING_ROOTS, MISSING_ROOTS_DISMISSED, PLUGINS, PLUGIN_ICONS, PLUGIN_TARGETS, SELECTED_PLUGIN, VendorGroup,
+    close_inventory, find_duplicates, group_by_vendor, sorted_plugins,
+};
+use crate::settings::{DoubleClickAction, PluginSort, QuickAction, SETTINGS, update_settings};
+use crate::shortcuts::{ShortcutAction, is_text_editing_key, shortcut_hint};
 use dioxus::prelude::*;
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 
 #[component]
-fn PluginHeader() -> Element {
+fn PluginHeader(read_only: bool, show_bundle_id: bool) -> Element {
+    let sort = SETTINGS.read().plugin_sort;
+    let name_arrow = match sort {
+        PluginSort::NameAscending => " ▲",
+        PluginSort::NameDescending => " ▼",
+        _ => "",
+    };
+    let version_arrow = match sort {
+        PluginSort::HighestVersion => " ▼",
+        PluginSort::LowestVersion => " ▲",
+        _ => "",
+    };
+    let installed_arrow = match sort {
+        PluginSort::NewestFirst => " ▼",
+        PluginSort::OldestFirst => " ▲",
+        _ => "",
+    };
+
     rsx! {
-        div { class: "plugin-grid-row plugins-header",
-            div { class: "header-cell plugin-header", "Plugin" }
-            div { class: "header-cell version-header", "Version" }
-            div { class: "header-cell size-header", "Size" }
-            div { class: "header-cell actions-header", "Remove" }
+        div { class: if show_bundle_id { "plugin-grid-row with-bundle-id plugins-header" } else { "plugin-grid-row plugins-header" },
+            div {
+                class: "header-cell plugin-header sortable",
+                onclick: move |_| update_settings(|settings| settings.plugin_sort = settings.plugin_sort.toggle_name()),
+                {t("column-plugin")}
+                "{name_arrow}"
+            }
+            if show_bundle_id {
+                div { class: "header-cell bundle-id-header", {t("column-bundle-id")} }
+            }
+            div {
+                class: "header-cell version-header sortable",
+                onclick: move |_| update_settings(|settings| settings.plugin_sort = settings.plugin_sort.toggle_version()),
+                {t("column-version")}
+                "{version_arrow}"
+            }
+            div { class: "header-cell size-header", {t("column-size")} }
+            div {
+                class: "header-cell installed-header sortable",
+                onclick: move |_| update_settings(|settings| settings.plugin_sort = settings.plugin_sort.toggle_installed()),
+                {t("column-installed")}
+                "{installed_arrow}"
+            }
+            div { class: "header-cell actions-header",
+                if read_only { {t("column-import")} } else { {t("column-remove")} }
+            }
         }
     }
 }
 
 #[component]
 fn PluginBadge(plugin_type: PluginType) -> Element {
+    rsx! {
+        match plugin_type {
+            PluginType::Native => rsx! { span { class: "badge-native", {t("badge-native")} } },
+            PluginType::Installed => rsx! { span { class: "badge-installed", {t("badge-installed")} } },
+            PluginType::Dev => rsx! { span { class: "badge-dev", title: t("badge-dev-hint"), {t("badge-dev")} } },
+            PluginType::Legacy => rsx! { span { class: "badge-legacy", title: t("badge-legacy-hint"), {t("badge-legacy")} } },
+        }
+    }
+}
+
+// Manifest icon, or a colored initial when the plugin ships none
+#[component]
+fn PluginIcon(name: String, icon: Option<String>) -> Element {
+    rsx! {
+        match icon {
+            Some(src) => rsx! { img { class: "plugin-icon", src, alt: "" } },
+            None => rsx! {
+                span {
+                    class: "plugin-icon plugin-avatar",
+                    style: "background: hsl({avatar_hue(&name)}, 45%, 35%)",
+                    {avatar_initial(&name)}
+                }
+            },
+        }
+    }
+}
+
+// Shown when none of the apps in the manifest's HostList is installed
+#[component]
+fn MissingHostBadge(targets: String) -> Element {
+    rsx! {
+        span {
+            class: "badge-missing-host",
+            title: t_args("badge-missing-host-hint", &[("hosts", &targets)]),
+            {t("badge-missing-host")}
+        }
+    }
+}
+
+// The panel needs a newer CEP than the targeted apps ship, so it will never load
+#[component]
+fn RuntimeBadge(shortfall: RuntimeShortfall) -> Element {
+    let required = shortfall.required.to_string();
+    let available = shortfall.available.to_string();
+    rsx! {
+        span {
+            class: "badge-runtime",
+            title: t_args(
+                "badge-runtime-hint",
+                &[("required", &required), ("host", host_display_name(shortfall.host)), ("available", &available)],
+            ),
+            {t_args("badge-runtime", &[("required", &required)])}
+        }
+    }
+}
+
+// Every extension in the bundle is invisible, so it never shows up in Window > Extensions
+#[component]
+fn BackgroundBadge() -> Element {
+    rsx! {
+        span { class: "badge-background", title: t("badge-background-hint"), {t("badge-background")} }
+    }
+}
+
+// Another folder holds the same bundle ID - hosts load only one of the copies
+#[component]
+fn DuplicateBadge(copies: usize) -> Element {
     rsx! {
         span {
-            class: if matches!(plugin_type, PluginType::Native) { "badge-native" } else { "badge-installed" },
-            if matches!(plugin_type, PluginType::Native) { "native" } else { "installed" }
+            class: "badge-duplicate",
+            title: t_args("badge-duplicate-hint", &[("count", &copies.to_string())]),
+            {t("badge-duplicate")}
         }
     }
 }
@@ -37,22 +161,7 @@ fn RemoveButton(plugin_path: PathBuf, can_remove: bool) -> Element {
             disabled: !can_remove,
             onclick: move |_| {
                 log::info!("Remove button clicked for: {:?}", plugin_path);
-                let plugin_path = plugin_path.clone();
-                spawn(async move {
-                    log::info!("Starting plugin removal for: {:?}", plugin_path);
-                    match remove_plugin(&plugin_path) {
-                        Ok(_) => {
-                            log::info!("Plugin removed successfully: {:?}", plugin_path);
-                            show_success("Plugin removed successfully!".to_string());
-                            trigger_refresh();
-                        }
-                        Err(e) => {
-                            let error_msg = format!("Failed to remove plugin: {}", e);
-                            log::error!("{}", error_msg);
-                            show_error(error_msg);
-                        }
-                    }
-                });
+                remove_plugin_with_feedback(plugin_path.clone());
             },
             dangerous_inner_html: include_str!("../../assets/icons/trash.svg")
         }
@@ -60,37 +169,416 @@ fn RemoveButton(plugin_path: PathBuf, can_remove: bool) -> Element {
 }
 
 #[component]
-fn PluginCard(plugin: Plugin, is_newly_installed: bool) -> Element {
+fn ImportButton(plugin_path: PathBuf) -> Element {
+    rsx! {
+        button {
+            class: "remove-btn import-btn",
+            title: t("menu-import"),
+            onclick: move |_| import_plugin_with_feedback(plugin_path.clone()),
+            dangerous_inner_html: include_str!("../../assets/icons/download.svg")
+        }
+    }
+}
+
+// Hover buttons picked in settings; a read-only inventory only gets the ones that change nothing
+#[component]
+fn QuickActions(plugin: Plugin, read_only: bool, on_details: EventHandler<()>) -> Element {
+    let hidden = SETTINGS.read().hidden_quick_actions.clone();
+    let actions: Vec<QuickAction> = QuickAction::ALL
+        .into_iter()
+        .filter(|action| !hidden.contains(action))
+        .filter(|action| !read_only || matches!(action, QuickAction::Reveal | QuickAction::Details))
+        .collect();
+    if actions.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        div { class: "quick-actions",
+            for action in actions {
+                button {
+                    key: "{action:?}",
+                    class: if action == QuickAction::Remove { "quick-action danger" } else { "quick-action" },
+                    title: t(action.label_key()),
+                    disabled: matches!(action, QuickAction::Disable | QuickAction::Remove) && !plugin.can_remove,
+                    onclick: {
+                        let plugin = plugin.clone();
+                        move |_| run_quick_action(action, plugin.clone(), on_details)
+                    },
+                    dangerous_inner_html: quick_action_icon(action),
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn FavoriteButton(bundle_id: String, favorite: bool) -> Element {
+    rsx! {
+        button {
+            class: if favorite { "favorite-toggle active" } else { "favorite-toggle" },
+            title: if favorite { t("favorite-remove") } else { t("favorite-add") },
+            onclick: move |event| {
+                event.stop_propagation(); // Starring doesn't change the selection
+                toggle_favorite(&bundle_id);
+            },
+            if favorite { "★" } else { "☆" }
+        }
+    }
+}
+
+#[component]
+fn VendorHeader(vendor: String, count: usize, disk_bytes: u64, collapsed: bool, on_toggle: EventHandler<()>) -> Element {
+    rsx! {
+        div { class: "vendor-header", onclick: move |_| on_toggle.call(()),
+            span { class: "vendor-arrow", if collapsed { "▸" } else { "▾" } }
+            span { class: "vendor-name",
+                if vendor.is_empty() { {t("vendor-unknown")} } else { "{vendor}" }
+            }
+            span { class: "vendor-subtotal",
+                {t_args("vendor-subtotal", &[("count", &count.to_string()), ("size", &format_size(disk_bytes))])}
+            }
+        }
+    }
+}
+
+#[component]
+fn DisabledPlugins(plugins: Vec<Plugin>) -> Element {
+    rsx! {
+        div { class: "disabled-plugins",
+            div { class: "disabled-plugins-title", {t_args("disabled-plugins-title", &[("count", &plugins.len().to_string())])} }
+            for plugin in plugins {
+                div { key: "{plugin.path.display()}", class: "disabled-plugin",
+                    span { class: "disabled-plugin-name", "{plugin.name}" }
+                    span { class: "plugin-version", "{plugin.version}" }
+                    button {
+                        class: "disabled-plugin-enable",
+                        disabled: !plugin.can_remove,
+                        onclick: {
+                            let plugin = plugin.clone();
+                            move |_| enable_plugin_with_feedback(plugin.clone())
+                        },
+                        {t("menu-enable")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Leftovers hosts may still trip over, with why each one isn't a plugin
+#[component]
+fn BrokenExtensions(extensions: Vec<BrokenExtension>) -> Element {
+    rsx! {
+        div { class: "disabled-plugins broken-extensions",
+            div { class: "disabled-plugins-title", title: t("broken-extensions-hint"),
+                {t_args("broken-extensions-title", &[("count", &extensions.len().to_string())])}
+            }
+            for extension in extensions {
+                div { key: "{extension.path.display()}", class: "disabled-plugin broken-extension",
+                    div { class: "broken-extension-text",
+                        span { class: "disabled-plugin-name", {extension.path.file_name().unwrap_or_default().to_string_lossy().to_string()} }
+                        span { class: "broken-extension-error", "{extension.error}" }
+                    }
+                    button {
+                        class: "disabled-plugin-enable",
+                        onclick: {
+                            let path = extension.path.clone();
+                            move |_| open_folder_with_feedback(&path)
+                        },
+                        {t("broken-extension-open")}
+                    }
+                    button {
+                        class: "disabled-plugin-enable",
+                        onclick: {
+                            let path = extension.path.clone();
+                            move |_| remove_plugin_with_feedback(path.clone())
+                        },
+                        {t("menu-remove")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn InventoryBanner(folder: PathBuf, count: usize) -> Element {
+    rsx! {
+        div { class: "inventory-banner",
+            div { class: "inventory-text",
+                {t_args("inventory-browsing", &[("count", &count.to_string())])}
+                span { class: "inventory-folder", "{folder.display()}" }
+            }
+            button {
+                class: "inventory-close",
+                onclick: move |_| close_inventory(),
+                {t("inventory-close")}
+            }
+        }
+    }
+}
+
+#[component]
+fn DuplicatesBanner(duplicates: Vec<Duplicate>) -> Element {
+    rsx! {
+        div { class: "inventory-banner duplicates-banner",
+            div { class: "inventory-text",
+                {t_args("duplicates-banner", &[("count", &duplicates.len().to_string())])}
+            }
+            button {
+                class: "inventory-close",
+                onclick: move |_| resolve_duplicates(duplicates.clone()),
+                {t("duplicates-resolve")}
+            }
+        }
+    }
+}
+
+// Installs create the folders too, but an empty list on a fresh machine looks like a failed scan
+#[component]
+fn MissingRootsBanner(roots: Vec<ExtensionRoot>) -> Element {
+    let paths = roots.iter().filter_map(ExtensionRoot::path).map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ");
+
+    rsx! {
+        div { class: "inventory-banner missing-roots-banner",
+            div { class: "inventory-text",
+                {t("missing-roots-banner")}
+                span { class: "inventory-folder", "{paths}" }
+            }
+            div { class: "missing-roots-actions",
+                button { class: "inventory-close", onclick: move |_| dismiss_missing_roots(), {t("missing-roots-dismiss")} }
+                button {
+                    class: "inventory-close",
+                    title: if roots.contains(&ExtensionRoot::System) { t("missing-roots-admin-hint") } else { String::new() },
+                    onclick: move |_| create_missing_roots(roots.clone()),
+                    {t("missing-roots-create")}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn PluginCard(
+    plugin: Plugin,
+    is_newly_installed: bool,
+    is_selected: bool,
+    read_only: bool,
+    missing_hosts: Option<String>,
+    shortfall: Option<RuntimeShortfall>,
+    background_only: bool,
+    duplicate_copies: Option<usize>,
+    tags: Vec<String>,
+    favorite: bool,
+    show_bundle_id: bool,
+    on_context_menu: EventHandler<(f64, f64)>,
+    on_double_click: EventHandler<()>,
+    on_details: EventHandler<()>,
+) -> Element {
+    let mut classes = vec!["plugin-grid-row", "plugin-card"];
+    if show_bundle_id {
+        classes.push("with-bundle-id");
+    }
+    if is_newly_installed {
+        classes.push("newly-added");
+    }
+    if is_selected {
+        classes.push("selected");
+    }
+    if duplicate_copies.is_some() {
+        classes.push("duplicate");
+    }
+    let mut expanded = use_signal(|| false);
+    if expanded() {
+        classes.push("expanded");
+    }
+    let plugin_path = plugin.path.clone();
+
     rsx! {
         div {
             key: "{plugin.path.display()}",
-            class: if is_newly_installed { "plugin-grid-row plugin-card newly-added" } else { "plugin-grid-row plugin-card" },
+            class: classes.join(" "),
+            onclick: {
+                let plugin_path = plugin_path.clone();
+                move |_| *SELECTED_PLUGIN.write() = Some(plugin_path.clone())
+            },
+            ondoubleclick: move |_| on_double_click.call(()),
+            oncontextmenu: move |event| {
+                event.prevent_default();
+                *SELECTED_PLUGIN.write() = Some(plugin_path.clone());
+                let point = event.client_coordinates();
+                on_context_menu.call((point.x, point.y));
+            },
             div { class: "plugin-info",
                 div { class: "plugin-name",
+                    button {
+                        class: "plugin-expand",
+                        title: if expanded() { t("expanded-collapse") } else { t("expanded-expand") },
+                        aria_expanded: expanded(),
+                        onclick: move |event| {
+                            event.stop_propagation();
+                            expanded.toggle();
+                        },
+                        if expanded() { "▾" } else { "▸" }
+                    }
+                    FavoriteButton { bundle_id: plugin.bundle_id.clone(), favorite }
+                    PluginIcon { name: plugin.name.clone(), icon: PLUGIN_ICONS.read().get(&plugin.path).cloned() }
                     "{plugin.name}"
-                    PluginBadge { plugin_type: plugin.plugin_type }
+                    PluginBadge { plugin_type: plugin.plugin_type.clone() }
+                    if let Some(targets) = missing_hosts {
+                        MissingHostBadge { targets }
+                    }
+                    if let Some(shortfall) = shortfall {
+                        RuntimeBadge { shortfall }
+                    }
+                    if background_only {
+                        BackgroundBadge {}
+                    }
+                    if let Some(copies) = duplicate_copies {
+                        DuplicateBadge { copies }
+                    }
+                    for tag in tags {
+                        span { key: "{tag}", class: "plugin-tag", "{tag}" }
+                    }
+                    QuickActions { plugin: plugin.clone(), read_only, on_details }
                 }
             }
+            if show_bundle_id {
+                div { class: "plugin-bundle-id", title: "{plugin.bundle_id}", "{plugin.bundle_id}" }
+            }
             div { class: "plugin-version", "{plugin.version}" }
-            div { class: "plugin-size", "{plugin.size}" }
+            div {
+                class: "plugin-size",
+                title: t_args("size-on-disk", &[("size", &plugin.disk_size)]),
+                "{plugin.size}"
+            }
+            match plugin.installed_at {
+                Some(timestamp) => rsx! {
+                    div {
+                        class: "plugin-installed",
+                        title: LocalTime::from_unix(timestamp).date_label(),
+                        {relative_date_label(timestamp, unix_now())}
+                    }
+                },
+                None => rsx! { div { class: "plugin-installed", "—" } },
+            }
             div { class: "plugin-actions",
-                RemoveButton { plugin_path: plugin.path, can_remove: plugin.can_remove }
+                if read_only || plugin.plugin_type == PluginType::Legacy {
+                    ImportButton { plugin_path: plugin.path.clone() }
+                } else {
+                    RemoveButton { plugin_path: plugin.path.clone(), can_remove: plugin.can_remove }
+                }
+            }
+            if expanded() {
+                ExpandedDetails { plugin: plugin.clone(), read_only, on_details }
             }
         }
     }
 }
 
 #[component]
-pub fn PluginsPanel() -> Element {
-    let plugins = use_resource(move || {
-        let _ = REFRESH_TRIGGER();
-        async move {
-            crate::data_operations::scan_cep_plugins().unwrap_or_else(|e| {
-                log::error!("Failed to scan plugins: {}", e);
-                Vec::new()
-            })
+fn ExpandedField(label: String, value: String, #[props(default)] copy_item: Option<String>) -> Element {
+    rsx! {
+        div { class: "expanded-field",
+            span { class: "expanded-label", "{label}" }
+            span { class: "expanded-value", title: "{value}", "{value}" }
+            if let Some(item) = copy_item {
+                button {
+                    class: "quick-action",
+                    title: t("details-copy"),
+                    onclick: {
+                        let value = value.clone();
+                        move |event: MouseEvent| {
+                            event.stop_propagation();
+                            copy_to_clipboard(value.clone(), &item);
+                        }
+                    },
+                    dangerous_inner_html: include_str!("../../assets/icons/copy.svg"),
+                }
+            }
         }
-    });
+    }
+}
+
+// The row opened in place: what the grid columns leave out, plus every quick action
+#[component]
+fn ExpandedDetails(plugin: Plugin, read_only: bool, on_details: EventHandler<()>) -> Element {
+    let hosts = PLUGIN_TARGETS
+        .read()
+        .get(&plugin.path)
+        .map(|targets| {
+            targets
+                .hosts
+                .iter()
+                .map(|host| match &host.version {
+                    Some(version) => format!("{} {}", host_display_name(&host.name), version),
+                    None => host_display_name(&host.name).to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .filter(|hosts| !hosts.is_empty())
+        .unwrap_or_else(|| t("details-none"));
+    let installed = match plugin.installed_at {
+        Some(timestamp) => {
+            let time = LocalTime::from_unix(timestamp);
+            format!("{} {}", time.date_label(), time.time_label())
+        }
+        None => "—".to_string(),
+    };
+
+    rsx! {
+        div { class: "plugin-expanded",
+            ExpandedField { label: t("details-bundle-id"), value: plugin.bundle_id.clone(), copy_item: t("item-bundle-id") }
+            ExpandedField { label: t("item-path"), value: plugin.path.display().to_string(), copy_item: t("item-path") }
+            ExpandedField { label: t("expanded-hosts"), value: hosts }
+            ExpandedField { label: t("column-installed"), value: installed }
+            QuickActions { plugin: plugin.clone(), read_only, on_details }
+        }
+    }
+}
+
+#[component]
+pub fn PluginsPanel() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    let inventory = INVENTORY.read();
+    let installed = PLUGINS.read();
+    let read_only = inventory.is_some();
+    let plugins = match &*inventory {
+        Some(inventory) => Some(&inventory.plugins),
+        None => installed.as_ref(),
+    };
+    let selected = SELECTED_PLUGIN();
+    let sort = SETTINGS.read().plugin_sort;
+    let grouped = SETTINGS.read().group_by_vendor;
+    // Foreign inventories have no parsed host lists - the filter only applies to installed plugins
+    let host_filter = if read_only { None } else { SETTINGS.read().host_filter.clone() };
+    let mut host_codes = listed_host_codes(PLUGIN_TARGETS.read().values());
+    if let Some(code) = host_filter.as_ref().filter(|code| !host_codes.contains(code)) {
+        host_codes.push(code.clone()); // Still selectable after its last plugin went away
+    }
+    let tag_filter = SETTINGS.read().tag_filter.clone();
+    let show_bundle_id = SETTINGS.read().show_bundle_id;
+    let mut tags = all_tags(&PLUGIN_NOTES.read());
+    if let Some(tag) = tag_filter.as_ref().filter(|tag| !tags.contains(tag)) {
+        tags.push(tag.clone()); // Same as the host filter - the selection stays visible
+    }
+    let mut collapsed_vendors = use_signal(HashSet::<String>::new);
+    // A foreign inventory is never modified, so only installed plugins are checked
+    let duplicates = if read_only { Vec::new() } else { plugins.map(|plugins| find_duplicates(plugins)).unwrap_or_default() };
+    let mut search = use_signal(String::new);
+    let mut context_menu = use_signal(|| None::<(Plugin, f64, f64)>);
+    let mut details = use_signal(|| None::<Plugin>);
+    let disabled_plugins: Vec<Plugin> = if read_only {
+        Vec::new()
+    } else {
+        DISABLED_PLUGINS.read().iter().filter(|plugin| matches_search(plugin, &search.read())).cloned().collect()
+    };
+    let broken_extensions: Vec<BrokenExtension> = if read_only { Vec::new() } else { BROKEN_EXTENSIONS() };
+    let missing_roots: Vec<ExtensionRoot> = if read_only || MISSING_ROOTS_DISMISSED() { Vec::new() } else { MISSING_ROOTS() };
+    let search_placeholder = format!("{}{}", t("search-placeholder"), shortcut_hint(ShortcutAction::FocusSearch));
 
     let last_installed = LAST_INSTALLED_PLUGIN();
 
@@ -105,20 +593,309 @@ pub fn PluginsPanel() -> Element {
 
     rsx! {
         div { class: "section plugins-panel",
-            PluginHeader {}
+            if let Some(inventory) = &*inventory {
+                InventoryBanner { folder: inventory.folder.clone(), count: inventory.plugins.len() }
+            }
+            div { class: "plugin-toolbar",
+                input {
+                    id: "plugin-search",
+                    class: "plugin-search",
+                    r#type: "search",
+                    placeholder: search_placeholder,
+                    value: "{search}",
+                    oninput: move |event| search.set(event.value()),
+                    onkeydown: move |event| {
+                        // Typing and clipboard keys belong to the text field (e.g. Delete, Cmd+V)
+                        if is_text_editing_key(&event) {
+                            event.stop_propagation();
+                        }
+                    },
+                }
+                if !read_only {
+                    select {
+                        class: "plugin-host-filter",
+                        value: host_filter.clone().unwrap_or_default(),
+                        onchange: move |event| {
+                            let code = Some(event.value()).filter(|code| !code.is_empty());
+                            update_settings(|settings| settings.host_filter = code);
+                        },
+                        option { value: "", selected: host_filter.is_none(), {t("host-filter-all")} }
+                        for code in host_codes {
+                            option {
+                                value: "{code}",
+                                selected: host_filter.as_ref() == Some(&code),
+                                {host_display_name(&code).to_string()}
+                            }
+                        }
+                    }
+                }
+                if !tags.is_empty() {
+                    select {
+                        class: "plugin-host-filter",
+                        value: tag_filter.clone().unwrap_or_default(),
+                        onchange: move |event| {
+                            let tag = Some(event.value()).filter(|tag| !tag.is_empty());
+                            update_settings(|settings| settings.tag_filter = tag);
+                        },
+                        option { value: "", selected: tag_filter.is_none(), {t("tag-filter-all")} }
+                        for tag in tags {
+                            option { value: "{tag}", selected: tag_filter.as_ref() == Some(&tag), "#{tag}" }
+                        }
+                    }
+                }
+                label { class: "plugin-group-toggle",
+                    input {
+                        r#type: "checkbox",
+                        checked: grouped,
+                        onchange: move |event| update_settings(|settings| settings.group_by_vendor = event.checked()),
+                    }
+                    {t("group-by-vendor")}
+                }
+            }
+            if !missing_roots.is_empty() {
+                MissingRootsBanner { roots: missing_roots.clone() }
+            }
+            if !duplicates.is_empty() {
+                DuplicatesBanner { duplicates: duplicates.clone() }
+            }
+            PluginHeader { read_only, show_bundle_id }
             div { class: "plugins-grid",
-                if let Some(plugin_list) = &*plugins.read() {
-                    for plugin in plugin_list {
-                        PluginCard {
-                            plugin: plugin.clone(),
-                            is_newly_installed: last_installed.as_ref() == Some(&plugin.path)
+                if let Some(plugin_list) = plugins {
+                    for (group, rows) in plugin_sections(plugin_list, sort, grouped, &search.read(), host_filter.as_deref(), tag_filter.as_deref(), &collapsed_vendors.read()) {
+                        if let Some(group) = group {
+                            VendorHeader {
+                                key: "vendor-{group.vendor}",
+                                vendor: group.vendor.clone(),
+                                count: group.plugins.len(),
+                                disk_bytes: group.disk_bytes,
+                                collapsed: collapsed_vendors.read().contains(&group.vendor),
+                                on_toggle: {
+                                    let vendor = group.vendor.clone();
+                                    move |_| {
+                                        let mut collapsed = collapsed_vendors.write();
+                                        if !collapsed.remove(&vendor) {
+                                            collapsed.insert(vendor.clone());
+                                        }
+                                    }
+                                },
+                            }
+                        }
+                        for plugin in rows {
+                            PluginCard {
+                                plugin: plugin.clone(),
+                                is_newly_installed: last_installed.as_ref() == Some(&plugin.path),
+                                is_selected: selected.as_ref() == Some(&plugin.path),
+                                read_only,
+                                missing_hosts: if read_only { None } else { missing_hosts_label(&plugin.path) },
+                                shortfall: if read_only { None } else { plugin_runtime_shortfall(&plugin.path) },
+                                background_only: runs_in_background(&plugin.path),
+                                duplicate_copies: duplicate_of(&duplicates, plugin).map(|duplicate| duplicate.remove.len() + 1),
+                                tags: PLUGIN_NOTES.read().get(&plugin.bundle_id).map(|note| note.tags.clone()).unwrap_or_default(),
+                                favorite: is_favorite(&PLUGIN_NOTES.read(), &plugin.bundle_id),
+                                show_bundle_id,
+                                on_context_menu: {
+                                    let plugin = plugin.clone();
+                                    move |(x, y)| context_menu.set(Some((plugin.clone(), x, y)))
+                                },
+                                on_double_click: {
+                                    let plugin = plugin.clone();
+                                    move |_| run_double_click_action(plugin.clone(), details)
+                                },
+                                on_details: {
+                                    let plugin = plugin.clone();
+                                    move |_| details.set(Some(plugin.clone()))
+                                }
+                            }
                         }
                     }
+                    if !disabled_plugins.is_empty() {
+                        DisabledPlugins { plugins: disabled_plugins }
+                    }
+                    if !broken_extensions.is_empty() {
+                        BrokenExtensions { extensions: broken_extensions }
+                    }
                 } else {
-                    div { class: "loading-message", "Loading plugins..." }
+                    div { class: "loading-message", {t("loading-plugins")} }
+                }
+            }
+            if let Some((plugin, x, y)) = context_menu() {
+                ContextMenu {
+                    x,
+                    y,
+                    items: plugin_menu_items(plugin.clone(), read_only, duplicate_of(&duplicates, &plugin).cloned(), details),
+                    on_close: move |_| context_menu.set(None),
                 }
             }
+            if let Some(plugin) = details() {
+                DetailsDrawer { plugin, read_only, on_close: move |_| details.set(None) }
+            }
         }
     }
 }
 
+
+// Helper functions
+// Rows matching the search and host filter in display order; grouped, each vendor comes with its
+// header and a collapsed vendor with no rows
+fn plugin_sections<'a>(
+    plugins: &'a [Plugin],
+    sort: PluginSort,
+    grouped: bool,
+    search: &str,
+    host: Option<&str>,
+    tag: Option<&str>,
+    collapsed: &HashSet<String>,
+) -> Vec<(Option<VendorGroup<'a>>, Vec<&'a Plugin>)> {
+    let targets = PLUGIN_TARGETS.read();
+    let notes = PLUGIN_NOTES.read();
+    // Favorites come first whatever the sort; within them and the rest the sort still applies
+    let (favorites, rows): (Vec<&Plugin>, Vec<&Plugin>) = sorted_plugins(plugins, sort)
+        .into_iter()
+        .filter(|plugin| matches_search(plugin, search))
+        .filter(|plugin| host.is_none_or(|code| targets.get(&plugin.path).is_some_and(|targets| targets_host(targets, code))))
+        .filter(|plugin| tag.is_none_or(|tag| has_tag(&notes, &plugin.bundle_id, tag)))
+        .partition(|plugin| is_favorite(&notes, &plugin.bundle_id));
+    if !grouped {
+        return vec![(None, favorites.into_iter().chain(rows).collect())];
+    }
+
+    // Grouped, favorites sit above the vendor groups without a header
+    let pinned = (!favorites.is_empty()).then_some((None, favorites));
+    pinned
+        .into_iter()
+        .chain(group_by_vendor(rows).into_iter().map(|group| {
+            let rows = if collapsed.contains(&group.vendor) { Vec::new() } else { group.plugins.clone() };
+            (Some(group), rows)
+        }))
+        .collect()
+}
+
+fn plugin_menu_items(
+    plugin: Plugin,
+    read_only: bool,
+    duplicate: Option<Duplicate>,
+    mut details: Signal<Option<Plugin>>,
+) -> Vec<ContextMenuItem> {
+    let reveal_label = if cfg!(target_os = "macos") { t("menu-reveal-finder") } else { t("menu-reveal-explorer") };
+    let (bundle_id, version, path) = (plugin.bundle_id.clone(), plugin.version.clone(), plugin.path.clone());
+
+    let legacy = plugin.plugin_type == PluginType::Legacy;
+    let last_item = if read_only || legacy {
+        let path = plugin.path.clone();
+        ContextMenuItem::new(t("menu-import"), move |_| import_plugin_with_feedback(path.clone()))
+    } else if plugin.plugin_type == PluginType::Dev {
+        let path = plugin.path.clone();
+        ContextMenuItem::new(t("menu-unlink-dev"), move |_| unlink_dev_folder_with_feedback(path.clone()))
+    } else if !plugin.can_remove && elevation_available() {
+        let path = plugin.path.clone();
+        ContextMenuItem::new(t("menu-remove-as-admin"), move |_| remove_plugin_as_admin(path.clone())).danger()
+    } else {
+        let path = plugin.path.clone();
+        ContextMenuItem::new(t("menu-remove"), move |_| remove_plugin_with_feedback(path.clone()))
+            .danger()
+            .disabled(!plugin.can_remove)
+    };
+
+    // Also deletes the panel's settings, cache and logs - only where a plain removal is offered
+    let deep_clean = (!read_only && !legacy && plugin.plugin_type != PluginType::Dev && plugin.can_remove).then(|| {
+        let path = plugin.path.clone();
+        ContextMenuItem::new(t("menu-remove-deep-clean"), move |_| remove_plugin_deep_clean(path.clone())).danger()
+    });
+
+    let mut items = vec![
+        ContextMenuItem::new(t("menu-details"), {
+            let plugin = plugin.clone();
+            move |_| details.set(Some(plugin.clone()))
+        }),
+        ContextMenuItem::new(reveal_label, {
+            let path = plugin.path.clone();
+            move |_| reveal_with_feedback(&path)
+        }),
+        ContextMenuItem::new(t("menu-copy-bundle-id"), move |_| copy_to_clipboard(bundle_id.clone(), &t("item-bundle-id"))),
+        ContextMenuItem::new(t("menu-copy-version"), move |_| copy_to_clipboard(version.clone(), &t("item-version"))),
+        ContextMenuItem::new(t("menu-copy-path"), move |_| copy_to_clipboard(path.display().to_string(), &t("item-path"))),
+        ContextMenuItem::new(t("menu-open-manifest"), {
+            let path = plugin.path.clone();
+            move |_| open_manifest_with_feedback(&path)
+        }),
+        ContextMenuItem::new(t("menu-export-zxp"), {
+            let plugin = plugin.clone();
+            move |_| export_plugin_with_feedback(plugin.clone())
+        }),
+    ];
+    if let Some(duplicate) = duplicate {
+        items.push(ContextMenuItem::new(t("menu-resolve-duplicate"), move |_| resolve_duplicates(vec![duplicate.clone()])));
+    }
+    if legacy {
+        let path = plugin.path.clone();
+        items.push(
+            ContextMenuItem::new(t("menu-remove"), move |_| remove_plugin_with_feedback(path.clone()))
+                .danger()
+                .disabled(!plugin.can_remove),
+        );
+    } else if !read_only {
+        let can_remove = plugin.can_remove;
+        items.push(ContextMenuItem::new(t("menu-disable"), move |_| disable_plugin_with_feedback(plugin.clone())).disabled(!can_remove));
+    }
+    items.push(last_item);
+    items.extend(deep_clean);
+    items
+}
+
+fn run_quick_action(action: QuickAction, plugin: Plugin, on_details: EventHandler<()>) {
+    match action {
+        QuickAction::Reveal => reveal_with_feedback(&plugin.path),
+        QuickAction::Details => on_details.call(()),
+        QuickAction::Disable => disable_plugin_with_feedback(plugin),
+        QuickAction::Remove => remove_plugin_with_feedback(plugin.path),
+    }
+}
+
+fn quick_action_icon(action: QuickAction) -> &'static str {
+    match action {
+        QuickAction::Reveal => include_str!("../../assets/icons/folder-open.svg"),
+        QuickAction::Details => include_str!("../../assets/icons/info.svg"),
+        QuickAction::Disable => include_str!("../../assets/icons/power.svg"),
+        QuickAction::Remove => include_str!("../../assets/icons/trash.svg"),
+    }
+}
+
+fn run_double_click_action(plugin: Plugin, mut details: Signal<Option<Plugin>>) {
+    match SETTINGS.read().double_click {
+        DoubleClickAction::OpenDetails => details.set(Some(plugin)),
+        DoubleClickAction::RevealInFileManager => reveal_with_feedback(&plugin.path),
+        DoubleClickAction::OpenManifest => open_manifest_with_feedback(&plugin.path),
+    }
+}
+
+// Names of the targeted hosts when none of them is installed, e.g. "After Effects, Premiere Pro"
+fn missing_hosts_label(plugin_path: &Path) -> Option<String> {
+    let plugin_targets = PLUGIN_TARGETS.read();
+    let hosts = &plugin_targets.get(plugin_path)?.hosts;
+    if !targets_only_missing_hosts(hosts, &HOST_APPS.read()) {
+        return None;
+    }
+    let mut names: Vec<&str> = hosts.iter().map(|host| host_display_name(&host.name)).collect();
+    names.dedup();
+    Some(names.join(", "))
+}
+
+fn duplicate_of<'a>(duplicates: &'a [Duplicate], plugin: &Plugin) -> Option<&'a Duplicate> {
+    duplicates.iter().find(|duplicate| duplicate.bundle_id == plugin.bundle_id)
+}
+
+fn plugin_runtime_shortfall(plugin_path: &Path) -> Option<RuntimeShortfall> {
+    runtime_shortfall(PLUGIN_TARGETS.read().get(plugin_path)?, &HOST_APPS.read())
+}
+
+fn runs_in_background(plugin_path: &Path) -> bool {
+    PLUGIN_TARGETS
+        .read()
+        .get(plugin_path)
+        .is_some_and(|targets| !targets.ui_kinds.is_empty() && targets.ui_kinds.iter().all(|kind| *kind == UiKind::Invisible))
+}
+
+fn matches_search(plugin: &Plugin, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    query.is_empty() || plugin.name.to_lowercase().contains(&query)
+}
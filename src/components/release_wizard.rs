@@ -0,0 +1,170 @@
+use crate::actions::{copy_to_clipboard, pick_certificate, pick_extension_folder, prepare_release, scaffold_release_project};
+use crate::components::signing_panel::{PathField, TextField};
+use crate::i18n::t;
+use crate::release::{ReleaseRequest, ReleaseStep, StepOutcome};
+use crate::settings::SETTINGS;
+use crate::signing::{DEFAULT_TSA_URL, find_zxpsigncmd};
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+#[component]
+pub fn ReleaseWizard() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let tool = find_zxpsigncmd(SETTINGS.read().zxpsigncmd_path.as_deref());
+
+    let mut source = use_signal(|| None::<PathBuf>);
+    let mut has_manifest = use_signal(|| false);
+    let bundle_id = use_signal(String::new);
+    let name = use_signal(String::new);
+
+    let mut certificate = use_signal(|| None::<PathBuf>);
+    let password = use_signal(String::new);
+    let mut timestamp = use_signal(|| true);
+    let tsa_url = use_signal(|| DEFAULT_TSA_URL.to_string());
+    let download_url = use_signal(String::new);
+
+    let progress = use_signal(Vec::<(ReleaseStep, StepOutcome)>::new);
+    let mut feed_json = use_signal(|| None::<String>);
+    let mut busy = use_signal(|| false);
+
+    let can_scaffold = !bundle_id().trim().is_empty() && !name().trim().is_empty() && !busy();
+    let can_release =
+        tool.is_some() && has_manifest() && certificate().is_some() && !password().is_empty() && !busy();
+
+    rsx! {
+        div { class: "section signing-panel release-wizard",
+            div { class: "signing-intro", {t("release-intro")} }
+
+            div { class: "signing-section",
+                h3 { {t("release-project-title")} }
+                PathField {
+                    label_key: "release-project",
+                    path: source(),
+                    on_choose: move |_| {
+                        if let Some(folder) = pick_extension_folder() {
+                            has_manifest.set(folder.join("CSXS").join("manifest.xml").is_file());
+                            source.set(Some(folder));
+                            feed_json.set(None);
+                        }
+                    },
+                }
+                if source().is_some() && !has_manifest() {
+                    div { class: "signing-warning", {t("release-no-manifest")} }
+                    div { class: "signing-grid",
+                        TextField { label_key: "release-bundle-id", value: bundle_id, password: false }
+                        TextField { label_key: "release-name", value: name, password: false }
+                    }
+                    button {
+                        class: "signing-button",
+                        disabled: !can_scaffold,
+                        onclick: move |_| {
+                            let Some(folder) = source() else {
+                                return;
+                            };
+                            spawn(async move {
+                                busy.set(true);
+                                if scaffold_release_project(folder, bundle_id(), name()).await {
+                                    has_manifest.set(true);
+                                }
+                                busy.set(false);
+                            });
+                        },
+                        {t("release-create-manifest")}
+                    }
+                }
+            }
+
+            div { class: "signing-section",
+                h3 { {t("release-signing-title")} }
+                if tool.is_none() {
+                    span { class: "signing-warning", {t("signing-tool-missing")} }
+                }
+                PathField {
+                    label_key: "signing-certificate",
+                    path: certificate(),
+                    on_choose: move |_| {
+                        if let Some(file) = pick_certificate() {
+                            certificate.set(Some(file));
+                        }
+                    },
+                }
+                TextField { label_key: "signing-password", value: password, password: true }
+                label { class: "signing-checkbox",
+                    input {
+                        r#type: "checkbox",
+                        checked: timestamp(),
+                        onchange: move |event| timestamp.set(event.checked()),
+                    }
+                    {t("signing-timestamp")}
+                }
+                if timestamp() {
+                    TextField { label_key: "signing-tsa-url", value: tsa_url, password: false }
+                }
+                TextField { label_key: "release-download-url", value: download_url, password: false }
+                button {
+                    class: "signing-button primary",
+                    disabled: !can_release,
+                    onclick: move |_| {
+                        let (Some(source), Some(certificate)) = (source(), certificate()) else {
+                            return;
+                        };
+                        let request = ReleaseRequest {
+                            source,
+                            certificate,
+                            password: password(),
+                            tsa_url: timestamp().then(|| tsa_url.cloned()),
+                            download_url: download_url(),
+                        };
+                        spawn(async move {
+                            busy.set(true);
+                            feed_json.set(None);
+                            let json = prepare_release(request, progress).await;
+                            feed_json.set(json);
+                            busy.set(false);
+                        });
+                    },
+                    if busy() { {t("signing-working")} } else { {t("release-prepare")} }
+                }
+            }
+
+            if !progress.read().is_empty() {
+                div { class: "signing-section",
+                    h3 { {t("release-steps-title")} }
+                    ul { class: "release-steps",
+                        for step in ReleaseStep::ALL {
+                            {
+                                let outcome = progress.read().iter().find(|(done, _)| *done == step).map(|(_, outcome)| outcome.clone());
+                                let (state, detail) = match outcome {
+                                    None => ("pending", String::new()),
+                                    Some(StepOutcome::Running) => ("running", t("release-step-running")),
+                                    Some(StepOutcome::Done(detail)) => ("done", detail),
+                                    Some(StepOutcome::Failed(detail)) => ("failed", detail),
+                                };
+                                rsx! {
+                                    li { key: "{step.label_key()}", class: "release-step {state}",
+                                        span { class: "release-step-name", {t(step.label_key())} }
+                                        span { class: "release-step-detail", "{detail}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(json) = feed_json() {
+                div { class: "signing-section",
+                    div { class: "signing-path-row",
+                        h3 { {t("release-feed-title")} }
+                        button {
+                            class: "signing-button",
+                            onclick: move |_| copy_to_clipboard(json.clone(), &t("release-feed-title")),
+                            {t("release-copy-feed")}
+                        }
+                    }
+                    pre { class: "release-feed", "{json}" }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::i18n::t;
+use crate::settings::SETTINGS;
+use crate::shortcuts::{SHORTCUT_SHEET_OPEN, SHORTCUTS};
+use dioxus::prelude::*;
+
+// Cheat sheet built from the keybinding registry, so it always lists what the handler matches
+#[component]
+pub fn ShortcutSheet() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    rsx! {
+        div { class: "shortcut-sheet-overlay", onclick: move |_| *SHORTCUT_SHEET_OPEN.write() = false }
+        div { class: "shortcut-sheet",
+            div { class: "shortcut-sheet-title", {t("shortcut-sheet-title")} }
+            for shortcut in SHORTCUTS {
+                div { key: "{shortcut.action:?}", class: "shortcut-sheet-row",
+                    span { class: "shortcut-sheet-label", {t(shortcut.action.label_key())} }
+                    kbd { class: "shortcut-sheet-key", {shortcut.display()} }
+                }
+            }
+            div { class: "shortcut-sheet-hint", {t("shortcut-sheet-hint")} }
+        }
+    }
+}
@@ -1,48 +1,256 @@
+use dioxus::html::HasFileData;
 use dioxus::prelude::*;
-use crate::file_operations::{select_zxp_file, install_zxp};
-use crate::message::{show_error, show_success, show_info, trigger_refresh, mark_plugin_as_newly_installed};
+use crate::actions::{
+    set_cep_flag,
+    backup_all_plugins, choose_hot_folder, export_plugin_list, install_folder_from_picker, install_from_path, install_from_picker, link_dev_folder_from_picker,
+    inspect_zxp_from_picker, open_inventory_from_picker, restore_plugin_set_from_picker,
+};
+use crate::background::run_blocking;
+use crate::components::install_queue::InstallQueue;
+use crate::cep_preferences::{CepFlag, CepLogLevel, preferences_supported, read_all_preferences};
+use crate::file_operations::parse_pasted_path;
+use crate::data_operations::{CEP_EXTENSIONS_PATH, SYSTEM_EXTENSIONS_PATH};
+use crate::i18n::{t, t_args, Locale};
+use crate::message::{MessageType, ToastDurations, ToastPosition};
+use crate::navigation::{show_view, View, ACTIVE_VIEW};
+use crate::shortcuts::{shortcut_hint, ShortcutAction};
+use crate::scheduler::{MaintenanceSchedule, MaintenanceTask};
+use crate::hot_folder::HotFolderCleanup;
+use crate::logging::LogLevel;
+use crate::telemetry::{discard_pending_usage, preview_report};
+use crate::settings::{clear_recent_installs, set_keep_quarantine, set_language, set_log_level, update_settings, DoubleClickAction, MotionPreference, QuickAction, Theme, SETTINGS};
 
+// One row per detected com.adobe.CSXS.N domain
 #[component]
-pub fn Sidebar() -> Element {
-    let install_handler = move |_| {
-        spawn(async move {
-            match select_zxp_file() {
-                Ok(zxp_path) => {
-                    log::info!("Selected ZXP file: {:?}", zxp_path);
-                    match install_zxp(&zxp_path) {
-                        Ok(installed_path) => {
-                            log::info!("ZXP installation successful");
-                            mark_plugin_as_newly_installed(installed_path);
-                            show_success("Plugin installed successfully!".to_string());
-                            trigger_refresh();
-                        }
-                        Err(e) => {
-                            let error_msg = format!("Installation failed: {}", e);
-                            log::error!("{}", error_msg);
-                            show_error(error_msg);
+fn CepPreferencesSetting() -> Element {
+    let mut preferences = use_resource(move || async move { run_blocking(read_all_preferences).await });
+
+    rsx! {
+        div { class: "setting-item",
+            label { class: "setting-label", {t("setting-cep-preferences")} }
+            if !preferences_supported() {
+                div { class: "setting-value", {t("cep-preferences-unsupported")} }
+            } else {
+                match &*preferences.read() {
+                    None => rsx! { div { class: "setting-value", {t("cep-preferences-loading")} } },
+                    Some(domains) if domains.is_empty() => rsx! { div { class: "setting-value", {t("cep-preferences-none")} } },
+                    Some(domains) => rsx! {
+                        for domain in domains.clone() {
+                            div { key: "{domain.version}", class: "cep-preferences-domain",
+                                span { class: "setting-value", "CSXS {domain.version}" }
+                                label { class: "setting-checkbox",
+                                    input {
+                                        r#type: "checkbox",
+                                        checked: domain.debug_mode,
+                                        onchange: move |event| {
+                                            let value = if event.checked() { "1" } else { "0" };
+                                            spawn(async move {
+                                                set_cep_flag(domain.version, CepFlag::PlayerDebugMode, value.to_string()).await;
+                                                preferences.restart();
+                                            });
+                                        },
+                                    }
+                                    {t("cep-debug-mode")}
+                                }
+                                label { class: "setting-inline",
+                                    span { {t("cep-log-level")} }
+                                    select {
+                                        class: "setting-select",
+                                        onchange: move |event| {
+                                            let value = event.value();
+                                            spawn(async move {
+                                                set_cep_flag(domain.version, CepFlag::LogLevel, value).await;
+                                                preferences.restart();
+                                            });
+                                        },
+                                        if domain.log_level.is_none() {
+                                            option { value: "", selected: true, disabled: true, {t("cep-log-unset")} }
+                                        }
+                                        for level in CepLogLevel::ALL {
+                                            option {
+                                                value: "{level.code()}",
+                                                selected: domain.log_level == Some(level),
+                                                {t(level.label_key())}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
                         }
-                    }
-                }
-                Err(e) => {
-                    log::info!("File selection cancelled or failed: {}", e);
-                    // Don't show cancellation as error - it's user choice
+                    },
                 }
             }
-        });
+        }
+    }
+}
+
+#[component]
+pub fn Sidebar() -> Element {
+    let language = SETTINGS.read().language; // Re-render when the UI language changes
+    let maintenance = SETTINGS.read().maintenance.clone();
+    let log_level = SETTINGS.read().log_level;
+    let double_click = SETTINGS.read().double_click;
+    let theme = SETTINGS.read().theme;
+    let motion = SETTINGS.read().motion;
+    let toast_position = SETTINGS.read().toast_position;
+    let toast_durations = SETTINGS.read().toast_durations;
+    let check_for_updates = SETTINGS.read().check_for_updates;
+    let inspect_before_install = SETTINGS.read().inspect_before_install;
+    let scan_scripts = SETTINGS.read().scan_scripts;
+    let keep_quarantine = SETTINGS.read().keep_quarantine;
+    let tray_icon = SETTINGS.read().tray_icon;
+    let mute_system_notifications = SETTINGS.read().mute_system_notifications;
+    let telemetry = SETTINGS.read().telemetry;
+    let hot_folder = SETTINGS.read().hot_folder.clone();
+    let hidden_quick_actions = SETTINGS.read().hidden_quick_actions.clone();
+    let show_bundle_id = SETTINGS.read().show_bundle_id;
+    let recent_installs = SETTINGS.read().recent_installs.clone();
+    let active_view = ACTIVE_VIEW();
+    let browse_title = format!("{}{}", t("browse-files"), shortcut_hint(ShortcutAction::OpenPicker));
+
+    // Some Linux webviews never deliver dropped file paths - offer a path field there from the start,
+    // and everywhere else once a drop arrives without paths
+    let mut drop_unavailable = use_signal(|| cfg!(target_os = "linux"));
+    let mut drag_over = use_signal(|| false);
+    let mut pasted_path = use_signal(String::new);
+    let mut telemetry_preview = use_signal(|| None::<String>); // Report JSON while the preview is open
+    let mut submit_path = move || {
+        if let Some(path) = parse_pasted_path(&pasted_path()) {
+            pasted_path.set(String::new());
+            install_from_path(path);
+        }
     };
 
     rsx! {
         div { class: "section sidebar",
             div { class: "install-section",
 
-                div { class: "drop-zone",
+                div {
+                    class: if drag_over() { "drop-zone drag-over" } else { "drop-zone" },
+                    ondragover: move |event| {
+                        event.prevent_default(); // Required for the drop event to fire
+                        drag_over.set(true);
+                    },
+                    ondragleave: move |_| drag_over.set(false),
+                    ondrop: move |event| {
+                        event.prevent_default();
+                        drag_over.set(false);
+
+                        let paths = event.files().map(|files| files.files()).unwrap_or_default();
+                        if paths.is_empty() {
+                            log::warn!("Drop event arrived without file paths, enabling path input");
+                            drop_unavailable.set(true);
+                            return;
+                        }
+                        for path in paths {
+                            install_from_path(path.into());
+                        }
+                    },
                     span { class: "drop-icon", dangerous_inner_html: include_str!("../../assets/icons/download.svg") }
-                    div { class: "drop-text", "Drop ZXP files here" }
-                    div { class: "drop-subtext", "or click to browse" }
+                    div { class: "drop-text", {t("drop-zone-title")} }
+                    div { class: "drop-subtext",
+                        if drop_unavailable() { {t("drop-zone-unavailable")} } else { {t("drop-zone-subtitle")} }
+                    }
                     button { 
                         class: "browse-btn",
-                        onclick: install_handler,
-                        "Browse Files" 
+                        title: browse_title,
+                        onclick: move |_| install_from_picker(),
+                        {t("browse-files")}
+                    }
+                    if drop_unavailable() {
+                        input {
+                            class: "path-input",
+                            r#type: "text",
+                            placeholder: t("paste-path-placeholder"),
+                            value: "{pasted_path}",
+                            oninput: move |event| pasted_path.set(event.value()),
+                            onkeydown: move |event| {
+                                // Typing belongs to the field, not the global shortcuts
+                                event.stop_propagation();
+                                if event.key() == Key::Enter {
+                                    submit_path();
+                                }
+                            },
+                        }
+                    }
+                }
+                InstallQueue {}
+                button {
+                    class: "secondary-btn",
+                    onclick: move |_| open_inventory_from_picker(),
+                    {t("open-inventory")}
+                }
+                button {
+                    class: "secondary-btn",
+                    onclick: move |_| export_plugin_list(),
+                    {t("export-plugin-list")}
+                }
+                button {
+                    class: "secondary-btn",
+                    title: t("install-folder-hint"),
+                    onclick: move |_| install_folder_from_picker(),
+                    {t("install-folder")}
+                }
+                button {
+                    class: "secondary-btn",
+                    title: t("inspect-zxp-hint"),
+                    onclick: move |_| inspect_zxp_from_picker(),
+                    {t("inspect-zxp")}
+                }
+                button {
+                    class: "secondary-btn",
+                    title: t("link-dev-folder-hint"),
+                    onclick: move |_| link_dev_folder_from_picker(),
+                    {t("link-dev-folder")}
+                }
+                div { class: "secondary-row",
+                    button {
+                        class: "secondary-btn",
+                        title: t("backup-all-hint"),
+                        onclick: move |_| backup_all_plugins(),
+                        {t("backup-all")}
+                    }
+                    button {
+                        class: "secondary-btn",
+                        title: t("restore-set-hint"),
+                        onclick: move |_| restore_plugin_set_from_picker(),
+                        {t("restore-set")}
+                    }
+                }
+
+                if !recent_installs.is_empty() {
+                    div { class: "recent-section",
+                        div { class: "recent-header",
+                            span { class: "setting-label", {t("recent-installs")} }
+                            button {
+                                class: "recent-clear",
+                                onclick: move |_| clear_recent_installs(),
+                                {t("recent-clear")}
+                            }
+                        }
+                        for path in recent_installs {
+                            button {
+                                key: "{path.display()}",
+                                class: "recent-item",
+                                title: "{path.display()}",
+                                disabled: !path.exists(), // Moved or deleted since
+                                onclick: {
+                                    let path = path.clone();
+                                    move |_| install_from_path(path.clone())
+                                },
+                                {path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default()}
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "nav-section",
+                for view in View::ALL {
+                    button {
+                        class: if view == active_view { "nav-item active" } else { "nav-item" },
+                        onclick: move |_| show_view(view),
+                        {t(view.label_key())}
                     }
                 }
             }
@@ -50,16 +258,408 @@ pub fn Sidebar() -> Element {
             div { class: "settings-section",
 
                 div { class: "setting-item",
-                    label { class: "setting-label", "CEP Extensions Path" }
-                    div { class: "setting-value", "~/Library/Application Support/Adobe/CEP/extensions/" }
+                    label { class: "setting-label", {t("setting-cep-path")} }
+                    div { class: "setting-value", "{SYSTEM_EXTENSIONS_PATH}" }
                 }
 
                 div { class: "setting-item",
-                    label { class: "setting-label", "User Extensions Path" }
-                    div { class: "setting-value", "~/Library/Application Support/Adobe/CEP/extensions/" }
+                    label { class: "setting-label", {t("setting-user-path")} }
+                    div { class: "setting-value", "{CEP_EXTENSIONS_PATH}" }
                 }
 
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-language")} }
+                    select {
+                        class: "setting-select",
+                        value: "{language.code()}",
+                        onchange: move |event| {
+                            if let Some(locale) = Locale::from_code(&event.value()) {
+                                set_language(locale);
+                            }
+                        },
+                        for locale in Locale::ALL {
+                            option {
+                                value: "{locale.code()}",
+                                selected: locale == language,
+                                "{locale.display_name()}"
+                            }
+                        }
+                    }
+                }
 
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-theme")} }
+                    select {
+                        class: "setting-select",
+                        value: "{theme.code()}",
+                        onchange: move |event| {
+                            if let Some(theme) = Theme::from_code(&event.value()) {
+                                update_settings(|settings| settings.theme = theme);
+                            }
+                        },
+                        for option_theme in Theme::ALL {
+                            option {
+                                value: "{option_theme.code()}",
+                                selected: option_theme == theme,
+                                {t(option_theme.label_key())}
+                            }
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-motion")} }
+                    select {
+                        class: "setting-select",
+                        value: "{motion.code()}",
+                        onchange: move |event| {
+                            if let Some(motion) = MotionPreference::from_code(&event.value()) {
+                                update_settings(|settings| settings.motion = motion);
+                            }
+                        },
+                        for option_motion in MotionPreference::ALL {
+                            option {
+                                value: "{option_motion.code()}",
+                                selected: option_motion == motion,
+                                {t(option_motion.label_key())}
+                            }
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-log-level")} }
+                    select {
+                        class: "setting-select",
+                        value: "{log_level.code()}",
+                        onchange: move |event| {
+                            if let Some(level) = LogLevel::from_code(&event.value()) {
+                                set_log_level(level);
+                            }
+                        },
+                        for level in LogLevel::ALL {
+                            option {
+                                value: "{level.code()}",
+                                selected: level == log_level,
+                                {t(level.label_key())}
+                            }
+                        }
+                    }
+                }
+
+                CepPreferencesSetting {}
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-double-click")} }
+                    select {
+                        class: "setting-select",
+                        value: "{double_click.code()}",
+                        onchange: move |event| {
+                            if let Some(action) = DoubleClickAction::from_code(&event.value()) {
+                                update_settings(|settings| settings.double_click = action);
+                            }
+                        },
+                        for action in DoubleClickAction::ALL {
+                            option {
+                                value: "{action.code()}",
+                                selected: action == double_click,
+                                {t(action.label_key())}
+                            }
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-quick-actions")} }
+                    for action in QuickAction::ALL {
+                        label { class: "setting-checkbox",
+                            input {
+                                r#type: "checkbox",
+                                checked: !hidden_quick_actions.contains(&action),
+                                onchange: move |event| {
+                                    let shown = event.checked();
+                                    update_settings(|settings| {
+                                        settings.hidden_quick_actions.retain(|hidden| *hidden != action);
+                                        if !shown {
+                                            settings.hidden_quick_actions.push(action);
+                                        }
+                                    });
+                                },
+                            }
+                            {t(action.label_key())}
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-columns")} }
+                    label { class: "setting-checkbox",
+                        input {
+                            r#type: "checkbox",
+                            checked: show_bundle_id,
+                            onchange: move |event| {
+                                let shown = event.checked();
+                                update_settings(|settings| settings.show_bundle_id = shown);
+                            },
+                        }
+                        {t("column-bundle-id")}
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-toast-position")} }
+                    select {
+                        class: "setting-select",
+                        value: "{toast_position.code()}",
+                        onchange: move |event| {
+                            if let Some(position) = ToastPosition::from_code(&event.value()) {
+                                update_settings(|settings| settings.toast_position = position);
+                            }
+                        },
+                        for position in ToastPosition::ALL {
+                            option {
+                                value: "{position.code()}",
+                                selected: position == toast_position,
+                                {t(position.label_key())}
+                            }
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-toast-duration")} }
+                    for msg_type in MessageType::ALL {
+                        label { class: "setting-inline",
+                            span { {t(msg_type.label_key())} }
+                            select {
+                                class: "setting-select",
+                                value: "{toast_durations.get(msg_type)}",
+                                onchange: move |event| {
+                                    if let Ok(seconds) = event.value().parse::<u64>() {
+                                        update_settings(|settings| settings.toast_durations.set(msg_type, seconds));
+                                    }
+                                },
+                                for seconds in ToastDurations::CHOICES {
+                                    option {
+                                        value: "{seconds}",
+                                        selected: seconds == toast_durations.get(msg_type),
+                                        if seconds == 0 {
+                                            {t("toast-duration-sticky")}
+                                        } else {
+                                            {t_args("toast-duration-seconds", &[("seconds", &seconds.to_string())])}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-maintenance")} }
+                    label { class: "setting-checkbox",
+                        input {
+                            r#type: "checkbox",
+                            checked: maintenance.enabled,
+                            onchange: move |event| {
+                                let enabled = event.checked();
+                                update_settings(|settings| settings.maintenance.enabled = enabled);
+                            },
+                        }
+                        {t("maintenance-enabled")}
+                    }
+                    input {
+                        class: "setting-select",
+                        r#type: "time",
+                        value: "{maintenance.time_label()}",
+                        disabled: !maintenance.enabled,
+                        onchange: move |event| {
+                            if let Some((hour, minute)) = MaintenanceSchedule::parse_time(&event.value()) {
+                                update_settings(|settings| {
+                                    settings.maintenance.hour = hour;
+                                    settings.maintenance.minute = minute;
+                                });
+                            }
+                        },
+                    }
+                    for task in MaintenanceTask::ALL {
+                        label { class: "setting-checkbox",
+                            input {
+                                r#type: "checkbox",
+                                checked: maintenance.tasks.contains(&task),
+                                disabled: !maintenance.enabled,
+                                onchange: move |event| {
+                                    let checked = event.checked();
+                                    update_settings(|settings| {
+                                        settings.maintenance.tasks.retain(|existing| *existing != task);
+                                        if checked {
+                                            settings.maintenance.tasks.push(task);
+                                        }
+                                    });
+                                },
+                            }
+                            {t(task.label_key())}
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-label", {t("setting-hot-folder")} }
+                    label { class: "setting-checkbox",
+                        input {
+                            r#type: "checkbox",
+                            checked: hot_folder.enabled,
+                            disabled: hot_folder.folder.is_none(),
+                            onchange: move |event| {
+                                let enabled = event.checked();
+                                update_settings(|settings| settings.hot_folder.enabled = enabled);
+                            },
+                        }
+                        {t("hot-folder-enabled")}
+                    }
+                    button {
+                        class: "secondary-btn hot-folder-btn",
+                        title: t("hot-folder-hint"),
+                        onclick: move |_| choose_hot_folder(),
+                        match &hot_folder.folder {
+                            Some(folder) => rsx! { {folder.display().to_string()} },
+                            None => rsx! { {t("hot-folder-choose")} },
+                        }
+                    }
+                    select {
+                        class: "setting-select",
+                        value: "{hot_folder.cleanup.code()}",
+                        disabled: !hot_folder.enabled,
+                        onchange: move |event| {
+                            if let Some(cleanup) = HotFolderCleanup::from_code(&event.value()) {
+                                update_settings(|settings| settings.hot_folder.cleanup = cleanup);
+                            }
+                        },
+                        for cleanup in HotFolderCleanup::ALL {
+                            option {
+                                value: "{cleanup.code()}",
+                                selected: cleanup == hot_folder.cleanup,
+                                {t(cleanup.label_key())}
+                            }
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-checkbox",
+                        input {
+                            r#type: "checkbox",
+                            checked: inspect_before_install,
+                            onchange: move |event| {
+                                let enabled = event.checked();
+                                update_settings(|settings| settings.inspect_before_install = enabled);
+                            },
+                        }
+                        {t("setting-inspect-before-install")}
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-checkbox",
+                        title: t("setting-scan-scripts-hint"),
+                        input {
+                            r#type: "checkbox",
+                            checked: scan_scripts,
+                            onchange: move |event| {
+                                let enabled = event.checked();
+                                update_settings(|settings| settings.scan_scripts = enabled);
+                            },
+                        }
+                        {t("setting-scan-scripts")}
+                    }
+                }
+
+                if cfg!(target_os = "macos") {
+                    div { class: "setting-item",
+                        label { class: "setting-checkbox",
+                            title: t("setting-clear-quarantine-hint"),
+                            input {
+                                r#type: "checkbox",
+                                checked: !keep_quarantine,
+                                onchange: move |event| set_keep_quarantine(!event.checked()),
+                            }
+                            {t("setting-clear-quarantine")}
+                        }
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-checkbox",
+                        title: t("setting-tray-icon-hint"),
+                        input {
+                            r#type: "checkbox",
+                            checked: tray_icon,
+                            onchange: move |event| {
+                                let enabled = event.checked();
+                                update_settings(|settings| settings.tray_icon = enabled);
+                            },
+                        }
+                        {t("setting-tray-icon")}
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-checkbox",
+                        title: t("setting-system-notifications-hint"),
+                        input {
+                            r#type: "checkbox",
+                            checked: !mute_system_notifications,
+                            onchange: move |event| {
+                                let muted = !event.checked();
+                                update_settings(|settings| settings.mute_system_notifications = muted);
+                            },
+                        }
+                        {t("setting-system-notifications")}
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-checkbox",
+                        input {
+                            r#type: "checkbox",
+                            checked: check_for_updates,
+                            onchange: move |event| {
+                                let enabled = event.checked();
+                                update_settings(|settings| settings.check_for_updates = enabled);
+                            },
+                        }
+                        {t("setting-check-updates")}
+                    }
+                }
+
+                div { class: "setting-item",
+                    label { class: "setting-checkbox",
+                        title: t("setting-telemetry-hint"),
+                        input {
+                            r#type: "checkbox",
+                            checked: telemetry,
+                            onchange: move |event| {
+                                let enabled = event.checked();
+                                update_settings(|settings| settings.telemetry = enabled);
+                                if !enabled {
+                                    discard_pending_usage();
+                                }
+                            },
+                        }
+                        {t("setting-telemetry")}
+                    }
+                    button {
+                        class: "telemetry-preview-toggle",
+                        onclick: move |_| {
+                            let shown = telemetry_preview().is_some();
+                            telemetry_preview.set(if shown { None } else { Some(preview_report()) });
+                        },
+                        if telemetry_preview().is_some() { {t("setting-telemetry-preview-hide")} } else { {t("setting-telemetry-preview")} }
+                    }
+                    if let Some(preview) = telemetry_preview() {
+                        pre { class: "telemetry-preview", "{preview}" }
+                    }
+                }
             }
         }
     }
@@ -0,0 +1,169 @@
+use crate::actions::{create_certificate, locate_signing_tool, pick_certificate, pick_extension_folder, sign_and_package};
+use crate::i18n::{t, t_args};
+use crate::settings::SETTINGS;
+use crate::shortcuts::is_text_editing_key;
+use crate::signing::{CertificateRequest, DEFAULT_TSA_URL, find_zxpsigncmd};
+use dioxus::prelude::*;
+use std::path::PathBuf;
+
+#[component]
+pub fn TextField(label_key: &'static str, value: Signal<String>, password: bool) -> Element {
+    let mut value = value;
+    rsx! {
+        label { class: "signing-field",
+            span { class: "signing-label", {t(label_key)} }
+            input {
+                class: "signing-input",
+                r#type: if password { "password" } else { "text" },
+                value: "{value}",
+                oninput: move |event| value.set(event.value()),
+                onkeydown: move |event| {
+                    if is_text_editing_key(&event) {
+                        event.stop_propagation(); // Typing "?" or Delete must not trigger shortcuts
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+pub fn PathField(label_key: &'static str, path: Option<PathBuf>, on_choose: EventHandler<()>) -> Element {
+    rsx! {
+        div { class: "signing-field",
+            span { class: "signing-label", {t(label_key)} }
+            div { class: "signing-path-row",
+                span { class: "signing-path",
+                    match &path {
+                        Some(path) => rsx! { "{path.display()}" },
+                        None => rsx! { {t("signing-none-chosen")} },
+                    }
+                }
+                button { class: "signing-button", onclick: move |_| on_choose.call(()), {t("signing-choose")} }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn SigningPanel() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let tool = find_zxpsigncmd(SETTINGS.read().zxpsigncmd_path.as_deref());
+
+    // New certificate
+    let country = use_signal(String::new);
+    let state = use_signal(String::new);
+    let organization = use_signal(String::new);
+    let common_name = use_signal(String::new);
+    let new_password = use_signal(String::new);
+
+    // Package
+    let mut source = use_signal(|| None::<PathBuf>);
+    let mut certificate = use_signal(|| None::<PathBuf>);
+    let password = use_signal(String::new);
+    let mut timestamp = use_signal(|| true);
+    let tsa_url = use_signal(|| DEFAULT_TSA_URL.to_string());
+    let mut busy = use_signal(|| false);
+
+    let request = CertificateRequest {
+        country: country(),
+        state: state(),
+        organization: organization(),
+        common_name: common_name(),
+        password: new_password(),
+    };
+    let can_create = tool.is_some() && request.is_complete() && !busy();
+    let can_sign = tool.is_some() && source().is_some() && certificate().is_some() && !password().is_empty() && !busy();
+
+    rsx! {
+        div { class: "section signing-panel",
+            div { class: "signing-intro", {t("signing-intro")} }
+
+            div { class: "signing-tool",
+                match &tool {
+                    Some(path) => rsx! { span { class: "signing-path", {t_args("signing-tool-found", &[("path", &path.display().to_string())])} } },
+                    None => rsx! { span { class: "signing-warning", {t("signing-tool-missing")} } },
+                }
+                button { class: "signing-button", onclick: move |_| locate_signing_tool(), {t("signing-locate-tool")} }
+            }
+
+            div { class: "signing-section",
+                h3 { {t("signing-certificate-title")} }
+                div { class: "signing-grid",
+                    TextField { label_key: "signing-country", value: country, password: false }
+                    TextField { label_key: "signing-state", value: state, password: false }
+                    TextField { label_key: "signing-organization", value: organization, password: false }
+                    TextField { label_key: "signing-common-name", value: common_name, password: false }
+                    TextField { label_key: "signing-password", value: new_password, password: true }
+                }
+                button {
+                    class: "signing-button primary",
+                    disabled: !can_create,
+                    onclick: move |_| {
+                        let request = request.clone();
+                        let mut password = password;
+                        spawn(async move {
+                            busy.set(true);
+                            if let Some(created) = create_certificate(request.clone()).await {
+                                certificate.set(Some(created));
+                                password.set(request.password);
+                            }
+                            busy.set(false);
+                        });
+                    },
+                    {t("signing-create-certificate")}
+                }
+            }
+
+            div { class: "signing-section",
+                h3 { {t("signing-package-title")} }
+                PathField {
+                    label_key: "signing-source",
+                    path: source(),
+                    on_choose: move |_| {
+                        if let Some(folder) = pick_extension_folder() {
+                            source.set(Some(folder));
+                        }
+                    },
+                }
+                PathField {
+                    label_key: "signing-certificate",
+                    path: certificate(),
+                    on_choose: move |_| {
+                        if let Some(file) = pick_certificate() {
+                            certificate.set(Some(file));
+                        }
+                    },
+                }
+                TextField { label_key: "signing-password", value: password, password: true }
+                label { class: "signing-checkbox",
+                    input {
+                        r#type: "checkbox",
+                        checked: timestamp(),
+                        onchange: move |event| timestamp.set(event.checked()),
+                    }
+                    {t("signing-timestamp")}
+                }
+                if timestamp() {
+                    TextField { label_key: "signing-tsa-url", value: tsa_url, password: false }
+                }
+                button {
+                    class: "signing-button primary",
+                    disabled: !can_sign,
+                    onclick: move |_| {
+                        let (Some(source), Some(certificate)) = (source(), certificate()) else {
+                            return;
+                        };
+                        let tsa = timestamp().then(|| tsa_url.cloned());
+                        spawn(async move {
+                            busy.set(true);
+                            sign_and_package(source, certificate, password(), tsa).await;
+                            busy.set(false);
+                        });
+                    },
+                    if busy() { {t("signing-working")} } else { {t("signing-sign")} }
+                }
+            }
+        }
+    }
+}
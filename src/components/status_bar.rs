@@ -1,41 +1,99 @@
 use dioxus::prelude::*;
-use crate::message::{MESSAGE, REFRESH_TRIGGER};
+use std::time::Duration;
+use crate::components::activity_panel::ActivityPanel;
+use crate::file_operations::open_url;
+use crate::i18n::{t, t_args};
+use crate::message::{show_error, trigger_refresh};
+use crate::data_operations::format_size;
+use crate::plugin_store::{ACTIVE_SCAN, PLUGINS, SHOWING_CACHED, cancel_scan, total_disk_bytes};
+use crate::settings::SETTINGS;
+use crate::shortcuts::{shortcut_hint, ShortcutAction};
+use crate::update_check::{AVAILABLE_UPDATE, CURRENT_VERSION};
+
+// How often the scan counter is read from the scanning thread
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
 
 #[component]
 pub fn StatusBar() -> Element {
-    // React to refresh signal to count plugins
-    let plugin_count = use_resource(move || {
-        let _ = REFRESH_TRIGGER(); // Create dependency on global signal
-        async move {
-            match crate::data_operations::scan_cep_plugins() {
-                Ok(plugins) => plugins.len(),
-                Err(_) => 0,
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    // Count and size come from the shared scan result
+    let (plugin_count, disk_bytes) = match PLUGINS.read().as_ref() {
+        Some(plugins) => (Some(plugins.len()), Some(total_disk_bytes(plugins))),
+        None => (None, None),
+    };
+    let status = match plugin_count {
+        Some(count) if SHOWING_CACHED() => t_args("status-plugins-cached", &[("count", &count.to_string())]),
+        Some(count) => t_args("status-plugins-installed", &[("count", &count.to_string())]),
+        None => t("status-loading"),
+    };
+    let mut activity_open = use_signal(|| false);
+
+    // The scan counts atomics on a worker thread, so poll them like the log viewer polls its buffer
+    let mut scan_counts = use_signal(|| None::<(usize, usize, u64)>);
+    use_future(move || async move {
+        loop {
+            tokio::time::sleep(PROGRESS_INTERVAL).await;
+            let counts = ACTIVE_SCAN.peek().as_ref().map(|progress| {
+                let (processed, total) = progress.counts();
+                (processed, total, progress.measured_bytes())
+            });
+            if counts != *scan_counts.peek() {
+                scan_counts.set(counts);
             }
         }
     });
-    
-    // Read message once to avoid multiple borrows
-    let current_message = MESSAGE.read();
+    let scanning = if ACTIVE_SCAN.read().is_some() { scan_counts() } else { None };
+
+    // Before the first list arrives, the size adds up as the scan measures each plugin
+    let usage = disk_bytes
+        .or(scanning.map(|(_, _, measured)| measured).filter(|measured| *measured > 0))
+        .map(|bytes| format!(" | {}", t_args("status-disk-usage", &[("size", &format_size(bytes))])))
+        .unwrap_or_default();
     
     rsx! {
         div { class: "status-bar",
-            if !current_message.content.is_empty() {
-                div { 
-                    class: "message",
-                    "data-type": "{current_message.msg_type:?}",
-                    "{current_message.content}" 
+            div { "ZXP Manager v{CURRENT_VERSION} | {status}{usage}" }
+            div { class: "status-actions",
+                if let Some((processed, total, _)) = scanning {
+                    span { class: "status-scan-progress",
+                        {t_args("status-scan-progress", &[("processed", &processed.to_string()), ("total", &total.to_string())])}
+                    }
+                    button {
+                        class: "status-update",
+                        title: t("status-scan-cancel-hint"),
+                        onclick: move |_| cancel_scan(),
+                        {t("status-scan-cancel")}
+                    }
                 }
-            } else {
-                // Show normal status
-                match &*plugin_count.read() {
-                    Some(count) => rsx! { 
-                        div { "ZXP Manager v1.0.0 | Plugins installed: {count}" }
-                    },
-                    None => rsx! { 
-                        div { "ZXP Manager v1.0.0 | Loading..." }
+                if let Some(update) = AVAILABLE_UPDATE() {
+                    button {
+                        class: "status-update",
+                        title: update.url.clone(),
+                        onclick: move |_| {
+                            if let Err(e) = open_url(&update.url) {
+                                show_error("toast-error", &[("error", &e.to_string())]);
+                            }
+                        },
+                        {t_args("update-available", &[("version", &update.version)])}
                     }
                 }
+                button {
+                    class: if activity_open() { "status-btn active" } else { "status-btn" },
+                    title: t("activity-title"),
+                    onclick: move |_| activity_open.toggle(),
+                    dangerous_inner_html: include_str!("../../assets/icons/history.svg")
+                }
+                button {
+                    class: "status-btn",
+                    title: format!("{}{}", t("rescan"), shortcut_hint(ShortcutAction::Rescan)),
+                    onclick: move |_| trigger_refresh(),
+                    dangerous_inner_html: include_str!("../../assets/icons/refresh.svg")
+                }
             }
         }
+        if activity_open() {
+            ActivityPanel { on_close: move |_| activity_open.set(false) }
+        }
     }
-}
\ No newline at end of file
+}
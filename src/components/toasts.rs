@@ -0,0 +1,42 @@
+use crate::i18n::t;
+use crate::message::{TOASTS, dismiss_all_toasts, dismiss_toast};
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+
+// Stack of concurrent messages, newest at the bottom - above the status bar or in a top corner
+#[component]
+pub fn ToastStack() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let position = SETTINGS.read().toast_position;
+    let toasts = TOASTS.read();
+
+    rsx! {
+        div { class: "toast-stack", "data-position": position.code(),
+            for toast in toasts.iter() {
+                div {
+                    key: "{toast.id}",
+                    class: "toast",
+                    "data-type": "{toast.message.msg_type:?}",
+                    div { class: "toast-content", "{toast.message.content}" }
+                    button {
+                        class: "toast-dismiss",
+                        title: t("toast-dismiss"),
+                        onclick: {
+                            let id = toast.id;
+                            move |_| dismiss_toast(id)
+                        },
+                        "×"
+                    }
+                }
+            }
+            // Sticky errors pile up; one click clears them all
+            if toasts.len() > 1 {
+                button {
+                    class: "toast-dismiss-all",
+                    onclick: move |_| dismiss_all_toasts(),
+                    {t("toast-dismiss-all")}
+                }
+            }
+        }
+    }
+}
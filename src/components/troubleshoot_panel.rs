@@ -0,0 +1,154 @@
+use crate::diagnostics::{build_report, collect_extension_copies};
+use crate::i18n::{t, t_args};
+use crate::message::{show_error, show_success, trigger_refresh};
+use crate::plugin_store::{HOST_APPS, PLUGINS, SELECTED_PLUGIN, expect_change};
+use crate::settings::SETTINGS;
+use crate::troubleshoot::{CheckStatus, CheckStep, Fix, Troubleshooter, apply_fix, run_install_checks, run_panel_checks};
+use dioxus::prelude::*;
+use std::path::PathBuf;
+use std::time::Duration;
+
+// Pause between revealed steps so the checks read as a sequence rather than a wall of results
+const STEP_DELAY: Duration = Duration::from_millis(250);
+
+#[component]
+fn StepRow(number: usize, step: CheckStep, on_fix: EventHandler<Fix>) -> Element {
+    let args: Vec<(&str, &str)> = step.args.iter().map(|(name, value)| (*name, value.as_str())).collect();
+    let class = match step.status {
+        CheckStatus::Pass => "troubleshoot-step step-pass",
+        CheckStatus::Warning => "troubleshoot-step step-warning",
+        CheckStatus::Fail => "troubleshoot-step step-fail",
+    };
+
+    rsx! {
+        div { class,
+            div { class: "troubleshoot-step-number", "{number}" }
+            div { class: "troubleshoot-step-info",
+                div { class: "troubleshoot-step-title", {t(step.title_key)} }
+                div { class: "troubleshoot-step-detail", {t_args(step.detail_key, &args)} }
+            }
+            if let Some(fix) = step.fix.clone() {
+                button {
+                    class: "troubleshoot-fix",
+                    onclick: move |_| on_fix.call(fix.clone()),
+                    {t(fix.label_key())}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn TroubleshootPanel() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+
+    let mut active = use_signal(|| Troubleshooter::PanelNotAppearing);
+    let mut plugin_path = use_signal(|| SELECTED_PLUGIN.peek().clone()); // Start with the plugin selected in the list
+    let mut steps = use_signal(Vec::<CheckStep>::new);
+    let mut revealed = use_signal(|| 0usize);
+
+    let mut run_checks = move || {
+        let plugins = PLUGINS().unwrap_or_default();
+        let results = match active() {
+            Troubleshooter::PanelNotAppearing => {
+                let Some(plugin) = plugin_path().and_then(|path| plugins.iter().find(|plugin| plugin.path == path).cloned()) else {
+                    return;
+                };
+                run_panel_checks(&plugin, &build_report(collect_extension_copies()), &HOST_APPS.read())
+            }
+            Troubleshooter::InstallPermission => run_install_checks(&plugins),
+        };
+
+        let count = results.len();
+        steps.set(results);
+        revealed.set(0);
+        spawn(async move {
+            while revealed() < count {
+                tokio::time::sleep(STEP_DELAY).await;
+                revealed += 1;
+            }
+        });
+    };
+
+    let apply = move |fix: Fix| {
+        match apply_fix(&fix) {
+            Ok(message_key) => {
+                show_success(message_key, &[]);
+                if let Fix::RemoveCopy(path) = &fix {
+                    expect_change(path.clone());
+                    trigger_refresh();
+                }
+            }
+            Err(e) => {
+                show_error("toast-fix-failed", &[("error", &e.to_string())]);
+            }
+        }
+        run_checks(); // Re-check so the step shows whether the fix worked
+    };
+
+    let plugins = PLUGINS().unwrap_or_default();
+    let selected = plugin_path().map(|path| path.display().to_string()).unwrap_or_default();
+    let visible: Vec<CheckStep> = steps.read().iter().take(revealed()).cloned().collect();
+    let running = revealed() < steps.read().len();
+
+    rsx! {
+        div { class: "section troubleshoot-panel",
+            div { class: "troubleshoot-tabs",
+                for troubleshooter in Troubleshooter::ALL {
+                    button {
+                        class: if troubleshooter == active() { "troubleshoot-tab active" } else { "troubleshoot-tab" },
+                        onclick: move |_| {
+                            active.set(troubleshooter);
+                            steps.set(Vec::new());
+                        },
+                        {t(troubleshooter.title_key())}
+                    }
+                }
+            }
+            div { class: "troubleshoot-intro", {t(active().intro_key())} }
+
+            div { class: "troubleshoot-controls",
+                if active() == Troubleshooter::PanelNotAppearing {
+                    select {
+                        class: "setting-select",
+                        value: "{selected}",
+                        onchange: move |event| {
+                            let value = event.value();
+                            plugin_path.set((!value.is_empty()).then(|| PathBuf::from(value)));
+                            steps.set(Vec::new());
+                        },
+                        option { value: "", {t("troubleshoot-choose-plugin")} }
+                        for plugin in plugins {
+                            option {
+                                key: "{plugin.path.display()}",
+                                value: "{plugin.path.display()}",
+                                selected: plugin_path().as_ref() == Some(&plugin.path),
+                                "{plugin.name}"
+                            }
+                        }
+                    }
+                }
+                button {
+                    class: "troubleshoot-run",
+                    disabled: running || (active() == Troubleshooter::PanelNotAppearing && plugin_path().is_none()),
+                    onclick: move |_| run_checks(),
+                    {t("troubleshoot-run")}
+                }
+            }
+
+            div { class: "troubleshoot-steps",
+                for (index, step) in visible.into_iter().enumerate() {
+                    StepRow {
+                        key: "{index}",
+                        number: index + 1,
+                        step,
+                        on_fix: apply,
+                    }
+                }
+                if running {
+                    div { class: "troubleshoot-running", {t("troubleshoot-running")} }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::actions::{ZXP_INSPECTION, close_zxp_inspection, compare_with_installed, copy_to_clipboard, install_inspected_zxp, installed_copy};
+use crate::data_operations::format_size;
+use crate::file_operations::InspectionRow;
+use crate::host_detection::host_display_name;
+use crate::i18n::{t, t_args};
+use crate::settings::SETTINGS;
+use dioxus::prelude::*;
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+// "Open ZXP...": what a package holds and who signed it, read without installing anything
+#[component]
+pub fn ZxpInspector() -> Element {
+    let _ = SETTINGS.read().language; // Re-render when the UI language changes
+    let mut expanded = use_signal(HashSet::<PathBuf>::new); // Folders start collapsed, cleared for the next package
+    let inspection = ZXP_INSPECTION.read();
+    let Some(inspection) = inspection.as_ref() else {
+        return rsx! {};
+    };
+    let file_name = inspection.zxp_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let hosts = inspection
+        .hosts
+        .iter()
+        .map(|host| match &host.version {
+            Some(version) => format!("{} {}", host_display_name(&host.name), version),
+            None => host_display_name(&host.name).to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let extensions = inspection
+        .extensions
+        .iter()
+        .map(|entry| format!("{} ({})", entry.id, t(entry.ui_kind().label_key())))
+        .collect::<Vec<_>>()
+        .join(", ");
+    // A row shows once every folder above it is open
+    let rows: Vec<(InspectionRow, bool)> = inspection
+        .tree()
+        .into_iter()
+        .filter(|row| row.path.ancestors().skip(1).filter(|folder| !folder.as_os_str().is_empty()).all(|folder| expanded.read().contains(folder)))
+        .map(|row| {
+            let open = expanded.read().contains(&row.path);
+            (row, open)
+        })
+        .collect();
+    let installed = installed_copy(&inspection.info.bundle_id);
+    let mut close = move || {
+        expanded.write().clear();
+        close_zxp_inspection();
+    };
+
+    rsx! {
+        div { class: "install-preview-overlay", onclick: move |_| close() }
+        div { class: "install-preview zxp-inspector",
+            div { class: "install-preview-title", {t_args("inspector-title", &[("file", &file_name)])} }
+            div { class: "zxp-inspector-facts",
+                span { class: "zxp-inspector-label", {t("inspector-name")} }
+                span { "{inspection.info.name} {inspection.info.version}" }
+                span { class: "zxp-inspector-label", {t("inspector-bundle-id")} }
+                span { class: "zxp-inspector-mono", "{inspection.info.bundle_id}" }
+                span { class: "zxp-inspector-label", {t("inspector-hosts")} }
+                span { if hosts.is_empty() { {t("inspector-none")} } else { "{hosts}" } }
+                span { class: "zxp-inspector-label", {t("inspector-extensions")} }
+                span { if extensions.is_empty() { {t("inspector-none")} } else { "{extensions}" } }
+                span { class: "zxp-inspector-label", {t("inspector-signature")} }
+                match (inspection.signed, inspection.signer.clone()) {
+                    (true, Some(signer)) => rsx! { span { {t_args("inspector-signed-by", &[("signer", &signer)])} } },
+                    (true, None) => rsx! { span { class: "install-preview-warning", {t("inspector-signer-unreadable")} } },
+                    (false, _) => rsx! { span { class: "install-preview-warning", {t("inspector-unsigned")} } },
+                }
+                span { class: "zxp-inspector-label", {t("inspector-contents")} }
+                span {
+                    {t_args(
+                        "inspector-totals",
+                        &[
+                            ("count", &inspection.file_count().to_string()),
+                            ("size", &format_size(inspection.total_size())),
+                            ("compressed", &format_size(inspection.compressed_size())),
+                        ],
+                    )}
+                }
+            }
+            if let Some(reason) = inspection.blocked.clone() {
+                div { class: "install-preview-mismatch", {t_args("inspector-blocked", &[("reason", &reason)])} }
+            }
+            if inspection.unsafe_entries > 0 {
+                div { class: "install-preview-mismatch",
+                    {t_args("inspector-unsafe-entries", &[("count", &inspection.unsafe_entries.to_string())])}
+                }
+            }
+            div { class: "install-preview-checksum",
+                span { class: "install-preview-checksum-label", "SHA-256" }
+                match inspection.sha256.clone() {
+                    Some(sha256) => rsx! {
+                        span { class: "install-preview-hash", "{sha256}" }
+                        button {
+                            class: "install-preview-button",
+                            onclick: move |_| copy_to_clipboard(sha256.clone(), "SHA-256"),
+                            {t("install-preview-copy")}
+                        }
+                    },
+                    None => rsx! { span { class: "install-preview-warning", {t("install-preview-hash-failed")} } },
+                }
+            }
+            div { class: "install-preview-files",
+                for (row, open) in rows {
+                    TreeRow {
+                        key: "{row.path.display()}",
+                        open,
+                        on_toggle: move |path: PathBuf| {
+                            let mut expanded = expanded.write();
+                            if !expanded.remove(&path) {
+                                expanded.insert(path);
+                            }
+                        },
+                        row: row.clone(),
+                    }
+                }
+            }
+            div { class: "install-preview-actions",
+                if let Some(installed) = installed {
+                    button {
+                        class: "install-preview-button",
+                        title: t("compare-hint"),
+                        onclick: {
+                            let zxp_path = inspection.zxp_path.clone();
+                            move |_| compare_with_installed(zxp_path.clone(), installed.clone())
+                        },
+                        {t("compare-button")}
+                    }
+                }
+                button { class: "install-preview-button", onclick: move |_| close(), {t("inspector-close")} }
+                button {
+                    class: "install-preview-button primary",
+                    disabled: inspection.blocked.is_some() || inspection.unsafe_entries > 0, // Would only fail later
+                    onclick: move |_| {
+                        expanded.write().clear();
+                        install_inspected_zxp();
+                    },
+                    {t("install-preview-install")}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn TreeRow(row: InspectionRow, open: bool, on_toggle: EventHandler<PathBuf>) -> Element {
+    let indent = format!("padding-left: calc(var(--space-sm) + {}rem)", row.depth);
+    let marker = match (row.is_dir, open) {
+        (true, true) => "▾",
+        (true, false) => "▸",
+        (false, _) => "",
+    };
+    let (path, is_dir) = (row.path.clone(), row.is_dir);
+
+    rsx! {
+        div {
+            class: if row.is_dir { "install-preview-file zxp-inspector-folder" } else { "install-preview-file" },
+            style: "{indent}",
+            title: "{row.path.display()}",
+            onclick: move |_| {
+                if is_dir {
+                    on_toggle.call(path.clone());
+                }
+            },
+            span { class: "zxp-inspector-marker", "{marker}" }
+            span { class: "install-preview-path", {row.name()} }
+            if row.is_symlink {
+                span { class: "install-preview-badge", {t("inspector-link")} }
+            }
+            if row.executable {
+                span { class: "install-preview-badge", {t("inspector-executable")} }
+            }
+            if row.is_dir {
+                span { class: "install-preview-size", {t_args("inspector-folder-files", &[("count", &row.file_count.to_string())])} }
+            }
+            span { class: "install-preview-size", {format_size(row.size)} }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use dioxus::prelude::*;
+use rfd::{MessageButtons, MessageDialog, MessageDialogResult, MessageLevel};
+use std::backtrace::Backtrace;
+use std::fs;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+
+use crate::actions::copy_to_clipboard;
+use crate::clock::{LocalTime, unix_now};
+use crate::file_operations::open_in_default_app;
+use crate::i18n::t;
+use crate::message::show_error;
+use crate::update_check::CURRENT_VERSION;
+
+// Panics - including ones in spawned tasks, which otherwise vanish - leave a crash report in
+// <data dir>/zxp-manager/crashes; the next launch offers to open or copy the newest one.
+
+// Reports kept; older ones are deleted when a new one is written
+const KEPT_REPORTS: usize = 10;
+
+// Holds the path of a report nobody was told about yet
+const PENDING_MARKER: &str = "pending";
+
+pub fn crash_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("crashes"))
+}
+
+// Call first thing in main(); the default hook still runs afterwards and prints to stderr
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = render_report(&panic_message(info), &Backtrace::force_capture().to_string());
+        match write_report(&report) {
+            Some(path) => log::error!("Panic - crash report written to {:?}", path),
+            None => log::error!("Panic - the crash report could not be written"),
+        }
+        default_hook(info);
+    }));
+}
+
+// Offers the report a crash left behind - call once from the root component
+pub fn use_crash_report_prompt() {
+    use_hook(|| {
+        let Some(report) = take_pending_report() else {
+            return;
+        };
+        log::info!("The last session crashed, report at {:?}", report);
+        spawn(async move {
+            let (open, copy) = (t("crash-report-open"), t("crash-report-copy"));
+            let choice = MessageDialog::new()
+                .set_level(MessageLevel::Warning)
+                .set_title(t("crash-report-title"))
+                .set_description(format!("{}\n\n{}", t("crash-report-body"), report.display()))
+                .set_buttons(MessageButtons::YesNoCancelCustom(open.clone(), copy.clone(), t("crash-report-close")))
+                .show();
+
+            match choice {
+                MessageDialogResult::Custom(label) if label == open => {
+                    if let Err(e) = open_in_default_app(&report) {
+                        show_error("toast-error", &[("error", &e.to_string())]);
+                    }
+                }
+                MessageDialogResult::Custom(label) if label == copy => match fs::read_to_string(&report) {
+                    Ok(text) => copy_to_clipboard(text, &t("crash-report-item")),
+                    Err(e) => log::error!("Failed to read crash report {:?}: {}", report, e),
+                },
+                _ => {}
+            }
+        });
+    });
+}
+
+pub fn render_report(message: &str, backtrace: &str) -> String {
+    let now = LocalTime::now();
+    format!(
+        "ZXP Manager crash report\n\nVersion: {}\nOS: {} ({})\nTime: {} {}\nThread: {}\n\n{}\n\nBacktrace:\n{}\n",
+        CURRENT_VERSION,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        now.date_label(),
+        now.time_label(),
+        std::thread::current().name().unwrap_or("unnamed"),
+        message,
+        backtrace
+    )
+}
+
+// Helper functions
+fn panic_message(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|text| text.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string());
+    match info.location() {
+        Some(location) => format!("{} at {}:{}:{}", message, location.file(), location.line(), location.column()),
+        None => message,
+    }
+}
+
+// Best effort - a failing panic hook must not panic again
+fn write_report(report: &str) -> Option<PathBuf> {
+    let dir = crash_dir()?;
+    fs::create_dir_all(&dir).ok()?;
+    let path = dir.join(format!("crash-{}-{}.txt", unix_now(), std::process::id()));
+    fs::write(&path, report).ok()?;
+    fs::write(dir.join(PENDING_MARKER), path.to_string_lossy().as_bytes()).ok()?;
+    prune_reports(&dir);
+    Some(path)
+}
+
+fn take_pending_report() -> Option<PathBuf> {
+    take_pending_report_in(&crash_dir()?)
+}
+
+fn take_pending_report_in(dir: &Path) -> Option<PathBuf> {
+    let marker = dir.join(PENDING_MARKER);
+    let report = PathBuf::from(fs::read_to_string(&marker).ok()?.trim());
+    if let Err(e) = fs::remove_file(&marker) {
+        log::error!("Failed to clear the crash marker {:?}: {}", marker, e);
+    }
+    report.is_file().then_some(report)
+}
+
+fn prune_reports(dir: &Path) {
+    let mut reports: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.file_name().is_some_and(|name| name.to_string_lossy().starts_with("crash-")))
+        .collect();
+    reports.sort(); // Timestamps first in the name, so oldest first
+    let excess = reports.len().saturating_sub(KEPT_REPORTS);
+    for old in reports.into_iter().take(excess) {
+        let _ = fs::remove_file(old);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prune_reports_keeps_the_newest() {
+        let dir = std::env::temp_dir().join(format!("zxpm-crashes-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for index in 0..KEPT_REPORTS + 2 {
+            fs::write(dir.join(format!("crash-{:010}-1.txt", 1_700_000_000 + index)), "report").unwrap();
+        }
+        fs::write(dir.join(PENDING_MARKER), "x").unwrap();
+        prune_reports(&dir);
+
+        let mut left: Vec<String> = fs::read_dir(&dir).unwrap().flatten().map(|entry| entry.file_name().to_string_lossy().to_string()).collect();
+        left.sort();
+        assert_eq!(left.len(), KEPT_REPORTS + 1);
+        assert_eq!(left[0], "crash-1700000002-1.txt");
+        assert!(left.contains(&PENDING_MARKER.to_string()));
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_render_report_includes_version_and_backtrace() {
+        let report = render_report("boom at src/main.rs:1:1", "0: main");
+        assert!(report.contains(CURRENT_VERSION) && report.contains("boom") && report.contains("Backtrace:\n0: main"));
+    }
+
+    #[test]
+    fn test_pending_report_is_offered_once() {
+        let dir = std::env::temp_dir().join(format!("zxpm-pending-crash-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let report = dir.join("crash-1700000000-1.txt");
+        fs::write(&report, "report").unwrap();
+        fs::write(dir.join(PENDING_MARKER), report.to_string_lossy().as_bytes()).unwrap();
+
+        assert_eq!(take_pending_report_in(&dir), Some(report.clone()));
+        assert!(!dir.join(PENDING_MARKER).exists());
+        assert_eq!(take_pending_report_in(&dir), None);
+
+        // A marker left behind by a report that was deleted since is cleared without offering anything
+        fs::write(dir.join(PENDING_MARKER), dir.join("crash-gone.txt").to_string_lossy().as_bytes()).unwrap();
+        assert_eq!(take_pending_report_in(&dir), None);
+        assert!(!dir.join(PENDING_MARKER).exists());
+        assert!(report.is_file());
+        let _ = fs::remove_dir_all(dir);
+    }
+}
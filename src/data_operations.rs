@@ -1,27 +1,98 @@
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use quick_xml::events::Event;
 use quick_xml::reader::Reader;
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 use std::os::unix::fs::MetadataExt;
+use crate::i18n::t_args;
+use crate::manifest::detect_manifest;
 
 // Constants
-const CEP_EXTENSIONS_PATH: &str = "~/Library/Application Support/Adobe/CEP/extensions/";
+pub const SYSTEM_EXTENSIONS_PATH: &str = "/Library/Application Support/Adobe/CEP/extensions/";
+pub const CEP_EXTENSIONS_PATH: &str = "~/Library/Application Support/Adobe/CEP/extensions/";
+
+// Next to each extensions root, so disabling is a rename on the same volume; hosts never look there
+pub const DISABLED_FOLDER_NAME: &str = "extensions-disabled";
+pub const EXTENSIONS_FOLDER_NAME: &str = "extensions";
+
+// Where Extension Manager and the CS-era ServiceManagers put extensions before the CEP folders,
+// under Application Support/Adobe for all users and for the current user. ExMan's own database
+// only points back into these folders, so scanning them finds the same extensions.
+pub const LEGACY_EXTENSION_FOLDERS: [&str; 4] =
+    ["CS5ServiceManager/extensions", "CS5.5ServiceManager/extensions", "CS6ServiceManager/extensions", "CEPServiceManager4/extensions"];
 
 // Data structures
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Plugin {
     pub name: String,
+    pub bundle_id: String,
     pub version: String,
-    pub size: String,
+    pub size: String,      // Logical - the bytes the files contain
+    pub disk_size: String, // Allocated on disk, see measure_folder()
+    pub disk_bytes: u64,   // Same as a number for totals; 0 when it couldn't be measured
     pub path: PathBuf,
     pub plugin_type: PluginType,
     pub can_remove: bool,
+    pub installed_at: Option<i64>, // Unix seconds, from folder_timestamp()
 }
 
-#[derive(Debug, Clone, PartialEq)]
+// Shared fixture: an installed, removable plugin at /extensions/<bundle ID>, named after its ID
+#[cfg(test)]
+impl Plugin {
+    pub fn for_test(bundle_id: &str, version: &str) -> Plugin {
+        Plugin {
+            name: bundle_id.to_string(),
+            bundle_id: bundle_id.to_string(),
+            version: version.to_string(),
+            size: "1 KB".to_string(),
+            disk_size: "4 KB".to_string(),
+            disk_bytes: 4096,
+            path: PathBuf::from("/extensions").join(bundle_id),
+            plugin_type: PluginType::Installed,
+            can_remove: true,
+            installed_at: None,
+        }
+    }
+}
+
+// Logical bytes are what the files contain; on disk is the space they take, which is what removing frees
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FolderSize {
+    pub logical: u64,
+    pub on_disk: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PluginType {
     Native,      // Bundle ID starts with "com.adobe."
     Installed,   // Third-party plugins
+    Dev,         // Link to a working folder, made with link_dev_folder()
+    Legacy,      // In a LEGACY_EXTENSION_FOLDERS location, which current hosts don't load
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtensionRoot {
+    System, // All users, needs admin rights to modify
+    User,   // Current user only
+}
+
+impl ExtensionRoot {
+    // Order in which host apps load the roots - later roots override earlier ones on ID collisions
+    pub const LOAD_ORDER: [ExtensionRoot; 2] = [ExtensionRoot::System, ExtensionRoot::User];
+
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            ExtensionRoot::System => Some(PathBuf::from(SYSTEM_EXTENSIONS_PATH)),
+            ExtensionRoot::User => {
+                let relative = CEP_EXTENSIONS_PATH.trim_start_matches("~/");
+                dirs::home_dir().map(|home| home.join(relative))
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -31,37 +102,134 @@ pub struct PluginInfo {
     pub version: String,
 }
 
-#[derive(Debug)]
-pub enum PluginError {
-    DirectoryNotFound,
-    PermissionDenied,
-    ManifestNotFound,
-    InvalidManifest,
+// One <Extension> of a bundle, merged from ExtensionList and DispatchInfoList
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ExtensionEntry {
+    pub id: String,
+    pub ui_type: Option<String>, // Panel, ModalDialog, Modeless, Custom
+    pub menu: Option<String>,    // Window > Extensions label
+    pub main_path: Option<String>,
+    pub script_path: Option<String>,
+    pub cef_parameters: Vec<String>, // <CEFCommandLine> flags, e.g. --enable-nodejs
+    pub auto_visible: Option<bool>,
+    pub start_on: Vec<String>, // Lifecycle events that launch the extension
+    pub icons: Vec<(String, String)>, // (Type, relative path) from <Icons>, e.g. ("DarkNormal", "./icons/dark.png")
 }
 
-impl std::fmt::Display for PluginError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ExtensionEntry {
+    pub fn ui_kind(&self) -> UiKind {
+        UiKind::from_ui_type(self.ui_type.as_deref())
+    }
+}
+
+// How an extension shows up in the host, from <UI><Type>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiKind {
+    Panel,
+    ModalDialog,
+    Modeless,
+    Invisible, // Custom, or no UI at all - runs in the background and never opens a window
+}
+
+impl UiKind {
+    pub fn from_ui_type(ui_type: Option<&str>) -> UiKind {
+        match ui_type.map(str::trim) {
+            Some(value) if value.eq_ignore_ascii_case("Panel") => UiKind::Panel,
+            Some(value) if value.eq_ignore_ascii_case("ModalDialog") => UiKind::ModalDialog,
+            Some(value) if value.eq_ignore_ascii_case("Modeless") => UiKind::Modeless,
+            _ => UiKind::Invisible,
+        }
+    }
+
+    pub fn label_key(self) -> &'static str {
         match self {
-            PluginError::DirectoryNotFound => write!(f, "Directory not found"),
-            PluginError::PermissionDenied => write!(f, "Permission denied"),
-            PluginError::ManifestNotFound => write!(f, "Manifest not found"),
-            PluginError::InvalidManifest => write!(f, "Invalid manifest"),
+            UiKind::Panel => "ui-kind-panel",
+            UiKind::ModalDialog => "ui-kind-modal-dialog",
+            UiKind::Modeless => "ui-kind-modeless",
+            UiKind::Invisible => "ui-kind-invisible",
         }
     }
 }
 
-impl std::error::Error for PluginError {}
+// <Host Name="PHXS" Version="[22.0,99.9]"/> from the manifest's HostList
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostRequirement {
+    pub name: String,            // Host app code, e.g. PHXS, ILST, AEFT
+    pub version: Option<String>, // Single version or [min,max] range
+}
 
-impl From<std::io::Error> for PluginError {
-    fn from(error: std::io::Error) -> Self {
+// A folder in an extensions root that can't be read as a plugin - no manifest, one that doesn't
+// parse, or a dev link whose folder is gone. Hosts still trip over these leftovers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrokenExtension {
+    pub path: PathBuf,
+    pub error: PluginError,
+}
+
+// How far a running scan has got, shared with the status bar while the walk runs on a worker
+// thread. Every entry of the CEP roots counts once; cancelling stops the walk at the next entry.
+#[derive(Debug, Clone, Default)]
+pub struct ScanProgress {
+    processed: Arc<AtomicUsize>,
+    total: Arc<AtomicUsize>,
+    measured: Arc<AtomicU64>, // On-disk bytes of the plugins found so far
+    pub cancel: CancellationToken,
+}
+
+impl ScanProgress {
+    // (processed, total) - total is known before the first folder is read
+    pub fn counts(&self) -> (usize, usize) {
+        (self.processed.load(Ordering::Relaxed), self.total.load(Ordering::Relaxed))
+    }
+
+    pub fn measured_bytes(&self) -> u64 {
+        self.measured.load(Ordering::Relaxed)
+    }
+}
+
+// Paths say which folder or manifest failed; InvalidManifest carries the parser's reason
+#[derive(Debug, Clone, PartialEq)]
+pub enum PluginError {
+    DirectoryNotFound(PathBuf),
+    PermissionDenied(PathBuf),
+    ManifestNotFound(PathBuf),
+    InvalidManifest(String),
+}
+
+impl PluginError {
+    // For failed reads of an extensions folder
+    fn reading(path: &Path, error: std::io::Error) -> Self {
         match error.kind() {
-            std::io::ErrorKind::NotFound => PluginError::DirectoryNotFound,
-            std::io::ErrorKind::PermissionDenied => PluginError::PermissionDenied,
-            _ => PluginError::DirectoryNotFound,
+            std::io::ErrorKind::PermissionDenied => PluginError::PermissionDenied(path.to_path_buf()),
+            _ => PluginError::DirectoryNotFound(path.to_path_buf()),
         }
     }
+
+    // Stable support code, listed in the in-app error reference
+    pub fn code(&self) -> &'static str {
+        match self {
+            PluginError::DirectoryNotFound(_) => "ZXPM-1001",
+            PluginError::PermissionDenied(_) => "ZXPM-1002",
+            PluginError::ManifestNotFound(_) => "ZXPM-1003",
+            PluginError::InvalidManifest(_) => "ZXPM-1004",
+        }
+    }
+}
+
+impl std::fmt::Display for PluginError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            PluginError::DirectoryNotFound(path) => t_args("error-directory-not-found", &[("path", &path.display().to_string())]),
+            PluginError::PermissionDenied(path) => t_args("error-permission-denied-reading", &[("path", &path.display().to_string())]),
+            PluginError::ManifestNotFound(path) => t_args("error-manifest-not-found", &[("path", &path.display().to_string())]),
+            PluginError::InvalidManifest(reason) => t_args("error-invalid-manifest", &[("reason", reason)]),
+        };
+        write!(f, "{} ({})", message, self.code())
+    }
 }
 
+impl std::error::Error for PluginError {}
+
 // Data operations
 pub fn can_remove_plugin(plugin_path: &Path) -> bool {
     // Check if current user owns the plugin directory
@@ -76,64 +244,129 @@ pub fn can_remove_plugin(plugin_path: &Path) -> bool {
 }
 
 pub fn scan_cep_plugins() -> Result<Vec<Plugin>, PluginError> {
-    // 1. Use system-wide CEP extensions directory
-    let cep_path = Path::new("/Library/Application Support/Adobe/CEP/extensions/");
-    
-    // 2. Check if directory exists
-    if !cep_path.exists() {
-        log::warn!("CEP extensions directory not found: {:?}", cep_path);
-        return Ok(Vec::new());
-    }
-    
-    // 3. Read directory contents
-    let entries = fs::read_dir(&cep_path)?;
+    scan_cep_plugins_with(&ScanProgress::default())
+}
+
+// Same scan, counting into `progress`; a cancelled scan returns what it found so far
+pub fn scan_cep_plugins_with(progress: &ScanProgress) -> Result<Vec<Plugin>, PluginError> {
+    let roots: Vec<PathBuf> = ExtensionRoot::LOAD_ORDER.iter().filter_map(|root| root.path()).collect();
+    let total: usize = roots.iter().map(|root| fs::read_dir(root).map_or(0, |entries| entries.count())).sum();
+    progress.total.fetch_add(total, Ordering::Relaxed);
+
+    // Scan every extensions root in host load order; an unreadable one doesn't hide the others
     let mut plugins = Vec::new();
-    
-    // 4. For each subdirectory
-    for entry in entries {
-        let entry = entry?;
-        let path = entry.path();
-        
-        // Only process directories
-        if !path.is_dir() {
-            continue;
-        }
-        
-        // Check if it's a valid plugin
-        if !is_valid_plugin(&path) {
-            continue;
-        }
-        
-        // Parse manifest
-        let manifest_path = path.join("CSXS").join("manifest.xml");
-        match parse_manifest_xml(&manifest_path) {
-            Ok(plugin_info) => {
-                let plugin_type = determine_plugin_type(&plugin_info.bundle_id);
-                let size = calculate_folder_size(&path);
-                
-                plugins.push(Plugin {
-                    name: plugin_info.name,
-                    version: plugin_info.version,
-                    size,
-                    path: path.clone(),
-                    plugin_type,
-                    can_remove: can_remove_plugin(&path),
-                });
-            }
-            Err(e) => {
-                log::warn!("Failed to parse manifest for {:?}: {}", path, e);
-            }
+    for cep_path in &roots {
+        match scan_root(cep_path, progress) {
+            Ok(found) => plugins.extend(found),
+            Err(e) => log::warn!("Skipping extensions root {:?}: {}", cep_path, e),
         }
     }
-    
     Ok(plugins)
 }
 
+// Roots not created yet - fresh machines often have neither until a first install
+pub fn missing_extension_roots() -> Vec<ExtensionRoot> {
+    ExtensionRoot::LOAD_ORDER.into_iter().filter(|root| root.path().is_some_and(|path| !path.is_dir())).collect()
+}
+
+// Plugins moved aside with "Disable", from every root's disabled folder
+pub fn scan_disabled_plugins() -> Vec<Plugin> {
+    ExtensionRoot::LOAD_ORDER
+        .iter()
+        .filter_map(|root| root.path())
+        .map(|root| disabled_folder(&root))
+        .filter(|folder| folder.is_dir())
+        .flat_map(|folder| scan_extensions_root(&folder).unwrap_or_default())
+        .collect()
+}
+
+// Extensions left in legacy locations, listed next to the CEP folders' and marked Legacy
+pub fn scan_legacy_plugins() -> Vec<Plugin> {
+    legacy_extension_roots()
+        .iter()
+        .filter(|root| root.is_dir())
+        .flat_map(|root| scan_extensions_root(root).unwrap_or_default())
+        .map(|plugin| Plugin { plugin_type: PluginType::Legacy, ..plugin })
+        .collect()
+}
+
+pub fn legacy_extension_roots() -> Vec<PathBuf> {
+    let system = PathBuf::from("/Library/Application Support/Adobe");
+    let user = dirs::home_dir().map(|home| home.join("Library/Application Support/Adobe"));
+    [Some(system), user]
+        .into_iter()
+        .flatten()
+        .flat_map(|base| LEGACY_EXTENSION_FOLDERS.iter().map(move |folder| base.join(folder)))
+        .collect()
+}
+
+pub fn disabled_folder(extensions_root: &Path) -> PathBuf {
+    extensions_root.with_file_name(DISABLED_FOLDER_NAME)
+}
+
+pub fn scan_extensions_root(cep_path: &Path) -> Result<Vec<Plugin>, PluginError> {
+    scan_root(cep_path, &ScanProgress::default())
+}
+
+// Every CEP root's folders that scan_extensions_root() leaves out
+pub fn scan_broken_extensions() -> Vec<BrokenExtension> {
+    ExtensionRoot::LOAD_ORDER.iter().filter_map(|root| root.path()).flat_map(|root| find_broken_extensions(&root)).collect()
+}
+
+pub fn find_broken_extensions(extensions_root: &Path) -> Vec<BrokenExtension> {
+    let mut broken: Vec<BrokenExtension> = fs::read_dir(extensions_root)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| !path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'))) // .DS_Store and friends
+        .filter_map(|path| match read_folder_manifest(&path) {
+            Some(Err(error)) => Some(BrokenExtension { path, error }),
+            _ => None,
+        })
+        .collect();
+    broken.sort_by(|a, b| a.path.cmp(&b.path));
+    broken
+}
+
+// A root entry's manifest, or why it has none; None for plain files, which are never plugins
+fn read_folder_manifest(path: &Path) -> Option<Result<PluginInfo, PluginError>> {
+    if !path.is_dir() {
+        // A dev link whose working folder was moved or deleted
+        let target = fs::read_link(path).unwrap_or_else(|_| path.to_path_buf());
+        return is_dev_link(path).then_some(Err(PluginError::DirectoryNotFound(target)));
+    }
+    let Some((parser, manifest_path)) = detect_manifest(path) else {
+        return Some(Err(PluginError::ManifestNotFound(path.join("CSXS").join("manifest.xml"))));
+    };
+    let result = read_manifest_text(&manifest_path).and_then(|content| parser.parse(&content));
+    if let Err(e) = &result {
+        log::warn!("Failed to parse {} for {:?}: {}", parser.name(), path, e);
+    }
+    Some(result)
+}
+
+// Dev folders are linked into an extensions root; Rust reports Windows junctions as symlinks too
+pub fn is_dev_link(path: &Path) -> bool {
+    fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_symlink())
+}
+
+// Later of creation and modification time - reinstalling over a folder keeps its creation time,
+// but usually changes its top-level entries. Filesystems without creation times fall back to mtime.
+pub fn folder_timestamp(path: &Path) -> Option<i64> {
+    let metadata = fs::metadata(path).ok()?;
+    let time = [metadata.created().ok(), metadata.modified().ok()].into_iter().flatten().max()?;
+    let seconds = time.duration_since(std::time::UNIX_EPOCH).ok()?.as_secs();
+    i64::try_from(seconds).ok()
+}
+
 pub fn parse_manifest_xml(manifest_path: &Path) -> Result<PluginInfo, PluginError> {
-    let xml_content = fs::read_to_string(manifest_path)
-        .map_err(|_| PluginError::ManifestNotFound)?;
-    
-    let mut reader = Reader::from_str(&xml_content);
+    parse_manifest_str(&read_manifest_text(manifest_path)?)
+}
+
+// Same as parse_manifest_xml, for manifests already in memory, e.g. read from a ZXP
+pub fn parse_manifest_str(xml_content: &str) -> Result<PluginInfo, PluginError> {
+    let mut reader = Reader::from_str(xml_content);
     reader.config_mut().trim_text(true);
     
     let mut bundle_id = String::new();
@@ -144,13 +377,13 @@ pub fn parse_manifest_xml(manifest_path: &Path) -> Result<PluginInfo, PluginErro
     
     loop {
         match reader.read_event_into(&mut buf) {
-            Err(_) => return Err(PluginError::InvalidManifest),
+            Err(e) => return Err(PluginError::InvalidManifest(e.to_string())),
             Ok(Event::Eof) => break,
             
             // Look for ExtensionBundleId attribute
-            Ok(Event::Start(ref e)) if e.name().as_ref() == b"ExtensionManifest" => {
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.name().as_ref() == b"ExtensionManifest" => {
                 for attr in e.attributes() {
-                    let attr = attr.map_err(|_| PluginError::InvalidManifest)?;
+                    let attr = attr.map_err(|e| PluginError::InvalidManifest(e.to_string()))?;
                     match attr.key.as_ref() {
                         b"ExtensionBundleId" => {
                             bundle_id = String::from_utf8_lossy(&attr.value).to_string();
@@ -171,7 +404,7 @@ pub fn parse_manifest_xml(manifest_path: &Path) -> Result<PluginInfo, PluginErro
     }
     
     if bundle_id.is_empty() {
-        return Err(PluginError::InvalidManifest);
+        return Err(PluginError::InvalidManifest("ExtensionManifest has no ExtensionBundleId".to_string()));
     }
     
     // Use bundle_id as fallback name if name is empty
@@ -186,17 +419,220 @@ pub fn parse_manifest_xml(manifest_path: &Path) -> Result<PluginInfo, PluginErro
     })
 }
 
-pub fn calculate_folder_size(path: &Path) -> String {
-    match calculate_folder_size_bytes(path) {
-        Ok(bytes) => format_size(bytes),
+pub fn parse_extension_entries(manifest_path: &Path) -> Result<Vec<ExtensionEntry>, PluginError> {
+    parse_extension_entries_content(&read_manifest_text(manifest_path)?)
+}
+
+pub fn parse_extension_entries_content(xml_content: &str) -> Result<Vec<ExtensionEntry>, PluginError> {
+    // No trim_text here: it would eat the spaces around entities like "Tools &amp; More"
+    let mut reader = Reader::from_str(xml_content);
+    
+    let mut entries: Vec<ExtensionEntry> = Vec::new();
+    let mut current: Option<usize> = None; // Entry whose DispatchInfo is being read
+    let mut stack: Vec<Vec<u8>> = Vec::new();
+    let mut text = String::new();
+    let mut icon_type = String::new(); // Type attribute of the <Icon> being read
+    
+    let mut buf = Vec::new();
+    
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Err(e) => return Err(PluginError::InvalidManifest(e.to_string())),
+            Ok(Event::Eof) => break,
+            
+            // <Extension Id="..."> appears in both ExtensionList and DispatchInfoList
+            Ok(Event::Start(ref e)) => {
+                if e.name().as_ref() == b"Extension" {
+                    current = extension_id_attribute(e).map(|id| entry_index(&mut entries, id));
+                }
+                if e.name().as_ref() == b"Icon" {
+                    icon_type = attribute_value(e, b"Type").unwrap_or_default();
+                }
+                stack.push(e.name().as_ref().to_vec());
+                text.clear();
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"Extension" => {
+                if let Some(id) = extension_id_attribute(e) {
+                    entry_index(&mut entries, id);
+                }
+            }
+            Ok(Event::Text(ref e)) => {
+                text.push_str(&e.decode().map_err(|e| PluginError::InvalidManifest(e.to_string()))?);
+            }
+            Ok(Event::GeneralRef(ref e)) => {
+                let name = e.decode().map_err(|e| PluginError::InvalidManifest(e.to_string()))?;
+                if let Some(resolved) = quick_xml::escape::resolve_predefined_entity(&name) {
+                    text.push_str(resolved);
+                }
+            }
+            Ok(Event::End(_)) => {
+                let name = stack.pop().unwrap_or_default();
+                let parent = stack.last().map(Vec::as_slice).unwrap_or_default();
+                let value = text.trim().to_string();
+                
+                if let Some(index) = current {
+                    let entry = &mut entries[index];
+                    match (parent, name.as_slice()) {
+                        (b"UI", b"Type") => entry.ui_type = Some(value),
+                        (b"UI", b"Menu") => entry.menu = Some(value),
+                        (b"Resources", b"MainPath") => entry.main_path = Some(value),
+                        (b"Resources", b"ScriptPath") => entry.script_path = Some(value),
+                        (b"CEFCommandLine", b"Parameter") if !value.is_empty() => entry.cef_parameters.push(value),
+                        (b"Lifecycle", b"AutoVisible") => entry.auto_visible = Some(value.eq_ignore_ascii_case("true")),
+                        (b"StartOn", b"Event") if !value.is_empty() => entry.start_on.push(value),
+                        (b"Icons", b"Icon") if !value.is_empty() => entry.icons.push((icon_type.clone(), value)),
+                        (_, b"Extension") => current = None,
+                        _ => {}
+                    }
+                }
+                text.clear();
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+    
+    Ok(entries)
+}
+
+pub fn parse_host_list(manifest_path: &Path) -> Result<Vec<HostRequirement>, PluginError> {
+    parse_host_list_str(&read_manifest_text(manifest_path)?)
+}
+
+// Same for a manifest read into memory, e.g. from a ZXP
+pub fn parse_host_list_str(xml_content: &str) -> Result<Vec<HostRequirement>, PluginError> {
+    let mut reader = Reader::from_str(xml_content);
+    let mut hosts: Vec<HostRequirement> = Vec::new();
+    let mut buf = Vec::new();
+    
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Err(e) => return Err(PluginError::InvalidManifest(e.to_string())),
+            Ok(Event::Eof) => break,
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.name().as_ref() == b"Host" => {
+                let mut host = HostRequirement { name: String::new(), version: None };
+                for attr in e.attributes() {
+                    let attr = attr.map_err(|e| PluginError::InvalidManifest(e.to_string()))?;
+                    let value = String::from_utf8_lossy(&attr.value).trim().to_string();
+                    match attr.key.as_ref() {
+                        b"Name" => host.name = value,
+                        b"Version" if !value.is_empty() => host.version = Some(value),
+                        _ => {}
+                    }
+                }
+                if !host.name.is_empty() && !hosts.contains(&host) {
+                    hosts.push(host);
+                }
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+    
+    Ok(hosts)
+}
+
+// CSXS version from <RequiredRuntime Name="CSXS" Version="11.0"/>, the CEP runtime the panels need
+pub fn parse_required_runtime(manifest_path: &Path) -> Result<Option<String>, PluginError> {
+    let xml_content = read_manifest_text(manifest_path)?;
+    
+    let mut reader = Reader::from_str(&xml_content);
+    let mut buf = Vec::new();
+    
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Err(e) => return Err(PluginError::InvalidManifest(e.to_string())),
+            Ok(Event::Eof) => return Ok(None),
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.name().as_ref() == b"RequiredRuntime" => {
+                let mut is_csxs = false;
+                let mut version = None;
+                for attr in e.attributes() {
+                    let attr = attr.map_err(|e| PluginError::InvalidManifest(e.to_string()))?;
+                    let value = String::from_utf8_lossy(&attr.value).trim().to_string();
+                    match attr.key.as_ref() {
+                        b"Name" => is_csxs = value == "CSXS",
+                        b"Version" if !value.is_empty() => version = Some(value),
+                        _ => {}
+                    }
+                }
+                if is_csxs && version.is_some() {
+                    return Ok(version);
+                }
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+// Formatted logical and on-disk sizes, plus the on-disk bytes
+pub fn calculate_folder_size(path: &Path) -> (String, String, u64) {
+    match measure_folder(path) {
+        Ok(size) => (format_size(size.logical), format_size(size.on_disk), size.on_disk),
         Err(e) => {
             log::warn!("Failed to calculate size for {:?}: {}", path, e);
-            "Unknown".to_string()
+            ("Unknown".to_string(), "Unknown".to_string(), 0)
         }
     }
 }
 
+// Summing file lengths misreports what a folder really takes up: small files still fill whole blocks,
+// sparse files have holes that take none, and APFS clones share their blocks with the original
+pub fn measure_folder(path: &Path) -> Result<FolderSize, std::io::Error> {
+    let mut usage = DiskUsage::default();
+    usage.add_folder(path)?;
+    Ok(usage.total())
+}
+
 // Helper functions
+fn scan_root(cep_path: &Path, progress: &ScanProgress) -> Result<Vec<Plugin>, PluginError> {
+    // 1. Check if directory exists
+    if !cep_path.exists() {
+        log::warn!("CEP extensions directory not found: {:?}", cep_path);
+        return Ok(Vec::new());
+    }
+    
+    // 2. Read directory contents
+    let entries = fs::read_dir(cep_path).map_err(|e| PluginError::reading(cep_path, e))?;
+    let mut plugins = Vec::new();
+    
+    // 3. For each subdirectory
+    for entry in entries {
+        if progress.cancel.is_cancelled() {
+            break;
+        }
+        let entry = entry.map_err(|e| PluginError::reading(cep_path, e))?;
+        let path = entry.path();
+        progress.processed.fetch_add(1, Ordering::Relaxed);
+        
+        // Folders with a manifest in any format; the rest are listed by scan_broken_extensions()
+        match read_folder_manifest(&path) {
+            Some(Ok(plugin_info)) => {
+                let plugin_type =
+                    if is_dev_link(&path) { PluginType::Dev } else { determine_plugin_type(&plugin_info.bundle_id) };
+                let (size, disk_size, disk_bytes) = calculate_folder_size(&path);
+                progress.measured.fetch_add(disk_bytes, Ordering::Relaxed);
+                
+                plugins.push(Plugin {
+                    name: plugin_info.name,
+                    bundle_id: plugin_info.bundle_id,
+                    version: plugin_info.version,
+                    size,
+                    disk_size,
+                    disk_bytes,
+                    path: path.clone(),
+                    plugin_type,
+                    can_remove: can_remove_plugin(&path),
+                    installed_at: folder_timestamp(&path),
+                });
+            }
+            Some(Err(_)) | None => continue,
+        }
+    }
+    
+    Ok(plugins)
+}
+
 pub fn determine_plugin_type(bundle_id: &str) -> PluginType {
     if bundle_id.starts_with("com.adobe.") {
         PluginType::Native
@@ -205,11 +641,49 @@ pub fn determine_plugin_type(bundle_id: &str) -> PluginType {
     }
 }
 
-fn is_valid_plugin(plugin_dir: &Path) -> bool {
-    plugin_dir.join("CSXS").join("manifest.xml").exists()
+fn extension_id_attribute(element: &quick_xml::events::BytesStart) -> Option<String> {
+    attribute_value(element, b"Id")
+}
+
+fn attribute_value(element: &quick_xml::events::BytesStart, name: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == name)
+        .map(|attr| String::from_utf8_lossy(&attr.value).to_string())
 }
 
-fn calculate_folder_size_bytes(path: &Path) -> Result<u64, std::io::Error> {
+fn entry_index(entries: &mut Vec<ExtensionEntry>, id: String) -> usize {
+    match entries.iter().position(|entry| entry.id == id) {
+        Some(index) => index,
+        None => {
+            entries.push(ExtensionEntry { id, ..Default::default() });
+            entries.len() - 1
+        }
+    }
+}
+
+// Manifests come from untrusted vendor folders - tolerate BOMs and UTF-16, reject anything else undecodable
+pub fn read_manifest_text(manifest_path: &Path) -> Result<String, PluginError> {
+    let bytes = fs::read(manifest_path).map_err(|_| PluginError::ManifestNotFound(manifest_path.to_path_buf()))?;
+    decode_manifest(&bytes).ok_or_else(|| PluginError::InvalidManifest("not UTF-8 or UTF-16 text".to_string()))
+}
+
+pub fn decode_manifest(bytes: &[u8]) -> Option<String> {
+    let utf16 = |chunks: std::slice::ChunksExact<u8>, to_unit: fn([u8; 2]) -> u16| {
+        let units: Vec<u16> = chunks.map(|pair| to_unit([pair[0], pair[1]])).collect();
+        String::from_utf16(&units).ok()
+    };
+
+    match bytes {
+        [0xEF, 0xBB, 0xBF, rest @ ..] => String::from_utf8(rest.to_vec()).ok(),
+        [0xFF, 0xFE, rest @ ..] if rest.len() % 2 == 0 => utf16(rest.chunks_exact(2), u16::from_le_bytes),
+        [0xFE, 0xFF, rest @ ..] if rest.len() % 2 == 0 => utf16(rest.chunks_exact(2), u16::from_be_bytes),
+        _ => String::from_utf8(bytes.to_vec()).ok(),
+    }
+}
+
+pub fn calculate_folder_size_bytes(path: &Path) -> Result<u64, std::io::Error> {
     let mut total_size = 0;
     
     for entry in fs::read_dir(path)? {
@@ -226,7 +700,103 @@ fn calculate_folder_size_bytes(path: &Path) -> Result<u64, std::io::Error> {
     Ok(total_size)
 }
 
-fn format_size(bytes: u64) -> String {
+#[derive(Default)]
+struct DiskUsage {
+    logical: u64,
+    allocated: u64,                  // Blocks not shared with any clone
+    files: HashSet<(u64, u64)>,      // (device, inode) - hard links take their blocks once
+    clone_shared: HashMap<u64, u64>, // Clone family -> blocks its members share, counted once
+}
+
+impl DiskUsage {
+    fn add_folder(&mut self, path: &Path) -> Result<(), std::io::Error> {
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+
+            if metadata.is_file() {
+                self.add_file(&entry.path(), &metadata);
+            } else if metadata.is_dir() {
+                self.add_folder(&entry.path())?;
+            }
+        }
+        Ok(())
+    }
+
+    fn add_file(&mut self, path: &Path, metadata: &fs::Metadata) {
+        if !self.files.insert((metadata.dev(), metadata.ino())) {
+            return; // Another link to a file already counted
+        }
+        self.logical += metadata.len();
+        let allocated = metadata.blocks() * 512; // st_blocks is in 512-byte units whatever the block size
+
+        match clone_sharing(path) {
+            Some((clone_id, private)) => {
+                self.allocated += private.min(allocated);
+                let shared = self.clone_shared.entry(clone_id).or_default();
+                *shared = (*shared).max(allocated.saturating_sub(private));
+            }
+            None => self.allocated += allocated,
+        }
+    }
+
+    fn total(&self) -> FolderSize {
+        FolderSize { logical: self.logical, on_disk: self.allocated + self.clone_shared.values().sum::<u64>() }
+    }
+}
+
+// APFS reports each clone's full allocation in st_blocks; its private size is the part not shared
+// with other clones. (clone ID, private bytes), or None on other filesystems and systems.
+#[cfg(target_os = "macos")]
+fn clone_sharing(path: &Path) -> Option<(u64, u64)> {
+    use std::os::unix::ffi::OsStrExt;
+
+    #[repr(C, packed(4))]
+    struct Reply {
+        length: u32,
+        returned: libc::attribute_set_t,
+        private_size: libc::off_t,
+        clone_id: u64,
+    }
+
+    let wanted = libc::ATTR_CMNEXT_PRIVATESIZE | libc::ATTR_CMNEXT_CLONEID;
+    let mut request = libc::attrlist {
+        bitmapcount: libc::ATTR_BIT_MAP_COUNT,
+        reserved: 0,
+        commonattr: libc::ATTR_CMN_RETURNED_ATTRS,
+        volattr: 0,
+        dirattr: 0,
+        fileattr: 0,
+        forkattr: wanted, // Extended common attributes go here with FSOPT_ATTR_CMN_EXTENDED
+    };
+    let path = std::ffi::CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut reply = std::mem::MaybeUninit::<Reply>::zeroed();
+
+    // SAFETY: the path is NUL-terminated and the buffer size matches the reply struct
+    let status = unsafe {
+        libc::getattrlist(
+            path.as_ptr(),
+            (&mut request as *mut libc::attrlist).cast(),
+            reply.as_mut_ptr().cast(),
+            size_of::<Reply>(),
+            libc::FSOPT_NOFOLLOW | libc::FSOPT_ATTR_CMN_EXTENDED,
+        )
+    };
+    // SAFETY: zeroed is a valid Reply, and getattrlist only filled it in
+    let reply = unsafe { reply.assume_init() };
+    let returned = reply.returned.forkattr;
+    if status != 0 || returned & wanted != wanted {
+        return None; // Not APFS, or a macOS without clone attributes
+    }
+    Some((reply.clone_id, u64::try_from(reply.private_size).unwrap_or(0)))
+}
+
+#[cfg(not(target_os = "macos"))]
+fn clone_sharing(_path: &Path) -> Option<(u64, u64)> {
+    None
+}
+
+pub fn format_size(bytes: u64) -> String {
     if bytes < 1024 {
         format!("{} B", bytes)
     } else if bytes < 1024 * 1024 {
@@ -234,4 +804,245 @@ fn format_size(bytes: u64) -> String {
     } else {
         format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Directory with sample manifests: ok-*.xml must parse, bad-*.xml must be rejected
+    const CORPUS_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/corpus/manifests");
+
+    fn parse_bytes(content: &[u8]) -> (Result<PluginInfo, PluginError>, Result<Vec<ExtensionEntry>, PluginError>) {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let file_name = format!("zxpm-test-{}-{}.xml", std::process::id(), COUNTER.fetch_add(1, Ordering::Relaxed));
+        let manifest_path = std::env::temp_dir().join(file_name);
+
+        fs::write(&manifest_path, content).unwrap();
+        let result = (parse_manifest_xml(&manifest_path), parse_extension_entries(&manifest_path));
+        let _ = fs::remove_file(&manifest_path);
+        result
+    }
+
+    const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ExtensionManifest ExtensionBundleId="com.example.tools" ExtensionBundleVersion="1.2.0" ExtensionBundleName="Tools">
+  <ExecutionEnvironment>
+    <HostList>
+      <Host Name="PHXS" Version="[22.0,99.9]" />
+      <Host Name="ILST" Version="25.0" />
+      <Host Name="PHXS" Version="[22.0,99.9]" />
+    </HostList>
+    <RequiredRuntimeList>
+      <RequiredRuntime Name="CSXS" Version="11.0" />
+    </RequiredRuntimeList>
+  </ExecutionEnvironment>
+  <ExtensionList>
+    <Extension Id="com.example.tools.panel" Version="1.2.0" />
+    <Extension Id="com.example.tools.helper" Version="1.2.0" />
+  </ExtensionList>
+  <DispatchInfoList>
+    <Extension Id="com.example.tools.panel">
+      <DispatchInfo>
+        <Resources>
+          <MainPath>./index.html</MainPath>
+          <CEFCommandLine>
+            <Parameter>--enable-nodejs</Parameter>
+            <Parameter>--mixed-context</Parameter>
+          </CEFCommandLine>
+        </Resources>
+        <Lifecycle><AutoVisible>true</AutoVisible></Lifecycle>
+        <UI>
+          <Type>Panel</Type><Menu>Tools &amp; More</Menu>
+          <Icons>
+            <Icon Type="Normal">./icons/light.png</Icon>
+            <Icon Type="DarkNormal">./icons/dark.png</Icon>
+          </Icons>
+        </UI>
+      </DispatchInfo>
+    </Extension>
+    <Extension Id="com.example.tools.helper">
+      <DispatchInfo>
+        <UI><Type>Custom</Type></UI>
+      </DispatchInfo>
+    </Extension>
+  </DispatchInfoList>
+</ExtensionManifest>"#;
+
+    #[test]
+    fn test_parse_extension_entries() {
+        let manifest_path = std::env::temp_dir().join(format!("zxpm-test-entries-{}.xml", std::process::id()));
+        fs::write(&manifest_path, MANIFEST).unwrap();
+
+        let entries = parse_extension_entries(&manifest_path).unwrap();
+        let _ = fs::remove_file(&manifest_path);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].id, "com.example.tools.panel");
+        assert_eq!(entries[0].ui_type.as_deref(), Some("Panel"));
+        assert_eq!(entries[0].menu.as_deref(), Some("Tools & More"));
+        assert_eq!(entries[0].main_path.as_deref(), Some("./index.html"));
+        assert_eq!(entries[0].cef_parameters, vec!["--enable-nodejs", "--mixed-context"]);
+        assert_eq!(entries[0].auto_visible, Some(true));
+        assert_eq!(entries[0].icons[1], ("DarkNormal".to_string(), "./icons/dark.png".to_string()));
+        assert_eq!(entries[1].ui_type.as_deref(), Some("Custom"));
+        assert_eq!(entries[1].menu, None);
+        assert_eq!(entries[0].ui_kind(), UiKind::Panel);
+        assert_eq!(entries[1].ui_kind(), UiKind::Invisible);
+        assert_eq!(UiKind::from_ui_type(Some("Modeless")), UiKind::Modeless);
+        assert_eq!(UiKind::from_ui_type(None), UiKind::Invisible);
+    }
+
+    #[test]
+    fn test_parse_host_list() {
+        let manifest_path = std::env::temp_dir().join(format!("zxpm-test-hosts-{}.xml", std::process::id()));
+        fs::write(&manifest_path, MANIFEST).unwrap();
+
+        let hosts = parse_host_list(&manifest_path).unwrap();
+        let runtime = parse_required_runtime(&manifest_path).unwrap();
+        let _ = fs::remove_file(&manifest_path);
+
+        assert_eq!(runtime.as_deref(), Some("11.0"));
+
+        // Repeated <Host> lines collapse into one requirement
+        assert_eq!(hosts, vec![
+            HostRequirement { name: "PHXS".to_string(), version: Some("[22.0,99.9]".to_string()) },
+            HostRequirement { name: "ILST".to_string(), version: Some("25.0".to_string()) },
+        ]);
+    }
+
+    #[test]
+    fn test_manifest_corpus() {
+        let mut checked = 0;
+
+        for entry in fs::read_dir(CORPUS_DIR).unwrap().flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let (info, entries) = parse_bytes(&fs::read(entry.path()).unwrap());
+
+            if name.starts_with("ok-") {
+                assert!(info.is_ok(), "{} should parse", name);
+                assert!(entries.is_ok(), "{} should parse", name);
+            } else if name.starts_with("bad-") {
+                assert!(info.is_err(), "{} should be rejected", name);
+            }
+            checked += 1;
+        }
+
+        assert!(checked > 0, "corpus is empty");
+    }
+
+    #[test]
+    fn test_decode_manifest_encodings() {
+        let utf16le: Vec<u8> = [0xFF, 0xFE].into_iter().chain("<a/>".encode_utf16().flat_map(u16::to_le_bytes)).collect();
+        let utf16be: Vec<u8> = [0xFE, 0xFF].into_iter().chain("<a/>".encode_utf16().flat_map(u16::to_be_bytes)).collect();
+
+        assert_eq!(decode_manifest(b"\xEF\xBB\xBF<a/>").as_deref(), Some("<a/>"));
+        assert_eq!(decode_manifest(&utf16le).as_deref(), Some("<a/>"));
+        assert_eq!(decode_manifest(&utf16be).as_deref(), Some("<a/>"));
+        assert_eq!(decode_manifest(&[0xFF, 0xFE, 0x3C]), None); // Odd-length UTF-16
+        assert_eq!(decode_manifest(&[0xC3, 0x28]), None); // Invalid UTF-8
+    }
+
+    #[test]
+    fn test_measure_folder_counts_allocation_once() {
+        let dir = std::env::temp_dir().join(format!("zxpm-size-{}", std::process::id()));
+        fs::create_dir_all(dir.join("client")).unwrap();
+        fs::write(dir.join("client").join("index.html"), b"<html></html>").unwrap();
+        fs::hard_link(dir.join("client").join("index.html"), dir.join("index.html")).unwrap();
+        fs::File::create(dir.join("sparse.bin")).unwrap().set_len(16 * 1024 * 1024).unwrap(); // A hole, no data
+
+        let size = measure_folder(&dir).unwrap();
+        assert_eq!(size.logical, 16 * 1024 * 1024 + 13); // The hard link adds nothing
+        assert!(size.on_disk < 1024 * 1024 && size.on_disk.is_multiple_of(512));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_broken_extensions_are_listed_not_scanned() {
+        let root = std::env::temp_dir().join(format!("zxpm-broken-{}", std::process::id()));
+        for folder in ["good", "broken", "empty", ".hidden"] {
+            fs::create_dir_all(root.join(folder).join("CSXS")).unwrap();
+        }
+        fs::write(root.join("good/CSXS/manifest.xml"), r#"<ExtensionManifest ExtensionBundleId="com.good"/>"#).unwrap();
+        fs::write(root.join("broken/CSXS/manifest.xml"), "<ExtensionManifest").unwrap();
+        std::os::unix::fs::symlink(root.join("moved-away"), root.join("dangling")).unwrap();
+        fs::write(root.join("notes.txt"), "").unwrap();
+
+        let plugins = scan_extensions_root(&root).unwrap();
+        assert_eq!(plugins.iter().map(|plugin| plugin.bundle_id.as_str()).collect::<Vec<_>>(), ["com.good"]);
+        let broken: Vec<(String, &str)> = find_broken_extensions(&root)
+            .iter()
+            .map(|entry| (entry.path.file_name().unwrap().to_string_lossy().to_string(), entry.error.code()))
+            .collect();
+        assert_eq!(
+            broken,
+            [("broken".to_string(), "ZXPM-1004"), ("dangling".to_string(), "ZXPM-1001"), ("empty".to_string(), "ZXPM-1003")]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_scan_progress_counts_and_cancels() {
+        let root = std::env::temp_dir().join(format!("zxpm-progress-{}", std::process::id()));
+        for folder in ["a", "b", "c"] {
+            fs::create_dir_all(root.join(folder).join("CSXS")).unwrap();
+            let manifest = format!(r#"<ExtensionManifest ExtensionBundleId="com.{}"/>"#, folder);
+            fs::write(root.join(folder).join("CSXS/manifest.xml"), manifest).unwrap();
+        }
+
+        let progress = ScanProgress::default();
+        assert_eq!(scan_root(&root, &progress).unwrap().len(), 3);
+        assert_eq!(progress.counts().0, 3);
+        assert!(progress.measured_bytes() > 0);
+
+        let cancelled = ScanProgress::default();
+        cancelled.cancel.cancel();
+        assert!(scan_root(&root, &cancelled).unwrap().is_empty());
+        assert_eq!(cancelled.counts().0, 0);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    proptest! {
+        #[test]
+        fn prop_arbitrary_bytes_never_panic(content in proptest::collection::vec(any::<u8>(), 0..2048)) {
+            let _ = parse_bytes(&content);
+        }
+
+        #[test]
+        fn prop_truncated_manifest_never_panics(cut in 0..MANIFEST.len()) {
+            let _ = parse_bytes(&MANIFEST.as_bytes()[..cut]);
+        }
+
+        #[test]
+        fn prop_attribute_values_round_trip(
+            bundle_id in "[a-zA-Z0-9._-]{1,4096}",
+            name in "[^<>&\"\\x00-\\x1F]{0,4096}",
+        ) {
+            let manifest = format!(
+                r#"<ExtensionManifest ExtensionBundleId="{}" ExtensionBundleName="{}" ExtensionBundleVersion="1.0"/>"#,
+                bundle_id, name
+            );
+            let (info, _) = parse_bytes(manifest.as_bytes());
+            let info = info.unwrap();
+
+            prop_assert_eq!(info.bundle_id, bundle_id.clone());
+            prop_assert_eq!(info.name, if name.is_empty() { bundle_id } else { name });
+        }
+
+        #[test]
+        fn prop_deep_nesting_never_panics(depth in 1usize..2000) {
+            let manifest = format!(
+                r#"<ExtensionManifest ExtensionBundleId="com.deep">{}{}</ExtensionManifest>"#,
+                "<UI>".repeat(depth),
+                "</UI>".repeat(depth)
+            );
+            let (info, entries) = parse_bytes(manifest.as_bytes());
+
+            prop_assert_eq!(info.unwrap().bundle_id, "com.deep");
+            prop_assert!(entries.unwrap().is_empty());
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::clock::unix_now;
+use crate::data_operations::{CEP_EXTENSIONS_PATH, ExtensionRoot};
+use crate::file_operations::{FileOperationError, create_folder_link};
+use crate::host_detection::{HostApp, app_executable};
+use crate::temp_files::{DEBUG_SANDBOX_FOLDER, app_temp_root};
+
+// Starts a host app for reproducing panel issues: its home folder points at a throwaway sandbox, so it
+// comes up with factory preferences, and CEP logs at its most verbose level. The real setup is untouched.
+
+// CEP's most verbose log level - logs go to ~/Library/Logs/CSXS
+const DEBUG_LOG_LEVEL: &str = "6";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DebugLaunch {
+    pub executable: PathBuf,
+    pub sandbox: PathBuf,
+    pub env: Vec<(&'static str, PathBuf)>, // Variables that move the app's preferences into the sandbox
+    pub debug_logging: bool, // False where CEP's flags can't be kept inside the sandbox
+}
+
+// A new sandbox per session under the app's temp folder, e.g. zxp-manager/debug/PHXS-1760400000
+pub fn sandbox_path(app: &HostApp) -> PathBuf {
+    app_temp_root().join(DEBUG_SANDBOX_FOLDER).join(format!("{}-{}", app.code, unix_now()))
+}
+
+pub fn plan_debug_launch(app: &HostApp, executable: PathBuf, sandbox: &Path) -> DebugLaunch {
+    let env = if cfg!(target_os = "windows") {
+        vec![
+            ("USERPROFILE", sandbox.to_path_buf()),
+            ("APPDATA", sandbox.join("AppData").join("Roaming")),
+            ("LOCALAPPDATA", sandbox.join("AppData").join("Local")),
+        ]
+    } else {
+        // CFPreferences reads CFFIXED_USER_HOME, everything else HOME
+        vec![("HOME", sandbox.to_path_buf()), ("CFFIXED_USER_HOME", sandbox.to_path_buf())]
+    };
+    // CEP reads its flags from the registry on Windows, which has no per-session copy - setting them
+    // there would change the user's real setup, so that session runs with their current log level
+    let debug_logging = app.cep_version.is_some() && !cfg!(target_os = "windows");
+    log::debug!("Planned debug launch of {} from {:?}", app.name, executable);
+    DebugLaunch { executable, sandbox: sandbox.to_path_buf(), env, debug_logging }
+}
+
+pub fn launch_debug_session(app: &HostApp) -> Result<DebugLaunch, FileOperationError> {
+    let executable = app_executable(app).ok_or_else(|| FileOperationError::FileNotFound(app.path.clone()))?;
+    let launch = plan_debug_launch(app, executable, &sandbox_path(app));
+    prepare_sandbox(app, &launch)?;
+
+    let mut child = Command::new(&launch.executable)
+        .envs(launch.env.iter().map(|(name, value)| (*name, value.as_os_str())))
+        .current_dir(&launch.sandbox)
+        .spawn()
+        .map_err(|_| FileOperationError::OpenFailed(launch.executable.display().to_string()))?;
+
+    // Reaped in the background so the finished session doesn't linger as a zombie
+    std::thread::spawn(move || {
+        let _ = child.wait();
+    });
+    log::info!("Started {} for debugging with preferences in {:?}", app.name, launch.sandbox);
+    Ok(launch)
+}
+
+// Helper functions
+fn prepare_sandbox(app: &HostApp, launch: &DebugLaunch) -> Result<(), FileOperationError> {
+    for (_, folder) in &launch.env {
+        fs::create_dir_all(folder).map_err(|e| FileOperationError::extract(folder, e))?;
+    }
+    link_user_extensions(&launch.sandbox);
+
+    match app.cep_version {
+        Some(version) if launch.debug_logging => write_debug_preferences(&launch.sandbox, version),
+        Some(_) => log::warn!("Not setting CEP debug flags for {}: they live in the real registry on Windows", app.name),
+        None => {}
+    }
+    Ok(())
+}
+
+// Panels installed for the current user live under the real home - link them in so they still load
+fn link_user_extensions(sandbox: &Path) {
+    let Some(real_root) = ExtensionRoot::User.path().filter(|root| root.is_dir()) else {
+        return;
+    };
+    let sandbox_root = sandbox.join(CEP_EXTENSIONS_PATH.trim_start_matches("~/").trim_end_matches('/'));
+    let result = sandbox_root
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| create_folder_link(&real_root, &sandbox_root));
+
+    if let Err(e) = result {
+        log::warn!("Couldn't link user extensions into {:?}, only all-users panels will load: {}", sandbox_root, e);
+    }
+}
+
+// The flags go into the sandbox's own CSXS preferences, so they end with the session
+fn write_debug_preferences(sandbox: &Path, version: u32) {
+    let domain = sandbox.join("Library").join("Preferences").join(format!("com.adobe.CSXS.{}", version));
+    let domain = domain.display().to_string();
+    let commands = [
+        ["write", domain.as_str(), "LogLevel", DEBUG_LOG_LEVEL],
+        // Unsigned builds of the panel under test load in this session only
+        ["write", domain.as_str(), "PlayerDebugMode", "1"],
+    ];
+
+    for command in commands {
+        let status = Command::new("defaults").args(command).status();
+        if !status.is_ok_and(|status| status.success()) {
+            log::warn!("Failed to set a CEP debug preference with defaults {:?}", command);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_launch_points_home_at_sandbox() {
+        let app = HostApp {
+            code: "PHXS",
+            name: "Adobe Photoshop 2025".to_string(),
+            version: None,
+            cep_version: Some(12),
+            path: PathBuf::from("/Applications/Adobe Photoshop 2025/Adobe Photoshop 2025.app"),
+        };
+        let sandbox = sandbox_path(&app);
+        assert!(sandbox.file_name().unwrap().to_string_lossy().starts_with("PHXS-"));
+
+        let launch = plan_debug_launch(&app, app.path.join("Contents/MacOS/Adobe Photoshop 2025"), &sandbox);
+        assert!(!launch.env.is_empty());
+        assert!(launch.env.iter().all(|(_, folder)| folder.starts_with(&sandbox)));
+        assert_eq!(launch.debug_logging, !cfg!(target_os = "windows"));
+
+        let no_cep = HostApp { cep_version: None, ..app.clone() };
+        assert!(!plan_debug_launch(&no_cep, app.path.clone(), &sandbox).debug_logging);
+    }
+}
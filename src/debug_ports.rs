@@ -0,0 +1,207 @@
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data_operations::{Plugin, PluginError};
+use crate::file_operations::FileOperationError;
+
+// Remote debugging of CEP panels: a `.debug` file at the top of the extension folder maps each
+// extension ID and host app to a localhost port, where Chrome DevTools can attach to the panel.
+
+pub const DEBUG_FILE_NAME: &str = ".debug";
+
+// Where generated entries start - the port Adobe's samples use
+pub const FIRST_DEBUG_PORT: u16 = 8088;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugEntry {
+    pub extension_id: String,
+    pub host: String, // Host app code, e.g. PHXS
+    pub port: u16,
+}
+
+// One port claimed by more than one extension/host pair; hosts can run side by side, so a
+// port has to be unique across all of them
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortConflict {
+    pub port: u16,
+    pub users: Vec<(PathBuf, DebugEntry)>, // Plugin folder and the entry claiming the port
+}
+
+pub fn debug_file_path(plugin_dir: &Path) -> PathBuf {
+    plugin_dir.join(DEBUG_FILE_NAME)
+}
+
+// No .debug file means no entries
+pub fn read_debug_file(plugin_dir: &Path) -> Result<Vec<DebugEntry>, PluginError> {
+    let path = debug_file_path(plugin_dir);
+    match fs::read_to_string(&path) {
+        Ok(content) => parse_debug_file(&content),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => Err(PluginError::PermissionDenied(path)),
+        Err(e) => Err(PluginError::InvalidManifest(e.to_string())),
+    }
+}
+
+pub fn parse_debug_file(content: &str) -> Result<Vec<DebugEntry>, PluginError> {
+    let mut reader = Reader::from_str(content);
+    let mut entries = Vec::new();
+    let mut extension_id: Option<String> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Err(e) => return Err(PluginError::InvalidManifest(e.to_string())),
+            Ok(Event::Eof) => break,
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) => {
+                let attribute = |key: &[u8]| {
+                    e.attributes()
+                        .flatten()
+                        .find(|attr| attr.key.as_ref() == key)
+                        .map(|attr| String::from_utf8_lossy(&attr.value).trim().to_string())
+                };
+                match e.name().as_ref() {
+                    b"Extension" => extension_id = attribute(b"Id"),
+                    b"Host" => {
+                        let (Some(id), Some(host), Some(port)) =
+                            (&extension_id, attribute(b"Name"), attribute(b"Port").and_then(|port| port.parse().ok()))
+                        else {
+                            continue; // A Host with no usable Name or Port is skipped, as CEP does
+                        };
+                        entries.push(DebugEntry { extension_id: id.clone(), host, port });
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(entries)
+}
+
+// Entries of one extension are grouped, in the order the extensions first appear
+pub fn render_debug_file(entries: &[DebugEntry]) -> String {
+    let mut order: Vec<&str> = Vec::new();
+    for entry in entries {
+        if !order.contains(&entry.extension_id.as_str()) {
+            order.push(&entry.extension_id);
+        }
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<ExtensionList>\n");
+    for id in order {
+        xml.push_str(&format!("  <Extension Id=\"{}\">\n    <HostList>\n", escape_attribute(id)));
+        for entry in entries.iter().filter(|entry| entry.extension_id == id) {
+            xml.push_str(&format!("      <Host Name=\"{}\" Port=\"{}\"/>\n", escape_attribute(&entry.host), entry.port));
+        }
+        xml.push_str("    </HostList>\n  </Extension>\n");
+    }
+    xml.push_str("</ExtensionList>\n");
+    xml
+}
+
+// Saving no entries removes the file
+pub fn write_debug_file(plugin_dir: &Path, entries: &[DebugEntry]) -> Result<(), FileOperationError> {
+    let path = debug_file_path(plugin_dir);
+    let result = if entries.is_empty() {
+        if path.exists() { fs::remove_file(&path) } else { Ok(()) }
+    } else {
+        fs::write(&path, render_debug_file(entries))
+    };
+    result.map_err(|e| FileOperationError::extract(&path, e))?;
+    log::info!("Wrote {} debug entries for {:?}", entries.len(), plugin_dir);
+    Ok(())
+}
+
+// Every plugin's .debug entries; unreadable files are logged and left out
+pub fn collect_debug_entries(plugins: &[Plugin]) -> Vec<(PathBuf, DebugEntry)> {
+    plugins
+        .iter()
+        .flat_map(|plugin| {
+            let entries = read_debug_file(&plugin.path).unwrap_or_else(|e| {
+                log::warn!("Failed to read {:?}: {}", debug_file_path(&plugin.path), e);
+                Vec::new()
+            });
+            entries.into_iter().map(|entry| (plugin.path.clone(), entry))
+        })
+        .collect()
+}
+
+pub fn find_port_conflicts(entries: &[(PathBuf, DebugEntry)]) -> Vec<PortConflict> {
+    let mut by_port: BTreeMap<u16, Vec<(PathBuf, DebugEntry)>> = BTreeMap::new();
+    for (path, entry) in entries {
+        by_port.entry(entry.port).or_default().push((path.clone(), entry.clone()));
+    }
+    by_port.into_iter().filter(|(_, users)| users.len() > 1).map(|(port, users)| PortConflict { port, users }).collect()
+}
+
+// One entry per extension and host, on ports nobody else uses yet; pairs that already have an
+// entry keep it
+pub fn generate_entries(
+    existing: &[DebugEntry],
+    extension_ids: &[String],
+    hosts: &[String],
+    used_ports: &HashSet<u16>,
+) -> Vec<DebugEntry> {
+    let mut entries = existing.to_vec();
+    let mut taken: HashSet<u16> = used_ports.iter().copied().chain(existing.iter().map(|entry| entry.port)).collect();
+    let mut next = FIRST_DEBUG_PORT;
+
+    for id in extension_ids {
+        for host in hosts {
+            if entries.iter().any(|entry| &entry.extension_id == id && &entry.host == host) {
+                continue;
+            }
+            while taken.contains(&next) {
+                next += 1;
+            }
+            taken.insert(next);
+            entries.push(DebugEntry { extension_id: id.clone(), host: host.clone(), port: next });
+        }
+    }
+    entries
+}
+
+// Helper functions
+fn escape_attribute(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_file_round_trip() {
+        let entries = vec![
+            DebugEntry { extension_id: "com.example.panel".to_string(), host: "PHXS".to_string(), port: 8088 },
+            DebugEntry { extension_id: "com.example.dialog".to_string(), host: "PHXS".to_string(), port: 8090 },
+            DebugEntry { extension_id: "com.example.panel".to_string(), host: "ILST".to_string(), port: 8089 },
+        ];
+        let parsed = parse_debug_file(&render_debug_file(&entries)).unwrap();
+        assert_eq!(parsed, [entries[0].clone(), entries[2].clone(), entries[1].clone()]); // Grouped by extension
+    }
+
+    #[test]
+    fn test_generated_ports_skip_used_and_conflicts_are_found() {
+        let ids = vec!["com.example.panel".to_string()];
+        let hosts = vec!["PHXS".to_string(), "AEFT".to_string()];
+        let entries = generate_entries(&[], &ids, &hosts, &HashSet::from([8088]));
+        assert_eq!(entries.iter().map(|entry| entry.port).collect::<Vec<_>>(), [8089, 8090]);
+        assert_eq!(generate_entries(&entries, &ids, &hosts, &HashSet::new()), entries); // Nothing new to add
+
+        let other = DebugEntry { extension_id: "com.other".to_string(), host: "PHXS".to_string(), port: 8090 };
+        let all = vec![
+            (PathBuf::from("/ext/a"), entries[0].clone()),
+            (PathBuf::from("/ext/a"), entries[1].clone()),
+            (PathBuf::from("/ext/b"), other),
+        ];
+        let conflicts = find_port_conflicts(&all);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!((conflicts[0].port, conflicts[0].users.len()), (8090, 2));
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data_operations::{ExtensionEntry, ExtensionRoot, disabled_folder, legacy_extension_roots, parse_extension_entries, parse_manifest_xml};
+
+// UI types that get an entry in the host's Window > Extensions menu
+const MENU_UI_TYPES: [&str; 3] = ["Panel", "ModalDialog", "Modeless"];
+
+// Data structures
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtensionCopy {
+    pub root: ExtensionRoot,
+    pub folder: PathBuf,
+    pub bundle_version: String,
+    pub entry: ExtensionEntry,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtensionResolution {
+    pub id: String,
+    pub winner: ExtensionCopy,
+    pub shadowed: Vec<ExtensionCopy>, // Copies the host ignores because the winner overrides them
+}
+
+impl ExtensionResolution {
+    // Two copies in the same root - which one loads depends on the host's directory order
+    pub fn is_ambiguous(&self) -> bool {
+        self.shadowed.iter().any(|copy| copy.root == self.winner.root)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MenuEntry {
+    pub label: String,
+    pub extension_ids: Vec<String>, // More than one means a duplicate menu item
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DiagnosticsReport {
+    pub resolutions: Vec<ExtensionResolution>,
+    pub menu_entries: Vec<MenuEntry>,
+    pub hidden: Vec<ExtensionCopy>, // Loaded extensions that never appear in the menu
+}
+
+impl DiagnosticsReport {
+    pub fn collisions(&self) -> impl Iterator<Item = &ExtensionResolution> {
+        self.resolutions.iter().filter(|resolution| !resolution.shadowed.is_empty())
+    }
+}
+
+// Diagnostics operations
+// Every extension and bundle ID a readable manifest declares - caches are keyed by extension ID,
+// settings folders often by bundle ID. Disabled and legacy plugins count too: Enable and Import
+// bring them back with their data
+pub fn installed_extension_ids() -> HashSet<String> {
+    let roots: Vec<PathBuf> = ExtensionRoot::LOAD_ORDER.iter().filter_map(|root| root.path()).collect();
+    let mut ids = extension_ids_in(&roots);
+    for folder in legacy_extension_roots() {
+        manifest_ids_in(&folder, &mut ids);
+    }
+    ids
+}
+
+pub fn collect_extension_copies() -> Vec<ExtensionCopy> {
+    let mut copies = Vec::new();
+
+    for root in ExtensionRoot::LOAD_ORDER {
+        let Some(root_path) = root.path() else {
+            continue;
+        };
+        let Ok(entries) = fs::read_dir(&root_path) else {
+            continue; // Missing roots simply contribute nothing
+        };
+
+        // Sort folders so the simulated load order is deterministic
+        let mut folders: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).filter(|path| path.is_dir()).collect();
+        folders.sort();
+
+        for folder in folders {
+            let manifest_path = folder.join("CSXS").join("manifest.xml");
+            let (Ok(info), Ok(extensions)) = (parse_manifest_xml(&manifest_path), parse_extension_entries(&manifest_path)) else {
+                log::warn!("Skipping {:?} in diagnostics: unreadable manifest", folder);
+                continue;
+            };
+
+            for entry in extensions {
+                copies.push(ExtensionCopy {
+                    root,
+                    folder: folder.clone(),
+                    bundle_version: info.version.clone(),
+                    entry,
+                });
+            }
+        }
+    }
+
+    copies
+}
+
+// Copies must be in load order, as returned by collect_extension_copies()
+pub fn build_report(copies: Vec<ExtensionCopy>) -> DiagnosticsReport {
+    let mut resolutions: Vec<ExtensionResolution> = Vec::new();
+
+    for copy in copies {
+        match resolutions.iter_mut().find(|resolution| resolution.id == copy.entry.id) {
+            // A later root overrides earlier ones; inside one root the first folder wins
+            Some(resolution) if copy.root != resolution.winner.root => {
+                let previous = std::mem::replace(&mut resolution.winner, copy);
+                resolution.shadowed.push(previous);
+            }
+            Some(resolution) => resolution.shadowed.push(copy),
+            None => resolutions.push(ExtensionResolution {
+                id: copy.entry.id.clone(),
+                winner: copy,
+                shadowed: Vec::new(),
+            }),
+        }
+    }
+
+    let mut menu_entries: Vec<MenuEntry> = Vec::new();
+    let mut hidden = Vec::new();
+
+    for resolution in &resolutions {
+        let entry = &resolution.winner.entry;
+        let has_menu_type = entry.ui_type.as_deref().is_some_and(|ui_type| MENU_UI_TYPES.contains(&ui_type));
+
+        match entry.menu.as_deref().filter(|label| has_menu_type && !label.is_empty()) {
+            Some(label) => match menu_entries.iter_mut().find(|menu| menu.label == label) {
+                Some(menu) => menu.extension_ids.push(entry.id.clone()),
+                None => menu_entries.push(MenuEntry {
+                    label: label.to_string(),
+                    extension_ids: vec![entry.id.clone()],
+                }),
+            },
+            None => hidden.push(resolution.winner.clone()),
+        }
+    }
+
+    menu_entries.sort_by_key(|menu| menu.label.to_lowercase());
+
+    DiagnosticsReport { resolutions, menu_entries, hidden }
+}
+
+// Helper functions
+// The roots' plugins and the ones moved aside into their disabled folders
+fn extension_ids_in(roots: &[PathBuf]) -> HashSet<String> {
+    let mut ids = HashSet::new();
+    for root in roots {
+        manifest_ids_in(root, &mut ids);
+        manifest_ids_in(&disabled_folder(root), &mut ids);
+    }
+    ids
+}
+
+fn manifest_ids_in(folder: &Path, ids: &mut HashSet<String>) {
+    for plugin in fs::read_dir(folder).into_iter().flatten().flatten() {
+        let manifest_path = plugin.path().join("CSXS").join("manifest.xml");
+        if let Ok(info) = parse_manifest_xml(&manifest_path) {
+            ids.insert(info.bundle_id);
+        }
+        ids.extend(parse_extension_entries(&manifest_path).unwrap_or_default().into_iter().map(|entry| entry.id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn copy(root: ExtensionRoot, folder: &str, id: &str, menu: Option<&str>) -> ExtensionCopy {
+        ExtensionCopy {
+            root,
+            folder: PathBuf::from(folder),
+            bundle_version: "1.0.0".to_string(),
+            entry: ExtensionEntry {
+                id: id.to_string(),
+                ui_type: Some("Panel".to_string()),
+                menu: menu.map(str::to_string),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_user_root_overrides_system_root() {
+        let report = build_report(vec![
+            copy(ExtensionRoot::System, "/system/a", "com.a.panel", Some("A")),
+            copy(ExtensionRoot::User, "/user/a", "com.a.panel", Some("A")),
+        ]);
+
+        let resolution = report.collisions().next().unwrap();
+        assert_eq!(resolution.winner.folder, PathBuf::from("/user/a"));
+        assert_eq!(resolution.shadowed[0].folder, PathBuf::from("/system/a"));
+        assert!(!resolution.is_ambiguous());
+    }
+
+    #[test]
+    fn test_duplicate_menu_labels_and_hidden_extensions() {
+        let report = build_report(vec![
+            copy(ExtensionRoot::System, "/system/a", "com.a.panel", Some("Tools")),
+            copy(ExtensionRoot::System, "/system/b", "com.b.panel", Some("Tools")),
+            copy(ExtensionRoot::System, "/system/c", "com.c.helper", None),
+        ]);
+
+        assert_eq!(report.menu_entries.len(), 1);
+        assert_eq!(report.menu_entries[0].extension_ids, vec!["com.a.panel", "com.b.panel"]);
+        assert_eq!(report.hidden[0].entry.id, "com.c.helper");
+    }
+
+    fn write_manifest(folder: &Path, bundle_id: &str, extension_id: &str) {
+        fs::create_dir_all(folder.join("CSXS")).unwrap();
+        let manifest = format!(
+            r#"<ExtensionManifest ExtensionBundleId="{bundle_id}" ExtensionBundleVersion="1.0"><ExtensionList><Extension Id="{extension_id}" Version="1.0"/></ExtensionList></ExtensionManifest>"#
+        );
+        fs::write(folder.join("CSXS").join("manifest.xml"), manifest).unwrap();
+    }
+
+    #[test]
+    fn test_installed_ids_include_bundle_ids() {
+        let root = std::env::temp_dir().join(format!("zxpm-ids-{}", std::process::id()));
+        write_manifest(&root.join("tools"), "com.example.tools", "com.example.tools.panel");
+
+        let ids = extension_ids_in(std::slice::from_ref(&root));
+        assert!(ids.contains("com.example.tools")); // Settings folders named after the bundle stay
+        assert!(ids.contains("com.example.tools.panel"));
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_installed_ids_include_disabled_plugins() {
+        let base = std::env::temp_dir().join(format!("zxpm-disabled-ids-{}", std::process::id()));
+        let root = base.join("extensions");
+        write_manifest(&disabled_folder(&root).join("off"), "com.example.off", "com.example.off.panel");
+        fs::create_dir_all(&root).unwrap();
+
+        let ids = extension_ids_in(&[root]);
+        assert!(ids.contains("com.example.off") && ids.contains("com.example.off.panel"));
+        let _ = fs::remove_dir_all(&base);
+    }
+}
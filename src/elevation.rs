@@ -0,0 +1,218 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::data_operations::{CEP_EXTENSIONS_PATH, ExtensionRoot, SYSTEM_EXTENSIONS_PATH, is_dev_link};
+use crate::file_operations::{archive_installed_version, FileOperationError, InstalledPlugin, install_folder_into, install_zxp_into, remove_plugin};
+use crate::temp_files::temp_path;
+
+// Retries of file operations with administrator rights, after the system prompt:
+// osascript "with administrator privileges" on macOS, a UAC prompt on Windows
+
+// cmd.exe reads these even inside double quotes, or can be made to leave them
+const CMD_METACHARACTERS: &[char] = &['"', '&', '%', '^', '!', '|', '<', '>', '\r', '\n'];
+
+pub fn elevation_available() -> bool {
+    cfg!(target_os = "macos") || cfg!(target_os = "windows")
+}
+
+// Extracts into a staging folder as the current user, then copies it into the all-users root elevated
+pub fn install_zxp_elevated(zxp_path: &Path) -> Result<InstalledPlugin, FileOperationError> {
+    install_staged(zxp_path, |staging| install_zxp_into(zxp_path, staging))
+}
+
+// The same for an unpacked extension folder, so its checks run before the prompt
+pub fn install_folder_elevated(source: &Path) -> Result<InstalledPlugin, FileOperationError> {
+    install_staged(source, |staging| install_folder_into(source, staging))
+}
+
+pub fn remove_plugin_elevated(plugin_path: &Path) -> Result<(), FileOperationError> {
+    if !plugin_path.is_dir() {
+        return remove_plugin(plugin_path); // Same errors as a normal removal
+    }
+
+    let quoted = shell_quote(plugin_path);
+    run_elevated(&format!("/bin/rm -rf {quoted}"), || Ok(format!("rmdir /S /Q {}", cmd_quote(plugin_path)?)), plugin_path)?;
+
+    // Exit codes don't say whether the user cancelled the UAC prompt - the folder does
+    if plugin_path.exists() {
+        return Err(FileOperationError::PermissionDenied(plugin_path.to_path_buf()));
+    }
+    log::info!("Removed {:?} with administrator rights", plugin_path);
+    Ok(())
+}
+
+// Creates a missing extensions root with every folder above it. The all-users root stays root's,
+// like the rest of /Library; the user's own is handed to them even when a parent needed the prompt
+pub fn create_extension_root(root: ExtensionRoot) -> Result<PathBuf, FileOperationError> {
+    let path = root.path().ok_or_else(|| FileOperationError::FileNotFound(PathBuf::from(CEP_EXTENSIONS_PATH)))?;
+    let created = first_missing_ancestor(&path);
+    match fs::create_dir_all(&path) {
+        Ok(()) => {}
+        Err(e) if e.kind() == io::ErrorKind::PermissionDenied && elevation_available() => {
+            let quoted = shell_quote(&path);
+            let shell = match (root, created.as_deref()) {
+                (ExtensionRoot::User, Some(created)) => {
+                    let uid = unsafe { libc::getuid() };
+                    format!("/bin/mkdir -p {quoted} && /usr/sbin/chown -R {uid} {}", shell_quote(created))
+                }
+                _ => format!("/bin/mkdir -p {quoted}"),
+            };
+            run_elevated(&shell, || Ok(format!("mkdir {}", cmd_quote(&path)?)), &path)?;
+            if !path.is_dir() {
+                return Err(FileOperationError::PermissionDenied(path));
+            }
+        }
+        Err(e) => return Err(FileOperationError::extract(&path, e)),
+    }
+    log::info!("Created extensions folder {:?}", path);
+    Ok(path)
+}
+
+// Single-quoted for /bin/sh, safe for any path
+pub fn shell_quote(path: &Path) -> String {
+    format!("'{}'", path.display().to_string().replace('\'', "'\\''"))
+}
+
+// `shell` runs under /bin/sh on macOS, `cmd` builds the line for cmd.exe on Windows (only called there);
+// a cancelled prompt is PermissionDenied on `target`
+pub fn run_elevated(
+    shell: &str,
+    cmd: impl FnOnce() -> Result<String, FileOperationError>,
+    target: &Path,
+) -> Result<(), FileOperationError> {
+    let denied = || FileOperationError::PermissionDenied(target.to_path_buf());
+    let status = if cfg!(target_os = "macos") {
+        let script = format!("do shell script \"{}\" with administrator privileges", shell.replace('\\', "\\\\").replace('"', "\\\""));
+        Command::new("osascript").args(["-e", &script]).status()
+    } else if cfg!(target_os = "windows") {
+        let command = uac_command(&cmd()?);
+        Command::new("powershell").args(["-NoProfile", "-NonInteractive", "-Command", &command]).status()
+    } else {
+        return Err(denied());
+    };
+
+    match status {
+        Ok(status) if status.success() => Ok(()),
+        Ok(status) => {
+            log::warn!("Elevated command failed or was cancelled ({})", status);
+            Err(denied())
+        }
+        Err(e) => {
+            log::error!("Failed to request administrator rights: {}", e);
+            Err(denied())
+        }
+    }
+}
+
+// Helper functions
+// Double-quoted for cmd.exe. Quotes don't stop cmd from expanding `%` and `!` or splitting on a stray
+// `"`, and there is no reliable escape inside them, so a path with any of these is refused instead
+fn cmd_quote(path: &Path) -> Result<String, FileOperationError> {
+    let text = path.display().to_string();
+    if text.contains(CMD_METACHARACTERS) {
+        let reason = io::Error::new(io::ErrorKind::InvalidInput, "the path has characters cmd.exe would interpret");
+        return Err(FileOperationError::extract(path, reason));
+    }
+    Ok(format!("\"{text}\""))
+}
+
+// Start-Process only reports its own failure, so the elevated cmd.exe's exit code is passed on by hand;
+// a declined UAC prompt throws, which -ErrorAction Stop turns into a non-zero exit as well
+fn uac_command(cmd: &str) -> String {
+    let arguments = format!("/c {}", cmd).replace('\'', "''");
+    format!("$p = Start-Process -FilePath cmd.exe -ArgumentList '{arguments}' -Verb RunAs -PassThru -Wait -ErrorAction Stop; exit $p.ExitCode")
+}
+
+// The topmost folder of `path` that doesn't exist yet; None when it all does
+fn first_missing_ancestor(path: &Path) -> Option<PathBuf> {
+    path.ancestors().take_while(|folder| !folder.exists()).last().map(Path::to_path_buf)
+}
+
+fn install_staged(
+    source: &Path,
+    stage: impl FnOnce(&Path) -> Result<InstalledPlugin, FileOperationError>,
+) -> Result<InstalledPlugin, FileOperationError> {
+    let staging = temp_path("staging");
+
+    let result = stage(&staging).and_then(|staged| {
+        let folder_name = staged.path.file_name().ok_or_else(|| FileOperationError::InvalidExtension(source.to_path_buf()))?;
+        let target = Path::new(SYSTEM_EXTENSIONS_PATH).join(folder_name);
+        // A dev link leads into a working folder: only the link goes, the same as without elevation
+        let unlink = is_dev_link(&target);
+        if !unlink {
+            archive_installed_version(&target); // Reading the installed folder needs no rights
+        }
+        copy_elevated(&staged.path, &target, unlink)?;
+        Ok(InstalledPlugin { path: target, info: staged.info })
+    });
+
+    let _ = fs::remove_dir_all(&staging);
+    if let Ok(installed) = &result {
+        log::info!("Installed {:?} to {:?} with administrator rights", source, installed.path);
+    }
+    result
+}
+
+fn copy_elevated(source: &Path, target: &Path, unlink: bool) -> Result<(), FileOperationError> {
+    let (source_quoted, target_quoted) = (shell_quote(source), shell_quote(target));
+    let shell_unlink = if unlink { format!("/bin/rm {target_quoted} && ") } else { String::new() };
+    run_elevated(
+        &format!("{shell_unlink}/bin/mkdir -p {target_quoted} && /usr/bin/ditto {source_quoted} {target_quoted}"),
+        || copy_command(source, target, unlink),
+        target,
+    )?;
+
+    if !target.join("CSXS").join("manifest.xml").is_file() {
+        return Err(FileOperationError::PermissionDenied(target.to_path_buf()));
+    }
+    Ok(())
+}
+
+// /I makes xcopy treat the missing target as a folder, so no trailing backslash is needed - one
+// right before the closing quote would escape it. rmdir without /S takes a junction, never the
+// folder behind it.
+fn copy_command(source: &Path, target: &Path, unlink: bool) -> Result<String, FileOperationError> {
+    let (source, target) = (cmd_quote(source)?, cmd_quote(target)?);
+    let unlink = if unlink { format!("rmdir {target} && ") } else { String::new() };
+    Ok(format!("{unlink}xcopy {source} {target} /E /I /Y /Q"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shell_quote_and_missing_folders() {
+        assert_eq!(shell_quote(Path::new("/Library/CEP/com.example.panel")), "'/Library/CEP/com.example.panel'");
+        assert_eq!(shell_quote(Path::new("/tmp/it's here")), "'/tmp/it'\\''s here'");
+
+        let base = temp_path("missing-root");
+        fs::create_dir_all(&base).unwrap();
+        assert_eq!(first_missing_ancestor(&base.join("CEP").join("extensions")), Some(base.join("CEP")));
+        assert_eq!(first_missing_ancestor(&base), None);
+        fs::remove_dir_all(&base).unwrap();
+    }
+
+    #[test]
+    fn test_windows_commands() {
+        let (source, target) = (Path::new(r"C:\Temp\staging\panel"), Path::new(r"C:\Program Files\Common Files\Adobe\CEP\extensions\panel"));
+        assert_eq!(
+            copy_command(source, target, false).unwrap(),
+            r#"xcopy "C:\Temp\staging\panel" "C:\Program Files\Common Files\Adobe\CEP\extensions\panel" /E /I /Y /Q"#
+        );
+        assert!(copy_command(source, target, true).unwrap().starts_with(r#"rmdir "C:\Program Files\Common Files\Adobe\CEP\extensions\panel" && xcopy "#));
+
+        for name in [r#"pa"nel"#, "a & calc", "%PATH%", "caret^", "bang!", "a | b"] {
+            assert!(cmd_quote(&Path::new(r"C:\Temp").join(name)).is_err(), "{name}");
+            assert!(copy_command(source, &Path::new(r"C:\Temp").join(name), false).is_err(), "{name}");
+        }
+
+        let command = uac_command(r#"rmdir /S /Q "C:\Users\O'Brien\panel""#);
+        assert_eq!(
+            command,
+            r#"$p = Start-Process -FilePath cmd.exe -ArgumentList '/c rmdir /S /Q "C:\Users\O''Brien\panel"' -Verb RunAs -PassThru -Wait -ErrorAction Stop; exit $p.ExitCode"#
+        );
+    }
+}
@@ -0,0 +1,76 @@
+// Support codes shown in toasts and logs, e.g. "Invalid or corrupt ZXP file (ZXPM-1044)".
+// Codes are assigned by `PluginError::code()` and `FileOperationError::code()`;
+// never renumber or reuse one - vendors quote them in their support docs.
+// 1001-1039: scanning and manifest parsing, 1040-1099: install, export and file operations.
+pub const ERROR_CODES: [&str; 20] = [
+    "ZXPM-1001",
+    "ZXPM-1002",
+    "ZXPM-1003",
+    "ZXPM-1004",
+    "ZXPM-1040",
+    "ZXPM-1041",
+    "ZXPM-1042",
+    "ZXPM-1043",
+    "ZXPM-1044",
+    "ZXPM-1045",
+    "ZXPM-1046",
+    "ZXPM-1047",
+    "ZXPM-1048",
+    "ZXPM-1049",
+    "ZXPM-1050",
+    "ZXPM-1051",
+    "ZXPM-1052",
+    "ZXPM-1053",
+    "ZXPM-1054",
+    "ZXPM-1055",
+];
+
+// Translation key explaining a code for the reference view, e.g. "error-code-1044"
+pub fn description_key(code: &str) -> String {
+    format!("error-code-{}", code.trim_start_matches("ZXPM-"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_operations::PluginError;
+    use crate::file_operations::FileOperationError;
+    use crate::i18n::t;
+    use crate::policy::DenyReason;
+    use std::io;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn test_every_error_has_a_documented_code() {
+        let codes: Vec<&str> = [
+            PluginError::DirectoryNotFound(PathBuf::new()).code(),
+            PluginError::PermissionDenied(PathBuf::new()).code(),
+            PluginError::ManifestNotFound(PathBuf::new()).code(),
+            PluginError::InvalidManifest(String::new()).code(),
+            FileOperationError::DialogCancelled.code(),
+            FileOperationError::InvalidExtension(PathBuf::new()).code(),
+            FileOperationError::FileNotFound(PathBuf::new()).code(),
+            FileOperationError::PermissionDenied(PathBuf::new()).code(),
+            FileOperationError::InvalidZip(PathBuf::new()).code(),
+            FileOperationError::extract(Path::new(""), io::Error::other("")).code(),
+            FileOperationError::archive(Path::new(""), io::Error::other("")).code(),
+            FileOperationError::OpenFailed(String::new()).code(),
+            FileOperationError::AlreadyInstalled(PathBuf::new()).code(),
+            FileOperationError::InvalidPluginSet(PathBuf::new()).code(),
+            FileOperationError::InsufficientSpace { required: 0, available: 0 }.code(),
+            FileOperationError::BlockedByPolicy(DenyReason::NotAllowed).code(),
+            FileOperationError::SigningToolMissing.code(),
+            FileOperationError::SigningFailed(String::new()).code(),
+            FileOperationError::CorruptedInstall { path: PathBuf::new(), files: Vec::new() }.code(),
+            FileOperationError::KeychainFailed(String::new()).code(),
+        ]
+        .to_vec();
+        assert_eq!(codes, ERROR_CODES.to_vec());
+
+        for code in ERROR_CODES {
+            let key = description_key(code);
+            assert_ne!(t(&key), key, "{} has no description", code); // Missing keys come back unchanged
+        }
+        assert!(FileOperationError::InvalidZip(PathBuf::new()).to_string().ends_with("(ZXPM-1044)"));
+    }
+}
@@ -0,0 +1,57 @@
+use dioxus::desktop::tao::event::Event;
+use dioxus::desktop::window;
+use dioxus::prelude::*;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::actions::install_opened_file;
+
+// ZXP files the OS opens with the app: paths on the command line (Windows and Linux file
+// associations) and open-file events (double-clicks in Finder). Each one waits in the install
+// preview, so nothing installs without a click in the window.
+
+// How often the root component picks up newly opened files
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+// Filled from main() and the event loop, both outside the runtime that signals need
+static OPENED_FILES: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+pub fn queue_launch_files(files: Vec<PathBuf>) {
+    if let Ok(mut queued) = OPENED_FILES.lock() {
+        queued.extend(files);
+    }
+}
+
+// Event loop callback set in main(); macOS sends open-file events instead of arguments, both at
+// launch - before any component could listen - and while the app runs
+pub fn queue_opened_event<T>(event: &Event<'_, T>) {
+    if let Event::Opened { urls } = event {
+        queue_launch_files(urls.iter().filter_map(|url| url.to_file_path().ok()).filter(|path| is_zxp(path)).collect());
+    }
+}
+
+// Hands queued files to the install flow - call once from the root component
+pub fn use_file_open() {
+    use_future(|| async {
+        loop {
+            let files = OPENED_FILES.lock().map(|mut queued| std::mem::take(&mut *queued)).unwrap_or_default();
+            if !files.is_empty() {
+                show_window(); // May be hidden in the tray, and the preview needs it
+                files.into_iter().for_each(install_opened_file);
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    });
+}
+
+// Helper functions
+fn is_zxp(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("zxp"))
+}
+
+fn show_window() {
+    let desktop = window();
+    desktop.window.set_visible(true);
+    desktop.window.set_focus();
+}
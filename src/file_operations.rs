@@ -1,42 +1,364 @@
+use std::collections::{BTreeMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::io::Read;
+use std::io::{self, BufWriter, Read, Write};
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+#[cfg(unix)]
+use std::ffi::CString;
+#[cfg(unix)]
+use std::os::unix::ffi::OsStrExt;
+#[cfg(unix)]
+use std::os::unix::fs::{MetadataExt, PermissionsExt};
 use rfd::FileDialog;
-use zip::ZipArchive;
-use crate::data_operations::parse_manifest_xml;
+use sha2::{Digest, Sha256};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+use crate::data_operations::{
+    calculate_folder_size_bytes, decode_manifest, is_dev_link, parse_extension_entries_content, ExtensionEntry, ExtensionRoot, determine_plugin_type, disabled_folder, format_size, measure_folder,
+    parse_host_list, parse_host_list_str, parse_manifest_str, parse_manifest_xml, HostRequirement, PluginInfo, PluginType,
+    EXTENSIONS_FOLDER_NAME, SYSTEM_EXTENSIONS_PATH,
+};
+use crate::i18n::{t, t_args};
+use crate::policy::{folder_publisher, load_policy, signer_common_name, DenyReason, PolicyStatus, SIGNATURES_FILE};
 
+// Real manifests are a few KB - anything bigger is not worth reading into memory
+const MAX_MANIFEST_SIZE: u64 = 1024 * 1024;
+
+// Filesystems round files up to whole blocks - keep some room beyond the raw byte count
+const SPACE_HEADROOM: u64 = 10 * 1024 * 1024;
+
+// Files from a downloaded ZXP can carry macOS's quarantine flag, and Gatekeeper then refuses to run
+// the binaries and Node helpers a panel bundles. Cleared after every install unless turned off.
+const QUARANTINE_ATTRIBUTE: &str = "com.apple.quarantine";
+static CLEAR_QUARANTINE: AtomicBool = AtomicBool::new(true);
+
+// Upgrades pack the folder they replace into the version history first. Off until main() turns it
+// on, so tests and benchmarks never write into the user's data folder.
+static VERSION_HISTORY: AtomicBool = AtomicBool::new(false);
+const KEPT_VERSIONS: usize = 5; // Per plugin; older archives are deleted
+
+// Corrupted files named in the error message; the rest are only counted
+const SHOWN_CORRUPTED_FILES: usize = 3;
+
+// Entries are decompressed straight to disk through a buffer this size, so a bundled runtime or
+// model of several GB never has to fit in memory
+const EXTRACT_BUFFER: usize = 256 * 1024;
+
+// Entries written by the running extraction and how many the archive has; 0 total while none runs.
+// Installs go through the queue one at a time, so one counter is enough for the sidebar to poll.
+static EXTRACTED_ENTRIES: AtomicUsize = AtomicUsize::new(0);
+static EXTRACT_TOTAL: AtomicUsize = AtomicUsize::new(0);
+
+// Links followed while checking where a link leads, as many as the kernel follows before ELOOP
+const MAX_LINK_HOPS: usize = 40;
+
+// Variants carry the path they failed on and, where the OS reported one, the io::Error behind it
 #[derive(Debug)]
 pub enum FileOperationError {
     DialogCancelled,
-    InvalidExtension,
-    FileNotFound,
-    PermissionDenied,
-    InvalidZip,
-    ExtractError,
+    InvalidExtension(PathBuf),
+    FileNotFound(PathBuf),
+    PermissionDenied(PathBuf),
+    InvalidZip(PathBuf),
+    ExtractError { path: PathBuf, source: io::Error },
+    ArchiveError { path: PathBuf, source: io::Error },
+    OpenFailed(String), // Path or URL handed to the OS opener
+    AlreadyInstalled(PathBuf),
+    InvalidPluginSet(PathBuf),
+    InsufficientSpace { required: u64, available: u64 },
+    BlockedByPolicy(DenyReason),
+    SigningToolMissing,
+    SigningFailed(String), // Last line ZXPSignCmd printed
+    CorruptedInstall { path: PathBuf, files: Vec<PathBuf> }, // Extracted files that don't match the archive's CRC32
+    KeychainFailed(String), // What the OS credential store reported
+}
+
+impl FileOperationError {
+    // Failed writes while installing, importing or moving; permission problems have a remedy, so they get their own variant
+    pub fn extract(path: &Path, source: io::Error) -> Self {
+        match source.kind() {
+            io::ErrorKind::PermissionDenied => FileOperationError::PermissionDenied(path.to_path_buf()),
+            _ => FileOperationError::ExtractError { path: path.to_path_buf(), source },
+        }
+    }
+
+    // Failed writes while creating an archive, report or backup
+    pub fn archive(path: &Path, source: io::Error) -> Self {
+        match source.kind() {
+            io::ErrorKind::PermissionDenied => FileOperationError::PermissionDenied(path.to_path_buf()),
+            _ => FileOperationError::ArchiveError { path: path.to_path_buf(), source },
+        }
+    }
+
+    // Stable support code, listed in the in-app error reference
+    pub fn code(&self) -> &'static str {
+        match self {
+            FileOperationError::DialogCancelled => "ZXPM-1040",
+            FileOperationError::InvalidExtension(_) => "ZXPM-1041",
+            FileOperationError::FileNotFound(_) => "ZXPM-1042",
+            FileOperationError::PermissionDenied(_) => "ZXPM-1043",
+            FileOperationError::InvalidZip(_) => "ZXPM-1044",
+            FileOperationError::ExtractError { .. } => "ZXPM-1045",
+            FileOperationError::ArchiveError { .. } => "ZXPM-1046",
+            FileOperationError::OpenFailed(_) => "ZXPM-1047",
+            FileOperationError::AlreadyInstalled(_) => "ZXPM-1048",
+            FileOperationError::InvalidPluginSet(_) => "ZXPM-1049",
+            FileOperationError::InsufficientSpace { .. } => "ZXPM-1050",
+            FileOperationError::BlockedByPolicy(_) => "ZXPM-1051",
+            FileOperationError::SigningToolMissing => "ZXPM-1052",
+            FileOperationError::SigningFailed(_) => "ZXPM-1053",
+            FileOperationError::CorruptedInstall { .. } => "ZXPM-1054",
+            FileOperationError::KeychainFailed(_) => "ZXPM-1055",
+        }
+    }
 }
 
 impl std::fmt::Display for FileOperationError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = match self {
+            FileOperationError::DialogCancelled => t("error-dialog-cancelled"),
+            FileOperationError::InvalidExtension(path) => path_message("error-invalid-extension", path),
+            FileOperationError::FileNotFound(path) => path_message("error-file-not-found", path),
+            FileOperationError::PermissionDenied(path) if path.starts_with(SYSTEM_EXTENSIONS_PATH) => {
+                format!("{} - {}", path_message("error-permission-denied", path), t("error-hint-user-folder"))
+            }
+            FileOperationError::PermissionDenied(path) => path_message("error-permission-denied", path),
+            FileOperationError::InvalidZip(path) => path_message("error-invalid-zip", path),
+            FileOperationError::ExtractError { path, source } => {
+                t_args("error-extract-failed", &[("path", &path.display().to_string()), ("error", &source.to_string())])
+            }
+            FileOperationError::ArchiveError { path, source } => {
+                t_args("error-archive-failed", &[("path", &path.display().to_string()), ("error", &source.to_string())])
+            }
+            FileOperationError::OpenFailed(target) => t_args("error-open-failed", &[("target", target)]),
+            FileOperationError::AlreadyInstalled(path) => path_message("error-already-installed", path),
+            FileOperationError::InvalidPluginSet(path) => path_message("error-invalid-plugin-set", path),
+            FileOperationError::InsufficientSpace { required, available } => {
+                t_args("error-insufficient-space", &[("required", &format_size(*required)), ("available", &format_size(*available))])
+            }
+            FileOperationError::BlockedByPolicy(reason) => t_args("error-blocked-by-policy", &[("reason", &reason.to_string())]),
+            FileOperationError::SigningToolMissing => t("error-signing-tool-missing"),
+            FileOperationError::SigningFailed(output) => t_args("error-signing-failed", &[("output", output)]),
+            FileOperationError::CorruptedInstall { path, files } => {
+                let names: Vec<String> = files.iter().take(SHOWN_CORRUPTED_FILES).map(|file| file.display().to_string()).collect();
+                t_args(
+                    "error-corrupted-install",
+                    &[("path", &path.display().to_string()), ("count", &files.len().to_string()), ("files", &names.join(", "))],
+                )
+            }
+            FileOperationError::KeychainFailed(error) => t_args("error-keychain-failed", &[("error", error)]),
+        };
+        write!(f, "{} ({})", message, self.code())
+    }
+}
+
+impl std::error::Error for FileOperationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            FileOperationError::DialogCancelled => write!(f, "File dialog was cancelled"),
-            FileOperationError::InvalidExtension => write!(f, "File must have .zxp extension"),
-            FileOperationError::FileNotFound => write!(f, "File not found"),
-            FileOperationError::PermissionDenied => write!(f, "Permission denied"),
-            FileOperationError::InvalidZip => write!(f, "Invalid or corrupt ZXP file"),
-            FileOperationError::ExtractError => write!(f, "Failed to extract ZXP file"),
+            FileOperationError::ExtractError { source, .. } | FileOperationError::ArchiveError { source, .. } => Some(source),
+            _ => None,
         }
     }
 }
 
-impl std::error::Error for FileOperationError {}
-
 // File operations
-pub fn select_zxp_file() -> Result<PathBuf, FileOperationError> {
+// A CEP cache, log or storage entry left behind by an extension that is no longer installed
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrphanedEntry {
+    pub extension_id: String,
+    pub path: PathBuf,
+    pub size: u64, // Bytes on disk, folders included recursively - what cleaning frees
+}
+
+// A replaced version of a plugin, packed as <history>/<bundle ID>/<version>.zxp
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchivedVersion {
+    pub version: String,
+    pub path: PathBuf,
+    pub archived_at: Option<i64>, // Unix seconds, from the archive's timestamp
+}
+
+// Where an install put the plugin, and what its manifest says it is
+#[derive(Debug, Clone)]
+pub struct InstalledPlugin {
+    pub path: PathBuf,
+    pub info: PluginInfo,
+}
+
+// Everything installing a ZXP would write, read from the archive's central directory
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstallPreview {
+    pub zxp_path: PathBuf,
+    pub extension_id: String,
+    pub destination: PathBuf,
+    pub files: Vec<PreviewFile>,
+    pub sha256: Option<String>, // Lowercase hex of the ZXP, compared with a vendor's published checksum
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviewFile {
+    pub path: PathBuf, // Relative to the destination
+    pub size: u64,     // Extracted bytes
+    pub overwrites: bool,
+}
+
+impl InstallPreview {
+    pub fn total_size(&self) -> u64 {
+        self.files.iter().map(|file| file.size).sum()
+    }
+
+    pub fn overwrite_count(&self) -> usize {
+        self.files.iter().filter(|file| file.overwrites).count()
+    }
+}
+
+// A ZXP opened read-only in the inspector, for vetting packages from unknown sources
+#[derive(Debug, Clone)]
+pub struct ZxpInspection {
+    pub zxp_path: PathBuf,
+    pub info: PluginInfo,
+    pub hosts: Vec<HostRequirement>,
+    pub extensions: Vec<ExtensionEntry>,
+    pub signed: bool,             // Has META-INF/signatures.xml
+    pub signer: Option<String>,   // Common name of the signing certificate
+    pub blocked: Option<String>,  // Why the install policy would refuse it
+    pub sha256: Option<String>,
+    pub entries: Vec<ArchiveEntry>,
+    pub unsafe_entries: usize,    // Names pointing outside the extension folder; extraction refuses the package
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchiveEntry {
+    pub path: PathBuf,
+    pub size: u64,       // Extracted bytes
+    pub compressed: u64, // Bytes in the archive
+    pub is_dir: bool,
+    pub is_symlink: bool,
+    pub executable: bool,
+}
+
+// One line of the inspector's tree; a folder's size and count cover everything below it
+#[derive(Debug, Clone, PartialEq)]
+pub struct InspectionRow {
+    pub path: PathBuf,
+    pub depth: usize,
+    pub is_dir: bool,
+    pub size: u64,
+    pub file_count: usize,
+    pub is_symlink: bool,
+    pub executable: bool,
+}
+
+impl InspectionRow {
+    pub fn name(&self) -> String {
+        self.path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default()
+    }
+}
+
+impl ZxpInspection {
+    pub fn total_size(&self) -> u64 {
+        self.entries.iter().map(|entry| entry.size).sum()
+    }
+
+    pub fn compressed_size(&self) -> u64 {
+        self.entries.iter().map(|entry| entry.compressed).sum()
+    }
+
+    pub fn file_count(&self) -> usize {
+        self.entries.iter().filter(|entry| !entry.is_dir).count()
+    }
+
+    // Depth first, folders before files at each level, names in byte order
+    pub fn tree(&self) -> Vec<InspectionRow> {
+        // ZIPs often leave folder entries out - every file's parents are folders too
+        let mut folders: BTreeMap<PathBuf, (u64, usize)> = BTreeMap::new();
+        for entry in &self.entries {
+            let skip = if entry.is_dir { 0 } else { 1 };
+            for folder in entry.path.ancestors().skip(skip).filter(|folder| !folder.as_os_str().is_empty()) {
+                let totals = folders.entry(folder.to_path_buf()).or_default();
+                if !entry.is_dir {
+                    totals.0 += entry.size;
+                    totals.1 += 1;
+                }
+            }
+        }
+
+        let row = |path: &Path, is_dir: bool| InspectionRow {
+            path: path.to_path_buf(),
+            depth: path.components().count() - 1,
+            is_dir,
+            size: 0,
+            file_count: 0,
+            is_symlink: false,
+            executable: false,
+        };
+        let mut rows: Vec<InspectionRow> = folders
+            .iter()
+            .map(|(path, (size, file_count))| InspectionRow { size: *size, file_count: *file_count, ..row(path, true) })
+            .chain(self.entries.iter().filter(|entry| !entry.is_dir).map(|entry| InspectionRow {
+                size: entry.size,
+                file_count: 1,
+                is_symlink: entry.is_symlink,
+                executable: entry.executable,
+                ..row(&entry.path, false)
+            }))
+            .collect();
+        rows.sort_by_cached_key(|row| tree_key(&row.path, row.is_dir));
+        rows
+    }
+}
+
+// What installing a ZXP over an installed folder would change, matched by path, size and CRC32
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstallComparison {
+    pub zxp_path: PathBuf,
+    pub installed_path: PathBuf,
+    pub installed_version: String,
+    pub incoming_version: String,
+    pub changes: Vec<FileChange>, // Sorted by path
+    pub unchanged: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileChange {
+    pub path: PathBuf, // Relative to the extension folder
+    pub kind: ChangeKind,
+    pub installed_size: Option<u64>,
+    pub incoming_size: Option<u64>,
+}
+
+impl FileChange {
+    // Signing rewrites META-INF on every build, so a difference there alone changes nothing a host loads
+    pub fn is_packaging(&self) -> bool {
+        self.path.starts_with("META-INF") || self.path == Path::new("mimetype")
+    }
+}
+
+impl InstallComparison {
+    pub fn count(&self, kind: ChangeKind) -> usize {
+        self.changes.iter().filter(|change| change.kind == kind).count()
+    }
+
+    // True when the "update" only differs in its signature - or not at all
+    pub fn changes_only_packaging(&self) -> bool {
+        self.changes.iter().all(FileChange::is_packaging)
+    }
+}
+
+pub fn select_zxp_file(start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
     // Opens native file picker dialog
     // Filters for .zxp files only
     // Returns selected file path or error if cancelled/invalid
     
-    let file_path = FileDialog::new()
+    let file_path = dialog_in(start_dir)
         .add_filter("ZXP Files", &["zxp"])
         .set_title("Select ZXP Plugin File")
         .pick_file()
@@ -44,60 +366,553 @@ pub fn select_zxp_file() -> Result<PathBuf, FileOperationError> {
     
     // Validate extension (double-check)
     if !is_valid_zxp_extension(&file_path) {
-        return Err(FileOperationError::InvalidExtension);
+        return Err(FileOperationError::InvalidExtension(file_path));
     }
     
     log::info!("Selected ZXP file: {:?}", file_path);
     Ok(file_path)
 }
 
-pub fn install_zxp(zxp_path: &Path) -> Result<PathBuf, FileOperationError> {
-    // 1. Validate ZXP file exists and has correct extension
-    // 2. Open ZXP (ZIP) file for reading  
-    // 3. Parse manifest.xml from ZIP to get Extension ID
-    // 4. Create target directory: /Library/.../extensions/{extension_id}/
-    // 5. Extract all ZIP contents to target directory
-    // 6. OS handles permission prompts if needed
+pub fn parse_pasted_path(text: &str) -> Option<PathBuf> {
+    // Accepts what file managers put on the clipboard: quoted paths, file:// URLs, ~/ paths
+    let text = text.trim().trim_matches(|c| c == '"' || c == '\'');
+    let text = match text.strip_prefix("file://") {
+        Some(url_path) => decode_percent_escapes(url_path)?,
+        None => text.to_string(),
+    };
     
-    if !zxp_path.exists() {
-        return Err(FileOperationError::FileNotFound);
+    if text.is_empty() {
+        return None;
     }
     
-    if !is_valid_zxp_extension(zxp_path) {
-        return Err(FileOperationError::InvalidExtension);
+    match text.strip_prefix("~/") {
+        Some(relative) => dirs::home_dir().map(|home| home.join(relative)),
+        None => Some(PathBuf::from(text)),
     }
+}
+
+pub fn zxp_paths_from_text(text: &str) -> Vec<PathBuf> {
+    // One path per line; anything that isn't a .zxp is ignored
+    text.lines()
+        .filter_map(parse_pasted_path)
+        .filter(|path| is_valid_zxp_extension(path))
+        .collect()
+}
+
+pub fn install_zxp(zxp_path: &Path) -> Result<InstalledPlugin, FileOperationError> {
+    install_zxp_into(zxp_path, Path::new(SYSTEM_EXTENSIONS_PATH))
+}
+
+pub fn install_zxp_into(zxp_path: &Path, extensions_root: &Path) -> Result<InstalledPlugin, FileOperationError> {
+    // 1. Validate ZXP file exists and has correct extension
+    // 2. Open ZXP (ZIP) file for reading  
+    // 3. Parse manifest.xml from ZIP to get Extension ID
+    // 4. Create target directory: <extensions_root>/{extension_id}/
+    // 5. Extract all ZIP contents to target directory
+    // 6. PermissionDenied for the all-users root is retried elevated by the caller
     
     log::info!("Installing ZXP file: {:?}", zxp_path);
     
     // Open ZIP archive
-    let file = fs::File::open(zxp_path)
-        .map_err(|_| FileOperationError::FileNotFound)?;
-    
-    let mut archive = ZipArchive::new(file)
-        .map_err(|_| FileOperationError::InvalidZip)?;
+    let mut archive = open_zxp(zxp_path)?;
     
     // Parse manifest.xml from ZIP to get Extension ID
-    let extension_id = extract_extension_id_from_zip(&mut archive)?;
+    let info = read_manifest_from_zip(&mut archive, zxp_path)?;
+    let extension_id = extension_folder_name(&info, zxp_path)?;
+    let publisher = read_small_entry(&mut archive, SIGNATURES_FILE).and_then(|xml| signer_common_name(&xml));
+    check_policy(&extension_id, publisher.as_deref())?;
     
     // Create target directory
-    let cep_path = Path::new("/Library/Application Support/Adobe/CEP/extensions/");
-    let target_dir = cep_path.join(&extension_id);
+    let target_dir = extensions_root.join(&extension_id);
     
     log::info!("Installing to directory: {:?}", target_dir);
     
+    // Refuse up front rather than failing halfway through extraction
+    ensure_free_space(&target_dir, uncompressed_size(&mut archive))?;
+    
+    replace_dev_link(&target_dir)?;
+    archive_installed_version(&target_dir);
+    
     // Create target directory if it doesn't exist
     fs::create_dir_all(&target_dir)
-        .map_err(|e| match e.kind() {
-            std::io::ErrorKind::PermissionDenied => FileOperationError::PermissionDenied,
-            _ => FileOperationError::ExtractError,
-        })?;
+        .map_err(|e| FileOperationError::extract(&target_dir, e))?;
     
     // Extract all files from ZIP to target directory
-    archive.extract(&target_dir)
-        .map_err(|_| FileOperationError::ExtractError)?;
+    extract_archive(&mut archive, &target_dir)?;
+    let corrupted = verify_extraction(&mut archive, &target_dir);
+    if !corrupted.is_empty() {
+        log::error!("{} extracted files in {:?} don't match the archive: {:?}", corrupted.len(), target_dir, corrupted);
+        return Err(FileOperationError::CorruptedInstall { path: target_dir, files: corrupted });
+    }
+    clear_quarantine(&target_dir);
     
-    log::info!("ZXP installation completed for: {}", extension_id);
-    Ok(target_dir)
+    log::info!("ZXP installation completed for: {} {}", extension_id, info.version);
+    Ok(InstalledPlugin { path: target_dir, info })
+}
+
+pub fn install_folder(source: &Path) -> Result<InstalledPlugin, FileOperationError> {
+    install_folder_into(source, Path::new(SYSTEM_EXTENSIONS_PATH))
+}
+
+// Unpacked extensions some developers ship instead of a ZXP: same destination, policy and space
+// checks as install_zxp_into, and an installed copy is upgraded in place the same way
+pub fn install_folder_into(source: &Path, extensions_root: &Path) -> Result<InstalledPlugin, FileOperationError> {
+    let invalid = || FileOperationError::InvalidExtension(source.to_path_buf());
+    if !source.is_dir() {
+        return Err(FileOperationError::FileNotFound(source.to_path_buf()));
+    }
+    let manifest_path = source.join("CSXS").join("manifest.xml");
+    if !manifest_path.is_file() {
+        return Err(invalid());
+    }
+    
+    let info = parse_manifest_xml(&manifest_path).map_err(|_| invalid())?;
+    let extension_id = extension_folder_name(&info, source)?;
+    check_policy(&extension_id, folder_publisher(source).as_deref())?;
+    
+    let target_dir = extensions_root.join(&extension_id);
+    // Picking the installed copy itself would copy every file onto itself
+    if target_dir.exists() && fs::canonicalize(source).ok() == fs::canonicalize(&target_dir).ok() {
+        return Err(FileOperationError::AlreadyInstalled(target_dir));
+    }
+    log::info!("Installing folder {:?} to {:?}", source, target_dir);
+    
+    let required = calculate_folder_size_bytes(source).map_err(|_| FileOperationError::FileNotFound(source.to_path_buf()))?;
+    ensure_free_space(&target_dir, required)?;
+    replace_dev_link(&target_dir)?;
+    archive_installed_version(&target_dir);
+    let fresh = !target_dir.exists();
+    if let Err(e) = copy_directory(source, &target_dir) {
+        if fresh {
+            let _ = fs::remove_dir_all(&target_dir); // Host apps would try to load a half-copied extension
+        }
+        return Err(e);
+    }
+    clear_quarantine(&target_dir); // Copies keep the source's extended attributes
+    
+    log::info!("Folder installation completed for: {} {}", extension_id, info.version);
+    Ok(InstalledPlugin { path: target_dir, info })
+}
+
+// Host apps the manifest of a ZXP or an unpacked extension folder lists
+pub fn source_host_list(source: &Path) -> Result<Vec<HostRequirement>, FileOperationError> {
+    let invalid = || FileOperationError::InvalidExtension(source.to_path_buf());
+    if source.is_dir() {
+        return parse_host_list(&source.join("CSXS").join("manifest.xml")).map_err(|_| invalid());
+    }
+    let xml = read_manifest_text_from_zip(&mut open_zxp(source)?, source)?;
+    parse_host_list_str(&xml).map_err(|_| FileOperationError::InvalidZip(source.to_path_buf()))
+}
+
+// Bundle ID, name and version the manifest of a ZXP or an unpacked extension folder declares
+pub fn source_plugin_info(source: &Path) -> Result<PluginInfo, FileOperationError> {
+    if source.is_dir() {
+        return parse_manifest_xml(&source.join("CSXS").join("manifest.xml")).map_err(|_| FileOperationError::InvalidExtension(source.to_path_buf()));
+    }
+    read_manifest_from_zip(&mut open_zxp(source)?, source)
+}
+
+pub fn preview_install(zxp_path: &Path) -> Result<InstallPreview, FileOperationError> {
+    preview_install_into(zxp_path, Path::new(SYSTEM_EXTENSIONS_PATH))
+}
+
+// Same checks as install_zxp_into up to extraction; nothing is written
+pub fn preview_install_into(zxp_path: &Path, extensions_root: &Path) -> Result<InstallPreview, FileOperationError> {
+    let mut archive = open_zxp(zxp_path)?;
+    let extension_id = extension_folder_name(&read_manifest_from_zip(&mut archive, zxp_path)?, zxp_path)?;
+    let destination = extensions_root.join(&extension_id);
+
+    let mut files = Vec::new();
+    for index in 0..archive.len() {
+        let Ok(entry) = archive.by_index_raw(index) else {
+            continue;
+        };
+        // Folders aren't files on disk, and extraction skips names that escape the destination
+        let Some(path) = entry.enclosed_name().filter(|_| !entry.is_dir()) else {
+            continue;
+        };
+        let overwrites = destination.join(&path).exists();
+        files.push(PreviewFile { path, size: entry.size(), overwrites });
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let sha256 = file_sha256(zxp_path).inspect_err(|e| log::warn!("Failed to hash {:?}: {}", zxp_path, e)).ok();
+
+    Ok(InstallPreview { zxp_path: zxp_path.to_path_buf(), extension_id, destination, files, sha256 })
+}
+
+// Reads the central directory, manifest and signature of a ZXP; nothing is extracted or written
+pub fn inspect_zxp(zxp_path: &Path) -> Result<ZxpInspection, FileOperationError> {
+    let invalid = || FileOperationError::InvalidZip(zxp_path.to_path_buf());
+    let mut archive = open_zxp(zxp_path)?;
+    let manifest = read_manifest_text_from_zip(&mut archive, zxp_path)?;
+    let info = parse_manifest_str(&manifest).map_err(|_| invalid())?;
+    // A damaged list section still leaves the rest worth showing
+    let hosts = parse_host_list_str(&manifest).unwrap_or_default();
+    let extensions = parse_extension_entries_content(&manifest).unwrap_or_default();
+
+    let signatures = read_small_entry(&mut archive, SIGNATURES_FILE);
+    let signer = signatures.as_deref().and_then(signer_common_name);
+    // Evaluated like an install would, minus the refusal
+    let blocked = match load_policy() {
+        PolicyStatus::Loaded(policy) => {
+            let is_native = determine_plugin_type(&info.bundle_id) == PluginType::Native;
+            policy.evaluate(&info.bundle_id, signer.as_deref(), is_native).err().map(|reason| FileOperationError::BlockedByPolicy(reason).to_string())
+        }
+        PolicyStatus::Missing | PolicyStatus::Invalid => None,
+    };
+
+    let mut entries = Vec::new();
+    let mut unsafe_entries = 0;
+    for index in 0..archive.len() {
+        let Ok(entry) = archive.by_index_raw(index) else {
+            continue;
+        };
+        let Some(path) = entry.enclosed_name() else {
+            unsafe_entries += 1;
+            continue;
+        };
+        entries.push(ArchiveEntry {
+            path,
+            size: entry.size(),
+            compressed: entry.compressed_size(),
+            is_dir: entry.is_dir(),
+            is_symlink: entry.is_symlink(),
+            executable: entry.unix_mode().is_some_and(|mode| mode & 0o111 != 0) && !entry.is_dir(),
+        });
+    }
+
+    let sha256 = file_sha256(zxp_path).inspect_err(|e| log::warn!("Failed to hash {:?}: {}", zxp_path, e)).ok();
+    log::info!("Inspected {:?}: {} {} with {} entries", zxp_path, info.bundle_id, info.version, entries.len());
+    Ok(ZxpInspection {
+        zxp_path: zxp_path.to_path_buf(),
+        info,
+        hosts,
+        extensions,
+        signed: signatures.is_some(),
+        signer,
+        blocked,
+        sha256,
+        entries,
+        unsafe_entries,
+    })
+}
+
+// Reads the archive's central directory and hashes only installed files whose size matches, so
+// nothing is extracted
+pub fn compare_zxp_with_folder(zxp_path: &Path, installed: &Path) -> Result<InstallComparison, FileOperationError> {
+    let current = parse_manifest_xml(&installed.join("CSXS").join("manifest.xml"))
+        .map_err(|_| FileOperationError::InvalidExtension(installed.to_path_buf()))?;
+    let mut archive = open_zxp(zxp_path)?;
+    let incoming = read_manifest_from_zip(&mut archive, zxp_path)?;
+
+    let mut installed_files = BTreeMap::new();
+    collect_file_sizes(installed, installed, &mut installed_files);
+    let mut changes = Vec::new();
+    let mut unchanged = 0;
+    for index in 0..archive.len() {
+        let Ok(entry) = archive.by_index_raw(index) else {
+            continue;
+        };
+        // Same entries extraction writes and verification checks
+        let Some(path) = entry.enclosed_name().filter(|_| !entry.is_dir() && !entry.is_symlink()) else {
+            continue;
+        };
+        let incoming_size = Some(entry.size());
+        match installed_files.remove(&path) {
+            None => changes.push(FileChange { path, kind: ChangeKind::Added, installed_size: None, incoming_size }),
+            Some(size) if size == entry.size() && file_crc32(&installed.join(&path)).ok() == Some(entry.crc32()) => unchanged += 1,
+            Some(size) => changes.push(FileChange { path, kind: ChangeKind::Changed, installed_size: Some(size), incoming_size }),
+        }
+    }
+    // Installing doesn't delete these, but a clean install of the new version wouldn't have them
+    changes.extend(installed_files.into_iter().map(|(path, size)| FileChange {
+        path,
+        kind: ChangeKind::Removed,
+        installed_size: Some(size),
+        incoming_size: None,
+    }));
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    log::info!(
+        "Compared {:?} ({}) with {:?} ({}): {} changes, {} unchanged",
+        zxp_path,
+        incoming.version,
+        installed,
+        current.version,
+        changes.len(),
+        unchanged
+    );
+    Ok(InstallComparison {
+        zxp_path: zxp_path.to_path_buf(),
+        installed_path: installed.to_path_buf(),
+        installed_version: current.version,
+        incoming_version: incoming.version,
+        changes,
+        unchanged,
+    })
+}
+
+// Unpacks entry by entry below target_dir, keeping each file's Unix mode so bundled binaries stay
+// executable. Entries and links pointing outside target_dir are refused.
+pub fn extract_archive(archive: &mut ZipArchive<fs::File>, target_dir: &Path) -> Result<(), FileOperationError> {
+    // Links are checked against where entries really land, so resolve the folder's own path once
+    fs::create_dir_all(target_dir).map_err(|e| FileOperationError::extract(target_dir, e))?;
+    let root = fs::canonicalize(target_dir).map_err(|e| FileOperationError::extract(target_dir, e))?;
+    EXTRACTED_ENTRIES.store(0, Ordering::Relaxed);
+    EXTRACT_TOTAL.store(archive.len(), Ordering::Relaxed);
+    let result = (0..archive.len()).try_for_each(|index| {
+        extract_entry(archive, index, &root)?;
+        EXTRACTED_ENTRIES.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    });
+    EXTRACT_TOTAL.store(0, Ordering::Relaxed);
+    result
+}
+
+// (extracted, total) entries of the extraction in progress
+pub fn extraction_progress() -> Option<(usize, usize)> {
+    let total = EXTRACT_TOTAL.load(Ordering::Relaxed);
+    (total > 0).then(|| (EXTRACTED_ENTRIES.load(Ordering::Relaxed).min(total), total))
+}
+
+// Reads every extracted file back and compares its CRC32 with the archive's central directory,
+// catching failing disks and scanners that rewrite files mid-install. Returns the paths, relative
+// to target_dir, that are missing or differ.
+pub fn verify_extraction(archive: &mut ZipArchive<fs::File>, target_dir: &Path) -> Vec<PathBuf> {
+    let mut corrupted = Vec::new();
+    for index in 0..archive.len() {
+        let Ok(entry) = archive.by_index_raw(index) else {
+            continue;
+        };
+        // Same entries extraction writes; links are checked through what they point at, not their text
+        let Some(path) = entry.enclosed_name().filter(|_| !entry.is_dir() && !entry.is_symlink()) else {
+            continue;
+        };
+        if file_crc32(&target_dir.join(&path)).ok() != Some(entry.crc32()) {
+            corrupted.push(path);
+        }
+    }
+    corrupted
+}
+
+pub fn file_sha256(path: &Path) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    read_chunks(path, |chunk| hasher.update(chunk))?;
+    Ok(hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+// Takes a checksum the way vendors publish it: hex in either case, or a `shasum -a 256` line
+// ("<hash>  file.zxp"); None for anything that isn't a SHA-256
+pub fn parse_checksum(text: &str) -> Option<String> {
+    let hash = text.split_whitespace().next()?.to_lowercase();
+    (hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit())).then_some(hash)
+}
+
+pub fn set_version_history(enabled: bool) {
+    VERSION_HISTORY.store(enabled, Ordering::Relaxed);
+}
+
+pub fn version_history_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("backups").join("versions"))
+}
+
+// Called before an upgrade writes over an installed folder; a failure is logged and never fails the install
+pub fn archive_installed_version(plugin_dir: &Path) {
+    if !VERSION_HISTORY.load(Ordering::Relaxed) || !plugin_dir.is_dir() {
+        return;
+    }
+    let Some(history) = version_history_dir() else {
+        return;
+    };
+    match archive_version_into(plugin_dir, &history) {
+        Ok(archive) => log::info!("Archived the replaced version of {:?} as {:?}", plugin_dir, archive),
+        Err(e) => log::warn!("Failed to archive {:?} before the upgrade: {}", plugin_dir, e),
+    }
+}
+
+// Packs the installed folder into the history and drops the oldest archives past KEPT_VERSIONS
+pub fn archive_version_into(plugin_dir: &Path, history: &Path) -> Result<PathBuf, FileOperationError> {
+    let info = parse_manifest_xml(&plugin_dir.join("CSXS").join("manifest.xml"))
+        .map_err(|_| FileOperationError::InvalidExtension(plugin_dir.to_path_buf()))?;
+    if !is_safe_folder_name(&info.bundle_id) {
+        return Err(FileOperationError::InvalidExtension(plugin_dir.to_path_buf()));
+    }
+    let folder = history.join(&info.bundle_id);
+    fs::create_dir_all(&folder).map_err(|e| FileOperationError::archive(&folder, e))?;
+    let archive = folder.join(format!("{}.zxp", version_file_stem(&info.version)));
+    export_plugin_as_zxp(plugin_dir, &archive)?;
+
+    for stale in archived_versions_in(history, &info.bundle_id).into_iter().skip(KEPT_VERSIONS) {
+        let _ = fs::remove_file(&stale.path);
+    }
+    Ok(archive)
+}
+
+// Newest first
+pub fn archived_versions(bundle_id: &str) -> Vec<ArchivedVersion> {
+    version_history_dir().map(|history| archived_versions_in(&history, bundle_id)).unwrap_or_default()
+}
+
+pub fn archived_versions_in(history: &Path, bundle_id: &str) -> Vec<ArchivedVersion> {
+    if !is_safe_folder_name(bundle_id) {
+        return Vec::new();
+    }
+    let mut versions: Vec<ArchivedVersion> = fs::read_dir(history.join(bundle_id))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_valid_zxp_extension(path))
+        .map(|path| {
+            let mut archive = open_zxp(&path).ok();
+            let version = archive.as_mut().and_then(|archive| read_manifest_from_zip(archive, &path).ok()).map(|info| info.version);
+            ArchivedVersion {
+                version: version.unwrap_or_else(|| path.file_stem().unwrap_or_default().to_string_lossy().to_string()),
+                archived_at: fs::metadata(&path).and_then(|metadata| metadata.modified()).ok().and_then(unix_seconds),
+                path,
+            }
+        })
+        .collect();
+    versions.sort_by_key(|version| std::cmp::Reverse(version.archived_at));
+    versions
+}
+
+// Puts an archived version back in place of the installed one. The version being replaced goes
+// into the history first, so a revert can itself be reverted. The archive is unpacked and verified
+// beside the plugin and swapped in by renaming, so a failed revert leaves the installed version.
+pub fn revert_to_version(plugin_dir: &Path, archived: &Path) -> Result<InstalledPlugin, FileOperationError> {
+    let invalid = || FileOperationError::InvalidExtension(plugin_dir.to_path_buf());
+    if is_dev_link(plugin_dir) {
+        return Err(invalid()); // Would replace the working folder's link; dev folders have no history
+    }
+    let current = parse_manifest_xml(&plugin_dir.join("CSXS").join("manifest.xml")).map_err(|_| invalid())?;
+    let mut archive = open_zxp(archived)?;
+    let info = read_manifest_from_zip(&mut archive, archived)?;
+    if info.bundle_id != current.bundle_id {
+        return Err(FileOperationError::InvalidExtension(archived.to_path_buf()));
+    }
+    ensure_free_space(plugin_dir, uncompressed_size(&mut archive))?;
+    let name = plugin_dir.file_name().ok_or_else(invalid)?.to_string_lossy().to_string();
+    let (staging, replaced) = (plugin_dir.with_file_name(format!(".{name}.reverting")), plugin_dir.with_file_name(format!(".{name}.replaced")));
+    let _ = fs::remove_dir_all(&staging); // Left by a revert that was interrupted
+
+    // A fresh folder, so files only the newer version had don't linger
+    let staged = extract_archive(&mut archive, &staging).and_then(|_| {
+        let corrupted = verify_extraction(&mut archive, &staging);
+        if corrupted.is_empty() { Ok(()) } else { Err(FileOperationError::CorruptedInstall { path: plugin_dir.to_path_buf(), files: corrupted }) }
+    });
+    if let Err(e) = staged {
+        let _ = fs::remove_dir_all(&staging);
+        return Err(e);
+    }
+
+    // Re-archiving the same version would overwrite the archive being restored
+    if info.version != current.version {
+        archive_installed_version(plugin_dir);
+    }
+    log::info!("Reverting {:?} from {} to {}", plugin_dir, current.version, info.version);
+    let _ = fs::remove_dir_all(&replaced);
+    fs::rename(plugin_dir, &replaced).map_err(|e| {
+        let _ = fs::remove_dir_all(&staging);
+        FileOperationError::extract(plugin_dir, e)
+    })?;
+    if let Err(e) = fs::rename(&staging, plugin_dir) {
+        let _ = fs::rename(&replaced, plugin_dir);
+        let _ = fs::remove_dir_all(&staging);
+        return Err(FileOperationError::extract(plugin_dir, e));
+    }
+    if let Err(e) = fs::remove_dir_all(&replaced) {
+        log::warn!("Failed to remove the replaced version {:?}: {}", replaced, e);
+    }
+    clear_quarantine(plugin_dir);
+    Ok(InstalledPlugin { path: plugin_dir.to_path_buf(), info })
+}
+
+pub fn set_clear_quarantine(enabled: bool) {
+    CLEAR_QUARANTINE.store(enabled, Ordering::Relaxed);
+}
+
+// Recursively, on macOS only; a failure is logged and never fails the install
+pub fn clear_quarantine(folder: &Path) {
+    if !cfg!(target_os = "macos") || !CLEAR_QUARANTINE.load(Ordering::Relaxed) {
+        return;
+    }
+    match Command::new("xattr").arg("-dr").arg(QUARANTINE_ATTRIBUTE).arg(folder).status() {
+        Ok(status) if status.success() => log::info!("Cleared quarantine flags in {:?}", folder),
+        Ok(status) => log::warn!("xattr exited with {} clearing quarantine in {:?}", status, folder),
+        Err(e) => log::warn!("Failed to run xattr for {:?}: {}", folder, e),
+    }
+}
+
+pub fn uncompressed_size(archive: &mut ZipArchive<fs::File>) -> u64 {
+    // Sizes from the central directory - nothing is decompressed
+    (0..archive.len())
+        .filter_map(|index| archive.by_index_raw(index).ok().map(|entry| entry.size()))
+        .sum()
+}
+
+#[cfg(unix)]
+pub fn available_space(path: &Path) -> Option<u64> {
+    // statvfs needs an existing path - the destination folder often isn't created yet
+    let existing = path.ancestors().find(|dir| dir.exists())?;
+    let c_path = CString::new(existing.as_os_str().as_bytes()).ok()?;
+    let mut stats: libc::statvfs = unsafe { std::mem::zeroed() };
+    if unsafe { libc::statvfs(c_path.as_ptr(), &mut stats) } != 0 {
+        return None;
+    }
+    #[allow(clippy::unnecessary_cast)] // fsblkcnt_t is 32-bit on macOS
+    Some(stats.f_bavail as u64 * stats.f_frsize as u64)
+}
+
+// No statvfs here; ensure_free_space() then leaves it to the write itself
+#[cfg(not(unix))]
+pub fn available_space(_path: &Path) -> Option<u64> {
+    None
+}
+
+pub fn ensure_free_space(destination: &Path, required: u64) -> Result<(), FileOperationError> {
+    let Some(available) = available_space(destination) else {
+        // Some network volumes can't report it - let the write itself decide
+        log::warn!("Could not determine free space for {:?}", destination);
+        return Ok(());
+    };
+
+    if required.saturating_add(SPACE_HEADROOM) > available {
+        log::warn!("Not enough space in {:?}: need {} bytes, {} available", destination, required, available);
+        return Err(FileOperationError::InsufficientSpace { required, available });
+    }
+    Ok(())
+}
+
+// Probes with a real file - permission bits alone miss ACLs and read-only volumes
+pub fn can_write_in(folder: &Path) -> bool {
+    let probe = folder.join(format!(".zxpm-write-test-{}", std::process::id()));
+    match fs::write(&probe, b"") {
+        Ok(()) => {
+            let _ = fs::remove_file(&probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+// The first folder writing, replacing or removing `target` would be refused in: the folder that holds
+// it - or the nearest existing one above, which creating the missing ones needs - then target
+// itself. None when both take writes. A link is only checked where it lives, never followed.
+pub fn unwritable_folder(target: &Path) -> Option<PathBuf> {
+    let container = target.parent()?.ancestors().find(|folder| folder.is_dir())?;
+    let is_folder = target.symlink_metadata().is_ok_and(|metadata| metadata.is_dir());
+    let folders = [Some(container), is_folder.then_some(target)];
+    let refused = folders.into_iter().flatten().find(|folder| !can_write_in(folder))?;
+    log::warn!("No write access to {:?} for {:?}", refused, target);
+    Some(refused.to_path_buf())
+}
+
+// The folder installing a ZXP or an unpacked extension into extensions_root would write
+pub fn install_destination(source: &Path, extensions_root: &Path) -> Result<PathBuf, FileOperationError> {
+    let info = source_plugin_info(source)?;
+    Ok(extensions_root.join(extension_folder_name(&info, source)?))
 }
 
 pub fn remove_plugin(plugin_path: &Path) -> Result<(), FileOperationError> {
@@ -106,81 +921,1468 @@ pub fn remove_plugin(plugin_path: &Path) -> Result<(), FileOperationError> {
     // 3. Remove entire plugin directory and contents
     // 4. Handle any permission errors gracefully
     
+    if is_dev_link(plugin_path) {
+        return unlink_dev_folder(plugin_path); // Only the link goes, never the working folder behind it
+    }
+    
     if !plugin_path.exists() {
-        return Err(FileOperationError::FileNotFound);
+        return Err(FileOperationError::FileNotFound(plugin_path.to_path_buf()));
     }
     
     if !plugin_path.is_dir() {
-        return Err(FileOperationError::InvalidExtension);
+        return Err(FileOperationError::InvalidExtension(plugin_path.to_path_buf()));
     }
     
     log::info!("Removing plugin: {:?}", plugin_path);
     
     fs::remove_dir_all(plugin_path)
-        .map_err(|e| match e.kind() {
-            std::io::ErrorKind::PermissionDenied => FileOperationError::PermissionDenied,
-            _ => FileOperationError::ExtractError,
-        })?;
+        .map_err(|e| FileOperationError::extract(plugin_path, e))?;
     
     log::info!("Plugin removal completed");
     Ok(())
 }
 
-// Helper functions
-fn is_valid_zxp_extension(file_path: &Path) -> bool {
-    // Validates file has .zxp extension (case insensitive)
-    file_path
-        .extension()
-        .and_then(|ext| ext.to_str())
-        .map(|ext| ext.to_lowercase() == "zxp")
-        .unwrap_or(false)
+// Moves the folder out of its extensions root, so hosts stop loading it; returns the new path
+pub fn disable_plugin(plugin_path: &Path) -> Result<PathBuf, FileOperationError> {
+    let root = plugin_path.parent().ok_or_else(|| FileOperationError::FileNotFound(plugin_path.to_path_buf()))?;
+    move_plugin_folder(plugin_path, &disabled_folder(root))
 }
 
-fn extract_extension_id_from_zip(archive: &mut ZipArchive<fs::File>) -> Result<String, FileOperationError> {
-    // Find and read CSXS/manifest.xml from ZIP
-    let manifest_file = archive
-        .by_name("CSXS/manifest.xml")
-        .map_err(|_| FileOperationError::InvalidZip)?;
+// Moves a disabled plugin back into the extensions root it came from
+pub fn enable_plugin(plugin_path: &Path) -> Result<PathBuf, FileOperationError> {
+    let disabled = plugin_path.parent().ok_or_else(|| FileOperationError::FileNotFound(plugin_path.to_path_buf()))?;
+    move_plugin_folder(plugin_path, &disabled.with_file_name(EXTENSIONS_FOLDER_NAME))
+}
+
+pub fn select_inventory_folder(start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    // Opens native folder picker for a directory that contains extension folders
+    dialog_in(start_dir)
+        .set_title("Open Folder as Inventory")
+        .pick_folder()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn select_hot_folder(start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    // Folder watched for ZXPs to install automatically
+    dialog_in(start_dir)
+        .set_title("Choose Hot Folder")
+        .pick_folder()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn select_plugin_set_folder(title: &str, start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    // Backup picks where the new set folder goes, restore picks an existing set folder
+    dialog_in(start_dir)
+        .set_title(title)
+        .pick_folder()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn import_plugin_folder(source: &Path) -> Result<PathBuf, FileOperationError> {
+    // 1. Validate source is an extension folder
+    // 2. Refuse to overwrite an installed folder with the same name
+    // 3. Copy the folder into the system extensions directory
     
-    // Read manifest content
-    let mut content = String::new();
-    let mut reader = manifest_file;
-    reader.read_to_string(&mut content)
-        .map_err(|_| FileOperationError::InvalidZip)?;
+    let invalid = || FileOperationError::InvalidExtension(source.to_path_buf());
+    if !source.join("CSXS").join("manifest.xml").is_file() {
+        return Err(invalid());
+    }
     
-    // Parse manifest XML to get Extension ID
-    // Create temporary file for parsing (parse_manifest_xml expects Path)
-    let temp_dir = std::env::temp_dir();
-    let temp_manifest = temp_dir.join("temp_manifest.xml");
+    let bundle_id = parse_manifest_xml(&source.join("CSXS").join("manifest.xml"))
+        .map_err(|_| invalid())?
+        .bundle_id;
+    check_policy(&bundle_id, folder_publisher(source).as_deref())?;
     
-    fs::write(&temp_manifest, content)
-        .map_err(|_| FileOperationError::ExtractError)?;
+    let folder_name = source.file_name().ok_or_else(invalid)?;
+    let target_dir = Path::new(SYSTEM_EXTENSIONS_PATH).join(folder_name);
     
-    let plugin_info = parse_manifest_xml(&temp_manifest)
-        .map_err(|_| FileOperationError::InvalidZip)?;
+    if target_dir.exists() {
+        return Err(FileOperationError::AlreadyInstalled(target_dir));
+    }
     
-    // Clean up temp file
-    let _ = fs::remove_file(&temp_manifest);
+    log::info!("Importing plugin {:?} to {:?}", source, target_dir);
     
-    // Extract the main extension ID (before ".panel" if present)
-    let extension_id = plugin_info.bundle_id
-        .split(".panel")
-        .next()
-        .unwrap_or(&plugin_info.bundle_id)
-        .to_string();
+    let required = calculate_folder_size_bytes(source).map_err(|_| FileOperationError::FileNotFound(source.to_path_buf()))?;
+    ensure_free_space(&target_dir, required)?;
     
-    Ok(extension_id)
+    if let Err(e) = copy_directory(source, &target_dir) {
+        let _ = fs::remove_dir_all(&target_dir); // Don't leave a half-copied extension behind
+        return Err(e);
+    }
+    clear_quarantine(&target_dir); // Copies keep the source's extended attributes
+    
+    log::info!("Plugin import completed");
+    Ok(target_dir)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// Developer mode: hosts load a working folder in place through a link in the user extensions root,
+// so edits show up on the next panel reload without packaging. Returns the link.
+pub fn link_dev_folder(source: &Path) -> Result<PathBuf, FileOperationError> {
+    let root = ExtensionRoot::User.path().ok_or_else(|| FileOperationError::InvalidExtension(source.to_path_buf()))?;
+    link_dev_folder_into(source, &root)
+}
+
+pub fn link_dev_folder_into(source: &Path, extensions_root: &Path) -> Result<PathBuf, FileOperationError> {
+    let invalid = || FileOperationError::InvalidExtension(source.to_path_buf());
+    let source = fs::canonicalize(source).map_err(|_| FileOperationError::FileNotFound(source.to_path_buf()))?;
+    let manifest_path = source.join("CSXS").join("manifest.xml");
+    if !manifest_path.is_file() {
+        return Err(invalid());
+    }
     
-    #[test]
-    fn test_zxp_extension_validation() {
-        assert!(is_valid_zxp_extension(&PathBuf::from("test.zxp")));
-        assert!(is_valid_zxp_extension(&PathBuf::from("test.ZXP")));
-        assert!(!is_valid_zxp_extension(&PathBuf::from("test.zip")));
-        assert!(!is_valid_zxp_extension(&PathBuf::from("test")));
+    let bundle_id = parse_manifest_xml(&manifest_path).map_err(|_| invalid())?.bundle_id;
+    check_policy(&bundle_id, folder_publisher(&source).as_deref())?;
+    
+    let link = extensions_root.join(source.file_name().ok_or_else(invalid)?);
+    if link.symlink_metadata().is_ok() {
+        return Err(FileOperationError::AlreadyInstalled(link));
+    }
+    
+    fs::create_dir_all(extensions_root).map_err(|e| FileOperationError::extract(extensions_root, e))?;
+    create_folder_link(&source, &link).map_err(|e| FileOperationError::extract(&link, e))?;
+    log::info!("Linked dev folder {:?} as {:?}", source, link);
+    Ok(link)
+}
+
+// Removes the link only - the working folder it points at is left as it is
+pub fn unlink_dev_folder(link: &Path) -> Result<(), FileOperationError> {
+    if !is_dev_link(link) {
+        return Err(FileOperationError::InvalidExtension(link.to_path_buf()));
+    }
+    // A junction is a directory to Windows, a symlink a file to Unix
+    let result = if cfg!(target_os = "windows") { fs::remove_dir(link) } else { fs::remove_file(link) };
+    result.map_err(|e| FileOperationError::extract(link, e))?;
+    log::info!("Unlinked dev folder {:?}", link);
+    Ok(())
+}
+
+pub fn select_export_destination(default_name: &str, start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    // Opens native save dialog pre-filled with the suggested file name
+    dialog_in(start_dir)
+        .add_filter("ZXP Files", &["zxp"])
+        .set_title("Export Plugin as ZXP")
+        .set_file_name(default_name)
+        .save_file()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn select_extension_folder(start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    // Unpacked extension (folder with CSXS/manifest.xml) to package
+    dialog_in(start_dir)
+        .set_title("Select Extension Folder")
+        .pick_folder()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn select_certificate_file(start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    dialog_in(start_dir)
+        .add_filter("PKCS#12 Certificate", &["p12", "pfx"])
+        .set_title("Select Signing Certificate")
+        .pick_file()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn select_certificate_destination(start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    dialog_in(start_dir)
+        .add_filter("PKCS#12 Certificate", &["p12"])
+        .set_title("Save Self-Signed Certificate")
+        .set_file_name("certificate.p12")
+        .save_file()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn select_signing_tool(start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    dialog_in(start_dir)
+        .set_title("Locate ZXPSignCmd")
+        .pick_file()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn select_report_destination(title: &str, default_name: &str, start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    // The chosen extension decides between JSON and CSV
+    dialog_in(start_dir)
+        .add_filter("JSON", &["json"])
+        .add_filter("CSV", &["csv"])
+        .set_title(title)
+        .set_file_name(default_name)
+        .save_file()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn select_bundle_destination(default_name: &str, start_dir: Option<PathBuf>) -> Result<PathBuf, FileOperationError> {
+    dialog_in(start_dir)
+        .add_filter("ZIP", &["zip"])
+        .set_title("Export Diagnostics")
+        .set_file_name(default_name)
+        .save_file()
+        .ok_or(FileOperationError::DialogCancelled)
+}
+
+pub fn export_plugin_as_zxp(plugin_path: &Path, zxp_path: &Path) -> Result<(), FileOperationError> {
+    // Packs the installed folder back into a ZIP archive with a .zxp extension
+    // Note: the result is unsigned - hosts accept it from the extensions folder,
+    // but Adobe's own installers will reject it without a signature
+    
+    if !plugin_path.is_dir() {
+        return Err(FileOperationError::FileNotFound(plugin_path.to_path_buf()));
+    }
+    
+    log::info!("Exporting plugin {:?} to {:?}", plugin_path, zxp_path);
+    
+    let file = fs::File::create(zxp_path)
+        .map_err(|e| FileOperationError::archive(zxp_path, e))?;
+    
+    let mut writer = ZipWriter::new(file);
+    add_directory_to_zip(&mut writer, plugin_path, plugin_path, zxp_path)?;
+    writer.finish()
+        .map_err(|e| FileOperationError::archive(zxp_path, zip_io_error(e)))?;
+    
+    log::info!("Plugin export completed");
+    Ok(())
+}
+
+pub fn reveal_in_file_manager(path: &Path) -> Result<(), FileOperationError> {
+    // Shows the item selected in Finder/Explorer; Linux file managers get the parent folder
+    let mut command = if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg("-R").arg(path);
+        command
+    } else if cfg!(target_os = "windows") {
+        let mut command = Command::new("explorer");
+        command.arg(format!("/select,{}", path.display()));
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(path.parent().unwrap_or(path));
+        command
+    };
+    
+    command.spawn()
+        .map(|_| ())
+        .map_err(|_| FileOperationError::OpenFailed(path.display().to_string()))
+}
+
+pub fn open_in_default_app(path: &Path) -> Result<(), FileOperationError> {
+    // Opens a file with whatever application the OS associates with it
+    if !path.exists() {
+        return Err(FileOperationError::FileNotFound(path.to_path_buf()));
+    }
+    
+    Command::new(opener_program())
+        .arg(path)
+        .spawn()
+        .map(|_| ())
+        .map_err(|_| FileOperationError::OpenFailed(path.display().to_string()))
+}
+
+pub fn open_url(url: &str) -> Result<(), FileOperationError> {
+    // Only web pages - never hand arbitrary schemes to the OS opener
+    if !url.starts_with("https://") {
+        return Err(FileOperationError::OpenFailed(url.to_string()));
+    }
+    
+    Command::new(opener_program())
+        .arg(url)
+        .spawn()
+        .map(|_| ())
+        .map_err(|_| FileOperationError::OpenFailed(url.to_string()))
+}
+
+pub fn cep_cache_path() -> Option<PathBuf> {
+    // Hosts cache each extension's web storage and compiled assets here
+    dirs::cache_dir().map(|dir| dir.join("CSXS").join("cep_cache"))
+}
+
+pub fn cep_logs_path() -> Option<PathBuf> {
+    // CEP<N>-<HOST>.log and CEPHtmlEngine<N>-<HOST>-<version>-<extension>.log
+    dirs::home_dir().map(|home| home.join("Library").join("Logs").join("CSXS"))
+}
+
+pub fn cep_storage_path() -> Option<PathBuf> {
+    // Panels that keep settings in files put them in a folder named after their extension ID,
+    // next to the user extensions root
+    dirs::data_dir().map(|dir| dir.join("Adobe").join("CEP"))
+}
+
+pub fn clear_cep_cache() -> Result<usize, FileOperationError> {
+    // Removes everything inside the CEP cache folder, returns the number of removed entries
+    // Hosts rebuild the cache on the next launch of each extension
+    let Some(cache_path) = cep_cache_path().filter(|path| path.is_dir()) else {
+        return Ok(0); // No cache yet - nothing to clean
+    };
+    
+    let removed = remove_entries(&cache_path, |_| true)?;
+    log::info!("Cleared {} CEP cache entries", removed);
+    Ok(removed)
+}
+
+pub fn host_cache_entries(host_codes: &[&str]) -> usize {
+    // Cache entries of one host app, for showing what a reset would remove
+    cep_cache_path()
+        .and_then(|path| fs::read_dir(path).ok())
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| belongs_to_host(&entry.file_name().to_string_lossy(), host_codes))
+                .count()
+        })
+        .unwrap_or(0)
+}
+
+pub fn reset_host_cache(host_codes: &[&str]) -> Result<usize, FileOperationError> {
+    // Per-host reset: that app's CEP cache entries (<HOST>_<version>_<extension>) and CEP logs.
+    // Other hosts keep their caches, unlike clear_cep_cache()
+    let mut removed = 0;
+    for folder in [cep_cache_path(), cep_logs_path()].into_iter().flatten().filter(|path| path.is_dir()) {
+        removed += remove_entries(&folder, |name| belongs_to_host(name, host_codes))?;
+    }
+    
+    log::info!("Reset {} CEP cache and log entries for {:?}", removed, host_codes);
+    Ok(removed)
+}
+
+pub fn find_orphaned_cache(installed_ids: &HashSet<String>) -> Vec<OrphanedEntry> {
+    // Cache, engine log and storage entries whose extension ID no installed manifest declares any more
+    let storage = cep_storage_path();
+    let mut orphans = Vec::new();
+    for folder in [cep_cache_path(), cep_logs_path(), storage.clone()].into_iter().flatten() {
+        let Ok(entries) = fs::read_dir(&folder) else {
+            continue; // Never created - the machine has not run a panel yet
+        };
+        let in_storage = storage.as_ref() == Some(&folder);
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let extension_id = if in_storage {
+                Some(file_name.as_str()).filter(|_| entry.path().is_dir()).and_then(stored_extension_id)
+            } else {
+                cached_extension_id(&file_name)
+            };
+            let Some(extension_id) = extension_id.filter(|id| is_orphaned(id, installed_ids)) else {
+                continue;
+            };
+            let path = entry.path();
+            let size = if path.is_dir() {
+                measure_folder(&path).map(|size| size.on_disk).unwrap_or(0)
+            } else {
+                entry.metadata().map(|metadata| allocated_bytes(&metadata)).unwrap_or(0)
+            };
+            orphans.push(OrphanedEntry { extension_id: extension_id.to_string(), path, size });
+        }
+    }
+    orphans.sort_by(|a, b| a.extension_id.cmp(&b.extension_id).then_with(|| a.path.cmp(&b.path)));
+    orphans
+}
+
+// <HOST>_<version>_<extension> in the cache, CEPHtmlEngine<N>-<HOST>-<version>-<extension>.log in the logs
+pub fn cached_extension_id(file_name: &str) -> Option<&str> {
+    let id = match file_name.strip_suffix(".log") {
+        Some(log_name) => log_name.strip_prefix("CEPHtmlEngine")?.splitn(4, '-').nth(3),
+        None => file_name.splitn(3, '_').nth(2),
+    };
+    id.filter(|id| !id.is_empty())
+}
+
+// <extension ID> folders next to the extensions root; the roots themselves (extensions,
+// extensions-disabled) are not storage. Only reverse-DNS names count, each label non-empty and
+// the whole a single plain path component, so "Adobe", ".." or "a/../b" never name a folder
+pub fn stored_extension_id(file_name: &str) -> Option<&str> {
+    Some(file_name).filter(|name| {
+        name.contains('.')
+            && name.split('.').all(|label| !label.is_empty())
+            && !name.contains(['/', '\\', ':', '\0'])
+            && !name.starts_with(EXTENSIONS_FOLDER_NAME)
+    })
+}
+
+pub fn remove_orphaned_cache(orphans: &[OrphanedEntry]) -> (usize, u64) {
+    // Returns removed entries and freed bytes; failures are logged and left for the next detection
+    let (mut removed, mut freed) = (0, 0);
+    for orphan in orphans {
+        let result = if orphan.path.is_dir() { fs::remove_dir_all(&orphan.path) } else { fs::remove_file(&orphan.path) };
+        match result {
+            Ok(_) => {
+                removed += 1;
+                freed += orphan.size;
+            }
+            Err(e) => log::warn!("Failed to remove orphaned cache entry {:?}: {}", orphan.path, e),
+        }
+    }
+    
+    log::info!("Removed {} orphaned CEP cache and log entries", removed);
+    (removed, freed)
+}
+
+// A link to a whole folder, such as a dev folder linked into an extensions root
+#[cfg(unix)]
+pub fn create_folder_link(source: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(source, link)
+}
+
+// Junctions on Windows - unlike directory symlinks they need neither admin rights nor Developer Mode
+#[cfg(windows)]
+pub fn create_folder_link(source: &Path, link: &Path) -> io::Result<()> {
+    let status = Command::new("cmd").arg("/C").arg("mklink").arg("/J").arg(link).arg(source).status()?;
+    if status.success() { Ok(()) } else { Err(io::Error::other(format!("mklink /J exited with {}", status))) }
+}
+
+// Helper functions
+// A dev link at the destination leads into someone's working folder, which an install must never
+// write through - the link goes, like on removal, and the installed copy takes its place
+fn replace_dev_link(target_dir: &Path) -> Result<(), FileOperationError> {
+    if is_dev_link(target_dir) {
+        log::info!("Replacing dev link {:?} with the installed copy", target_dir);
+        unlink_dev_folder(target_dir)?;
+    }
+    Ok(())
+}
+
+fn remove_entries(folder: &Path, matches: impl Fn(&str) -> bool) -> Result<usize, FileOperationError> {
+    let entries = fs::read_dir(folder)
+        .map_err(|_| FileOperationError::PermissionDenied(folder.to_path_buf()))?;
+    let mut removed = 0;
+    
+    for entry in entries.flatten() {
+        if !matches(&entry.file_name().to_string_lossy()) {
+            continue;
+        }
+        let path = entry.path();
+        let result = if path.is_dir() { fs::remove_dir_all(&path) } else { fs::remove_file(&path) };
+        match result {
+            Ok(_) => removed += 1,
+            Err(e) => log::warn!("Failed to remove cache entry {:?}: {}", path, e),
+        }
+    }
+    Ok(removed)
+}
+
+fn belongs_to_host(file_name: &str, host_codes: &[&str]) -> bool {
+    host_codes.iter().any(|code| {
+        file_name.starts_with(&format!("{}_", code))
+            || file_name.contains(&format!("-{}-", code))
+            || file_name.ends_with(&format!("-{}.log", code))
+    })
+}
+
+fn is_orphaned(extension_id: &str, installed_ids: &HashSet<String>) -> bool {
+    // Adobe's own extensions ship inside the app bundles, outside every extensions root
+    !extension_id.starts_with("com.adobe.") && !installed_ids.contains(extension_id)
+}
+
+fn opener_program() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "open"
+    } else if cfg!(target_os = "windows") {
+        "explorer"
+    } else {
+        "xdg-open"
+    }
+}
+
+fn check_policy(bundle_id: &str, publisher: Option<&str>) -> Result<(), FileOperationError> {
+    match load_policy() {
+        PolicyStatus::Loaded(policy) => {
+            let is_native = determine_plugin_type(bundle_id) == PluginType::Native;
+            policy.evaluate(bundle_id, publisher, is_native).map_err(|reason| {
+                log::warn!("Install of {} blocked by policy: {:?}", bundle_id, reason);
+                FileOperationError::BlockedByPolicy(reason)
+            })
+        }
+        PolicyStatus::Missing | PolicyStatus::Invalid => Ok(()),
+    }
+}
+
+fn read_small_entry(archive: &mut ZipArchive<fs::File>, name: &str) -> Option<String> {
+    let entry = archive.by_name(name).ok()?;
+    if entry.size() > MAX_MANIFEST_SIZE {
+        return None;
+    }
+    let mut content = String::new();
+    entry.take(MAX_MANIFEST_SIZE).read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+fn move_plugin_folder(plugin_path: &Path, target_root: &Path) -> Result<PathBuf, FileOperationError> {
+    let not_found = || FileOperationError::FileNotFound(plugin_path.to_path_buf());
+    if !plugin_path.is_dir() {
+        return Err(not_found());
+    }
+    let folder_name = plugin_path.file_name().ok_or_else(not_found)?;
+    let target = target_root.join(folder_name);
+    if target.exists() {
+        return Err(FileOperationError::AlreadyInstalled(target)); // Never merge into or replace another copy
+    }
+
+    fs::create_dir_all(target_root).map_err(|e| FileOperationError::extract(target_root, e))?;
+    fs::rename(plugin_path, &target).map_err(|e| FileOperationError::extract(plugin_path, e))?;
+    log::info!("Moved plugin {:?} to {:?}", plugin_path, target);
+    Ok(target)
+}
+
+fn dialog_in(start_dir: Option<PathBuf>) -> FileDialog {
+    match start_dir {
+        Some(dir) => FileDialog::new().set_directory(dir),
+        None => FileDialog::new(), // OS default until something was picked
+    }
+}
+
+fn decode_percent_escapes(text: &str) -> Option<String> {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    
+    while index < bytes.len() {
+        if bytes[index] == b'%' {
+            let hex = text.get(index + 1..index + 3)?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    
+    String::from_utf8(decoded).ok()
+}
+
+// A relative link inside an extension, as the bundle had it
+#[cfg(unix)]
+fn create_link(target: &Path, link: &Path) -> io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+// Windows tells file and folder links apart, and creating either needs admin rights or Developer Mode
+#[cfg(windows)]
+fn create_link(target: &Path, link: &Path) -> io::Result<()> {
+    let resolved = link.parent().map(|parent| parent.join(target)).unwrap_or_else(|| target.to_path_buf());
+    if resolved.is_dir() { std::os::windows::fs::symlink_dir(target, link) } else { std::os::windows::fs::symlink_file(target, link) }
+}
+
+// st_blocks counts 512-byte units whatever the block size; elsewhere the length stands in
+#[cfg(unix)]
+fn allocated_bytes(metadata: &fs::Metadata) -> u64 {
+    metadata.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn allocated_bytes(metadata: &fs::Metadata) -> u64 {
+    metadata.len()
+}
+
+// Permission bits a packed file keeps; Windows has none to keep or restore
+#[cfg(unix)]
+fn unix_mode(metadata: &fs::Metadata) -> Option<u32> {
+    Some(metadata.permissions().mode())
+}
+
+#[cfg(not(unix))]
+fn unix_mode(_metadata: &fs::Metadata) -> Option<u32> {
+    None
+}
+
+#[cfg(unix)]
+fn set_unix_mode(path: &Path, mode: u32) -> io::Result<()> {
+    fs::set_permissions(path, fs::Permissions::from_mode(mode))
+}
+
+#[cfg(not(unix))]
+fn set_unix_mode(_path: &Path, _mode: u32) -> io::Result<()> {
+    Ok(())
+}
+
+fn copy_directory(source: &Path, target: &Path) -> Result<(), FileOperationError> {
+    copy_tree(source, source, target)
+}
+
+// Links inside the extension are recreated as links; ones leading out of it are copied through
+// when they point at a file and skipped when they point at a folder, which could loop back up
+fn copy_tree(base: &Path, source: &Path, target: &Path) -> Result<(), FileOperationError> {
+    fs::create_dir_all(target).map_err(|e| FileOperationError::extract(target, e))?;
+    
+    for entry in fs::read_dir(source).map_err(|e| FileOperationError::extract(source, e))? {
+        let entry = entry.map_err(|e| FileOperationError::extract(source, e))?;
+        let path = entry.path();
+        let destination = target.join(entry.file_name());
+        let file_type = entry.file_type().map_err(|e| FileOperationError::extract(&path, e))?;
+        // An upgrade replaces links the installed copy has instead of writing through them
+        if destination.symlink_metadata().is_ok_and(|existing| existing.is_symlink() || (file_type.is_symlink() && existing.is_file())) {
+            fs::remove_file(&destination).map_err(|e| FileOperationError::extract(&destination, e))?;
+        }
+        
+        if file_type.is_symlink() {
+            let link = fs::read_link(&path).map_err(|e| FileOperationError::extract(&path, e))?;
+            if resolve_link(base, source, &link, 0).is_some() {
+                create_link(&link, &destination).map_err(|e| FileOperationError::extract(&destination, e))?;
+            } else if path.is_file() {
+                fs::copy(&path, &destination).map_err(|e| FileOperationError::extract(&destination, e))?;
+            } else {
+                log::warn!("Skipped {:?}, a link to {:?} outside the extension", path, link);
+            }
+        } else if file_type.is_dir() {
+            copy_tree(base, &path, &destination)?;
+        } else {
+            // Also copies permission bits
+            fs::copy(&path, &destination).map_err(|e| FileOperationError::extract(&destination, e))?;
+        }
+    }
+    
+    Ok(())
+}
+
+// Read errors name the plugin file, write errors the archive being written
+fn add_directory_to_zip(writer: &mut ZipWriter<fs::File>, base: &Path, dir: &Path, zxp_path: &Path) -> Result<(), FileOperationError> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| FileOperationError::archive(dir, e))?;
+    
+    for entry in entries {
+        let path = entry.map_err(|e| FileOperationError::archive(dir, e))?.path();
+        let mut metadata = fs::symlink_metadata(&path)
+            .map_err(|e| FileOperationError::archive(&path, e))?;
+        
+        // ZIP entries always use forward slashes, relative to the plugin folder
+        let Ok(relative) = path.strip_prefix(base) else {
+            continue; // read_dir only yields children of base
+        };
+        let name = relative.to_string_lossy().replace('\\', "/");
+        
+        // Same rule as copy_tree: links inside stay links, so a restored version keeps them
+        if metadata.is_symlink() {
+            let link = fs::read_link(&path).map_err(|e| FileOperationError::archive(&path, e))?;
+            if resolve_link(base, dir, &link, 0).is_some() {
+                writer.add_symlink(name, link.to_string_lossy(), SimpleFileOptions::default())
+                    .map_err(|e| FileOperationError::archive(zxp_path, zip_io_error(e)))?;
+                continue;
+            }
+            if !path.is_file() {
+                log::warn!("Left {:?} out of the archive, a link to {:?} outside the extension", path, link);
+                continue;
+            }
+            metadata = fs::metadata(&path).map_err(|e| FileOperationError::archive(&path, e))?;
+        }
+        let mut options = SimpleFileOptions::default();
+        if let Some(mode) = unix_mode(&metadata) {
+            options = options.unix_permissions(mode);
+        }
+        
+        if metadata.is_dir() {
+            writer.add_directory(format!("{}/", name), options)
+                .map_err(|e| FileOperationError::archive(zxp_path, zip_io_error(e)))?;
+            add_directory_to_zip(writer, base, &path, zxp_path)?;
+        } else {
+            let content = fs::read(&path)
+                .map_err(|e| FileOperationError::archive(&path, e))?;
+            writer.start_file(name, options)
+                .map_err(|e| FileOperationError::archive(zxp_path, zip_io_error(e)))?;
+            writer.write_all(&content)
+                .map_err(|e| FileOperationError::archive(zxp_path, e))?;
+        }
+    }
+    
+    Ok(())
+}
+
+fn is_valid_zxp_extension(file_path: &Path) -> bool {
+    // Validates file has .zxp extension (case insensitive)
+    file_path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase() == "zxp")
+        .unwrap_or(false)
+}
+
+// "1.2.0 (beta)" -> "1.2.0__beta_"
+fn version_file_stem(version: &str) -> String {
+    version.chars().map(|c| if c.is_ascii_alphanumeric() || c == '.' || c == '-' { c } else { '_' }).collect()
+}
+
+fn unix_seconds(time: std::time::SystemTime) -> Option<i64> {
+    time.duration_since(std::time::UNIX_EPOCH).ok().and_then(|duration| i64::try_from(duration.as_secs()).ok())
+}
+
+fn is_safe_folder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name != "."
+        && name != ".."
+        && !name.contains(['/', '\\', '\0'])
+}
+
+fn read_manifest_from_zip(archive: &mut ZipArchive<fs::File>, zxp_path: &Path) -> Result<PluginInfo, FileOperationError> {
+    let invalid = || FileOperationError::InvalidZip(zxp_path.to_path_buf());
+    read_manifest_text_from_zip(archive, zxp_path).and_then(|xml| parse_manifest_str(&xml).map_err(|_| invalid()))
+}
+
+fn read_manifest_text_from_zip(archive: &mut ZipArchive<fs::File>, zxp_path: &Path) -> Result<String, FileOperationError> {
+    let invalid = || FileOperationError::InvalidZip(zxp_path.to_path_buf());
+
+    // Read CSXS/manifest.xml into memory - nothing touches the disk
+    let manifest_file = archive
+        .by_name("CSXS/manifest.xml")
+        .map_err(|_| invalid())?;
+    if manifest_file.size() > MAX_MANIFEST_SIZE {
+        return Err(invalid());
+    }
+    let mut content = Vec::new();
+    let mut reader = manifest_file.take(MAX_MANIFEST_SIZE);
+    reader.read_to_end(&mut content)
+        .map_err(|_| invalid())?;
+
+    decode_manifest(&content).ok_or_else(invalid)
+}
+
+// Folder the bundle installs into: the bundle ID without a trailing ".panel" part
+fn extension_folder_name(info: &PluginInfo, zxp_path: &Path) -> Result<String, FileOperationError> {
+    let extension_id = info.bundle_id
+        .split(".panel")
+        .next()
+        .unwrap_or(&info.bundle_id)
+        .to_string();
+    
+    // The ID becomes a folder name - a crafted manifest must not point outside the extensions root
+    if !is_safe_folder_name(&extension_id) {
+        return Err(FileOperationError::InvalidExtension(zxp_path.to_path_buf()));
+    }
+    
+    Ok(extension_id)
+}
+
+fn open_zxp(zxp_path: &Path) -> Result<ZipArchive<fs::File>, FileOperationError> {
+    if !zxp_path.exists() {
+        return Err(FileOperationError::FileNotFound(zxp_path.to_path_buf()));
+    }
+    if !is_valid_zxp_extension(zxp_path) {
+        return Err(FileOperationError::InvalidExtension(zxp_path.to_path_buf()));
+    }
+    let file = fs::File::open(zxp_path).map_err(|e| match e.kind() {
+        io::ErrorKind::PermissionDenied => FileOperationError::PermissionDenied(zxp_path.to_path_buf()),
+        _ => FileOperationError::FileNotFound(zxp_path.to_path_buf()),
+    })?;
+    ZipArchive::new(file).map_err(|_| FileOperationError::InvalidZip(zxp_path.to_path_buf()))
+}
+
+fn zip_io_error(error: zip::result::ZipError) -> io::Error {
+    match error {
+        zip::result::ZipError::Io(source) => source,
+        other => io::Error::other(other),
+    }
+}
+
+fn extract_entry(archive: &mut ZipArchive<fs::File>, index: usize, target_dir: &Path) -> Result<(), FileOperationError> {
+    let zip_error = |e: zip::result::ZipError| match e {
+        zip::result::ZipError::Io(source) => FileOperationError::extract(target_dir, source),
+        other => FileOperationError::ExtractError { path: target_dir.to_path_buf(), source: io::Error::other(other) },
+    };
+    let unsafe_entry = |name: &str| FileOperationError::ExtractError {
+        path: target_dir.join(name),
+        source: io::Error::new(io::ErrorKind::InvalidData, "archive entry points outside the extension folder"),
+    };
+
+    let mut entry = archive.by_index(index).map_err(zip_error)?;
+    let relative = entry.enclosed_name().ok_or_else(|| unsafe_entry(entry.name()))?;
+    let path = target_dir.join(&relative);
+    // Earlier link entries may stand in for folders on the way; every folder must resolve inside
+    if entry.is_dir() {
+        return create_folder_inside(target_dir, &relative).ok_or_else(|| unsafe_entry(entry.name()))?.map(|_| ());
+    }
+    let (Some(parent), Some(_)) = (relative.parent(), relative.file_name()) else {
+        return Err(unsafe_entry(entry.name()));
+    };
+    let real_parent = create_folder_inside(target_dir, parent).ok_or_else(|| unsafe_entry(entry.name()))??;
+    // Written in place of a link left by an older version, never through it
+    if path.symlink_metadata().is_ok_and(|metadata| metadata.is_symlink()) {
+        fs::remove_file(&path).map_err(|e| FileOperationError::extract(&path, e))?;
+    }
+
+    if entry.is_symlink() {
+        // The entry's content is the link target, a short relative path in well-formed bundles
+        let mut link = String::new();
+        entry.by_ref().take(MAX_MANIFEST_SIZE).read_to_string(&mut link).map_err(|e| FileOperationError::extract(&path, e))?;
+        if resolve_link(target_dir, &real_parent, Path::new(&link), 0).is_none() {
+            return Err(unsafe_entry(entry.name()));
+        }
+        if path.symlink_metadata().is_ok() {
+            fs::remove_file(&path).map_err(|e| FileOperationError::extract(&path, e))?;
+        }
+        return create_link(Path::new(&link), &path).map_err(|e| FileOperationError::extract(&path, e));
+    }
+
+    let file = fs::File::create(&path).map_err(|e| FileOperationError::extract(&path, e))?;
+    let mut writer = BufWriter::with_capacity(EXTRACT_BUFFER, file);
+    io::copy(&mut entry, &mut writer)
+        .and_then(|_| writer.flush())
+        .map_err(|e| FileOperationError::extract(&path, e))?;
+    // ZIPs made on Windows carry no mode; setuid and setgid bits are never restored
+    if let Some(mode) = entry.unix_mode() {
+        set_unix_mode(&path, mode & 0o777).map_err(|e| FileOperationError::extract(&path, e))?;
+    }
+    Ok(())
+}
+
+// Sizes of the regular files below dir, keyed by their path relative to base; links and unreadable
+// folders are left out
+fn collect_file_sizes(base: &Path, dir: &Path, sizes: &mut BTreeMap<PathBuf, u64>) {
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let path = entry.path();
+        match fs::symlink_metadata(&path) {
+            Ok(metadata) if metadata.is_dir() => collect_file_sizes(base, &path, sizes),
+            Ok(metadata) if metadata.is_file() => {
+                if let Ok(relative) = path.strip_prefix(base) {
+                    sizes.insert(relative.to_path_buf(), metadata.len());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// Sorts a path so folders come before the files next to them: every component but a file's last
+// one is a folder
+fn tree_key(path: &Path, is_dir: bool) -> Vec<(bool, String)> {
+    let count = path.components().count();
+    path.components()
+        .enumerate()
+        .map(|(index, component)| (index + 1 == count && !is_dir, component.as_os_str().to_string_lossy().to_string()))
+        .collect()
+}
+
+// Creates `relative` below root one folder at a time and returns its real path. None when a
+// folder on the way is a link leading out of root; creation errors come back as Some(Err)
+fn create_folder_inside(root: &Path, relative: &Path) -> Option<Result<PathBuf, FileOperationError>> {
+    let mut current = root.to_path_buf();
+    for component in relative.components() {
+        let next = current.join(component);
+        if next.symlink_metadata().is_err()
+            && let Err(e) = fs::create_dir(&next)
+        {
+            return Some(Err(FileOperationError::extract(&next, e)));
+        }
+        current = fs::canonicalize(&next).ok().filter(|real| real.starts_with(root) && real.is_dir())?;
+    }
+    Some(Ok(current))
+}
+
+// Where a link in `folder` pointing at `target` leads, following the links already on disk; None
+// when any step leaves root. ".." only climbs out of folders that exist, so a link created later
+// can't move an accepted one
+fn resolve_link(root: &Path, folder: &Path, target: &Path, hops: usize) -> Option<PathBuf> {
+    use std::path::Component;
+    if hops > MAX_LINK_HOPS {
+        return None;
+    }
+    let mut current = folder.to_path_buf();
+    for component in target.components() {
+        match component {
+            Component::Normal(name) => {
+                let next = current.join(name);
+                current = match fs::read_link(&next) {
+                    Ok(link) => resolve_link(root, &current, &link, hops + 1)?,
+                    Err(_) => next,
+                };
+            }
+            Component::ParentDir if current != root && current.is_dir() => {
+                current.pop();
+            }
+            Component::CurDir => {}
+            Component::ParentDir | Component::RootDir | Component::Prefix(_) => return None,
+        }
+        if !current.starts_with(root) {
+            return None;
+        }
+    }
+    Some(current)
+}
+
+fn file_crc32(path: &Path) -> io::Result<u32> {
+    let mut hasher = crc32fast::Hasher::new();
+    read_chunks(path, |chunk| hasher.update(chunk))?;
+    Ok(hasher.finalize())
+}
+
+fn read_chunks(path: &Path, mut consume: impl FnMut(&[u8])) -> io::Result<()> {
+    let mut file = fs::File::open(path)?;
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            return Ok(());
+        }
+        consume(&buffer[..read]);
+    }
+}
+
+fn path_message(key: &str, path: &Path) -> String {
+    t_args(key, &[("path", &path.display().to_string())])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    
+    fn temp_path(extension: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!("zxpm-test-{}-{}.{}", std::process::id(), id, extension))
+    }
+    
+    fn build_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer.start_file(*name, SimpleFileOptions::default()).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+    
+    fn manifest(bundle_id: &str) -> Vec<u8> {
+        format!(r#"<ExtensionManifest ExtensionBundleId="{}" ExtensionBundleVersion="1.0"/>"#, bundle_id).into_bytes()
+    }
+    
+    fn extension_id_of(zip_bytes: &[u8]) -> Result<String, FileOperationError> {
+        let zip_path = temp_path("zxp");
+        fs::write(&zip_path, zip_bytes).unwrap();
+        
+        let result = ZipArchive::new(fs::File::open(&zip_path).unwrap())
+            .map_err(|_| FileOperationError::InvalidZip(zip_path.clone()))
+            .and_then(|mut archive| read_manifest_from_zip(&mut archive, &zip_path))
+            .and_then(|info| extension_folder_name(&info, &zip_path));
+        let _ = fs::remove_file(&zip_path);
+        result
+    }
+    
+    #[test]
+    fn test_host_cache_entry_matching() {
+        let premiere = ["PPRO"];
+        assert!(belongs_to_host("PPRO_24.0.0_com.vendor.panel", &premiere));
+        assert!(belongs_to_host("CEP12-PPRO.log", &premiere));
+        assert!(belongs_to_host("CEPHtmlEngine12-PPRO-24.0.0-com.vendor.panel.log", &premiere));
+        assert!(!belongs_to_host("PHXS_25.0.0_com.vendor.panel", &premiere));
+        assert!(!belongs_to_host("com.vendor.PPRO_helper", &premiere));
+    }
+
+    #[test]
+    fn test_orphaned_cache_entry_matching() {
+        assert_eq!(cached_extension_id("PPRO_24.0.0_com.vendor.panel_v2"), Some("com.vendor.panel_v2"));
+        assert_eq!(cached_extension_id("CEPHtmlEngine12-PPRO-24.0.0-com.vendor.my-panel.log"), Some("com.vendor.my-panel"));
+        assert_eq!(cached_extension_id("CEP12-PPRO.log"), None);
+        assert_eq!(cached_extension_id(".DS_Store"), None);
+        assert_eq!(stored_extension_id("com.vendor.panel"), Some("com.vendor.panel"));
+        assert_eq!(stored_extension_id("extensions-disabled"), None);
+        assert_eq!(stored_extension_id(".DS_Store"), None);
+        assert_eq!(stored_extension_id("Adobe"), None);
+        assert_eq!(stored_extension_id(".."), None);
+        assert_eq!(stored_extension_id("com.example/../.."), None);
+        assert_eq!(stored_extension_id("com..example"), None);
+        
+        let installed = HashSet::from(["com.vendor.panel".to_string()]);
+        assert!(!is_orphaned("com.vendor.panel", &installed));
+        assert!(is_orphaned("com.gone.panel", &installed));
+        assert!(!is_orphaned("com.adobe.ccx.start", &installed));
+    }
+    
+    #[test]
+    fn test_free_space_guard() {
+        // Works for destinations that don't exist yet
+        let destination = std::env::temp_dir().join("zxpm-not-created").join("com.example.tools");
+        assert!(available_space(&destination).is_some());
+
+        assert!(ensure_free_space(&destination, 0).is_ok());
+        assert!(matches!(
+            ensure_free_space(&destination, u64::MAX / 2),
+            Err(FileOperationError::InsufficientSpace { .. })
+        ));
+    }
+
+    #[test]
+    fn test_zxp_extension_validation() {
+        assert!(is_valid_zxp_extension(&PathBuf::from("test.zxp")));
+        assert!(is_valid_zxp_extension(&PathBuf::from("test.ZXP")));
+        assert!(!is_valid_zxp_extension(&PathBuf::from("test.zip")));
+        assert!(!is_valid_zxp_extension(&PathBuf::from("test")));
+    }
+
+    #[test]
+    fn test_errors_carry_path_and_source() {
+        let system = Path::new(SYSTEM_EXTENSIONS_PATH).join("com.foo");
+        let denied = FileOperationError::extract(&system, io::Error::from(io::ErrorKind::PermissionDenied));
+        assert!(matches!(&denied, FileOperationError::PermissionDenied(path) if *path == system));
+        let message = denied.to_string();
+        assert!(message.contains("com.foo") && message.contains("try installing to the user folder"));
+
+        let failed = FileOperationError::archive(Path::new("/tmp/out.zxp"), io::Error::other("disk full"));
+        assert!(failed.to_string().contains("/tmp/out.zxp: disk full"));
+        assert_eq!(std::error::Error::source(&failed).map(|e| e.to_string()).as_deref(), Some("disk full"));
+    }
+
+    #[test]
+    fn test_parse_pasted_path() {
+        assert_eq!(parse_pasted_path("  /tmp/a.zxp\n"), Some(PathBuf::from("/tmp/a.zxp")));
+        assert_eq!(parse_pasted_path("\"/tmp/my plugin.zxp\""), Some(PathBuf::from("/tmp/my plugin.zxp")));
+        assert_eq!(parse_pasted_path("file:///tmp/my%20plugin.zxp"), Some(PathBuf::from("/tmp/my plugin.zxp")));
+        assert_eq!(parse_pasted_path("file:///tmp/bad%2"), None);
+        assert_eq!(parse_pasted_path("   "), None);
+    }
+    
+    #[test]
+    fn test_zxp_paths_from_text() {
+        let text = "/tmp/a.zxp\nnot a plugin\n\nfile:///tmp/b%20c.ZXP\n/tmp/readme.txt";
+        assert_eq!(zxp_paths_from_text(text), vec![PathBuf::from("/tmp/a.zxp"), PathBuf::from("/tmp/b c.ZXP")]);
+    }
+    
+    #[test]
+    fn test_extension_id_from_zip() {
+        let zip = build_zip(&[("CSXS/manifest.xml", &manifest("com.example.tools.panel"))]);
+        assert_eq!(extension_id_of(&zip).unwrap(), "com.example.tools");
+    }
+
+    #[test]
+    fn test_install_reports_manifest_name_and_version() {
+        let manifest = br#"<ExtensionManifest ExtensionBundleId="com.example.tools" ExtensionBundleName="Tools" ExtensionBundleVersion="2.1.0"/>"#;
+        let zip_path = temp_path("zxp");
+        fs::write(&zip_path, build_zip(&[("CSXS/manifest.xml", manifest)])).unwrap();
+        let root = temp_path("root");
+
+        let installed = install_zxp_into(&zip_path, &root).unwrap();
+        assert_eq!(installed.path, root.join("com.example.tools"));
+        assert_eq!((installed.info.name.as_str(), installed.info.version.as_str()), ("Tools", "2.1.0"));
+
+        let _ = fs::remove_file(&zip_path);
+        let _ = fs::remove_dir_all(&root);
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_folder_install_upgrades_in_place() {
+        let base = temp_path("dir");
+        let (source, root) = (base.join("Tools Panel"), base.join("root"));
+        fs::create_dir_all(source.join("CSXS")).unwrap();
+        fs::write(source.join("CSXS").join("manifest.xml"), manifest("com.example.folder.panel")).unwrap();
+        fs::write(source.join("index.html"), "v1").unwrap();
+        std::os::unix::fs::symlink("index.html", source.join("start.html")).unwrap();
+        std::os::unix::fs::symlink("..", source.join("parent")).unwrap(); // Would copy the folder into itself
+
+        let installed = install_folder_into(&source, &root).unwrap();
+        assert_eq!(installed.path, root.join("com.example.folder")); // Named by bundle ID, like a ZXP
+        assert_eq!(fs::read_link(installed.path.join("start.html")).unwrap(), PathBuf::from("index.html"));
+        assert!(!installed.path.join("parent").exists());
+        fs::write(source.join("index.html"), "v2").unwrap();
+        install_folder_into(&source, &root).unwrap();
+        assert_eq!(fs::read_to_string(installed.path.join("index.html")).unwrap(), "v2");
+
+        assert!(matches!(install_folder_into(&installed.path, &root), Err(FileOperationError::AlreadyInstalled(_))));
+        assert!(matches!(install_folder_into(&base, &root), Err(FileOperationError::InvalidExtension(_))));
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_archived_version_restores_over_the_upgrade() {
+        let base = temp_path("history");
+        let (plugin, history) = (base.join("com.example.rollback"), base.join("history"));
+        let write_version = |version: &str, file: &str| {
+            let _ = fs::remove_dir_all(&plugin);
+            fs::create_dir_all(plugin.join("CSXS")).unwrap();
+            let manifest = format!(r#"<ExtensionManifest ExtensionBundleId="com.example.rollback" ExtensionBundleVersion="{}"/>"#, version);
+            fs::write(plugin.join("CSXS").join("manifest.xml"), manifest).unwrap();
+            fs::write(plugin.join(file), version).unwrap();
+        };
+
+        write_version("1.0 beta", "old.js");
+        fs::write(plugin.join("helper"), "#!/bin/sh").unwrap();
+        fs::set_permissions(plugin.join("helper"), fs::Permissions::from_mode(0o755)).unwrap();
+        std::os::unix::fs::symlink("helper", plugin.join("helper-latest")).unwrap();
+        let archive = archive_version_into(&plugin, &history).unwrap();
+        assert_eq!(archive, history.join("com.example.rollback").join("1.0_beta.zxp"));
+        write_version("2.0", "new.js");
+
+        let versions = archived_versions_in(&history, "com.example.rollback");
+        assert_eq!(versions.iter().map(|version| version.version.as_str()).collect::<Vec<_>>(), ["1.0 beta"]);
+        let reverted = revert_to_version(&plugin, &versions[0].path).unwrap();
+        assert_eq!(reverted.info.version, "1.0 beta");
+        assert!(plugin.join("old.js").is_file() && !plugin.join("new.js").exists());
+        assert_eq!(fs::metadata(plugin.join("helper")).unwrap().permissions().mode() & 0o777, 0o755);
+        assert_eq!(fs::read_link(plugin.join("helper-latest")).unwrap(), PathBuf::from("helper"));
+
+        // An archive that fails halfway through extraction leaves the installed version as it was
+        let broken = history.join("com.example.rollback").join("0.9.zxp");
+        let manifest = br#"<ExtensionManifest ExtensionBundleId="com.example.rollback" ExtensionBundleVersion="0.9"/>"#;
+        fs::write(&broken, build_zip(&[("CSXS/manifest.xml", manifest), ("first.js", b"x"), ("../escape.js", b"x")])).unwrap();
+        assert!(revert_to_version(&plugin, &broken).is_err());
+        assert!(plugin.join("old.js").is_file() && plugin.join("CSXS").join("manifest.xml").is_file());
+        let leftovers = fs::read_dir(&base).unwrap().flatten().filter(|entry| entry.file_name().to_string_lossy().starts_with('.')).count();
+        assert_eq!(leftovers, 0);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_verification_finds_changed_and_missing_files() {
+        let zip = build_zip(&[
+            ("CSXS/manifest.xml", &manifest("com.example.verify")),
+            ("client/index.html", b"<html></html>"),
+            ("client/main.js", b"run()"),
+        ]);
+        let zip_path = temp_path("zxp");
+        let target = temp_path("dir");
+        fs::write(&zip_path, &zip).unwrap();
+        let mut archive = ZipArchive::new(fs::File::open(&zip_path).unwrap()).unwrap();
+        extract_archive(&mut archive, &target).unwrap();
+        assert!(verify_extraction(&mut archive, &target).is_empty());
+
+        fs::write(target.join("client/index.html"), "<html>tampered</html>").unwrap();
+        fs::remove_file(target.join("client/main.js")).unwrap();
+        let corrupted = verify_extraction(&mut archive, &target);
+        assert_eq!(corrupted, [PathBuf::from("client/index.html"), PathBuf::from("client/main.js")]);
+        assert!(FileOperationError::CorruptedInstall { path: target.clone(), files: corrupted }.to_string().ends_with("(ZXPM-1054)"));
+
+        let _ = fs::remove_file(&zip_path);
+        let _ = fs::remove_dir_all(&target);
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn test_extraction_keeps_modes_and_refuses_escaping_links() {
+        let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        writer.start_file("CSXS/manifest.xml", SimpleFileOptions::default()).unwrap();
+        writer.write_all(&manifest("com.example.modes")).unwrap();
+        writer.start_file("bin/ffmpeg", SimpleFileOptions::default().unix_permissions(0o755)).unwrap();
+        writer.write_all(&vec![7u8; 3 * EXTRACT_BUFFER]).unwrap();
+        writer.add_symlink("bin/current", "ffmpeg", SimpleFileOptions::default()).unwrap();
+        let zip = writer.finish().unwrap().into_inner();
+
+        let zip_path = temp_path("zxp");
+        let target = temp_path("dir");
+        fs::write(&zip_path, &zip).unwrap();
+        let mut archive = ZipArchive::new(fs::File::open(&zip_path).unwrap()).unwrap();
+        extract_archive(&mut archive, &target).unwrap();
+        let binary = target.join("bin/ffmpeg");
+        assert_eq!(fs::metadata(&binary).unwrap().permissions().mode() & 0o777, 0o755);
+        assert_eq!(fs::metadata(&binary).unwrap().len(), 3 * EXTRACT_BUFFER as u64);
+        assert_eq!(fs::read_link(target.join("bin/current")).unwrap(), PathBuf::from("ffmpeg"));
+        assert!(verify_extraction(&mut archive, &target).is_empty());
+        assert_eq!(extraction_progress(), None);
+
+        let bin = target.join("bin");
+        assert_eq!(resolve_link(&target, &bin, Path::new("../CSXS/manifest.xml"), 0), Some(target.join("CSXS/manifest.xml")));
+        assert_eq!(resolve_link(&target, &bin, Path::new("current/../ffmpeg"), 0), None); // ".." out of a file
+        assert_eq!(resolve_link(&target, &bin, Path::new("../../etc/passwd"), 0), None);
+        assert_eq!(resolve_link(&target, &bin, Path::new("/usr/bin/node"), 0), None);
+
+        let _ = fs::remove_file(&zip_path);
+        let _ = fs::remove_dir_all(&target);
+    }
+
+    #[test]
+    fn test_sha256_and_published_checksums() {
+        let path = temp_path("zxp");
+        fs::write(&path, "abc").unwrap();
+        let expected = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        assert_eq!(file_sha256(&path).unwrap(), expected);
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(parse_checksum(&format!("  {}  tools.zxp\n", expected.to_uppercase())).as_deref(), Some(expected));
+        assert_eq!(parse_checksum("d41d8cd98f00b204e9800998ecf8427e"), None); // MD5 length
+        assert_eq!(parse_checksum(""), None);
+    }
+    
+    #[test]
+    fn test_path_traversal_bundle_ids_are_rejected() {
+        for bundle_id in ["..", "../../etc", "a/b", "a\\b"] {
+            let zip = build_zip(&[("CSXS/manifest.xml", &manifest(bundle_id))]);
+            assert!(matches!(extension_id_of(&zip), Err(FileOperationError::InvalidExtension(_))), "{}", bundle_id);
+        }
+    }
+    
+    #[test]
+    fn test_nested_manifest_is_not_found() {
+        let nested = format!("{}CSXS/manifest.xml", "deep/".repeat(500));
+        let zip = build_zip(&[(&nested, &manifest("com.example.deep"))]);
+        assert!(matches!(extension_id_of(&zip), Err(FileOperationError::InvalidZip(_))));
+    }
+    
+    #[test]
+    fn test_oversized_manifest_is_rejected() {
+        let padding = " ".repeat(MAX_MANIFEST_SIZE as usize + 1);
+        let content = [manifest("com.example.big"), padding.into_bytes()].concat();
+        let zip = build_zip(&[("CSXS/manifest.xml", &content)]);
+        assert!(matches!(extension_id_of(&zip), Err(FileOperationError::InvalidZip(_))));
+    }
+    
+    #[test]
+    fn test_preview_lists_files_and_overwrites() {
+        let zip = build_zip(&[
+            ("CSXS/manifest.xml", &manifest("com.example.preview")),
+            ("client/", b""),
+            ("client/index.html", b"<html></html>"),
+            ("../escape.txt", b"x"),
+        ]);
+        let zip_path = temp_path("zxp");
+        let root = temp_path("dir");
+        fs::write(&zip_path, &zip).unwrap();
+        fs::create_dir_all(root.join("com.example.preview").join("client")).unwrap();
+        fs::write(root.join("com.example.preview").join("client").join("index.html"), "old").unwrap();
+
+        let preview = preview_install_into(&zip_path, &root).unwrap();
+
+        assert_eq!(preview.destination, root.join("com.example.preview"));
+        let paths: Vec<&Path> = preview.files.iter().map(|file| file.path.as_path()).collect();
+        assert_eq!(paths, [Path::new("CSXS/manifest.xml"), Path::new("client/index.html")]);
+        assert_eq!(preview.overwrite_count(), 1);
+        assert_eq!(preview.total_size(), manifest("com.example.preview").len() as u64 + 13);
+        assert!(!root.join("com.example.preview").join("CSXS").exists()); // Nothing was written
+        let _ = fs::remove_file(&zip_path);
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_inspection_tree_lists_folders_first() {
+        let zip = build_zip(&[
+            ("CSXS/manifest.xml", &manifest("com.example.inspect")),
+            ("index.html", b"<html></html>"),
+            ("client/lib/a.js", b"aa"),
+            ("client/b.js", b"bbb"),
+            ("../escape.txt", b"x"),
+        ]);
+        let zip_path = temp_path("zxp");
+        fs::write(&zip_path, &zip).unwrap();
+
+        let inspection = inspect_zxp(&zip_path).unwrap();
+        assert_eq!(inspection.info.bundle_id, "com.example.inspect");
+        assert!(!inspection.signed && inspection.signer.is_none());
+        assert_eq!((inspection.unsafe_entries, inspection.file_count()), (1, 4));
+        let tree: Vec<(String, usize, u64)> = inspection.tree().iter().map(|row| (row.name(), row.depth, row.size)).collect();
+        let manifest_size = manifest("com.example.inspect").len() as u64;
+        assert_eq!(
+            tree,
+            [
+                ("CSXS".to_string(), 0, manifest_size),
+                ("manifest.xml".to_string(), 1, manifest_size),
+                ("client".to_string(), 0, 5),
+                ("lib".to_string(), 1, 2),
+                ("a.js".to_string(), 2, 2),
+                ("b.js".to_string(), 1, 3),
+                ("index.html".to_string(), 0, 13),
+            ]
+        );
+        let _ = fs::remove_file(&zip_path);
+    }
+
+    #[test]
+    fn test_comparison_finds_added_removed_and_changed_files() {
+        let zip = build_zip(&[
+            ("CSXS/manifest.xml", &manifest("com.example.compare")),
+            ("index.html", b"<html>new</html>"),
+            ("main.js", b"run()"),
+            ("extra.js", b"more()"),
+            ("META-INF/signatures.xml", b"<Signatures/>"),
+        ]);
+        let zip_path = temp_path("zxp");
+        let installed = temp_path("dir");
+        fs::write(&zip_path, &zip).unwrap();
+        fs::create_dir_all(installed.join("CSXS")).unwrap();
+        fs::write(installed.join("CSXS").join("manifest.xml"), manifest("com.example.compare")).unwrap();
+        fs::write(installed.join("index.html"), "<html>old</html>").unwrap(); // Same size, other content
+        fs::write(installed.join("main.js"), "run()").unwrap();
+        fs::write(installed.join("legacy.js"), "old()").unwrap();
+
+        let comparison = compare_zxp_with_folder(&zip_path, &installed).unwrap();
+        let changes: Vec<(&str, ChangeKind)> =
+            comparison.changes.iter().map(|change| (change.path.to_str().unwrap(), change.kind)).collect();
+        assert_eq!(
+            changes,
+            [
+                ("META-INF/signatures.xml", ChangeKind::Added),
+                ("extra.js", ChangeKind::Added),
+                ("index.html", ChangeKind::Changed),
+                ("legacy.js", ChangeKind::Removed),
+            ]
+        );
+        assert_eq!(comparison.unchanged, 2);
+        assert!(!comparison.changes_only_packaging());
+
+        fs::write(installed.join("index.html"), "<html>new</html>").unwrap();
+        fs::write(installed.join("extra.js"), "more()").unwrap();
+        fs::remove_file(installed.join("legacy.js")).unwrap();
+        let comparison = compare_zxp_with_folder(&zip_path, &installed).unwrap();
+        assert_eq!(comparison.count(ChangeKind::Added), 1);
+        assert!(comparison.changes_only_packaging()); // Re-signed, nothing else differs
+        let _ = fs::remove_file(&zip_path);
+        let _ = fs::remove_dir_all(&installed);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_unwritable_folder_checks_container_and_target() {
+        let base = temp_path("dir");
+        let missing = base.join("Adobe").join("CEP").join("extensions").join("com.example.panel");
+        fs::create_dir_all(&base).unwrap();
+        assert_eq!(unwritable_folder(&missing), None); // Nearest existing folder takes writes
+        assert!(can_write_in(&base));
+
+        let installed = base.join("com.example.locked");
+        fs::create_dir_all(&installed).unwrap();
+        fs::set_permissions(&installed, fs::Permissions::from_mode(0o555)).unwrap();
+        // Root ignores permission bits, so only check the refusal where they apply
+        if !can_write_in(&installed) {
+            assert_eq!(unwritable_folder(&installed), Some(installed.clone()));
+        }
+        fs::set_permissions(&installed, fs::Permissions::from_mode(0o755)).unwrap();
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_disable_and_enable_move_between_roots() {
+        let base = temp_path("dir");
+        let plugin = base.join(EXTENSIONS_FOLDER_NAME).join("com.example.toggle");
+        fs::create_dir_all(plugin.join("CSXS")).unwrap();
+
+        let disabled = disable_plugin(&plugin).unwrap();
+        assert_eq!(disabled, base.join(crate::data_operations::DISABLED_FOLDER_NAME).join("com.example.toggle"));
+        assert!(!plugin.exists() && disabled.join("CSXS").is_dir());
+
+        fs::create_dir_all(&plugin).unwrap(); // Reinstalled meanwhile - enabling must not replace it
+        assert!(matches!(enable_plugin(&disabled), Err(FileOperationError::AlreadyInstalled(_))));
+        fs::remove_dir(&plugin).unwrap();
+        assert_eq!(enable_plugin(&disabled).unwrap(), plugin);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_dev_folder_link_and_unlink() {
+        let base = temp_path("dir");
+        let (source, root) = (base.join("work").join("my-panel"), base.join(EXTENSIONS_FOLDER_NAME));
+        fs::create_dir_all(source.join("CSXS")).unwrap();
+        fs::write(source.join("CSXS").join("manifest.xml"), manifest("com.example.dev")).unwrap();
+
+        let link = link_dev_folder_into(&source, &root).unwrap();
+        assert_eq!(link, root.join("my-panel"));
+        assert!(matches!(link_dev_folder_into(&source, &root), Err(FileOperationError::AlreadyInstalled(_))));
+        let plugins = crate::data_operations::scan_extensions_root(&root).unwrap();
+        assert_eq!(plugins[0].plugin_type, PluginType::Dev);
+
+        remove_plugin(&link).unwrap(); // Removing a dev plugin unlinks it
+        assert!(link.symlink_metadata().is_err() && source.join("CSXS").join("manifest.xml").is_file());
+        assert!(matches!(unlink_dev_folder(&source), Err(FileOperationError::InvalidExtension(_))));
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_install_over_dev_link_leaves_working_folder_alone() {
+        let base = temp_path("dir");
+        let (source, root) = (base.join("work").join("com.example.dev"), base.join(EXTENSIONS_FOLDER_NAME));
+        fs::create_dir_all(source.join("CSXS")).unwrap();
+        fs::write(source.join("CSXS").join("manifest.xml"), manifest("com.example.dev")).unwrap();
+        fs::write(source.join("index.html"), "working copy").unwrap();
+        let zip_path = temp_path("zxp");
+        fs::write(&zip_path, build_zip(&[("CSXS/manifest.xml", &manifest("com.example.dev")), ("index.html", b"packaged")])).unwrap();
+
+        let link = link_dev_folder_into(&source, &root).unwrap();
+        let installed = install_zxp_into(&zip_path, &root).unwrap();
+        assert_eq!(installed.path, link);
+        assert!(!is_dev_link(&link) && fs::read_to_string(link.join("index.html")).unwrap() == "packaged");
+        assert_eq!(fs::read_to_string(source.join("index.html")).unwrap(), "working copy");
+
+        let link = root.join("com.example.dev");
+        fs::remove_dir_all(&link).unwrap();
+        std::os::unix::fs::symlink(&source, &link).unwrap();
+        let copy = base.join("copy");
+        copy_directory(&source, &copy).unwrap();
+        fs::write(copy.join("index.html"), "copied").unwrap();
+        install_folder_into(&copy, &root).unwrap();
+        assert!(!is_dev_link(&link) && fs::read_to_string(link.join("index.html")).unwrap() == "copied");
+        assert_eq!(fs::read_to_string(source.join("index.html")).unwrap(), "working copy");
+        let _ = fs::remove_file(&zip_path);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_failed_folder_install_leaves_nothing_behind() {
+        let base = temp_path("dir");
+        let (source, root) = (base.join("com.example.partial"), base.join(EXTENSIONS_FOLDER_NAME));
+        fs::create_dir_all(source.join("CSXS")).unwrap();
+        fs::write(source.join("CSXS").join("manifest.xml"), manifest("com.example.partial")).unwrap();
+        fs::write(source.join("index.html"), "panel").unwrap();
+        // A socket can't be opened for copying, even by root
+        let _listener = std::os::unix::net::UnixListener::bind(source.join("zz.sock")).unwrap();
+
+        assert!(install_folder_into(&source, &root).is_err());
+        assert!(!root.join("com.example.partial").exists());
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_extract_skips_entries_outside_target() {
+        let zip = build_zip(&[("CSXS/manifest.xml", &manifest("com.example.escape")), ("../escape.txt", b"x")]);
+        let zip_path = temp_path("zxp");
+        let target = temp_path("dir");
+        fs::write(&zip_path, &zip).unwrap();
+        
+        let mut archive = ZipArchive::new(fs::File::open(&zip_path).unwrap()).unwrap();
+        assert!(extract_archive(&mut archive, &target).is_err());
+        assert!(!target.parent().unwrap().join("escape.txt").exists());
+        
+        // A link to "." makes "here/up" sit at the top, one folder higher than its name says
+        let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        writer.add_symlink("here", ".", SimpleFileOptions::default()).unwrap();
+        writer.add_symlink("here/up", "../escape.txt", SimpleFileOptions::default()).unwrap();
+        fs::write(&zip_path, writer.finish().unwrap().into_inner()).unwrap();
+        let mut archive = ZipArchive::new(fs::File::open(&zip_path).unwrap()).unwrap();
+        assert!(extract_archive(&mut archive, &target).is_err());
+        assert!(target.join("here").is_symlink() && !target.join("up").exists());
+
+        // Chained through a link already on disk: b/c/../.. reads as the top, but b is the top itself
+        for (entries, escaped) in [
+            (vec![("b", "."), ("a", "b/c/../..")], "a"),
+            (vec![("b", "x"), ("a", "b/../.."), ("x", ".")], "a"), // ".." out of b before b exists
+        ] {
+            let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+            writer.add_directory("c/", SimpleFileOptions::default()).unwrap(); // So "b/c" is a real folder
+            for (name, link) in entries {
+                writer.add_symlink(name, link, SimpleFileOptions::default()).unwrap();
+            }
+            writer.add_directory("a/escaped-folder/", SimpleFileOptions::default()).unwrap();
+            fs::write(&zip_path, writer.finish().unwrap().into_inner()).unwrap();
+            let _ = fs::remove_dir_all(&target);
+            let mut archive = ZipArchive::new(fs::File::open(&zip_path).unwrap()).unwrap();
+            assert!(extract_archive(&mut archive, &target).is_err());
+            assert!(target.join(escaped).symlink_metadata().is_err());
+        }
+
+        // A folder entry below a link that leads out is refused before anything is created
+        let outside = temp_path("dir");
+        fs::create_dir_all(&outside).unwrap();
+        let mut writer = ZipWriter::new(std::io::Cursor::new(Vec::new()));
+        writer.add_directory("lib/", SimpleFileOptions::default()).unwrap();
+        writer.add_directory("lib/escaped/", SimpleFileOptions::default()).unwrap();
+        fs::write(&zip_path, writer.finish().unwrap().into_inner()).unwrap();
+        let _ = fs::remove_dir_all(&target);
+        fs::create_dir_all(&target).unwrap();
+        std::os::unix::fs::symlink(&outside, target.join("lib")).unwrap(); // Left behind by an earlier, older check
+        let mut archive = ZipArchive::new(fs::File::open(&zip_path).unwrap()).unwrap();
+        assert!(extract_archive(&mut archive, &target).is_err());
+        assert!(!outside.join("escaped").exists());
+        let _ = fs::remove_dir_all(&outside);
+        let _ = fs::remove_file(&zip_path);
+        let _ = fs::remove_dir_all(&target);
+    }
+    
+    proptest! {
+        #[test]
+        fn prop_truncated_archives_never_panic(cut in 0usize..400) {
+            let zip = build_zip(&[("CSXS/manifest.xml", &manifest("com.example.truncated"))]);
+            let _ = extension_id_of(&zip[..cut.min(zip.len())]);
+        }
+        
+        #[test]
+        fn prop_arbitrary_archives_never_panic(bytes in proptest::collection::vec(any::<u8>(), 0..1024)) {
+            let _ = extension_id_of(&bytes);
+        }
+        
+        #[test]
+        fn prop_arbitrary_manifest_content_never_panics(content in proptest::collection::vec(any::<u8>(), 0..1024)) {
+            let zip = build_zip(&[("CSXS/manifest.xml", &content)]);
+            let _ = extension_id_of(&zip);
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,510 @@
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use std::fs;
+use std::path::{Path, PathBuf};
+use sysinfo::{Pid, Process, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+use crate::data_operations::{HostRequirement, UiKind};
+
+// Where Creative Cloud puts the apps
+const MAC_APPLICATIONS: &str = "/Applications";
+const WINDOWS_PROGRAM_FILES: &str = "C:\\Program Files\\Adobe";
+
+// CEP host codes used in manifests' <Host Name="..."> and the app names they belong to
+const HOST_APPS: [(&str, &str); 13] = [
+    ("PHXS", "Adobe Photoshop"),
+    ("ILST", "Adobe Illustrator"),
+    ("IDSN", "Adobe InDesign"),
+    ("AICY", "Adobe InCopy"),
+    ("AEFT", "Adobe After Effects"),
+    ("PPRO", "Adobe Premiere Pro"),
+    ("RUSH", "Adobe Premiere Rush"),
+    ("AUDT", "Adobe Audition"),
+    ("FLPR", "Adobe Animate"),
+    ("DRWV", "Adobe Dreamweaver"),
+    ("KBRG", "Adobe Bridge"),
+    ("AME", "Adobe Media Encoder"),
+    ("CHAR", "Adobe Character Animator"),
+];
+
+// Windows executable names - they don't follow the folder name, and some live under "Support Files"
+const WINDOWS_EXECUTABLES: [(&str, &str); 13] = [
+    ("PHXS", "Photoshop.exe"),
+    ("ILST", "Support Files\\Contents\\Windows\\Illustrator.exe"),
+    ("IDSN", "InDesign.exe"),
+    ("AICY", "InCopy.exe"),
+    ("AEFT", "Support Files\\AfterFX.exe"),
+    ("PPRO", "Adobe Premiere Pro.exe"),
+    ("RUSH", "Adobe Premiere Rush.exe"),
+    ("AUDT", "Adobe Audition.exe"),
+    ("FLPR", "Animate.exe"),
+    ("DRWV", "Dreamweaver.exe"),
+    ("KBRG", "Adobe Bridge.exe"),
+    ("AME", "Adobe Media Encoder.exe"),
+    ("CHAR", "Adobe Character Animator.exe"),
+];
+
+// The Chromium helpers hosts run panels in: CEPHtmlEngine, "CEPHtmlEngine Helper (Renderer)", CEPHtmlEngine.exe
+const CEP_ENGINE_PREFIX: &str = "CEPHtmlEngine";
+
+// A running CEP engine process and, where its path tells, the host that started it
+#[derive(Debug, Clone, PartialEq)]
+pub struct EngineProcess {
+    pub pid: u32,
+    pub host: Option<&'static str>, // Unknown when the executable's path can't be read
+}
+
+// One entry of the OS process list
+#[derive(Debug, Clone, PartialEq)]
+struct RunningProcess {
+    pid: u32,
+    name: String,         // Executable file name, e.g. "After Effects" or "Photoshop.exe"
+    exe: Option<PathBuf>, // None for other users' processes on some systems
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HostApp {
+    pub code: &'static str,
+    pub name: String,            // e.g. "Adobe Photoshop 2025"
+    pub version: Option<String>, // From Info.plist; unknown on Windows
+    pub cep_version: Option<u32>, // Major CEP runtime the app ships, from its release year
+    pub path: PathBuf,
+}
+
+// What a plugin's manifest says it runs in
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PluginTargets {
+    pub hosts: Vec<HostRequirement>,
+    pub required_runtime: Option<String>, // CSXS version, e.g. "11.0"
+    pub ui_kinds: Vec<UiKind>,            // One per extension in the bundle
+}
+
+// A plugin needs a newer CEP than any installed app it targets provides
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeShortfall {
+    pub required: u32,
+    pub host: &'static str, // Code of the targeted app with the newest runtime
+    pub available: u32,
+}
+
+pub fn detect_host_apps() -> Vec<HostApp> {
+    let mut apps = if cfg!(target_os = "windows") {
+        scan_windows(Path::new(WINDOWS_PROGRAM_FILES))
+    } else {
+        scan_applications(Path::new(MAC_APPLICATIONS))
+    };
+    apps.sort_by(|a, b| a.name.cmp(&b.name));
+    log::info!("Detected {} Adobe host apps", apps.len());
+    apps
+}
+
+// Host code for an app or folder name, e.g. "Adobe Premiere Pro 2025" -> PPRO
+pub fn host_code_for(app_name: &str) -> Option<&'static str> {
+    // Longest name first so "Adobe Premiere Pro" doesn't match a shorter prefix
+    HOST_APPS
+        .iter()
+        .filter(|(_, name)| app_name.starts_with(name))
+        .max_by_key(|(_, name)| name.len())
+        .map(|(code, _)| *code)
+}
+
+// Display name for a manifest host code
+pub fn host_display_name(code: &str) -> &str {
+    let code = normalize_code(code);
+    HOST_APPS
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, name)| name.trim_start_matches("Adobe "))
+        .unwrap_or(code)
+}
+
+// True when a plugin lists hosts and none of them is installed here
+pub fn targets_only_missing_hosts(hosts: &[HostRequirement], apps: &[HostApp]) -> bool {
+    !hosts.is_empty()
+        && !hosts.iter().any(|host| apps.iter().any(|app| app.code == normalize_code(&host.name)))
+}
+
+// True when the manifest's HostList includes the app
+pub fn is_targeted(targets: &PluginTargets, app: &HostApp) -> bool {
+    targets.hosts.iter().any(|host| normalize_code(&host.name) == app.code)
+}
+
+// None when the plugin can load somewhere, or when there isn't enough information to tell
+pub fn runtime_shortfall(targets: &PluginTargets, apps: &[HostApp]) -> Option<RuntimeShortfall> {
+    let required = major_version(targets.required_runtime.as_deref()?)?;
+    let targeted: Vec<&HostApp> = apps.iter().filter(|app| is_targeted(targets, app)).collect();
+
+    // An app of unknown vintage might be new enough - don't warn about it
+    if targeted.is_empty() || targeted.iter().any(|app| app.cep_version.is_none()) {
+        return None;
+    }
+    let newest = targeted.into_iter().max_by_key(|app| app.cep_version)?;
+    let available = newest.cep_version?;
+    (available < required).then_some(RuntimeShortfall { required, host: newest.code, available })
+}
+
+// The binary to start directly - needed to hand the app its own environment
+pub fn app_executable(app: &HostApp) -> Option<PathBuf> {
+    let executable = if cfg!(target_os = "windows") {
+        let (_, relative) = WINDOWS_EXECUTABLES.iter().find(|(code, _)| *code == app.code)?;
+        app.path.join(relative)
+    } else {
+        let plist = fs::read_to_string(app.path.join("Contents").join("Info.plist")).ok()?;
+        app.path.join("Contents").join("MacOS").join(plist_string(&plist, "CFBundleExecutable")?)
+    };
+    executable.is_file().then_some(executable)
+}
+
+// Codes of the Adobe hosts running right now, from the OS process list
+pub fn running_host_codes() -> Vec<&'static str> {
+    host_codes_in(&running_processes(), cfg!(target_os = "windows"))
+}
+
+// The running hosts a change to a plugin reaches: the ones its manifest lists, or every
+// running host when it lists none
+pub fn running_targeted_hosts(hosts: &[HostRequirement]) -> Vec<&'static str> {
+    running_host_codes()
+        .into_iter()
+        .filter(|code| hosts.is_empty() || hosts.iter().any(|host| normalize_code(&host.name) == *code))
+        .collect()
+}
+
+pub fn cep_engine_processes() -> Vec<EngineProcess> {
+    engine_processes_in(&running_processes())
+}
+
+// Force-quits the engines - a stuck one ignores a polite request. Hosts start fresh ones when a
+// panel is opened again. Returns the processes that are gone afterwards.
+pub fn stop_cep_engines(processes: &[EngineProcess]) -> Vec<EngineProcess> {
+    if processes.is_empty() {
+        return Vec::new();
+    }
+    // Listed again right before the signal: a PID from before the confirmation may belong to
+    // another program by now, so only ones still running an engine are stopped
+    let system = process_table();
+    for engine in processes {
+        let Some(process) = system.process(Pid::from_u32(engine.pid)) else {
+            continue; // Exited meanwhile
+        };
+        if !process_name(process).starts_with(CEP_ENGINE_PREFIX) {
+            log::warn!("Left PID {} alone, it no longer runs a CEP engine", engine.pid);
+            continue;
+        }
+        if !process.kill() {
+            log::warn!("Failed to stop CEP engine {}", engine.pid);
+        }
+    }
+
+    // Some may have been owned by another user or exited meanwhile - report what is actually gone
+    let remaining = cep_engine_processes();
+    processes.iter().filter(|process| !remaining.iter().any(|other| other.pid == process.pid)).cloned().collect()
+}
+
+// Every code a host writes under, for per-host cache files
+// True when the manifest lists the app with this code; PHSP and PHXS both match Photoshop
+pub fn targets_host(targets: &PluginTargets, code: &str) -> bool {
+    targets.hosts.iter().any(|host| normalize_code(&host.name) == normalize_code(code))
+}
+
+// Host codes any plugin lists, for the host filter; sorted by display name
+pub fn listed_host_codes<'a>(targets: impl IntoIterator<Item = &'a PluginTargets>) -> Vec<String> {
+    let mut codes: Vec<String> =
+        targets.into_iter().flat_map(|targets| targets.hosts.iter().map(|host| normalize_code(&host.name).to_string())).collect();
+    codes.sort_by(|a, b| host_display_name(a).cmp(host_display_name(b)).then(a.cmp(b)));
+    codes.dedup();
+    codes
+}
+
+pub fn host_code_aliases(code: &'static str) -> Vec<&'static str> {
+    if code == "PHXS" { vec!["PHXS", "PHSP"] } else { vec![code] }
+}
+
+// Helper functions
+// Every process with its executable's file name and, where the OS lets us read it, its path
+fn running_processes() -> Vec<RunningProcess> {
+    process_table()
+        .processes()
+        .values()
+        .map(|process| RunningProcess { pid: process.pid().as_u32(), name: process_name(process), exe: process.exe().map(Path::to_path_buf) })
+        .collect()
+}
+
+// Only what matching needs: names and executable paths, no CPU or memory sampling
+fn process_table() -> System {
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet));
+    system
+}
+
+// The name the OS keeps can be cut short; the executable's file name never is
+fn process_name(process: &Process) -> String {
+    process.exe().and_then(Path::file_name).unwrap_or(process.name()).to_string_lossy().to_string()
+}
+
+// Windows images are matched by file name, elsewhere executables are named after the app, some
+// without the vendor: "After Effects"
+fn host_codes_in(processes: &[RunningProcess], windows: bool) -> Vec<&'static str> {
+    let mut codes: Vec<&'static str> = processes
+        .iter()
+        .filter_map(|process| {
+            if windows {
+                WINDOWS_EXECUTABLES
+                    .iter()
+                    .find(|(_, executable)| executable.rsplit('\\').next().is_some_and(|name| name.eq_ignore_ascii_case(&process.name)))
+                    .map(|(code, _)| *code)
+            } else {
+                host_code_for(&process.name).or_else(|| host_code_for(&format!("Adobe {}", process.name)))
+            }
+        })
+        .collect();
+    codes.sort_unstable();
+    codes.dedup();
+    codes
+}
+
+// The host is the Adobe folder or bundle the engine ships in
+fn engine_processes_in(processes: &[RunningProcess]) -> Vec<EngineProcess> {
+    processes
+        .iter()
+        .filter(|process| process.name.starts_with(CEP_ENGINE_PREFIX))
+        .map(|process| EngineProcess {
+            pid: process.pid,
+            host: process.exe.as_ref().and_then(|exe| exe.iter().find_map(|component| host_code_for(&component.to_string_lossy()))),
+        })
+        .collect()
+}
+
+// CEP majors by Creative Cloud release year - the runtime moves with the yearly release, not per app:
+// CC 2018 shipped CEP 8, 2019 CEP 9, 2020 CEP 10, 2021-2023 CEP 11, 2024 onwards CEP 12
+fn cep_version_for(app_name: &str) -> Option<u32> {
+    let year: u32 = app_name
+        .split_whitespace()
+        .rev()
+        .find(|word| word.len() == 4 && word.chars().all(|c| c.is_ascii_digit()))?
+        .parse()
+        .ok()?;
+    match year {
+        0..=2017 => None,
+        2018 => Some(8),
+        2019 => Some(9),
+        2020 => Some(10),
+        2021..=2023 => Some(11),
+        _ => Some(12),
+    }
+}
+
+fn major_version(version: &str) -> Option<u32> {
+    version.trim().split('.').next()?.parse().ok()
+}
+
+// Photoshop has two codes: PHSP (standard) and PHXS (extended) - both mean the same app today
+fn normalize_code(code: &str) -> &str {
+    if code == "PHSP" { "PHXS" } else { code }
+}
+
+fn scan_applications(applications: &Path) -> Vec<HostApp> {
+    let Ok(entries) = fs::read_dir(applications) else {
+        return Vec::new();
+    };
+
+    let mut apps = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if !name.starts_with("Adobe ") {
+            continue;
+        }
+
+        if name.ends_with(".app") {
+            apps.extend(mac_app(&path));
+        } else if path.is_dir() {
+            // Most apps live in a folder of the same name: /Applications/Adobe Photoshop 2025/Adobe Photoshop 2025.app
+            for child in fs::read_dir(&path).into_iter().flatten().flatten() {
+                if child.file_name().to_string_lossy().ends_with(".app") {
+                    apps.extend(mac_app(&child.path()));
+                }
+            }
+        }
+    }
+    apps
+}
+
+fn mac_app(app_path: &Path) -> Option<HostApp> {
+    let name = app_path.file_stem()?.to_string_lossy().to_string();
+    let code = host_code_for(&name)?;
+    let version = fs::read_to_string(app_path.join("Contents").join("Info.plist"))
+        .ok()
+        .and_then(|plist| plist_string(&plist, "CFBundleShortVersionString"));
+    Some(HostApp { code, cep_version: cep_version_for(&name), name, version, path: app_path.to_path_buf() })
+}
+
+fn scan_windows(program_files: &Path) -> Vec<HostApp> {
+    fs::read_dir(program_files)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| {
+            let name = entry.file_name().to_string_lossy().to_string();
+            let code = host_code_for(&name)?;
+            Some(HostApp { code, cep_version: cep_version_for(&name), name, version: None, path: entry.path() })
+        })
+        .collect()
+}
+
+// Value of a top-level <key>..</key><string>..</string> pair in an XML property list
+fn plist_string(plist: &str, wanted_key: &str) -> Option<String> {
+    let mut reader = Reader::from_str(plist);
+    reader.config_mut().trim_text(true);
+
+    let mut current_element = Vec::new();
+    let mut last_key: Option<String> = None;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Err(_) | Ok(Event::Eof) => return None,
+            Ok(Event::Start(ref e)) => current_element = e.name().as_ref().to_vec(),
+            Ok(Event::Text(ref e)) => {
+                let text = e.decode().ok()?.to_string();
+                match current_element.as_slice() {
+                    b"key" => last_key = Some(text),
+                    b"string" if last_key.as_deref() == Some(wanted_key) => return Some(text),
+                    _ => {}
+                }
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"string" => last_key = None,
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_codes_from_app_names() {
+        assert_eq!(host_code_for("Adobe Photoshop 2025"), Some("PHXS"));
+        assert_eq!(host_code_for("Adobe Premiere Pro 2025"), Some("PPRO"));
+        assert_eq!(host_code_for("Adobe Premiere Rush"), Some("RUSH"));
+        assert_eq!(host_code_for("Adobe Creative Cloud"), None);
+        assert_eq!(host_display_name("PHSP"), "Photoshop");
+        assert_eq!(host_display_name("XYZ"), "XYZ");
+    }
+
+    fn process(pid: u32, exe: &str) -> RunningProcess {
+        let name = exe.rsplit(['/', '\\']).next().unwrap().to_string();
+        RunningProcess { pid, name, exe: Some(PathBuf::from(exe)) }
+    }
+
+    #[test]
+    fn test_running_hosts_from_process_lists() {
+        let mac = [
+            process(1, "/sbin/launchd"),
+            process(2, "/Applications/Adobe Photoshop 2025/Adobe Photoshop 2025.app/Contents/MacOS/Adobe Photoshop 2025"),
+            process(3, "/Applications/Adobe After Effects 2025/Adobe After Effects 2025.app/Contents/MacOS/After Effects"),
+            process(4, "/Library/Application Support/Adobe/Adobe Desktop Common/ADS/Adobe Desktop Service.app/Contents/MacOS/Adobe Desktop Service"),
+        ];
+        assert_eq!(host_codes_in(&mac, false), ["AEFT", "PHXS"]);
+
+        let windows = [
+            RunningProcess { pid: 4012, name: "explorer.exe".to_string(), exe: None },
+            process(7788, "C:\\Program Files\\Adobe\\Adobe Photoshop 2025\\Photoshop.exe"),
+            RunningProcess { pid: 9001, name: "AfterFX.exe".to_string(), exe: None },
+        ];
+        assert_eq!(host_codes_in(&windows, true), ["AEFT", "PHXS"]);
+    }
+
+    #[test]
+    fn test_engine_processes_from_process_lists() {
+        let engine = "/Applications/Adobe Photoshop 2025/Adobe Photoshop 2025.app/Contents/Required/CEP/CEPHtmlEngine/CEPHtmlEngine.app";
+        let processes = [
+            process(101, "/sbin/launchd"),
+            process(4242, &format!("{engine}/Contents/MacOS/CEPHtmlEngine")),
+            process(4243, &format!("{engine}/Contents/Frameworks/CEPHtmlEngine Helper (Renderer).app/Contents/MacOS/CEPHtmlEngine Helper (Renderer)")),
+            process(4300, "/opt/CEPHtmlEngine"),
+            RunningProcess { pid: 5120, name: "CEPHtmlEngine.exe".to_string(), exe: None },
+        ];
+        let found: Vec<(u32, Option<&str>)> = engine_processes_in(&processes).iter().map(|engine| (engine.pid, engine.host)).collect();
+        assert_eq!(found, [(4242, Some("PHXS")), (4243, Some("PHXS")), (4300, None), (5120, None)]);
+    }
+
+    #[test]
+    fn test_stopping_engines_skips_reused_pids() {
+        // Stands in for an engine that exited after the dialog, its PID handed to another program
+        let mut other = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+        let stopped = stop_cep_engines(&[EngineProcess { pid: other.id(), host: None }]);
+        assert!(other.try_wait().unwrap().is_none());
+        assert_eq!(stopped.len(), 1); // The engine itself is gone all the same
+        other.kill().unwrap();
+        let _ = other.wait();
+    }
+
+    #[test]
+    fn test_plist_version() {
+        let plist = r#"<?xml version="1.0" encoding="UTF-8"?>
+<plist version="1.0"><dict>
+  <key>CFBundleName</key><string>Adobe Photoshop 2025</string>
+  <key>CFBundleShortVersionString</key><string>26.1.0</string>
+</dict></plist>"#;
+        assert_eq!(plist_string(plist, "CFBundleShortVersionString").as_deref(), Some("26.1.0"));
+        assert_eq!(plist_string(plist, "CFBundleVersion"), None);
+    }
+
+    #[test]
+    fn test_missing_host_cross_reference() {
+        let photoshop = HostApp {
+            code: "PHXS",
+            name: "Adobe Photoshop 2025".to_string(),
+            version: None,
+            cep_version: Some(12),
+            path: PathBuf::new(),
+        };
+        let host = |name: &str| HostRequirement { name: name.to_string(), version: None };
+
+        assert!(!targets_only_missing_hosts(&[host("PHSP"), host("ILST")], std::slice::from_ref(&photoshop)));
+        assert!(targets_only_missing_hosts(&[host("AEFT")], &[photoshop]));
+        assert!(!targets_only_missing_hosts(&[], &[])); // No HostList - can't tell
+    }
+
+    #[test]
+    fn test_host_filter_codes() {
+        let targets = |codes: &[&str]| PluginTargets {
+            hosts: codes.iter().map(|code| HostRequirement { name: code.to_string(), version: None }).collect(),
+            required_runtime: None,
+            ui_kinds: Vec::new(),
+        };
+        let all = [targets(&["PPRO", "PHSP"]), targets(&["PHXS", "AEFT"])];
+
+        assert_eq!(listed_host_codes(&all), ["AEFT", "PHXS", "PPRO"]); // After Effects, Photoshop, Premiere Pro
+        assert!(targets_host(&all[0], "PHXS"));
+        assert!(!targets_host(&all[0], "AEFT"));
+    }
+
+    #[test]
+    fn test_runtime_shortfall() {
+        let premiere = HostApp {
+            code: "PPRO",
+            name: "Adobe Premiere Pro 2020".to_string(),
+            version: None,
+            cep_version: cep_version_for("Adobe Premiere Pro 2020"),
+            path: PathBuf::new(),
+        };
+        let targets = |runtime: &str| PluginTargets {
+            hosts: vec![HostRequirement { name: "PPRO".to_string(), version: None }],
+            required_runtime: Some(runtime.to_string()),
+            ui_kinds: Vec::new(),
+        };
+
+        assert_eq!(
+            runtime_shortfall(&targets("11.0"), std::slice::from_ref(&premiere)),
+            Some(RuntimeShortfall { required: 11, host: "PPRO", available: 10 })
+        );
+        assert_eq!(runtime_shortfall(&targets("9.0"), std::slice::from_ref(&premiere)), None);
+        assert_eq!(runtime_shortfall(&targets("11.0"), &[]), None); // Host not installed - the other badge covers it
+        assert_eq!(cep_version_for("Adobe Premiere Rush"), None);
+        assert_eq!(cep_version_for("Adobe Photoshop CC 2019"), Some(9));
+    }
+}
@@ -0,0 +1,260 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crate::background::{install_zxp, install_zxp_into, run_blocking};
+use crate::clock::unix_now;
+use crate::data_operations::{ExtensionRoot, SYSTEM_EXTENSIONS_PATH};
+use crate::file_operations::{FileOperationError, InstalledPlugin, install_destination, unwritable_folder};
+use crate::install_queue::{QueueState, enqueue, set_state, start};
+use crate::notifications::notify_when_away;
+use crate::message::{mark_plugin_as_newly_installed, show_error, show_success, trigger_refresh};
+use crate::plugin_store::expect_change;
+use crate::settings::{SETTINGS, remember_recent_install};
+use crate::validation::{Severity, validate_zxp};
+
+// Studios push ZXPs to artists through synced folders: every .zxp that lands in the hot folder is
+// validated and installed without asking, then archived or deleted. Checked while the app is open.
+
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+// Subfolders of the hot folder; files that failed are moved aside so they aren't retried forever
+pub const ARCHIVE_FOLDER: &str = "Installed";
+pub const FAILED_FOLDER: &str = "Failed";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum HotFolderCleanup {
+    #[default]
+    Archive, // Move into the Installed subfolder
+    Delete,
+}
+
+impl HotFolderCleanup {
+    pub const ALL: [HotFolderCleanup; 2] = [HotFolderCleanup::Archive, HotFolderCleanup::Delete];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            HotFolderCleanup::Archive => "archive",
+            HotFolderCleanup::Delete => "delete",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<HotFolderCleanup> {
+        HotFolderCleanup::ALL.into_iter().find(|cleanup| cleanup.code() == code)
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            HotFolderCleanup::Archive => "hot-folder-archive",
+            HotFolderCleanup::Delete => "hot-folder-delete",
+        }
+    }
+}
+
+// Persisted in Settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct HotFolder {
+    pub enabled: bool,
+    pub folder: Option<PathBuf>,
+    pub cleanup: HotFolderCleanup,
+}
+
+// Size and modification time - a file counts as fully written once these held for a whole poll
+pub type FileStamp = (u64, Option<SystemTime>);
+
+// Background watcher for the hot folder - call once from the root component
+pub fn use_hot_folder() {
+    use_future(|| async move {
+        let mut previous: HashMap<PathBuf, FileStamp> = HashMap::new();
+        let mut skipped: HashMap<PathBuf, FileStamp> = HashMap::new(); // Removed from the queue, left alone until they change
+
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+
+            let settings = SETTINGS.peek().hot_folder.clone();
+            let Some(folder) = settings.folder.filter(|_| settings.enabled) else {
+                previous.clear();
+                continue;
+            };
+            let listing = run_blocking(move || list_zxp_files(&folder)).await;
+            skipped.retain(|path, stamp| listing.get(path) == Some(stamp));
+            let settled: Vec<PathBuf> =
+                settled_files(&previous, &listing).into_iter().filter(|path| skipped.get(path) != listing.get(path)).collect();
+
+            if !settled.is_empty() {
+                for path in process_batch(settled, settings.cleanup).await {
+                    if let Some(stamp) = listing.get(&path) {
+                        skipped.insert(path, *stamp);
+                    }
+                }
+                previous.clear(); // Processed files are gone or moved; anything left is looked at afresh
+            } else {
+                previous = listing;
+            }
+        }
+    });
+}
+
+// Files whose stamp didn't change since the last poll, in name order
+pub fn settled_files(previous: &HashMap<PathBuf, FileStamp>, current: &HashMap<PathBuf, FileStamp>) -> Vec<PathBuf> {
+    let mut settled: Vec<PathBuf> =
+        current.iter().filter(|(path, stamp)| previous.get(*path) == Some(stamp)).map(|(path, _)| path.clone()).collect();
+    settled.sort();
+    settled
+}
+
+// Helper functions
+// Returns the files the user removed from the install queue before their turn
+async fn process_batch(zxp_paths: Vec<PathBuf>, cleanup: HotFolderCleanup) -> Vec<PathBuf> {
+    log::info!("Hot folder: picked up {} files", zxp_paths.len());
+    let mut installed = Vec::new();
+    let mut failed = Vec::new();
+    let mut skipped = Vec::new();
+
+    // Listed up front, so the sidebar shows the whole batch waiting
+    let queued: Vec<(u64, PathBuf)> = zxp_paths.into_iter().map(|path| (enqueue(path.clone(), false, true), path)).collect();
+    for (id, zxp_path) in queued {
+        if !start(id) {
+            log::info!("Hot folder: {:?} was removed from the queue", zxp_path);
+            skipped.push(zxp_path);
+            continue;
+        }
+        let file_name = zxp_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+        let result = install_unattended(zxp_path.clone()).await;
+        set_state(id, match &result {
+            Ok(_) => QueueState::Done,
+            Err(e) => QueueState::Failed(e.to_string()),
+        });
+        match result {
+            Ok(plugin) => {
+                remember_recent_install(&zxp_path);
+                expect_change(plugin.path.clone());
+                mark_plugin_as_newly_installed(plugin.path);
+                installed.push(format!("{} {}", plugin.info.name, plugin.info.version));
+                let path = zxp_path.clone();
+                if let Err(e) = run_blocking(move || clean_up(&path, cleanup)).await {
+                    log::error!("Hot folder: installed {:?} but couldn't clean it up: {}", zxp_path, e);
+                }
+            }
+            Err(e) => {
+                log::error!("Hot folder: failed to install {:?}: {}", zxp_path, e);
+                failed.push(file_name);
+                let path = zxp_path.clone();
+                if let Err(e) = run_blocking(move || move_into(&path, FAILED_FOLDER)).await {
+                    log::error!("Hot folder: couldn't move {:?} aside: {}", zxp_path, e);
+                }
+            }
+        }
+    }
+
+    if !installed.is_empty() {
+        let message = show_success("hot-folder-installed", &[("names", &installed.join(", "))]);
+        notify_when_away(&message);
+        trigger_refresh();
+    }
+    if !failed.is_empty() {
+        let message = show_error("hot-folder-failed", &[("names", &failed.join(", ")), ("folder", FAILED_FOLDER)]);
+        notify_when_away(&message);
+    }
+    skipped
+}
+
+// Nobody is there to answer dialogs: archives with validation errors are refused, and when the
+// all-users folder isn't writable the plugin goes to the current user's folder instead
+async fn install_unattended(zxp_path: PathBuf) -> Result<InstalledPlugin, FileOperationError> {
+    let path = zxp_path.clone();
+    let report = run_blocking(move || validate_zxp(&path)).await?;
+    if report.count(Severity::Error) > 0 {
+        let keys: Vec<&str> = report.findings.iter().map(|finding| finding.message_key).collect();
+        log::warn!("Hot folder: {:?} failed validation: {:?}", zxp_path, keys);
+        return Err(FileOperationError::InvalidExtension(zxp_path));
+    }
+
+    // Checked up front so a refused folder doesn't cost a half-finished attempt first
+    let source = zxp_path.clone();
+    let refused = run_blocking(move || {
+        install_destination(&source, Path::new(SYSTEM_EXTENSIONS_PATH)).ok().and_then(|destination| unwritable_folder(&destination))
+    })
+    .await;
+    let result = match refused {
+        Some(folder) => Err(FileOperationError::PermissionDenied(folder)),
+        None => install_zxp(zxp_path.clone()).await,
+    };
+    match result {
+        Err(FileOperationError::PermissionDenied(_)) => match ExtensionRoot::User.path() {
+            Some(root) => install_zxp_into(zxp_path, root).await,
+            None => Err(FileOperationError::PermissionDenied(zxp_path)),
+        },
+        result => result,
+    }
+}
+
+fn list_zxp_files(folder: &Path) -> HashMap<PathBuf, FileStamp> {
+    let Ok(entries) = fs::read_dir(folder) else {
+        return HashMap::new(); // Not there (yet) - a synced folder may appear later
+    };
+    entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|extension| extension.eq_ignore_ascii_case("zxp")))
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok().filter(|metadata| metadata.is_file())?;
+            Some((entry.path(), (metadata.len(), metadata.modified().ok())))
+        })
+        .collect()
+}
+
+fn clean_up(zxp_path: &Path, cleanup: HotFolderCleanup) -> Result<(), FileOperationError> {
+    match cleanup {
+        HotFolderCleanup::Archive => move_into(zxp_path, ARCHIVE_FOLDER),
+        HotFolderCleanup::Delete => fs::remove_file(zxp_path).map_err(|e| FileOperationError::extract(zxp_path, e)),
+    }
+}
+
+// Into a subfolder next to the file; a name already taken there gets the current time appended
+fn move_into(zxp_path: &Path, subfolder: &str) -> Result<(), FileOperationError> {
+    let folder = zxp_path.with_file_name(subfolder);
+    fs::create_dir_all(&folder).map_err(|e| FileOperationError::extract(&folder, e))?;
+
+    let file_name = zxp_path.file_name().ok_or_else(|| FileOperationError::FileNotFound(zxp_path.to_path_buf()))?;
+    let mut target = folder.join(file_name);
+    if target.exists() {
+        let stem = zxp_path.file_stem().unwrap_or_default().to_string_lossy();
+        target = folder.join(format!("{}-{}.zxp", stem, unix_now()));
+    }
+    fs::rename(zxp_path, &target).map_err(|e| FileOperationError::extract(&target, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_unchanged_files_are_settled() {
+        let stamp = |size: u64| (size, Some(SystemTime::UNIX_EPOCH));
+        let previous = HashMap::from([(PathBuf::from("/hot/b.zxp"), stamp(10)), (PathBuf::from("/hot/a.zxp"), stamp(5))]);
+        let current = HashMap::from([
+            (PathBuf::from("/hot/b.zxp"), stamp(10)),
+            (PathBuf::from("/hot/a.zxp"), stamp(8)), // Still being copied
+            (PathBuf::from("/hot/c.zxp"), stamp(3)), // New - wait one more poll
+        ]);
+        assert_eq!(settled_files(&previous, &current), vec![PathBuf::from("/hot/b.zxp")]);
+    }
+
+    #[test]
+    fn test_move_into_keeps_existing_files() {
+        let dir = std::env::temp_dir().join(format!("zxpm-hot-{}", std::process::id()));
+        fs::create_dir_all(dir.join(ARCHIVE_FOLDER)).unwrap();
+        fs::write(dir.join(ARCHIVE_FOLDER).join("tools.zxp"), b"old").unwrap();
+        fs::write(dir.join("tools.zxp"), b"new").unwrap();
+
+        move_into(&dir.join("tools.zxp"), ARCHIVE_FOLDER).unwrap();
+        assert!(!dir.join("tools.zxp").exists());
+        assert_eq!(fs::read_dir(dir.join(ARCHIVE_FOLDER)).unwrap().count(), 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+// Locale files are embedded at compile time and use a small Fluent subset:
+// `key = value` lines, `{ $arg }` placeholders and `#` comments.
+// To add a language: drop a new .ftl file in assets/locales/ and extend `Locale`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    English,
+    Ukrainian,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::English, Locale::Ukrainian];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::English => "en",
+            Locale::Ukrainian => "uk",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Locale> {
+        Locale::ALL.into_iter().find(|locale| locale.code() == code)
+    }
+
+    // Native name, so users can find their language regardless of the current one
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Locale::English => "English",
+            Locale::Ukrainian => "Українська",
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Locale::English => include_str!("../assets/locales/en.ftl"),
+            Locale::Ukrainian => include_str!("../assets/locales/uk.ftl"),
+        }
+    }
+
+    fn index(&self) -> usize {
+        Locale::ALL.iter().position(|locale| locale == self).unwrap_or(0)
+    }
+}
+
+// Plain atomic instead of a signal so translations also work outside the Dioxus runtime
+static CURRENT_LOCALE: AtomicUsize = AtomicUsize::new(0);
+
+type Catalog = HashMap<&'static str, &'static str>;
+
+static CATALOGS: OnceLock<HashMap<Locale, Catalog>> = OnceLock::new();
+
+pub fn set_locale(locale: Locale) {
+    CURRENT_LOCALE.store(locale.index(), Ordering::Relaxed);
+    log::debug!("UI language set to {}", locale.code());
+}
+
+pub fn current_locale() -> Locale {
+    Locale::ALL[CURRENT_LOCALE.load(Ordering::Relaxed)]
+}
+
+// Translate a message key for the current locale
+pub fn t(key: &str) -> String {
+    lookup(current_locale(), key).to_string()
+}
+
+// Translate a message key and substitute `{ $name }` placeholders
+pub fn t_args(key: &str, args: &[(&str, &str)]) -> String {
+    let mut message = t(key);
+    for (name, value) in args {
+        message = message.replace(&format!("{{ ${} }}", name), value);
+    }
+    message
+}
+
+// Helper functions
+fn lookup(locale: Locale, key: &str) -> &str {
+    let catalogs = CATALOGS.get_or_init(|| {
+        Locale::ALL
+            .into_iter()
+            .map(|locale| (locale, parse_catalog(locale.source())))
+            .collect()
+    });
+
+    // Fall back to English, then to the key itself, so a missing translation is visible but harmless
+    catalogs
+        .get(&locale)
+        .and_then(|catalog| catalog.get(key))
+        .or_else(|| catalogs.get(&Locale::English).and_then(|catalog| catalog.get(key)))
+        .copied()
+        .unwrap_or_else(|| {
+            log::warn!("Missing translation for key: {}", key);
+            key
+        })
+}
+
+fn parse_catalog(source: &'static str) -> Catalog {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.split_once('='))
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_placeholder_substitution() {
+        assert_eq!(
+            lookup(Locale::English, "toast-install-failed").replace("{ $error }", "boom"),
+            "Installation failed: boom"
+        );
+    }
+
+    #[test]
+    fn test_missing_key_falls_back_to_key() {
+        assert_eq!(lookup(Locale::Ukrainian, "no-such-key"), "no-such-key");
+    }
+
+    #[test]
+    fn test_locales_define_every_english_key() {
+        let english = parse_catalog(Locale::English.source());
+        for locale in Locale::ALL {
+            let catalog = parse_catalog(locale.source());
+            for key in english.keys() {
+                assert!(catalog.contains_key(key), "{} is missing {}", locale.code(), key);
+            }
+        }
+    }
+}
@@ -0,0 +1,132 @@
+use dioxus::prelude::*;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+// Installs run one at a time: picked, dropped or pasted files wait here for the actions worker,
+// hot folder batches for the watcher. The sidebar lists them while several are queued.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QueueState {
+    Waiting,
+    Extracting, // Validating, asking and installing - everything up to the result
+    Done,
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedInstall {
+    pub id: u64,
+    pub path: PathBuf,
+    pub state: QueueState,
+    pub inspect: bool,    // Goes to the install preview instead of installing right away
+    pub hot_folder: bool, // Installed by the hot folder watcher, not the queue worker
+}
+
+impl QueuedInstall {
+    pub fn is_finished(&self) -> bool {
+        matches!(self.state, QueueState::Done | QueueState::Failed(_))
+    }
+
+    pub fn file_name(&self) -> String {
+        self.path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default()
+    }
+}
+
+pub static INSTALL_QUEUE: GlobalSignal<Vec<QueuedInstall>> = Signal::global(Vec::new);
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+static WORKER_RUNNING: AtomicBool = AtomicBool::new(false);
+
+// Results of an earlier batch are dropped once a new batch starts on an idle queue
+pub fn enqueue(path: PathBuf, inspect: bool, hot_folder: bool) -> u64 {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    let mut queue = INSTALL_QUEUE.write();
+    if queue.iter().all(QueuedInstall::is_finished) {
+        queue.clear();
+    }
+    queue.push(QueuedInstall { id, path, state: QueueState::Waiting, inspect, hot_folder });
+    id
+}
+
+// The next waiting item for the worker, already marked as extracting
+pub fn take_next() -> Option<QueuedInstall> {
+    let mut queue = INSTALL_QUEUE.write();
+    let index = next_waiting(&queue)?;
+    queue[index].state = QueueState::Extracting;
+    Some(queue[index].clone())
+}
+
+// For the hot folder, which runs its own batch: false when the user removed the item meanwhile
+pub fn start(id: u64) -> bool {
+    let mut queue = INSTALL_QUEUE.write();
+    match queue.iter_mut().find(|item| item.id == id && item.state == QueueState::Waiting) {
+        Some(item) => {
+            item.state = QueueState::Extracting;
+            true
+        }
+        None => false,
+    }
+}
+
+pub fn set_state(id: u64, state: QueueState) {
+    if let Some(item) = INSTALL_QUEUE.write().iter_mut().find(|item| item.id == id) {
+        item.state = state;
+    }
+}
+
+// Cancelled installs and ones handed to the preview dialog leave the list
+pub fn forget(id: u64) {
+    INSTALL_QUEUE.write().retain(|item| item.id != id);
+}
+
+// Only waiting items can go; returns the removed one
+pub fn remove_waiting(id: u64) -> Option<QueuedInstall> {
+    let mut queue = INSTALL_QUEUE.write();
+    let index = queue.iter().position(|item| item.id == id && item.state == QueueState::Waiting)?;
+    Some(queue.remove(index))
+}
+
+pub fn clear_finished() {
+    INSTALL_QUEUE.write().retain(|item| !item.is_finished());
+}
+
+// True for the caller that should start the worker; the worker calls release_worker() when the
+// queue runs dry
+pub fn claim_worker() -> bool {
+    !WORKER_RUNNING.swap(true, Ordering::AcqRel)
+}
+
+pub fn release_worker() {
+    WORKER_RUNNING.store(false, Ordering::Release);
+}
+
+// Helper functions
+fn next_waiting(queue: &[QueuedInstall]) -> Option<usize> {
+    queue.iter().position(|item| item.state == QueueState::Waiting && !item.hot_folder)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_worker_skips_hot_folder_and_started_items() {
+        let item = |id: u64, state: QueueState, hot_folder: bool| QueuedInstall {
+            id,
+            path: PathBuf::from(format!("/tmp/{}.zxp", id)),
+            state,
+            inspect: false,
+            hot_folder,
+        };
+        let queue = vec![
+            item(1, QueueState::Done, false),
+            item(2, QueueState::Waiting, true),
+            item(3, QueueState::Extracting, false),
+            item(4, QueueState::Waiting, false),
+        ];
+        assert_eq!(next_waiting(&queue), Some(3));
+        assert_eq!(next_waiting(&queue[..3]), None);
+        assert!(queue[0].is_finished() && !queue[1].is_finished());
+        assert_eq!(queue[3].file_name(), "4.zxp");
+    }
+}
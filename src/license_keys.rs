@@ -0,0 +1,61 @@
+use keyring::Entry;
+
+use crate::file_operations::FileOperationError;
+
+// Optional license key per plugin, kept in the OS credential store (Keychain, Windows Credential
+// Manager, Secret Service) under the bundle ID - never in notes.json or the settings file.
+
+const SERVICE: &str = "zxp-manager-license";
+
+// Characters left readable at the end of a masked key, enough to tell two keys apart
+const VISIBLE_CHARS: usize = 4;
+
+// None when no key was saved for the plugin
+pub fn read_license(bundle_id: &str) -> Result<Option<String>, FileOperationError> {
+    match entry(bundle_id)?.get_password() {
+        Ok(key) => Ok(Some(key)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(FileOperationError::KeychainFailed(e.to_string())),
+    }
+}
+
+// An empty key removes the saved one
+pub fn save_license(bundle_id: &str, key: &str) -> Result<(), FileOperationError> {
+    let entry = entry(bundle_id)?;
+    let key = key.trim();
+    let result = if key.is_empty() {
+        match entry.delete_credential() {
+            Err(keyring::Error::NoEntry) => Ok(()),
+            other => other,
+        }
+    } else {
+        entry.set_password(key)
+    };
+    result.map_err(|e| FileOperationError::KeychainFailed(e.to_string()))?;
+    log::info!("{} the license key of {}", if key.is_empty() { "Removed" } else { "Saved" }, bundle_id);
+    Ok(())
+}
+
+// "••••••••-7Q2X": same length as the key, only the last characters shown
+pub fn mask_license(key: &str) -> String {
+    let count = key.chars().count();
+    let visible = if count > VISIBLE_CHARS * 2 { VISIBLE_CHARS } else { 0 }; // Short keys stay fully hidden
+    key.chars().enumerate().map(|(index, c)| if index < count - visible && c != '-' { '•' } else { c }).collect()
+}
+
+// Helper functions
+fn entry(bundle_id: &str) -> Result<Entry, FileOperationError> {
+    Entry::new(SERVICE, bundle_id).map_err(|e| FileOperationError::KeychainFailed(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mask_license_shows_only_the_end() {
+        assert_eq!(mask_license("ABCD-EFGH-7Q2X"), "••••-••••-7Q2X");
+        assert_eq!(mask_license("secret"), "••••••");
+        assert_eq!(mask_license(""), "");
+    }
+}
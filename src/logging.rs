@@ -0,0 +1,246 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::clock::{LocalTime, unix_now};
+
+// Lines kept in memory for the log viewer - older ones are dropped
+const BUFFER_CAPACITY: usize = 2000;
+
+// The current log file is rotated once it grows past this
+const MAX_LOG_SIZE: u64 = 1024 * 1024;
+
+// Rotated files kept next to the current one: zxp-manager.1.log (newest) .. zxp-manager.3.log
+const ROTATED_FILES: usize = 3;
+
+const LOG_FILE_STEM: &str = "zxp-manager";
+
+// Persisted in Settings - Debug only applies to the app's own modules, not dependencies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 4] = [LogLevel::Error, LogLevel::Warn, LogLevel::Info, LogLevel::Debug];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<LogLevel> {
+        LogLevel::ALL.into_iter().find(|level| level.code() == code)
+    }
+
+    // Translation key for the settings select
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            LogLevel::Error => "log-level-error",
+            LogLevel::Warn => "log-level-warn",
+            LogLevel::Info => "log-level-info",
+            LogLevel::Debug => "log-level-debug",
+        }
+    }
+
+    fn filter(&self) -> LevelFilter {
+        match self {
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+        }
+    }
+}
+
+struct LogFile {
+    file: File,
+    size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    pub timestamp: i64, // Unix seconds
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+// Writes to stderr and a rotating file, and keeps the most recent lines for the in-app log viewer
+struct AppLogger {
+    lines: Mutex<VecDeque<LogLine>>,
+    file: Mutex<Option<LogFile>>,
+}
+
+static LOGGER: AppLogger = AppLogger {
+    lines: Mutex::new(VecDeque::new()),
+    file: Mutex::new(None),
+};
+
+// Bumped on every new line so the viewer knows when to re-read
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+impl Log for AppLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        // Dependencies' debug output would drown the app's own lines
+        let own_module = metadata.target().starts_with(env!("CARGO_CRATE_NAME"));
+        metadata.level() <= log::max_level() && (metadata.level() <= Level::Info || own_module)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        let line = LogLine {
+            timestamp: unix_now(),
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        };
+        let formatted = format_line(&line);
+        eprintln!("{}", formatted);
+        self.write_to_file(&formatted);
+
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.push_back(line);
+            if lines.len() > BUFFER_CAPACITY {
+                lines.pop_front();
+            }
+        }
+        GENERATION.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn flush(&self) {
+        if let Ok(mut guard) = self.file.lock()
+            && let Some(log_file) = guard.as_mut()
+        {
+            let _ = log_file.file.flush();
+        }
+    }
+}
+
+impl AppLogger {
+    fn write_to_file(&self, formatted: &str) {
+        let Ok(mut guard) = self.file.lock() else {
+            return;
+        };
+
+        let needs_rotation = guard.as_ref().is_some_and(|log_file| log_file.size > MAX_LOG_SIZE);
+        if needs_rotation && let Some(dir) = log_dir() {
+            *guard = None; // Close the file before renaming it
+            *guard = rotate_logs(&dir).and_then(|_| open_log_file(&dir)).ok();
+        }
+
+        // None when there's no writable log folder - stderr and the viewer still work
+        if let Some(log_file) = guard.as_mut() {
+            write_line(log_file, formatted);
+        }
+    }
+}
+
+// Call first thing in main(), before Dioxus installs its own logger
+pub fn init() {
+    if let Some(dir) = log_dir() {
+        match open_log_file(&dir) {
+            Ok(log_file) => *LOGGER.file.lock().unwrap() = Some(log_file),
+            Err(e) => eprintln!("Failed to open log file in {:?}: {}", dir, e),
+        }
+    }
+
+    match log::set_logger(&LOGGER) {
+        Ok(_) => log::set_max_level(LevelFilter::Info),
+        Err(e) => eprintln!("Failed to install logger: {}", e),
+    }
+}
+
+pub fn set_level(level: LogLevel) {
+    log::set_max_level(level.filter());
+}
+
+pub fn log_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("logs"))
+}
+
+pub fn recent_lines() -> Vec<LogLine> {
+    LOGGER.lines.lock().map(|lines| lines.iter().cloned().collect()).unwrap_or_default()
+}
+
+pub fn generation() -> u64 {
+    GENERATION.load(Ordering::Relaxed)
+}
+
+pub fn format_line(line: &LogLine) -> String {
+    let time = LocalTime::from_unix(line.timestamp);
+    format!("{} {} {:<5} [{}] {}", time.date_label(), time.time_label(), line.level, line.target, line.message)
+}
+
+// Helper functions
+fn write_line(log_file: &mut LogFile, formatted: &str) {
+    if writeln!(log_file.file, "{}", formatted).is_ok() {
+        log_file.size += formatted.len() as u64 + 1;
+    }
+}
+
+fn open_log_file(dir: &Path) -> std::io::Result<LogFile> {
+    fs::create_dir_all(dir)?;
+    let file = OpenOptions::new().create(true).append(true).open(log_path(dir, 0))?;
+    let size = file.metadata()?.len();
+    Ok(LogFile { file, size })
+}
+
+// 0 is the current file, 1.. are rotated ones
+fn log_path(dir: &Path, index: usize) -> PathBuf {
+    match index {
+        0 => dir.join(format!("{}.log", LOG_FILE_STEM)),
+        index => dir.join(format!("{}.{}.log", LOG_FILE_STEM, index)),
+    }
+}
+
+// Shift every file one slot up, dropping the oldest
+fn rotate_logs(dir: &Path) -> std::io::Result<()> {
+    let _ = fs::remove_file(log_path(dir, ROTATED_FILES));
+    for index in (0..ROTATED_FILES).rev() {
+        let from = log_path(dir, index);
+        if from.exists() {
+            fs::rename(&from, log_path(dir, index + 1))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_logs_keeps_limited_history() {
+        let dir = std::env::temp_dir().join(format!("zxpm-test-logs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        for generation in 0..5 {
+            fs::write(log_path(&dir, 0), format!("generation {}", generation)).unwrap();
+            rotate_logs(&dir).unwrap();
+        }
+
+        assert!(!log_path(&dir, 0).exists());
+        assert_eq!(fs::read_to_string(log_path(&dir, 1)).unwrap(), "generation 4");
+        assert_eq!(fs::read_to_string(log_path(&dir, ROTATED_FILES)).unwrap(), "generation 2");
+        assert!(!log_path(&dir, ROTATED_FILES + 1).exists());
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -1,17 +1,99 @@
 use dioxus::prelude::*;
 
+mod actions;
+mod background;
+mod cep_preferences;
+mod cli;
+mod clock;
+mod command_palette;
+mod compliance;
+mod crash_report;
 mod data_operations;
+mod debug_launch;
+mod debug_ports;
+mod diagnostics;
+mod elevation;
+mod error_codes;
+mod file_open;
 mod file_operations;
+mod host_detection;
+mod hot_folder;
+mod i18n;
+mod install_queue;
+mod license_keys;
+mod logging;
+mod manifest;
+mod manifest_editor;
 mod message;
+mod navigation;
+mod notifications;
+mod overview;
+mod plugin_data;
+mod plugin_icons;
+mod plugin_list;
+mod plugin_notes;
+mod plugin_set;
+mod plugin_store;
+mod policy;
+mod release;
+mod scheduler;
+mod script_scan;
+mod session;
+mod settings;
+mod shortcuts;
+mod signing;
+mod support_bundle;
+mod telemetry;
+mod temp_files;
+mod tray;
+mod troubleshoot;
+mod update_check;
+mod validation;
+mod version;
 mod components {
+    pub mod activity_panel;
+    pub mod command_palette;
+    pub mod compliance_panel;
+    pub mod context_menu;
+    pub mod details_drawer;
+    pub mod debug_ports_panel;
+    pub mod diagnostics_panel;
+    pub mod error_codes_panel;
+    pub mod install_compare;
+    pub mod install_preview;
+    pub mod install_queue;
+    pub mod log_viewer;
+    pub mod overview_panel;
     pub mod plugins_panel;
+    pub mod release_wizard;
+    pub mod shortcut_sheet;
     pub mod sidebar;
+    pub mod signing_panel;
     pub mod status_bar;
+    pub mod toasts;
+    pub mod troubleshoot_panel;
+    pub mod zxp_inspector;
 }
 
+use components::command_palette::CommandPalette;
+use components::compliance_panel::CompliancePanel;
+use components::debug_ports_panel::DebugPortsPanel;
+use components::diagnostics_panel::DiagnosticsPanel;
+use components::install_compare::InstallCompareDialog;
+use components::install_preview::InstallPreviewDialog;
+use components::error_codes_panel::ErrorCodesPanel;
+use components::log_viewer::LogViewer;
+use components::overview_panel::OverviewPanel;
 use components::plugins_panel::PluginsPanel;
+use components::release_wizard::ReleaseWizard;
+use components::shortcut_sheet::ShortcutSheet;
 use components::sidebar::Sidebar;
+use components::signing_panel::SigningPanel;
 use components::status_bar::StatusBar;
+use components::toasts::ToastStack;
+use components::troubleshoot_panel::TroubleshootPanel;
+use components::zxp_inspector::ZxpInspector;
+use navigation::{ACTIVE_VIEW, View};
 
 static FAVICON: Asset = asset!("/assets/favicon.ico");
 static THEMES_CSS: Asset = asset!("/assets/themes.css");
@@ -19,27 +101,78 @@ static MAIN_CSS: Asset = asset!("/assets/main.css");
 static SIDEBAR_CSS: Asset = asset!("/assets/sidebar.css");
 static STATUS_BAR_CSS: Asset = asset!("/assets/status_bar.css");
 static PLUGINS_PANEL_CSS: Asset = asset!("/assets/plugins_panel.css");
+static DIAGNOSTICS_PANEL_CSS: Asset = asset!("/assets/diagnostics_panel.css");
+static CONTEXT_MENU_CSS: Asset = asset!("/assets/context_menu.css");
+static DETAILS_DRAWER_CSS: Asset = asset!("/assets/details_drawer.css");
+static TOASTS_CSS: Asset = asset!("/assets/toasts.css");
+static ACTIVITY_PANEL_CSS: Asset = asset!("/assets/activity_panel.css");
+static LOG_VIEWER_CSS: Asset = asset!("/assets/log_viewer.css");
+static COMPLIANCE_PANEL_CSS: Asset = asset!("/assets/compliance_panel.css");
+static ERROR_CODES_PANEL_CSS: Asset = asset!("/assets/error_codes_panel.css");
+static TROUBLESHOOT_PANEL_CSS: Asset = asset!("/assets/troubleshoot_panel.css");
+static OVERVIEW_PANEL_CSS: Asset = asset!("/assets/overview_panel.css");
+static SIGNING_PANEL_CSS: Asset = asset!("/assets/signing_panel.css");
+static RELEASE_WIZARD_CSS: Asset = asset!("/assets/release_wizard.css");
+static DEBUG_PORTS_PANEL_CSS: Asset = asset!("/assets/debug_ports_panel.css");
+static SHORTCUT_SHEET_CSS: Asset = asset!("/assets/shortcut_sheet.css");
+static COMMAND_PALETTE_CSS: Asset = asset!("/assets/command_palette.css");
+static INSTALL_PREVIEW_CSS: Asset = asset!("/assets/install_preview.css");
+static INSTALL_QUEUE_CSS: Asset = asset!("/assets/install_queue.css");
+static ZXP_INSPECTOR_CSS: Asset = asset!("/assets/zxp_inspector.css");
+static INSTALL_COMPARE_CSS: Asset = asset!("/assets/install_compare.css");
 static INTER_FONT: Asset = asset!("/assets/fonts/Inter-VariableFont_opsz,wght.ttf");
 static GOOGLE_SANS_CODE_FONT: Asset = asset!("/assets/fonts/GoogleSansCode-VariableFont_wght.ttf");
 
 fn main() {
-    use dioxus::desktop::{Config, tao::dpi::LogicalSize, tao::window::WindowBuilder};
+    logging::init();
+    settings::load_settings(); // Applies the saved language and log level before any output, not on the first `SETTINGS` read
+    crash_report::install_panic_hook();
+    temp_files::sweep_stale_files(); // Leftovers of runs that crashed or were killed
+    file_operations::set_version_history(true);
+
+    // Subcommands run headless and exit without opening a window
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(exit_code) = cli::run(&args) {
+        std::process::exit(exit_code);
+    }
+    file_open::queue_launch_files(cli::launch_files(&args).unwrap_or_default());
+
+    use dioxus::desktop::{Config, WindowCloseBehaviour, tao::dpi::LogicalSize, tao::window::WindowBuilder};
+
+    let close_behaviour =
+        if tray::hides_on_close() { WindowCloseBehaviour::LastWindowHides } else { WindowCloseBehaviour::LastWindowExitsApp };
 
     dioxus::LaunchBuilder::desktop()
         .with_cfg(
-            Config::default().with_window(
-                WindowBuilder::new()
-                    .with_title("ZXP Manager")
-                    .with_inner_size(LogicalSize::new(900.0, 600.0))
-                    .with_min_inner_size(LogicalSize::new(800.0, 500.0))
-                    .with_resizable(true),
-            ),
+            Config::default()
+                .with_window(
+                    WindowBuilder::new()
+                        .with_title("ZXP Manager")
+                        .with_inner_size(LogicalSize::new(900.0, 600.0))
+                        .with_min_inner_size(LogicalSize::new(800.0, 500.0))
+                        .with_resizable(true),
+                )
+                .with_close_behaviour(close_behaviour)
+                .with_custom_event_handler(|event, _| file_open::queue_opened_event(event)),
         )
         .launch(App);
 }
 
 #[component]
 fn App() -> Element {
+    plugin_store::use_plugin_scanner();
+    scheduler::use_maintenance_scheduler();
+    hot_folder::use_hot_folder();
+    update_check::use_update_check();
+    telemetry::use_telemetry();
+    session::use_session_restore();
+    crash_report::use_crash_report_prompt();
+    tray::use_tray_companion();
+    notifications::use_focus_tracking();
+    file_open::use_file_open();
+    let theme = settings::SETTINGS.read().theme;
+    let motion = settings::SETTINGS.read().motion;
+
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
         document::Style {
@@ -66,13 +199,62 @@ fn App() -> Element {
         document::Stylesheet { href: SIDEBAR_CSS }
         document::Stylesheet { href: STATUS_BAR_CSS }
         document::Stylesheet { href: PLUGINS_PANEL_CSS }
+        document::Stylesheet { href: DIAGNOSTICS_PANEL_CSS }
+        document::Stylesheet { href: CONTEXT_MENU_CSS }
+        document::Stylesheet { href: DETAILS_DRAWER_CSS }
+        document::Stylesheet { href: TOASTS_CSS }
+        document::Stylesheet { href: ACTIVITY_PANEL_CSS }
+        document::Stylesheet { href: LOG_VIEWER_CSS }
+        document::Stylesheet { href: COMPLIANCE_PANEL_CSS }
+        document::Stylesheet { href: ERROR_CODES_PANEL_CSS }
+        document::Stylesheet { href: TROUBLESHOOT_PANEL_CSS }
+        document::Stylesheet { href: OVERVIEW_PANEL_CSS }
+        document::Stylesheet { href: SIGNING_PANEL_CSS }
+        document::Stylesheet { href: RELEASE_WIZARD_CSS }
+        document::Stylesheet { href: DEBUG_PORTS_PANEL_CSS }
+        document::Stylesheet { href: SHORTCUT_SHEET_CSS }
+        document::Stylesheet { href: COMMAND_PALETTE_CSS }
+        document::Stylesheet { href: INSTALL_PREVIEW_CSS }
+        document::Stylesheet { href: INSTALL_QUEUE_CSS }
+        document::Stylesheet { href: ZXP_INSPECTOR_CSS }
+        document::Stylesheet { href: INSTALL_COMPARE_CSS }
 
-        div { class: "container",
+        div {
+            class: "container",
+            "data-theme": theme.code(),
+            "data-motion": motion.code(),
+            tabindex: "0",
+            onkeydown: shortcuts::handle_shortcut,
+            // Focus the container so shortcuts work before anything is clicked
+            onmounted: move |event| async move {
+                let _ = event.set_focus(true).await;
+            },
             div { class: "main-content",
                 Sidebar {}
-                PluginsPanel {}
+                match ACTIVE_VIEW() {
+                    View::Plugins => rsx! { PluginsPanel {} },
+                    View::Overview => rsx! { OverviewPanel {} },
+                    View::Diagnostics => rsx! { DiagnosticsPanel {} },
+                    View::Compliance => rsx! { CompliancePanel {} },
+                    View::Troubleshoot => rsx! { TroubleshootPanel {} },
+                    View::Signing => rsx! { SigningPanel {} },
+                    View::Release => rsx! { ReleaseWizard {} },
+                    View::DebugPorts => rsx! { DebugPortsPanel {} },
+                    View::Logs => rsx! { LogViewer {} },
+                    View::ErrorCodes => rsx! { ErrorCodesPanel {} },
+                }
             }
             StatusBar {}
+            ToastStack {}
+            InstallPreviewDialog {}
+            ZxpInspector {}
+            InstallCompareDialog {}
+            if shortcuts::SHORTCUT_SHEET_OPEN() {
+                ShortcutSheet {}
+            }
+            if command_palette::COMMAND_PALETTE_OPEN() {
+                CommandPalette {}
+            }
         }
     }
 }
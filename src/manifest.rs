@@ -0,0 +1,157 @@
+use quick_xml::events::Event;
+use quick_xml::reader::Reader;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data_operations::{PluginError, PluginInfo, parse_manifest_str};
+
+// Each plugin ecosystem describes itself in its own file. A parser knows where its manifest sits in
+// a plugin folder and how to read it; the scan picks the first one whose manifest is there.
+pub trait ManifestParser: Sync {
+    fn name(&self) -> &'static str;
+
+    // Manifest in this format inside the plugin folder, if there is one
+    fn locate(&self, plugin_dir: &Path) -> Option<PathBuf>;
+
+    fn parse(&self, content: &str) -> Result<PluginInfo, PluginError>;
+}
+
+// CEP panels - CSXS/manifest.xml
+pub struct CepXml;
+
+// UXP plugins - manifest.json at the top of the folder
+pub struct UxpJson;
+
+// Legacy Extension Manager packages - a single .mxi file at the top of the folder
+pub struct Mxi;
+
+// In detection order: a CEP panel that also ships a manifest.json is still a CEP panel
+pub static PARSERS: [&dyn ManifestParser; 3] = [&CepXml, &UxpJson, &Mxi];
+
+pub fn detect_manifest(plugin_dir: &Path) -> Option<(&'static dyn ManifestParser, PathBuf)> {
+    PARSERS.iter().find_map(|parser| parser.locate(plugin_dir).map(|manifest_path| (*parser, manifest_path)))
+}
+
+impl ManifestParser for CepXml {
+    fn name(&self) -> &'static str {
+        "CEP manifest.xml"
+    }
+
+    fn locate(&self, plugin_dir: &Path) -> Option<PathBuf> {
+        Some(plugin_dir.join("CSXS").join("manifest.xml")).filter(|path| path.is_file())
+    }
+
+    fn parse(&self, content: &str) -> Result<PluginInfo, PluginError> {
+        parse_manifest_str(content)
+    }
+}
+
+impl ManifestParser for UxpJson {
+    fn name(&self) -> &'static str {
+        "UXP manifest.json"
+    }
+
+    fn locate(&self, plugin_dir: &Path) -> Option<PathBuf> {
+        Some(plugin_dir.join("manifest.json")).filter(|path| path.is_file())
+    }
+
+    fn parse(&self, content: &str) -> Result<PluginInfo, PluginError> {
+        let manifest: serde_json::Value =
+            serde_json::from_str(content).map_err(|e| PluginError::InvalidManifest(e.to_string()))?;
+        let text = |value: Option<&serde_json::Value>| value.and_then(|value| value.as_str()).map(str::to_string);
+
+        let bundle_id = text(manifest.get("id"))
+            .filter(|id| !id.is_empty())
+            .ok_or_else(|| PluginError::InvalidManifest("manifest.json has no id".to_string()))?;
+        // Localized plugins give the name as { "default": ..., "<locale>": ... }
+        let name = text(manifest.get("name")).or_else(|| text(manifest.get("name").and_then(|name| name.get("default"))));
+        Ok(plugin_info(bundle_id, name, text(manifest.get("version"))))
+    }
+}
+
+impl ManifestParser for Mxi {
+    fn name(&self) -> &'static str {
+        "MXI"
+    }
+
+    fn locate(&self, plugin_dir: &Path) -> Option<PathBuf> {
+        fs::read_dir(plugin_dir)
+            .ok()?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("mxi")) && path.is_file())
+            .min() // Read-dir order varies - always pick the same file
+    }
+
+    fn parse(&self, content: &str) -> Result<PluginInfo, PluginError> {
+        let mut reader = Reader::from_str(content);
+        let mut buf = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Err(e) => return Err(PluginError::InvalidManifest(e.to_string())),
+                Ok(Event::Eof) => {
+                    return Err(PluginError::InvalidManifest("no macromedia-extension element".to_string()));
+                }
+                Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.name().as_ref() == b"macromedia-extension" => {
+                    let attribute = |key: &[u8]| {
+                        e.attributes()
+                            .flatten()
+                            .find(|attr| attr.key.as_ref() == key)
+                            .map(|attr| String::from_utf8_lossy(&attr.value).trim().to_string())
+                            .filter(|value| !value.is_empty())
+                    };
+                    let name = attribute(b"name");
+                    // Older packages have no id - Extension Manager identified them by name
+                    let bundle_id = attribute(b"id")
+                        .or_else(|| name.clone())
+                        .ok_or_else(|| PluginError::InvalidManifest("macromedia-extension has no id or name".to_string()))?;
+                    return Ok(plugin_info(bundle_id, name, attribute(b"version")));
+                }
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+}
+
+// Helper functions
+// Same fallbacks as CEP manifests: the ID stands in for a missing name
+fn plugin_info(bundle_id: String, name: Option<String>, version: Option<String>) -> PluginInfo {
+    PluginInfo {
+        name: name.filter(|name| !name.is_empty()).unwrap_or_else(|| bundle_id.clone()),
+        version: version.filter(|version| !version.is_empty()).unwrap_or_else(|| "Unknown".to_string()),
+        bundle_id,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsers_read_each_format() {
+        let uxp = UxpJson.parse(r#"{"id": "com.example.uxp", "name": {"default": "Layers Pro"}, "version": "2.1.0"}"#).unwrap();
+        assert_eq!((uxp.bundle_id.as_str(), uxp.name.as_str(), uxp.version.as_str()), ("com.example.uxp", "Layers Pro", "2.1.0"));
+        assert!(UxpJson.parse(r#"{"name": "No ID"}"#).is_err());
+
+        let mxi = Mxi.parse(r#"<?xml version="1.0"?><macromedia-extension name="Old Tools" version="1.5" type="Command"/>"#).unwrap();
+        assert_eq!((mxi.bundle_id.as_str(), mxi.version.as_str()), ("Old Tools", "1.5"));
+    }
+
+    #[test]
+    fn test_detect_manifest_by_layout() {
+        let dir = std::env::temp_dir().join(format!("zxpm-layout-{}", std::process::id()));
+        fs::create_dir_all(dir.join("CSXS")).unwrap();
+        fs::write(dir.join("manifest.json"), r#"{"id": "com.example.uxp"}"#).unwrap();
+        assert_eq!(detect_manifest(&dir).map(|(parser, _)| parser.name()), Some("UXP manifest.json"));
+
+        fs::write(dir.join("CSXS").join("manifest.xml"), r#"<ExtensionManifest ExtensionBundleId="com.example.cep"/>"#).unwrap();
+        let (parser, manifest_path) = detect_manifest(&dir).unwrap();
+        assert_eq!(parser.name(), "CEP manifest.xml");
+        assert_eq!(parser.parse(&fs::read_to_string(manifest_path).unwrap()).unwrap().bundle_id, "com.example.cep");
+
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(detect_manifest(&dir).is_none());
+    }
+}
@@ -0,0 +1,261 @@
+use quick_xml::Writer;
+use quick_xml::events::{BytesDecl, BytesStart, Event};
+use quick_xml::reader::Reader;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data_operations::{HostRequirement, PluginError, parse_extension_entries_content, parse_manifest_str, read_manifest_text};
+use crate::file_operations::FileOperationError;
+
+// Edits of an installed CEP manifest: display name, version string and host version ranges, the
+// usual way to make an older panel load in a newer host. The first save keeps the untouched
+// manifest next to it, so the edit can always be undone.
+
+const BACKUP_FILE: &str = "manifest.xml.original";
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ManifestEdit {
+    pub name: String,                 // ExtensionBundleName
+    pub version: String,              // ExtensionBundleVersion
+    pub hosts: Vec<HostRequirement>, // Every <Host> with this name gets the version; None drops the attribute
+}
+
+pub fn manifest_path(plugin_dir: &Path) -> PathBuf {
+    plugin_dir.join("CSXS").join("manifest.xml")
+}
+
+pub fn backup_path(plugin_dir: &Path) -> PathBuf {
+    plugin_dir.join("CSXS").join(BACKUP_FILE)
+}
+
+pub fn has_backup(plugin_dir: &Path) -> bool {
+    backup_path(plugin_dir).is_file()
+}
+
+// The fields as the manifest has them, without parse_manifest_str's fallbacks
+pub fn read_manifest_edit(plugin_dir: &Path) -> Result<ManifestEdit, PluginError> {
+    let content = read_manifest_text(&manifest_path(plugin_dir))?;
+    let mut reader = Reader::from_str(&content);
+    let mut edit = ManifestEdit::default();
+
+    loop {
+        match reader.read_event() {
+            Err(e) => return Err(PluginError::InvalidManifest(e.to_string())),
+            Ok(Event::Eof) => break,
+            Ok(Event::Start(e) | Event::Empty(e)) => match e.name().as_ref() {
+                b"ExtensionManifest" => {
+                    edit.name = attribute(&e, b"ExtensionBundleName").unwrap_or_default();
+                    edit.version = attribute(&e, b"ExtensionBundleVersion").unwrap_or_default();
+                }
+                b"Host" => {
+                    let host = HostRequirement {
+                        name: attribute(&e, b"Name").unwrap_or_default(),
+                        version: attribute(&e, b"Version").filter(|version| !version.is_empty()),
+                    };
+                    if !host.name.is_empty() && !edit.hosts.iter().any(|existing| existing.name == host.name) {
+                        edit.hosts.push(host);
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+    Ok(edit)
+}
+
+// Locale key and the offending value for every field that can't be saved; empty when the edit is fine
+pub fn edit_problems(edit: &ManifestEdit) -> Vec<(&'static str, String)> {
+    let mut problems = Vec::new();
+    if edit.name.trim().is_empty() {
+        problems.push(("manifest-edit-name-empty", String::new()));
+    }
+    if !is_version(edit.version.trim()) {
+        problems.push(("manifest-edit-bad-version", edit.version.clone()));
+    }
+    for host in &edit.hosts {
+        if let Some(range) = &host.version
+            && !is_version_range(range.trim())
+        {
+            problems.push(("manifest-edit-bad-range", format!("{} {}", host.name, range)));
+        }
+    }
+    problems
+}
+
+// Rewrites only the edited attributes; everything else in the file is written back as read
+pub fn apply_manifest_edit(content: &str, edit: &ManifestEdit) -> Result<String, PluginError> {
+    let invalid = |e: &dyn std::fmt::Display| PluginError::InvalidManifest(e.to_string());
+    let mut reader = Reader::from_str(content);
+    let mut writer = Writer::new(Vec::new());
+
+    loop {
+        let event = reader.read_event().map_err(|e| invalid(&e))?;
+        let event = match event {
+            Event::Eof => break,
+            Event::Start(e) if is_edited(&e) => Event::Start(edited_element(&e, edit).map_err(|e| invalid(&e))?),
+            Event::Empty(e) if is_edited(&e) => Event::Empty(edited_element(&e, edit).map_err(|e| invalid(&e))?),
+            // The file is written back as UTF-8, so a UTF-16 declaration would lie about it
+            Event::Decl(decl) if decl.encoding().is_some() => {
+                let version = String::from_utf8_lossy(&decl.version().map_err(|e| invalid(&e))?).to_string();
+                let standalone = decl.standalone().and_then(Result::ok).map(|value| String::from_utf8_lossy(&value).to_string());
+                Event::Decl(BytesDecl::new(&version, Some("UTF-8"), standalone.as_deref()))
+            }
+            other => other,
+        };
+        writer.write_event(event).map_err(|e| invalid(&e))?;
+    }
+
+    let updated = String::from_utf8(writer.into_inner()).map_err(|e| invalid(&e))?;
+    // Same bundle, still readable - or nothing gets written
+    let before = parse_manifest_str(content)?;
+    if parse_manifest_str(&updated)?.bundle_id != before.bundle_id {
+        return Err(PluginError::InvalidManifest("bundle ID changed".to_string()));
+    }
+    parse_extension_entries_content(&updated)?;
+    Ok(updated)
+}
+
+pub fn save_manifest_edit(plugin_dir: &Path, edit: &ManifestEdit) -> Result<(), FileOperationError> {
+    let path = manifest_path(plugin_dir);
+    let invalid = || FileOperationError::InvalidExtension(path.clone());
+    if !edit_problems(edit).is_empty() {
+        return Err(invalid());
+    }
+    let content = read_manifest_text(&path).map_err(|_| invalid())?;
+    let updated = apply_manifest_edit(&content, edit).map_err(|_| invalid())?;
+
+    // Only the first edit is backed up - later ones must not replace the original
+    let backup = backup_path(plugin_dir);
+    if !backup.exists() {
+        fs::copy(&path, &backup).map_err(|e| FileOperationError::extract(&backup, e))?;
+    }
+    fs::write(&path, updated).map_err(|e| FileOperationError::extract(&path, e))?;
+    log::info!("Edited manifest {:?}: {} {}", path, edit.name, edit.version);
+    Ok(())
+}
+
+pub fn restore_original_manifest(plugin_dir: &Path) -> Result<(), FileOperationError> {
+    let (backup, path) = (backup_path(plugin_dir), manifest_path(plugin_dir));
+    if !backup.is_file() {
+        return Err(FileOperationError::FileNotFound(backup));
+    }
+    fs::rename(&backup, &path).map_err(|e| FileOperationError::extract(&path, e))?;
+    log::info!("Restored the original manifest {:?}", path);
+    Ok(())
+}
+
+// Helper functions
+fn is_edited(element: &BytesStart) -> bool {
+    matches!(element.name().as_ref(), b"ExtensionManifest" | b"Host")
+}
+
+// The element with the edit's values in place of the old ones, other attributes kept in order
+fn edited_element(element: &BytesStart, edit: &ManifestEdit) -> Result<BytesStart<'static>, quick_xml::Error> {
+    let name = String::from_utf8_lossy(element.name().as_ref()).to_string();
+    let replacements: Vec<(&str, Option<&str>)> = if name == "ExtensionManifest" {
+        vec![("ExtensionBundleName", Some(edit.name.trim())), ("ExtensionBundleVersion", Some(edit.version.trim()))]
+    } else {
+        let host_name = attribute(element, b"Name").unwrap_or_default();
+        match edit.hosts.iter().find(|host| host.name == host_name) {
+            Some(host) => vec![("Version", host.version.as_deref().map(str::trim))],
+            None => Vec::new(),
+        }
+    };
+
+    let mut updated = BytesStart::new(name);
+    let mut written: Vec<&str> = Vec::new();
+    for attr in element.attributes() {
+        let attr = attr.map_err(quick_xml::Error::from)?;
+        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+        match replacements.iter().find(|(replaced, _)| *replaced == key) {
+            Some((replaced, value)) => {
+                if let Some(value) = value {
+                    updated.push_attribute((*replaced, *value));
+                }
+                written.push(replaced);
+            }
+            None => updated.push_attribute(attr),
+        }
+    }
+    // Attributes the manifest didn't have yet, e.g. a missing ExtensionBundleName
+    for (key, value) in &replacements {
+        if let (false, Some(value)) = (written.contains(key), value) {
+            updated.push_attribute((*key, *value));
+        }
+    }
+    Ok(updated.into_owned())
+}
+
+fn attribute(element: &BytesStart, name: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|attr| attr.key.as_ref() == name)
+        .and_then(|attr| attr.unescape_value().ok().map(|value| value.trim().to_string()))
+}
+
+// 1, 1.2, 1.2.3 or 1.2.3.4
+fn is_version(text: &str) -> bool {
+    let parts: Vec<&str> = text.split('.').collect();
+    parts.len() <= 4 && parts.iter().all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+// A single version or an interval such as [22.0,99.9]
+fn is_version_range(text: &str) -> bool {
+    let Some(inner) = text.strip_prefix(['[', '(']).and_then(|rest| rest.strip_suffix([']', ')'])) else {
+        return is_version(text);
+    };
+    match inner.split_once(',') {
+        Some((min, max)) => is_version(min.trim()) && is_version(max.trim()),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"<?xml version="1.0" encoding="UTF-16"?>
+<!-- Built by the vendor's tooling -->
+<ExtensionManifest Version="7.0" ExtensionBundleId="com.example.old" ExtensionBundleVersion="1.0.0" ExtensionBundleName="Old &amp; Good">
+  <ExecutionEnvironment>
+    <HostList>
+      <Host Name="PHXS" Version="[18.0,22.9]" />
+      <Host Name="ILST" Version="23.0" />
+    </HostList>
+  </ExecutionEnvironment>
+</ExtensionManifest>"#;
+
+    #[test]
+    fn test_apply_manifest_edit_rewrites_only_edited_attributes() {
+        let edit = ManifestEdit {
+            name: "Old & Better".to_string(),
+            version: "1.0.1".to_string(),
+            hosts: vec![
+                HostRequirement { name: "PHXS".to_string(), version: Some("[18.0,99.9]".to_string()) },
+                HostRequirement { name: "ILST".to_string(), version: None },
+            ],
+        };
+        let updated = apply_manifest_edit(MANIFEST, &edit).unwrap();
+        assert!(updated.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?>"#));
+        assert!(updated.contains("<!-- Built by the vendor's tooling -->"));
+        assert!(updated.contains(r#"ExtensionBundleName="Old &amp; Better""#));
+        assert!(updated.contains(r#"<Host Name="PHXS" Version="[18.0,99.9]"/>"#));
+        assert!(updated.contains(r#"<Host Name="ILST"/>"#));
+
+        let info = parse_manifest_str(&updated).unwrap();
+        assert_eq!((info.bundle_id.as_str(), info.version.as_str()), ("com.example.old", "1.0.1"));
+    }
+
+    #[test]
+    fn test_edit_problems() {
+        let mut edit = ManifestEdit { name: "Panel".to_string(), version: "2.1".to_string(), hosts: Vec::new() };
+        assert!(edit_problems(&edit).is_empty());
+        edit.version = "2.1-beta".to_string();
+        edit.hosts.push(HostRequirement { name: "AEFT".to_string(), version: Some("[18.0,".to_string()) });
+        let keys: Vec<&str> = edit_problems(&edit).into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["manifest-edit-bad-version", "manifest-edit-bad-range"]);
+        assert!(is_version_range("(18.0, 99.9]") && is_version_range("25") && !is_version_range("[18.0]"));
+    }
+}
@@ -1,7 +1,14 @@
 use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
 use std::time::Duration;
-use tokio_util::sync::CancellationToken;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::clock::{LocalTime, unix_now};
+use crate::i18n::t_args;
+use crate::plugin_list::{ListFormat, csv_field};
+use crate::settings::SETTINGS;
 
 #[derive(Clone, Debug)]
 pub struct Message {
@@ -9,27 +16,138 @@ pub struct Message {
     pub msg_type: MessageType,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum MessageType {
     Success,
     Error,
     Info,
-    None, // For empty/cleared state
 }
 
-// Global signal for the current message - accessible from anywhere
-pub static MESSAGE: GlobalSignal<Message> = Signal::global(|| Message {
-    content: String::new(),
-    msg_type: MessageType::None,
-});
+impl MessageType {
+    pub const ALL: [MessageType; 3] = [MessageType::Success, MessageType::Info, MessageType::Error];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            MessageType::Success => "toast-type-success",
+            MessageType::Error => "toast-type-error",
+            MessageType::Info => "toast-type-info",
+        }
+    }
+}
+
+// Where the toast stack appears - above the status bar unless the user moves it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum ToastPosition {
+    #[default]
+    StatusBar, // Bottom right, just above the status bar
+    TopRight,
+    TopCenter,
+}
+
+impl ToastPosition {
+    pub const ALL: [ToastPosition; 3] = [ToastPosition::StatusBar, ToastPosition::TopRight, ToastPosition::TopCenter];
 
-// Global signal to hold the cancellation token for the current timer
-// This allows us to cancel the previous message's timeout when a new message appears
-static MESSAGE_CANCEL_TOKEN: GlobalSignal<Option<CancellationToken>> = Signal::global(|| None);
+    pub fn code(&self) -> &'static str {
+        match self {
+            ToastPosition::StatusBar => "status-bar",
+            ToastPosition::TopRight => "top-right",
+            ToastPosition::TopCenter => "top-center",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<ToastPosition> {
+        ToastPosition::ALL.into_iter().find(|position| position.code() == code)
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ToastPosition::StatusBar => "toast-position-status-bar",
+            ToastPosition::TopRight => "toast-position-top-right",
+            ToastPosition::TopCenter => "toast-position-top-center",
+        }
+    }
+}
+
+// Seconds each kind of toast stays up; 0 keeps it until dismissed
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ToastDurations {
+    pub success: u64,
+    pub info: u64,
+    pub error: u64,
+}
+
+impl Default for ToastDurations {
+    fn default() -> Self {
+        // Success disappears quickly, errors stay a bit longer
+        Self { success: 3, info: 5, error: 6 }
+    }
+}
+
+impl ToastDurations {
+    // Offered in settings; any other value from a hand-edited file still works
+    pub const CHOICES: [u64; 7] = [3, 5, 6, 10, 15, 30, 0];
+
+    pub fn get(&self, msg_type: MessageType) -> u64 {
+        match msg_type {
+            MessageType::Success => self.success,
+            MessageType::Error => self.error,
+            MessageType::Info => self.info,
+        }
+    }
+
+    pub fn set(&mut self, msg_type: MessageType, seconds: u64) {
+        match msg_type {
+            MessageType::Success => self.success = seconds,
+            MessageType::Error => self.error = seconds,
+            MessageType::Info => self.info = seconds,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Toast {
+    pub id: u64,
+    pub message: Message,
+    pub sticky: bool, // Waits for the dismiss button instead of a timer
+}
+
+// Oldest toasts are dropped beyond this, so a long batch can't cover the window
+const MAX_VISIBLE_TOASTS: usize = 5;
+
+static NEXT_TOAST_ID: AtomicU64 = AtomicU64::new(0);
+
+// Global signal for the visible toasts, oldest first - accessible from anywhere
+pub static TOASTS: GlobalSignal<Vec<Toast>> = Signal::global(Vec::new);
 
 // Global refresh trigger signal - accessible from anywhere
 pub static REFRESH_TRIGGER: GlobalSignal<bool> = Signal::global(|| false);
 
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub timestamp: i64, // Unix seconds
+    pub content: String,
+    pub msg_type: MessageType,
+}
+
+// One row of a history export
+#[derive(Debug, Serialize)]
+struct HistoryRecord<'a> {
+    timestamp: i64,
+    time: String, // Local time, for readers who don't want to convert Unix seconds
+    #[serde(rename = "type")]
+    msg_type: &'static str,
+    message: &'a str,
+}
+
+const HISTORY_CSV_HEADER: [&str; 4] = ["timestamp", "time", "type", "message"];
+
+// Oldest entries are dropped beyond this, mostly to keep the saved file small
+const HISTORY_LIMIT: usize = 500;
+
+// Global signal for the activity history, oldest first - includes previous sessions when kept
+pub static HISTORY: GlobalSignal<Vec<HistoryEntry>> = Signal::global(load_history);
+
 // Global signal for last installed plugin (for animation)
 pub static LAST_INSTALLED_PLUGIN: GlobalSignal<Option<PathBuf>> = Signal::global(|| None);
 
@@ -41,6 +159,87 @@ pub fn clear_newly_installed_plugin() {
     *LAST_INSTALLED_PLUGIN.write() = None;
 }
 
+pub fn add_history_entry(content: String, msg_type: MessageType) {
+    let mut history = HISTORY.write();
+    history.push(HistoryEntry { timestamp: unix_now(), content, msg_type });
+
+    let overflow = history.len().saturating_sub(HISTORY_LIMIT);
+    history.drain(..overflow);
+
+    if SETTINGS.peek().keep_history {
+        save_history(&history);
+    }
+}
+
+pub fn clear_history() {
+    HISTORY.write().clear();
+    delete_saved_history();
+}
+
+pub fn history_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("history.json"))
+}
+
+// Called when the user turns keeping history across sessions on or off
+pub fn persist_history(keep: bool) {
+    if keep {
+        save_history(&HISTORY.peek());
+    } else {
+        delete_saved_history();
+    }
+}
+
+// Entries from `from` (inclusive) up to `until` (exclusive), either bound optional
+pub fn history_in_range(history: &[HistoryEntry], from: Option<i64>, until: Option<i64>) -> Vec<HistoryEntry> {
+    history
+        .iter()
+        .filter(|entry| from.is_none_or(|from| entry.timestamp >= from) && until.is_none_or(|until| entry.timestamp < until))
+        .cloned()
+        .collect()
+}
+
+pub fn render_history(entries: &[HistoryEntry], format: ListFormat) -> String {
+    let records: Vec<HistoryRecord> = entries
+        .iter()
+        .map(|entry| {
+            let time = LocalTime::from_unix(entry.timestamp);
+            HistoryRecord {
+                timestamp: entry.timestamp,
+                time: format!("{} {}", time.date_label(), time.time_label()),
+                msg_type: match entry.msg_type {
+                    MessageType::Success => "success",
+                    MessageType::Error => "error",
+                    MessageType::Info => "info",
+                },
+                message: &entry.content,
+            }
+        })
+        .collect();
+
+    match format {
+        ListFormat::Text => records
+            .iter()
+            .map(|record| format!("{}\t{}\t{}\n", record.time, record.msg_type, record.message))
+            .collect(),
+        ListFormat::Json => {
+            let mut json = serde_json::to_string_pretty(&records).unwrap_or_default();
+            json.push('\n');
+            json
+        }
+        ListFormat::Csv => {
+            let mut csv = HISTORY_CSV_HEADER.join(",") + "\n";
+            for record in &records {
+                let timestamp = record.timestamp.to_string();
+                let fields = [timestamp.as_str(), record.time.as_str(), record.msg_type, record.message];
+                let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+                csv.push_str(&row.join(","));
+                csv.push('\n');
+            }
+            csv
+        }
+    }
+}
+
 pub fn trigger_refresh() {
     // Read current value, then write opposite
     let current = REFRESH_TRIGGER();
@@ -48,65 +247,143 @@ pub fn trigger_refresh() {
 }
 
 pub fn show_message(content: String, msg_type: MessageType) {
-    // Step 1: Cancel any existing timer to prevent old messages from interfering
-    // If there's already a message timer running, we cancel it immediately
-    if let Some(existing_token) = MESSAGE_CANCEL_TOKEN.read().as_ref() {
-        existing_token.cancel(); // This stops the previous timer from clearing the message
-        log::debug!("Cancelled previous message timer");
-    }
-    
-    // Step 2: Create a new cancellation token for this message's timer
-    // This token will be used to cancel this timer if another message appears
-    let cancel_token = CancellationToken::new();
-    *MESSAGE_CANCEL_TOKEN.write() = Some(cancel_token.clone());
+    // Every message gets its own toast and timer, so results of a batch all stay visible
+    let id = NEXT_TOAST_ID.fetch_add(1, Ordering::Relaxed);
+    log::debug!("Showing {:?} toast #{}: {}", msg_type, id, content);
+    add_history_entry(content.clone(), msg_type);
     
-    // Step 3: Display the new message immediately
-    // User sees the new message right away, regardless of what was showing before
-    *MESSAGE.write() = Message { content, msg_type: msg_type.clone() };
-    log::debug!("Displayed new {:?} message: {}", msg_type, MESSAGE.read().content);
+    // How long this message type stays is up to the user; 0 waits for the dismiss button
+    let timeout_secs = SETTINGS.peek().toast_durations.get(msg_type);
+    {
+        let mut toasts = TOASTS.write();
+        toasts.push(Toast { id, message: Message { content, msg_type }, sticky: timeout_secs == 0 });
+        trim_toasts(&mut toasts);
+    }
     
-    // Step 4: Start the timeout timer in a separate async task
+    if timeout_secs == 0 {
+        return;
+    }
     spawn(async move {
-        // Determine how long this message type should be displayed
-        let timeout_secs = match msg_type {
-            MessageType::Success => 3, // Success messages disappear quickly
-            MessageType::Error => 4,   // Error messages stay a bit longer
-            MessageType::Info => 5,    // Info messages stay longest
-            MessageType::None => 0,    // None should not happen, but handle it
-        };
-        
-        // Step 5: Wait for either the timeout OR cancellation
-        // tokio::select! runs both futures and returns when the first one completes
-        tokio::select! {
-            // Branch A: Timer completed naturally - clear the message
-            _ = tokio::time::sleep(Duration::from_secs(timeout_secs)) => {
-                log::debug!("Message timer completed, clearing message");
-                *MESSAGE.write() = Message {
-                    content: String::new(),
-                    msg_type: MessageType::None,
-                };
-                // Clean up our token since we're done with it
-                *MESSAGE_CANCEL_TOKEN.write() = None;
-            }
-            
-            // Branch B: Timer was cancelled - do nothing
-            _ = cancel_token.cancelled() => {
-                log::debug!("Message timer was cancelled by newer message");
-                // Don't clear the message - a newer message is now showing
-                // The newer message has its own timer that will handle clearing
-            }
-        }
+        tokio::time::sleep(Duration::from_secs(timeout_secs)).await;
+        dismiss_toast(id); // No-op if the user already closed it
     });
 }
 
-pub fn show_error(content: String) {
-    show_message(content, MessageType::Error);
+pub fn dismiss_toast(id: u64) {
+    TOASTS.write().retain(|toast| toast.id != id);
+}
+
+pub fn dismiss_all_toasts() {
+    TOASTS.write().clear();
+}
+
+// Toasts take a message key and its arguments and translate here, so every toast follows the current
+// language; the shown text is returned for callers that also log or notify it
+pub fn show_error(key: &str, args: &[(&str, &str)]) -> String {
+    show_translated(key, args, MessageType::Error)
+}
+
+pub fn show_success(key: &str, args: &[(&str, &str)]) -> String {
+    show_translated(key, args, MessageType::Success)
 }
 
-pub fn show_success(content: String) {
-    show_message(content, MessageType::Success);
+pub fn show_info(key: &str, args: &[(&str, &str)]) -> String {
+    show_translated(key, args, MessageType::Info)
 }
 
-pub fn show_info(content: String) {
-    show_message(content, MessageType::Info);
-}
\ No newline at end of file
+// Helper functions
+fn show_translated(key: &str, args: &[(&str, &str)], msg_type: MessageType) -> String {
+    let content = t_args(key, args);
+    show_message(content.clone(), msg_type);
+    content
+}
+
+// Once the stack is full the oldest toast goes - one that would time out anyway before a sticky
+// error the user hasn't read yet
+fn trim_toasts(toasts: &mut Vec<Toast>) {
+    while toasts.len() > MAX_VISIBLE_TOASTS {
+        let oldest = toasts.iter().position(|toast| !toast.sticky).unwrap_or(0);
+        toasts.remove(oldest);
+    }
+}
+
+fn load_history() -> Vec<HistoryEntry> {
+    if !SETTINGS.peek().keep_history {
+        return Vec::new();
+    }
+    let Some(path) = history_path() else {
+        return Vec::new();
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            log::warn!("Failed to parse history {:?}: {}", path, e);
+            Vec::new()
+        }),
+        Err(_) => Vec::new(), // Nothing saved yet
+    }
+}
+
+fn save_history(history: &[HistoryEntry]) {
+    let Some(path) = history_path() else {
+        return;
+    };
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| serde_json::to_string(history).map_err(std::io::Error::from))
+        .and_then(|content| fs::write(&path, content));
+
+    if let Err(e) = result {
+        log::error!("Failed to save history {:?}: {}", path, e);
+    }
+}
+
+fn delete_saved_history() {
+    if let Some(path) = history_path().filter(|path| path.exists())
+        && let Err(e) = fs::remove_file(&path)
+    {
+        log::error!("Failed to delete history {:?}: {}", path, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_export_range_and_csv() {
+        let entry = |timestamp, content: &str, msg_type| HistoryEntry { timestamp, content: content.to_string(), msg_type };
+        let history = vec![
+            entry(100, "Installed Tools", MessageType::Success),
+            entry(200, "Export failed: disk full, retry", MessageType::Error),
+            entry(300, "Update available", MessageType::Info),
+        ];
+
+        assert_eq!(history_in_range(&history, None, None).len(), 3);
+        let selected = history_in_range(&history, Some(200), Some(300));
+        assert_eq!(selected, vec![history[1].clone()]);
+
+        let csv = render_history(&selected, ListFormat::Csv);
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("timestamp,time,type,message"));
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("200,"));
+        assert!(row.ends_with(",error,\"Export failed: disk full, retry\""));
+        assert!(render_history(&selected, ListFormat::Json).contains("\"type\": \"error\""));
+    }
+
+    #[test]
+    fn test_full_stack_drops_timed_toasts_before_sticky_ones() {
+        let toast = |id, sticky| Toast { id, message: Message { content: id.to_string(), msg_type: MessageType::Info }, sticky };
+        let mut toasts: Vec<Toast> = (0..MAX_VISIBLE_TOASTS as u64).map(|id| toast(id, id < 2)).collect();
+        toasts.push(toast(99, false));
+        trim_toasts(&mut toasts);
+        assert_eq!(toasts.iter().map(|toast| toast.id).collect::<Vec<_>>(), [0, 1, 3, 4, 99]);
+
+        let mut sticky: Vec<Toast> = (0..=MAX_VISIBLE_TOASTS as u64).map(|id| toast(id, true)).collect();
+        trim_toasts(&mut sticky);
+        assert_eq!(sticky.first().map(|toast| toast.id), Some(1));
+    }
+}
@@ -0,0 +1,54 @@
+use dioxus::prelude::*;
+
+// Views that can fill the main area next to the sidebar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Plugins,
+    Overview,
+    Diagnostics,
+    Compliance,
+    Troubleshoot,
+    Signing,
+    Release,
+    DebugPorts,
+    Logs,
+    ErrorCodes,
+}
+
+impl View {
+    pub const ALL: [View; 10] = [
+        View::Plugins,
+        View::Overview,
+        View::Diagnostics,
+        View::Compliance,
+        View::Troubleshoot,
+        View::Signing,
+        View::Release,
+        View::DebugPorts,
+        View::Logs,
+        View::ErrorCodes,
+    ];
+
+    // Translation key for the navigation label
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            View::Plugins => "nav-plugins",
+            View::Overview => "nav-overview",
+            View::Diagnostics => "nav-diagnostics",
+            View::Compliance => "nav-compliance",
+            View::Troubleshoot => "nav-troubleshoot",
+            View::Signing => "nav-signing",
+            View::Release => "nav-release",
+            View::DebugPorts => "nav-debug-ports",
+            View::Logs => "nav-logs",
+            View::ErrorCodes => "nav-error-codes",
+        }
+    }
+}
+
+// Global signal for the currently visible view
+pub static ACTIVE_VIEW: GlobalSignal<View> = Signal::global(|| View::Plugins);
+
+pub fn show_view(view: View) {
+    *ACTIVE_VIEW.write() = view;
+}
@@ -0,0 +1,89 @@
+use dioxus::desktop::tao::event::{Event, WindowEvent};
+use dioxus::desktop::use_wry_event_handler;
+use dioxus::prelude::*;
+use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::settings::SETTINGS;
+
+// Native notifications for results that arrive while the window is in the background or hidden
+// in the tray; the in-app toast is shown either way. Sent through the OS's own command-line
+// tools: osascript on macOS, a PowerShell toast on Windows, notify-send elsewhere.
+
+const TITLE: &str = "ZXP Manager";
+
+// Tracked from window events, so background tasks can ask without a window handle
+static WINDOW_FOCUSED: AtomicBool = AtomicBool::new(true);
+
+pub fn use_focus_tracking() {
+    use_wry_event_handler(|event, _| {
+        if let Event::WindowEvent { event: WindowEvent::Focused(focused), .. } = event {
+            WINDOW_FOCUSED.store(*focused, Ordering::Relaxed);
+        }
+    });
+}
+
+// Called next to the toast for installs, batch results and the update check
+pub fn notify_when_away(message: &str) {
+    if WINDOW_FOCUSED.load(Ordering::Relaxed) || SETTINGS.peek().mute_system_notifications {
+        return;
+    }
+    let (program, args) = notification_command(TITLE, message);
+    std::thread::spawn(move || match Command::new(&program).args(&args).output() {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => log::warn!("{} failed to show a notification: {}", program, String::from_utf8_lossy(&output.stderr).trim()),
+        Err(e) => log::warn!("Failed to run {} for a notification: {}", program, e),
+    });
+}
+
+pub fn notification_command(title: &str, body: &str) -> (String, Vec<String>) {
+    if cfg!(target_os = "macos") {
+        let script = format!("display notification {} with title {}", applescript_string(body), applescript_string(title));
+        ("osascript".to_string(), vec!["-e".to_string(), script])
+    } else if cfg!(target_os = "windows") {
+        ("powershell".to_string(), vec!["-NoProfile".to_string(), "-Command".to_string(), toast_script(title, body)])
+    } else {
+        ("notify-send".to_string(), vec![format!("--app-name={}", title), title.to_string(), body.to_string()])
+    }
+}
+
+// Helper functions
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+// WinRT toast shown under PowerShell's app ID, which every Windows install has registered
+fn toast_script(title: &str, body: &str) -> String {
+    let xml = format!(
+        "<toast><visual><binding template=\"ToastGeneric\"><text>{}</text><text>{}</text></binding></visual></toast>",
+        escape_xml(title),
+        escape_xml(body)
+    );
+    format!(
+        "[Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] | Out-Null; \
+         [Windows.Data.Xml.Dom.XmlDocument, Windows.Data.Xml.Dom.XmlDocument, ContentType = WindowsRuntime] | Out-Null; \
+         $xml = New-Object Windows.Data.Xml.Dom.XmlDocument; $xml.LoadXml('{}'); \
+         $appId = '{{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}}\\WindowsPowerShell\\v1.0\\powershell.exe'; \
+         [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier($appId).Show([Windows.UI.Notifications.ToastNotification]::new($xml))",
+        xml.replace('\'', "''")
+    )
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notification_text_is_quoted_for_each_shell() {
+        assert_eq!(applescript_string(r#"Say "hi" \o/"#), r#""Say \"hi\" \\o/""#);
+        let script = toast_script("ZXP Manager", "Tom's <Panel> & co");
+        assert!(script.contains("Tom''s &lt;Panel&gt; &amp; co"));
+
+        let (_, args) = notification_command(TITLE, "Installed Panel");
+        assert!(args.iter().any(|arg| arg.contains("Installed Panel")));
+    }
+}
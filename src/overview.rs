@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::data_operations::Plugin;
+use crate::host_detection::{PluginTargets, host_display_name};
+
+// How many extensions the Overview lists as the largest
+pub const LARGEST_COUNT: usize = 5;
+
+// Totals behind the Overview view - a quick look at what fills a bloated CEP folder
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Overview {
+    pub plugin_count: usize,
+    pub disk_bytes: u64,
+    pub by_host: Vec<Breakdown>,   // A plugin counts once for every host it lists
+    pub by_vendor: Vec<Breakdown>,
+    pub largest: Vec<Plugin>, // Biggest on disk first
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Breakdown {
+    pub label: String, // None of the hosts or vendors known - shown as "Unknown"
+    pub count: usize,
+    pub disk_bytes: u64,
+}
+
+pub fn build_overview(plugins: &[Plugin], targets: &HashMap<PathBuf, PluginTargets>) -> Overview {
+    let mut by_host: HashMap<String, Breakdown> = HashMap::new();
+    let mut by_vendor: HashMap<String, Breakdown> = HashMap::new();
+    let add = |groups: &mut HashMap<String, Breakdown>, label: String, plugin: &Plugin| {
+        let entry = groups.entry(label.clone()).or_insert(Breakdown { label, count: 0, disk_bytes: 0 });
+        entry.count += 1;
+        entry.disk_bytes += plugin.disk_bytes;
+    };
+
+    for plugin in plugins {
+        let mut hosts: Vec<&str> = targets
+            .get(&plugin.path)
+            .map(|targets| targets.hosts.iter().map(|host| host_display_name(&host.name)).collect())
+            .unwrap_or_default();
+        hosts.sort_unstable();
+        hosts.dedup(); // PHSP and PHXS are both Photoshop
+        if hosts.is_empty() {
+            add(&mut by_host, String::new(), plugin);
+        }
+        for host in hosts {
+            add(&mut by_host, host.to_string(), plugin);
+        }
+        add(&mut by_vendor, vendor_of(&plugin.bundle_id), plugin);
+    }
+
+    let mut largest: Vec<Plugin> = plugins.to_vec();
+    largest.sort_by_key(|plugin| std::cmp::Reverse(plugin.disk_bytes));
+    largest.truncate(LARGEST_COUNT);
+
+    Overview {
+        plugin_count: plugins.len(),
+        disk_bytes: plugins.iter().map(|plugin| plugin.disk_bytes).sum(),
+        by_host: sorted_breakdown(by_host),
+        by_vendor: sorted_breakdown(by_vendor),
+        largest,
+    }
+}
+
+// Reverse-DNS IDs name the vendor second: "com.acme.tools" -> "acme"; anything else has no vendor
+pub fn vendor_of(bundle_id: &str) -> String {
+    let mut parts = bundle_id.split('.');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(_), Some(vendor), Some(_)) if !vendor.is_empty() => vendor.to_lowercase(),
+        _ => String::new(),
+    }
+}
+
+// Helper functions
+// Most plugins first, then by label; the unknown group always goes last
+fn sorted_breakdown(groups: HashMap<String, Breakdown>) -> Vec<Breakdown> {
+    let mut sorted: Vec<Breakdown> = groups.into_values().collect();
+    sorted.sort_by(|a, b| {
+        (a.label.is_empty(), std::cmp::Reverse(a.count), &a.label).cmp(&(b.label.is_empty(), std::cmp::Reverse(b.count), &b.label))
+    });
+    sorted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_operations::HostRequirement;
+
+    fn plugin(id: &str, disk_bytes: u64) -> Plugin {
+        Plugin { disk_bytes, ..Plugin::for_test(id, "1.0") }
+    }
+
+    #[test]
+    fn test_overview_groups_by_host_and_vendor() {
+        let plugins = vec![plugin("com.acme.a", 100), plugin("com.acme.b", 300), plugin("org.tools.c", 200), plugin("loose", 50)];
+        let hosts = |codes: &[&str]| PluginTargets {
+            hosts: codes.iter().map(|code| HostRequirement { name: code.to_string(), version: None }).collect(),
+            required_runtime: None,
+            ui_kinds: Vec::new(),
+        };
+        let targets = HashMap::from([
+            (plugins[0].path.clone(), hosts(&["PHXS", "PHSP", "ILST"])),
+            (plugins[1].path.clone(), hosts(&["PHXS"])),
+        ]);
+
+        let overview = build_overview(&plugins, &targets);
+        assert_eq!((overview.plugin_count, overview.disk_bytes), (4, 650));
+        let groups = |breakdown: &[Breakdown]| breakdown.iter().map(|group| (group.label.clone(), group.count)).collect::<Vec<_>>();
+        assert_eq!(groups(&overview.by_host), [("Photoshop".into(), 2), ("Illustrator".into(), 1), (String::new(), 2)]);
+        assert_eq!(groups(&overview.by_vendor), [("acme".into(), 2), ("tools".into(), 1), (String::new(), 1)]);
+        assert_eq!(overview.largest.iter().map(|plugin| plugin.disk_bytes).collect::<Vec<_>>(), [300, 200, 100, 50]);
+    }
+}
@@ -0,0 +1,433 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Component, Path, PathBuf};
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::clock::unix_now;
+use crate::data_operations::{Plugin, measure_folder, parse_extension_entries};
+use crate::file_operations::{FileOperationError, OrphanedEntry, cached_extension_id, cep_cache_path, cep_logs_path, stored_extension_id};
+
+// Panel settings kept outside the extension folder, which a reinstall leaves orphaned or a cache
+// reset wipes: each extension's web storage in the CEP cache (<HOST>_<version>_<extension ID>) and
+// folders named after the bundle or an extension ID in the user data folder (Application Support,
+// AppData\Roaming), directly or under Adobe/CEP. One backup ZIP per bundle ID. A deep-clean removal
+// deletes the same locations plus the extensions' CEP engine logs.
+
+// First path component in the archive, standing for the folder it was taken from
+const CACHE_PREFIX: &str = "cep_cache";
+const DATA_PREFIX: &str = "data";
+const LOGS_PREFIX: &str = "cep_logs"; // Only searched for leftovers - logs are never backed up
+const INFO_FILE: &str = "plugin-data.json";
+
+// One file or folder of panel settings and where it goes in the archive, e.g. data/Adobe/CEP/com.example.panel
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataLocation {
+    pub path: PathBuf,
+    pub archive_name: String,
+}
+
+// What a backup holds; restore replaces exactly these locations
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataBackupInfo {
+    pub bundle_id: String,
+    pub created_at: i64, // Unix seconds
+    pub locations: Vec<String>,
+}
+
+pub fn data_backup_path(bundle_id: &str) -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("backups").join("plugin-data").join(format!("{}.zip", bundle_id)))
+}
+
+// `ids` holds the bundle ID and every extension ID its manifest declares
+pub fn find_plugin_data(ids: &[String]) -> Vec<DataLocation> {
+    locations_in(&data_roots(), ids)
+}
+
+// The bundle ID and every extension ID its manifest declares - the names its data goes under
+pub fn plugin_data_ids(plugin: &Plugin) -> Vec<String> {
+    let entries = parse_extension_entries(&plugin.path.join("CSXS").join("manifest.xml")).unwrap_or_default();
+    std::iter::once(plugin.bundle_id.clone()).chain(entries.into_iter().map(|entry| entry.id)).collect()
+}
+
+// A deep clean only takes IDs no other installed or disabled copy declares - that copy, in the other
+// root or waiting to be enabled, still reads the same settings and cache
+pub fn unshared_data_ids(plugin: &Plugin, others: &[Plugin]) -> Vec<String> {
+    let shared: HashSet<String> = others.iter().filter(|other| other.path != plugin.path).flat_map(plugin_data_ids).collect();
+    plugin_data_ids(plugin).into_iter().filter(|id| !shared.contains(id)).collect()
+}
+
+// What a deep-clean removal deletes besides the extension folder, for the preview and the cleanup
+pub fn find_plugin_leftovers(ids: &[String]) -> Vec<OrphanedEntry> {
+    let mut roots = data_roots();
+    roots.extend(cep_logs_path().map(|path| (LOGS_PREFIX, path)));
+    leftovers_in(&roots, ids)
+}
+
+// None when the plugin was never backed up
+pub fn read_backup_info(bundle_id: &str) -> Option<DataBackupInfo> {
+    let file = fs::File::open(data_backup_path(bundle_id)?).ok()?;
+    let mut archive = ZipArchive::new(file).ok()?;
+    let entry = archive.by_name(INFO_FILE).ok()?;
+    serde_json::from_reader(entry).ok()
+}
+
+// Replaces the previous backup of the bundle; Ok(0) when the panel stores nothing outside its folder
+pub fn backup_plugin_data(bundle_id: &str, ids: &[String]) -> Result<usize, FileOperationError> {
+    let locations = find_plugin_data(ids);
+    if locations.is_empty() {
+        return Ok(0);
+    }
+    let destination = data_backup_path(bundle_id).ok_or_else(|| FileOperationError::FileNotFound(PathBuf::from(bundle_id)))?;
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent).map_err(|e| FileOperationError::archive(parent, e))?;
+    }
+    write_backup(&destination, bundle_id, &locations)?;
+    log::info!("Backed up {} settings locations of {} to {:?}", locations.len(), bundle_id, destination);
+    Ok(locations.len())
+}
+
+pub fn restore_plugin_data(bundle_id: &str) -> Result<usize, FileOperationError> {
+    let source = data_backup_path(bundle_id).ok_or_else(|| FileOperationError::FileNotFound(PathBuf::from(bundle_id)))?;
+    let restored = restore_into(&source, &data_roots())?;
+    log::info!("Restored {} settings locations of {} from {:?}", restored, bundle_id, source);
+    Ok(restored)
+}
+
+// Helper functions
+fn data_roots() -> Vec<(&'static str, PathBuf)> {
+    [(CACHE_PREFIX, cep_cache_path()), (DATA_PREFIX, dirs::data_dir())]
+        .into_iter()
+        .filter_map(|(prefix, root)| Some((prefix, root?)))
+        .collect()
+}
+
+fn locations_in(roots: &[(&str, PathBuf)], ids: &[String]) -> Vec<DataLocation> {
+    let mut locations = Vec::new();
+    for (prefix, root) in roots {
+        if *prefix == CACHE_PREFIX || *prefix == LOGS_PREFIX {
+            for entry in fs::read_dir(root).into_iter().flatten().flatten() {
+                let name = entry.file_name().to_string_lossy().to_string();
+                if cached_extension_id(&name).is_some_and(|id| ids.iter().any(|wanted| wanted == id)) {
+                    locations.push(DataLocation { path: entry.path(), archive_name: format!("{}/{}", prefix, name) });
+                }
+            }
+        } else {
+            // Same names the orphan scan accepts - a bundle ID like "Adobe" would name another app's whole folder
+            for id in ids.iter().filter_map(|id| stored_extension_id(id)) {
+                for relative in [PathBuf::from(id), Path::new("Adobe").join("CEP").join(id)] {
+                    let path = root.join(&relative);
+                    if path.exists() {
+                        let name = relative.to_string_lossy().replace('\\', "/");
+                        locations.push(DataLocation { path, archive_name: format!("{}/{}", prefix, name) });
+                    }
+                }
+            }
+        }
+    }
+    locations.sort_by(|a, b| a.archive_name.cmp(&b.archive_name));
+    locations.dedup_by(|a, b| a.archive_name == b.archive_name);
+    locations
+}
+
+fn leftovers_in(roots: &[(&str, PathBuf)], ids: &[String]) -> Vec<OrphanedEntry> {
+    locations_in(roots, ids)
+        .into_iter()
+        .map(|location| {
+            let name = location.path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+            let extension_id = if location.archive_name.starts_with(DATA_PREFIX) { name.clone() } else { cached_extension_id(&name).unwrap_or(&name).to_string() };
+            let size = if location.path.is_dir() {
+                measure_folder(&location.path).map(|size| size.on_disk).unwrap_or(0)
+            } else {
+                fs::metadata(&location.path).map(|metadata| metadata.len()).unwrap_or(0)
+            };
+            OrphanedEntry { extension_id, path: location.path, size }
+        })
+        .collect()
+}
+
+fn write_backup(destination: &Path, bundle_id: &str, locations: &[DataLocation]) -> Result<(), FileOperationError> {
+    let archive_error = |e: zip::result::ZipError| FileOperationError::archive(destination, io::Error::other(e));
+    let file = fs::File::create(destination).map_err(|e| FileOperationError::archive(destination, e))?;
+    let mut writer = ZipWriter::new(file);
+
+    let info = DataBackupInfo {
+        bundle_id: bundle_id.to_string(),
+        created_at: unix_now(),
+        locations: locations.iter().map(|location| location.archive_name.clone()).collect(),
+    };
+    writer.start_file(INFO_FILE, SimpleFileOptions::default()).map_err(archive_error)?;
+    serde_json::to_writer_pretty(&mut writer, &info).map_err(|e| FileOperationError::archive(destination, e.into()))?;
+
+    for location in locations {
+        add_to_zip(&mut writer, &location.path, &location.archive_name, destination)?;
+    }
+    writer.finish().map_err(archive_error)?;
+    Ok(())
+}
+
+// Symlinks are skipped - a panel's storage never needs them and they could point anywhere
+fn add_to_zip(writer: &mut ZipWriter<fs::File>, path: &Path, name: &str, destination: &Path) -> Result<(), FileOperationError> {
+    let archive_error = |e: zip::result::ZipError| FileOperationError::archive(destination, io::Error::other(e));
+    let metadata = fs::symlink_metadata(path).map_err(|e| FileOperationError::archive(path, e))?;
+    if metadata.is_dir() {
+        writer.add_directory(format!("{}/", name), SimpleFileOptions::default()).map_err(archive_error)?;
+        for entry in fs::read_dir(path).map_err(|e| FileOperationError::archive(path, e))?.flatten() {
+            let child = format!("{}/{}", name, entry.file_name().to_string_lossy());
+            add_to_zip(writer, &entry.path(), &child, destination)?;
+        }
+    } else if metadata.is_file() {
+        let content = fs::read(path).map_err(|e| FileOperationError::archive(path, e))?;
+        writer.start_file(name, SimpleFileOptions::default()).map_err(archive_error)?;
+        writer.write_all(&content).map_err(|e| FileOperationError::archive(destination, e))?;
+    }
+    Ok(())
+}
+
+// Each backed-up location replaces what is there now, so old and new storage files never mix. All of
+// them are extracted beside their target first and only swapped in once every entry is written, so a
+// bad entry or a full disk leaves the current settings as they were
+fn restore_into(source: &Path, roots: &[(&str, PathBuf)]) -> Result<usize, FileOperationError> {
+    let invalid = || FileOperationError::InvalidZip(source.to_path_buf());
+    let file = fs::File::open(source).map_err(|_| FileOperationError::FileNotFound(source.to_path_buf()))?;
+    let mut archive = ZipArchive::new(file).map_err(|_| invalid())?;
+    let info: DataBackupInfo = serde_json::from_reader(archive.by_name(INFO_FILE).map_err(|_| invalid())?).map_err(|_| invalid())?;
+
+    let mut staged = Vec::new(); // Archive location, its target and the staging path beside it
+    for location in &info.locations {
+        let target = target_path(roots, Path::new(location)).ok_or_else(invalid)?;
+        let staging = beside(&target, "restoring").ok_or_else(invalid)?;
+        let _ = remove_path(&staging); // Left by a restore that was interrupted
+        staged.push((PathBuf::from(location), target, staging));
+    }
+
+    if let Err(e) = extract_locations(&mut archive, &staged, source) {
+        for (_, _, staging) in &staged {
+            let _ = remove_path(staging);
+        }
+        return Err(e);
+    }
+    for (index, (_, target, staging)) in staged.iter().enumerate() {
+        if let Err(e) = swap_in(staging, target) {
+            for (_, _, staging) in &staged[index..] {
+                let _ = remove_path(staging);
+            }
+            return Err(e);
+        }
+    }
+    Ok(info.locations.len())
+}
+
+// Entries outside the locations the info lists are skipped - restore only replaces what it names
+fn extract_locations(
+    archive: &mut ZipArchive<fs::File>,
+    staged: &[(PathBuf, PathBuf, PathBuf)],
+    source: &Path,
+) -> Result<(), FileOperationError> {
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|_| FileOperationError::InvalidZip(source.to_path_buf()))?;
+        let Some(name) = entry.enclosed_name().filter(|name| name != Path::new(INFO_FILE)) else {
+            continue;
+        };
+        let Some((rest, staging)) = staged.iter().find_map(|(location, _, staging)| Some((name.strip_prefix(location).ok()?, staging))) else {
+            log::warn!("Skipping {:?} in {:?}: not one of the backed-up locations", name, source);
+            continue;
+        };
+        let target = if rest.as_os_str().is_empty() { staging.clone() } else { staging.join(rest) };
+        if entry.is_dir() {
+            fs::create_dir_all(&target).map_err(|e| FileOperationError::extract(&target, e))?;
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| FileOperationError::extract(parent, e))?;
+        }
+        let mut output = fs::File::create(&target).map_err(|e| FileOperationError::extract(&target, e))?;
+        io::copy(&mut entry, &mut output).map_err(|e| FileOperationError::extract(&target, e))?;
+    }
+    Ok(())
+}
+
+// The current location steps aside until the staged copy is in place; a location the backup holds
+// nothing for is only removed
+fn swap_in(staging: &Path, target: &Path) -> Result<(), FileOperationError> {
+    let replaced = beside(target, "replaced").ok_or_else(|| FileOperationError::InvalidExtension(target.to_path_buf()))?;
+    let _ = remove_path(&replaced);
+    let existed = fs::symlink_metadata(target).is_ok();
+    if existed {
+        fs::rename(target, &replaced).map_err(|e| FileOperationError::extract(target, e))?;
+    }
+    if fs::symlink_metadata(staging).is_ok()
+        && let Err(e) = fs::rename(staging, target)
+    {
+        if existed {
+            let _ = fs::rename(&replaced, target);
+        }
+        return Err(FileOperationError::extract(target, e));
+    }
+    if existed && let Err(e) = remove_path(&replaced) {
+        log::warn!("Failed to remove the replaced settings {:?}: {}", replaced, e);
+    }
+    Ok(())
+}
+
+// .<name>.<suffix> next to `path`, on the same filesystem so the swap is a rename
+fn beside(path: &Path, suffix: &str) -> Option<PathBuf> {
+    let name = path.file_name()?.to_string_lossy().to_string();
+    Some(path.with_file_name(format!(".{name}.{suffix}")))
+}
+
+// Folders, files and links alike; a path that is already gone counts as removed
+fn remove_path(path: &Path) -> io::Result<()> {
+    let removed = match fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.is_dir() => fs::remove_dir_all(path),
+        Ok(_) => fs::remove_file(path),
+        Err(e) => Err(e),
+    };
+    match removed {
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        other => other,
+    }
+}
+
+// data/Adobe/CEP/x -> <data dir>/Adobe/CEP/x; None for unknown prefixes or paths that try to leave the root
+fn target_path(roots: &[(&str, PathBuf)], name: &Path) -> Option<PathBuf> {
+    let mut components = name.components();
+    let Some(Component::Normal(prefix)) = components.next() else {
+        return None;
+    };
+    let (_, root) = roots.iter().find(|(known, _)| prefix == *known)?;
+    let rest = components.as_path();
+    let safe = rest.components().all(|component| matches!(component, Component::Normal(_)));
+    (safe && !rest.as_os_str().is_empty()).then(|| root.join(rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_data_backup_round_trip() {
+        let base = std::env::temp_dir().join(format!("zxpm-plugin-data-{}", std::process::id()));
+        let (cache, data) = (base.join("cache"), base.join("data"));
+        let storage = cache.join("PHXS_26_com.example.panel").join("Local Storage");
+        fs::create_dir_all(&storage).unwrap();
+        fs::write(storage.join("leveldb.log"), "theme=dark").unwrap();
+        fs::create_dir_all(cache.join("PHXS_26_com.other.panel")).unwrap();
+        fs::create_dir_all(data.join("Adobe").join("CEP").join("com.example")).unwrap();
+        fs::write(data.join("Adobe").join("CEP").join("com.example").join("prefs.json"), "{}").unwrap();
+
+        let roots = vec![(CACHE_PREFIX, cache.clone()), (DATA_PREFIX, data.clone())];
+        let ids = vec!["com.example".to_string(), "com.example.panel".to_string()];
+        let locations = locations_in(&roots, &ids);
+        let names: Vec<&str> = locations.iter().map(|location| location.archive_name.as_str()).collect();
+        assert_eq!(names, ["cep_cache/PHXS_26_com.example.panel", "data/Adobe/CEP/com.example"]);
+
+        let backup = base.join("backup.zip");
+        write_backup(&backup, "com.example", &locations).unwrap();
+        fs::write(storage.join("leveldb.log"), "theme=light").unwrap();
+        fs::write(storage.join("stray.tmp"), "").unwrap();
+        assert_eq!(restore_into(&backup, &roots).unwrap(), 2);
+        assert_eq!(fs::read_to_string(storage.join("leveldb.log")).unwrap(), "theme=dark");
+        assert!(!storage.join("stray.tmp").exists());
+        assert!(cache.join("PHXS_26_com.other.panel").is_dir());
+
+        assert_eq!(target_path(&roots, Path::new("data/../escape")), None);
+        assert_eq!(target_path(&roots, Path::new("elsewhere/x")), None);
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn test_failed_restore_keeps_current_settings() {
+        let base = std::env::temp_dir().join(format!("zxpm-plugin-data-restore-{}", std::process::id()));
+        let (cache, data) = (base.join("cache"), base.join("data"));
+        let storage = cache.join("PHXS_26_com.example.panel");
+        fs::create_dir_all(&storage).unwrap();
+        fs::write(storage.join("leveldb.log"), "theme=dark").unwrap();
+        fs::create_dir_all(data.join("com.example")).unwrap();
+        let roots = vec![(CACHE_PREFIX, cache.clone()), (DATA_PREFIX, data.clone())];
+
+        let write_archive = |path: &Path, entries: &[(&str, &str)]| {
+            let mut writer = ZipWriter::new(fs::File::create(path).unwrap());
+            let info = DataBackupInfo {
+                bundle_id: "com.example".to_string(),
+                created_at: 0,
+                locations: vec!["cep_cache/PHXS_26_com.example.panel".to_string(), "data/com.example".to_string()],
+            };
+            writer.start_file(INFO_FILE, SimpleFileOptions::default()).unwrap();
+            serde_json::to_writer(&mut writer, &info).unwrap();
+            for (name, content) in entries {
+                writer.start_file(*name, SimpleFileOptions::default()).unwrap();
+                writer.write_all(content.as_bytes()).unwrap();
+            }
+            writer.finish().unwrap();
+        };
+
+        // The last entry needs a folder where the backup itself put a file, so extraction fails late
+        let broken = base.join("broken.zip");
+        write_archive(&broken, &[
+            ("cep_cache/PHXS_26_com.example.panel/leveldb.log", "theme=light"),
+            ("data/com.example/prefs.json", "{}"),
+            ("data/com.example/prefs.json/nested", "{}"),
+        ]);
+        assert!(restore_into(&broken, &roots).is_err());
+        assert_eq!(fs::read_to_string(storage.join("leveldb.log")).unwrap(), "theme=dark");
+        assert!(data.join("com.example").is_dir());
+        let leftovers = |dir: &Path| fs::read_dir(dir).unwrap().flatten().filter(|entry| entry.file_name().to_string_lossy().starts_with('.')).count();
+        assert_eq!(leftovers(&cache) + leftovers(&data), 0);
+
+        // Entries outside the listed locations are never written
+        let stray = base.join("stray.zip");
+        write_archive(&stray, &[("cep_cache/PHXS_26_com.example.panel/leveldb.log", "theme=light"), ("data/com.other/prefs.json", "{}")]);
+        assert_eq!(restore_into(&stray, &roots).unwrap(), 2);
+        assert_eq!(fs::read_to_string(storage.join("leveldb.log")).unwrap(), "theme=light");
+        assert!(!data.join("com.other").exists());
+        assert!(!data.join("com.example").exists()); // Listed without entries, so nothing takes its place
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn test_leftovers_include_engine_logs() {
+        let base = std::env::temp_dir().join(format!("zxpm-leftovers-{}", std::process::id()));
+        let (data, logs) = (base.join("data"), base.join("logs"));
+        fs::create_dir_all(data.join("com.example.panel")).unwrap();
+        fs::create_dir_all(&logs).unwrap();
+        fs::write(logs.join("CEPHtmlEngine12-PHXS-26.0.0-com.example.panel.log"), "started").unwrap();
+        fs::write(logs.join("CEP12-PHXS.log"), "shared").unwrap();
+
+        let roots = vec![(DATA_PREFIX, data.clone()), (LOGS_PREFIX, logs.clone())];
+        let leftovers = leftovers_in(&roots, &["com.example.panel".to_string()]);
+        let paths: Vec<&Path> = leftovers.iter().map(|entry| entry.path.as_path()).collect();
+        assert_eq!(paths, [logs.join("CEPHtmlEngine12-PHXS-26.0.0-com.example.panel.log"), data.join("com.example.panel")]);
+        assert!(leftovers.iter().all(|entry| entry.extension_id == "com.example.panel"));
+        assert_eq!(leftovers[0].size, 7);
+
+        // Manifest IDs that aren't reverse-DNS never reach another app's data
+        fs::create_dir_all(data.join("Adobe").join("CEP")).unwrap();
+        fs::create_dir_all(data.join("Google")).unwrap();
+        let unsafe_ids = ["Adobe", "Google", "..", "com.example/../../Google", "com..example"].map(String::from);
+        assert!(leftovers_in(&roots, &unsafe_ids).is_empty());
+        let _ = fs::remove_dir_all(base);
+    }
+
+    #[test]
+    fn test_deep_clean_spares_ids_of_other_copies() {
+        let base = std::env::temp_dir().join(format!("zxpm-shared-ids-{}", std::process::id()));
+        let copy = |folder: &str, extensions: &[&str]| {
+            let path = base.join(folder).join("com.example.suite");
+            fs::create_dir_all(path.join("CSXS")).unwrap();
+            let list: String = extensions.iter().map(|id| format!("<Extension Id=\"{}\"/>", id)).collect();
+            let manifest = format!("<ExtensionManifest ExtensionBundleId=\"com.example.suite\"><ExtensionList>{}</ExtensionList></ExtensionManifest>", list);
+            fs::write(path.join("CSXS").join("manifest.xml"), manifest).unwrap();
+            Plugin { path, ..Plugin::for_test("com.example.suite", "1.0") }
+        };
+        let removed = copy("user", &["com.example.suite.main", "com.example.suite.extra"]);
+        let disabled = copy("disabled", &["com.example.suite.main"]);
+
+        assert_eq!(plugin_data_ids(&removed), ["com.example.suite", "com.example.suite.main", "com.example.suite.extra"]);
+        assert_eq!(unshared_data_ids(&removed, std::slice::from_ref(&removed)), plugin_data_ids(&removed));
+        assert_eq!(unshared_data_ids(&removed, &[removed.clone(), disabled.clone()]), ["com.example.suite.extra"]);
+        let _ = fs::remove_dir_all(base);
+    }
+}
@@ -0,0 +1,110 @@
+use std::fs;
+use std::path::Path;
+
+use crate::data_operations::parse_extension_entries;
+
+// Icon types in order of preference - the UI is dark, so dark variants go first
+const ICON_PREFERENCE: [&str; 4] = ["DarkNormal", "Normal", "DarkRollOver", "RollOver"];
+
+// Panel icons are 23-46 px; anything bigger is not an icon and isn't worth inlining
+const MAX_ICON_BYTES: u64 = 256 * 1024;
+
+// The manifest's preferred panel icon as a data: URI, ready for an <img src>.
+// Inlined because the webview can't load files from arbitrary plugin folders.
+pub fn icon_data_uri(plugin_path: &Path) -> Option<String> {
+    let entries = parse_extension_entries(&plugin_path.join("CSXS").join("manifest.xml")).ok()?;
+    let mut icons: Vec<&(String, String)> = entries.iter().flat_map(|entry| entry.icons.iter()).collect();
+    // Unknown types sort last; the sort is stable, so manifest order breaks ties
+    icons.sort_by_key(|(kind, _)| ICON_PREFERENCE.iter().position(|wanted| wanted == kind).unwrap_or(ICON_PREFERENCE.len()));
+
+    let plugin_root = fs::canonicalize(plugin_path).ok()?;
+    icons.into_iter().find_map(|(_, relative)| load_icon(&plugin_root, relative))
+}
+
+// Letter for the generated avatar shown when a plugin has no usable icon
+pub fn avatar_initial(name: &str) -> String {
+    name.chars()
+        .find(|c| c.is_alphanumeric())
+        .map(|c| c.to_uppercase().to_string())
+        .unwrap_or_else(|| "?".to_string())
+}
+
+// Stable hue per name, so each plugin keeps its avatar color between launches
+pub fn avatar_hue(name: &str) -> u32 {
+    name.bytes().fold(0u32, |hash, byte| hash.wrapping_mul(31).wrapping_add(byte as u32)) % 360
+}
+
+// Helper functions
+fn load_icon(plugin_root: &Path, relative: &str) -> Option<String> {
+    // Manifests are untrusted - don't follow "../" out of the plugin folder
+    let icon_path = fs::canonicalize(plugin_root.join(relative.trim_start_matches("./"))).ok()?;
+    if !icon_path.starts_with(plugin_root) {
+        return None;
+    }
+
+    let mime = image_mime(&icon_path)?;
+    if fs::metadata(&icon_path).ok()?.len() > MAX_ICON_BYTES {
+        return None;
+    }
+    let bytes = fs::read(&icon_path).ok()?;
+    Some(format!("data:{};base64,{}", mime, encode_base64(&bytes)))
+}
+
+fn image_mime(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    match extension.as_str() {
+        "png" => Some("image/png"),
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "gif" => Some("image/gif"),
+        "svg" => Some("image/svg+xml"),
+        _ => None,
+    }
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().fold(0u32, |n, &byte| (n << 8) | byte as u32) << (8 * (3 - chunk.len()));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_icon_from_manifest() {
+        let plugin_dir = std::env::temp_dir().join(format!("zxpm-test-icon-{}", std::process::id()));
+        fs::create_dir_all(plugin_dir.join("CSXS")).unwrap();
+        fs::create_dir_all(plugin_dir.join("icons")).unwrap();
+        fs::write(
+            plugin_dir.join("CSXS").join("manifest.xml"),
+            r#"<ExtensionManifest ExtensionBundleId="com.example.icon"><DispatchInfoList><Extension Id="com.example.icon.panel"><DispatchInfo>
+<UI><Icons><Icon Type="Normal">./icons/light.png</Icon><Icon Type="DarkNormal">../outside.png</Icon></Icons></UI>
+</DispatchInfo></Extension></DispatchInfoList></ExtensionManifest>"#,
+        )
+        .unwrap();
+        fs::write(plugin_dir.join("icons").join("light.png"), b"png").unwrap();
+        fs::write(plugin_dir.with_file_name("outside.png"), b"x").unwrap();
+
+        // The preferred dark icon points outside the plugin folder, so the light one is used
+        let uri = icon_data_uri(&plugin_dir);
+        let _ = fs::remove_dir_all(&plugin_dir);
+        let _ = fs::remove_file(plugin_dir.with_file_name("outside.png"));
+
+        assert_eq!(uri.as_deref(), Some("data:image/png;base64,cG5n"));
+        assert_eq!(encode_base64(b"png"), "cG5n");
+        assert_eq!(avatar_initial("  éclair tools"), "É");
+        assert_eq!(avatar_initial("---"), "?");
+    }
+}
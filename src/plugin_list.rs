@@ -0,0 +1,149 @@
+use serde::Serialize;
+use std::path::Path;
+
+use crate::data_operations::{HostRequirement, Plugin, PluginType, parse_host_list};
+
+// Output formats shared by `zxp-manager list --format` and the GUI export
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ListFormat {
+    Text, // Tab-separated id, version, name, path - the CLI default
+    Json,
+    Csv,
+}
+
+impl ListFormat {
+    pub fn from_name(name: &str) -> Option<ListFormat> {
+        match name.to_ascii_lowercase().as_str() {
+            "text" | "tsv" => Some(ListFormat::Text),
+            "json" => Some(ListFormat::Json),
+            "csv" => Some(ListFormat::Csv),
+            _ => None,
+        }
+    }
+
+    // Picks the format from a save dialog's file name, JSON unless it ends in .csv
+    pub fn from_path(path: &Path) -> ListFormat {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ListFormat::Csv,
+            _ => ListFormat::Json,
+        }
+    }
+}
+
+// One row of an audit export
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PluginRecord {
+    pub name: String,
+    pub bundle_id: String,
+    pub version: String,
+    pub size: String,
+    pub disk_size: String,
+    pub path: String,
+    #[serde(rename = "type")]
+    pub plugin_type: &'static str,
+    pub host_apps: Vec<String>,
+}
+
+const CSV_HEADER: [&str; 8] = ["name", "bundle_id", "version", "size", "disk_size", "path", "type", "host_apps"];
+
+pub fn plugin_records(plugins: &[Plugin]) -> Vec<PluginRecord> {
+    plugins
+        .iter()
+        .map(|plugin| {
+            // A missing or broken manifest still gets a row, just without hosts
+            let hosts = parse_host_list(&plugin.path.join("CSXS").join("manifest.xml")).unwrap_or_default();
+            PluginRecord {
+                name: plugin.name.clone(),
+                bundle_id: plugin.bundle_id.clone(),
+                version: plugin.version.clone(),
+                size: plugin.size.clone(),
+                disk_size: plugin.disk_size.clone(),
+                path: plugin.path.display().to_string(),
+                plugin_type: match plugin.plugin_type {
+                    PluginType::Native => "native",
+                    PluginType::Installed => "installed",
+                    PluginType::Dev => "dev",
+                    PluginType::Legacy => "legacy",
+                },
+                host_apps: hosts.iter().map(host_label).collect(),
+            }
+        })
+        .collect()
+}
+
+pub fn render_plugin_list(plugins: &[Plugin], format: ListFormat) -> String {
+    match format {
+        ListFormat::Text => plugins
+            .iter()
+            .map(|plugin| format!("{}\t{}\t{}\t{}\n", plugin.bundle_id, plugin.version, plugin.name, plugin.path.display()))
+            .collect(),
+        ListFormat::Json => {
+            let mut json = serde_json::to_string_pretty(&plugin_records(plugins)).unwrap_or_default();
+            json.push('\n');
+            json
+        }
+        ListFormat::Csv => {
+            let mut csv = CSV_HEADER.join(",") + "\n";
+            for record in plugin_records(plugins) {
+                let fields = [
+                    record.name,
+                    record.bundle_id,
+                    record.version,
+                    record.size,
+                    record.disk_size,
+                    record.path,
+                    record.plugin_type.to_string(),
+                    record.host_apps.join("; "),
+                ];
+                let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+                csv.push_str(&row.join(","));
+                csv.push('\n');
+            }
+            csv
+        }
+    }
+}
+
+// Helper functions
+fn host_label(host: &HostRequirement) -> String {
+    match &host.version {
+        Some(version) => format!("{} {}", host.name, version),
+        None => host.name.clone(),
+    }
+}
+
+// RFC 4180 quoting - version ranges like [22.0,99.9] contain commas
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_quotes_only_when_needed() {
+        assert_eq!(csv_field("Tools"), "Tools");
+        assert_eq!(csv_field("PHXS [22.0,99.9]"), "\"PHXS [22.0,99.9]\"");
+        assert_eq!(csv_field("The \"Best\" Panel"), "\"The \"\"Best\"\" Panel\"");
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let plugins = vec![Plugin { name: "Tools, Pro".to_string(), ..Plugin::for_test("com.example.tools", "1.2.0") }];
+
+        let csv = render_plugin_list(&plugins, ListFormat::Csv);
+        assert_eq!(csv.lines().nth(1), Some("\"Tools, Pro\",com.example.tools,1.2.0,1 KB,4 KB,/extensions/com.example.tools,installed,"));
+
+        let json: serde_json::Value = serde_json::from_str(&render_plugin_list(&plugins, ListFormat::Json)).unwrap();
+        assert_eq!(json[0]["type"], "installed");
+        assert_eq!(json[0]["host_apps"], serde_json::json!([]));
+
+        assert_eq!(ListFormat::from_path(Path::new("plugins.CSV")), ListFormat::Csv);
+        assert_eq!(ListFormat::from_path(Path::new("plugins")), ListFormat::Json);
+    }
+}
@@ -0,0 +1,134 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+// Free-form notes, tags and favorites the user keeps per plugin, e.g. "license key sent to IT"
+// or "client-x". Keyed by bundle ID, so they survive reinstalls and follow a plugin into other roots.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PluginNote {
+    pub notes: String,
+    pub tags: Vec<String>, // Normalized with normalize_tag(), sorted
+    pub favorite: bool,    // Pinned to the top of the plugin list
+}
+
+impl PluginNote {
+    fn is_empty(&self) -> bool {
+        self.notes.trim().is_empty() && self.tags.is_empty() && !self.favorite
+    }
+}
+
+// Loaded once from disk on first access, saved after every change
+pub static PLUGIN_NOTES: GlobalSignal<BTreeMap<String, PluginNote>> = Signal::global(load_notes);
+
+pub fn notes_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("notes.json"))
+}
+
+pub fn set_notes(bundle_id: &str, notes: String) {
+    update_note(bundle_id, |note| note.notes = notes);
+}
+
+pub fn add_tag(bundle_id: &str, tag: &str) {
+    let Some(tag) = normalize_tag(tag) else {
+        return;
+    };
+    update_note(bundle_id, |note| {
+        if !note.tags.contains(&tag) {
+            note.tags.push(tag);
+            note.tags.sort();
+        }
+    });
+}
+
+pub fn remove_tag(bundle_id: &str, tag: &str) {
+    update_note(bundle_id, |note| note.tags.retain(|existing| existing != tag));
+}
+
+pub fn toggle_favorite(bundle_id: &str) {
+    update_note(bundle_id, |note| note.favorite = !note.favorite);
+}
+
+// Tags compare case-insensitively and never contain spaces: " Client X " -> "client-x"
+pub fn normalize_tag(text: &str) -> Option<String> {
+    let tag = text.split_whitespace().collect::<Vec<_>>().join("-").to_lowercase();
+    (!tag.is_empty()).then_some(tag)
+}
+
+// Every tag in use, for the filter
+pub fn all_tags(notes: &BTreeMap<String, PluginNote>) -> Vec<String> {
+    let mut tags: Vec<String> = notes.values().flat_map(|note| note.tags.iter().cloned()).collect();
+    tags.sort();
+    tags.dedup();
+    tags
+}
+
+pub fn has_tag(notes: &BTreeMap<String, PluginNote>, bundle_id: &str, tag: &str) -> bool {
+    notes.get(bundle_id).is_some_and(|note| note.tags.iter().any(|existing| existing == tag))
+}
+
+pub fn is_favorite(notes: &BTreeMap<String, PluginNote>, bundle_id: &str) -> bool {
+    notes.get(bundle_id).is_some_and(|note| note.favorite)
+}
+
+// Helper functions
+fn update_note(bundle_id: &str, change: impl FnOnce(&mut PluginNote)) {
+    let mut notes = PLUGIN_NOTES.write();
+    let mut note = notes.remove(bundle_id).unwrap_or_default();
+    change(&mut note);
+    if !note.is_empty() {
+        notes.insert(bundle_id.to_string(), note); // Emptied notes leave no entry behind
+    }
+    save_notes(&notes);
+}
+
+fn load_notes() -> BTreeMap<String, PluginNote> {
+    let Some(path) = notes_path() else {
+        return BTreeMap::new();
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            log::warn!("Failed to parse notes {:?}: {}", path, e);
+            BTreeMap::new()
+        }),
+        Err(_) => BTreeMap::new(), // Nothing saved yet
+    }
+}
+
+fn save_notes(notes: &BTreeMap<String, PluginNote>) {
+    let Some(path) = notes_path() else {
+        return;
+    };
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| serde_json::to_string_pretty(notes).map_err(std::io::Error::from))
+        .and_then(|content| fs::write(&path, content));
+
+    if let Err(e) = result {
+        log::error!("Failed to save notes {:?}: {}", path, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tags_are_normalized_and_collected() {
+        assert_eq!(normalize_tag("  Client X "), Some("client-x".to_string()));
+        assert_eq!(normalize_tag("   "), None);
+
+        let note = |tags: &[&str]| PluginNote { tags: tags.iter().map(|tag| tag.to_string()).collect(), ..Default::default() };
+        let notes = BTreeMap::from([
+            ("com.a".to_string(), note(&["temp", "client-x"])),
+            ("com.b".to_string(), note(&["client-x"])),
+        ]);
+        assert_eq!(all_tags(&notes), ["client-x", "temp"]);
+        assert!(has_tag(&notes, "com.b", "client-x") && !has_tag(&notes, "com.b", "temp"));
+    }
+}
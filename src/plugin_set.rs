@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::clock::LocalTime;
+use crate::data_operations::{calculate_folder_size_bytes, Plugin, PluginType};
+use crate::file_operations::{ensure_free_space, export_plugin_as_zxp, install_zxp, FileOperationError};
+
+// Written next to the ZXP files so a set can be restored on another machine
+pub const SET_MANIFEST_FILE: &str = "plugin-set.json";
+const SET_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginSet {
+    pub format_version: u32,
+    pub created: String, // Local "YYYY-MM-DD HH:MM:SS"
+    pub plugins: Vec<PluginSetEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginSetEntry {
+    pub name: String,
+    pub bundle_id: String,
+    pub version: String,
+    pub file: String, // ZXP file name, relative to the set folder
+}
+
+#[derive(Debug, Default)]
+pub struct RestoreReport {
+    pub installed: Vec<PathBuf>,
+    pub skipped: usize,                           // Bundle ID already installed
+    pub failed: Vec<(String, FileOperationError)>, // Plugin name and why it failed
+}
+
+// Exports every third-party plugin into `set_dir`, one ZXP each, plus the set manifest
+pub fn backup_plugin_set(plugins: &[Plugin], set_dir: &Path) -> Result<PluginSet, FileOperationError> {
+    let plugins: Vec<&Plugin> = plugins.iter().filter(|plugin| plugin.plugin_type == PluginType::Installed).collect();
+
+    // Archives are never larger than the folders they pack, so the folder total is a safe bound
+    let required = plugins.iter().map(|plugin| calculate_folder_size_bytes(&plugin.path).unwrap_or(0)).sum();
+    ensure_free_space(set_dir, required)?;
+
+    fs::create_dir_all(set_dir).map_err(|e| FileOperationError::archive(set_dir, e))?;
+
+    let now = LocalTime::now();
+    let mut set = PluginSet {
+        format_version: SET_FORMAT_VERSION,
+        created: format!("{} {}", now.date_label(), now.time_label()),
+        plugins: Vec::new(),
+    };
+
+    for plugin in plugins {
+        let file = format!("{}-{}.zxp", plugin.bundle_id, plugin.version);
+        export_plugin_as_zxp(&plugin.path, &set_dir.join(&file))?;
+        set.plugins.push(PluginSetEntry {
+            name: plugin.name.clone(),
+            bundle_id: plugin.bundle_id.clone(),
+            version: plugin.version.clone(),
+            file,
+        });
+    }
+
+    let manifest = set_dir.join(SET_MANIFEST_FILE);
+    let content = serde_json::to_string_pretty(&set).map_err(|e| FileOperationError::archive(&manifest, e.into()))?;
+    fs::write(&manifest, content).map_err(|e| FileOperationError::archive(&manifest, e))?;
+
+    log::info!("Backed up {} plugins to {:?}", set.plugins.len(), set_dir);
+    Ok(set)
+}
+
+pub fn read_plugin_set(set_dir: &Path) -> Result<PluginSet, FileOperationError> {
+    let manifest = set_dir.join(SET_MANIFEST_FILE);
+    let content = fs::read_to_string(&manifest).map_err(|_| FileOperationError::InvalidPluginSet(set_dir.to_path_buf()))?;
+    serde_json::from_str(&content).map_err(|e| {
+        log::warn!("Invalid plugin set manifest in {:?}: {}", set_dir, e);
+        FileOperationError::InvalidPluginSet(set_dir.to_path_buf())
+    })
+}
+
+// Installs a backed-up set, leaving plugins that are already installed untouched
+pub fn restore_plugin_set(set_dir: &Path, installed: &[Plugin]) -> Result<RestoreReport, FileOperationError> {
+    let set = read_plugin_set(set_dir)?;
+    let mut report = RestoreReport::default();
+
+    for entry in set.plugins {
+        if installed.iter().any(|plugin| plugin.bundle_id == entry.bundle_id) {
+            report.skipped += 1;
+            continue;
+        }
+
+        // The manifest comes from disk - never follow a file name out of the set folder
+        if !is_plain_file_name(&entry.file) {
+            report.failed.push((entry.name, FileOperationError::FileNotFound(PathBuf::from(&entry.file))));
+            continue;
+        }
+
+        match install_zxp(&set_dir.join(&entry.file)) {
+            Ok(installed) => report.installed.push(installed.path),
+            Err(e) => {
+                log::error!("Failed to restore {}: {}", entry.bundle_id, e);
+                report.failed.push((entry.name, e));
+            }
+        }
+    }
+
+    log::info!(
+        "Restored plugin set from {:?}: {} installed, {} skipped, {} failed",
+        set_dir,
+        report.installed.len(),
+        report.skipped,
+        report.failed.len()
+    );
+    Ok(report)
+}
+
+// Default folder name for a new set, e.g. zxp-plugin-set-2025-04-10
+pub fn default_set_folder_name() -> String {
+    format!("zxp-plugin-set-{}", LocalTime::now().date_label())
+}
+
+// Helper functions
+fn is_plain_file_name(name: &str) -> bool {
+    !name.is_empty() && Path::new(name).file_name().and_then(|file| file.to_str()) == Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_writes_zxp_files_and_manifest() {
+        let root = std::env::temp_dir().join(format!("zxpm-test-set-{}", std::process::id()));
+        let plugin_dir = root.join("installed").join("com.example.tools");
+        fs::create_dir_all(plugin_dir.join("CSXS")).unwrap();
+        fs::write(plugin_dir.join("CSXS").join("manifest.xml"), "<ExtensionManifest ExtensionBundleId=\"com.example.tools\"/>").unwrap();
+
+        let plugin = |bundle_id: &str, plugin_type: PluginType| Plugin { path: plugin_dir.clone(), plugin_type, ..Plugin::for_test(bundle_id, "1.0") };
+        let plugins = vec![plugin("com.example.tools", PluginType::Installed), plugin("com.adobe.native", PluginType::Native)];
+
+        let set_dir = root.join("set");
+        let set = backup_plugin_set(&plugins, &set_dir).unwrap();
+        let read_back = read_plugin_set(&set_dir).unwrap();
+        let zxp_written = set_dir.join("com.example.tools-1.0.zxp").is_file();
+        let _ = fs::remove_dir_all(&root);
+
+        assert_eq!(set, read_back);
+        assert_eq!(set.plugins.len(), 1); // Native Adobe extensions ship with the host
+        assert_eq!(set.plugins[0].file, "com.example.tools-1.0.zxp");
+        assert!(zxp_written);
+    }
+
+    #[test]
+    fn test_set_file_names_stay_inside_the_folder() {
+        assert!(is_plain_file_name("com.example.tools-1.0.zxp"));
+        assert!(!is_plain_file_name("../outside.zxp"));
+        assert!(!is_plain_file_name("/tmp/outside.zxp"));
+        assert!(!is_plain_file_name(""));
+    }
+}
@@ -0,0 +1,467 @@
+use dioxus::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::background::run_blocking;
+use crate::diagnostics::installed_extension_ids;
+use crate::file_operations::{OrphanedEntry, find_orphaned_cache};
+use crate::data_operations::{
+    BrokenExtension, ExtensionEntry, ExtensionRoot, Plugin, PluginError, PluginType, ScanProgress, format_size, missing_extension_roots,
+    parse_extension_entries, parse_host_list, parse_required_runtime, scan_broken_extensions, scan_cep_plugins_with, scan_disabled_plugins, scan_extensions_root, scan_legacy_plugins,
+};
+use crate::host_detection::{HostApp, PluginTargets, detect_host_apps};
+use crate::plugin_icons::icon_data_uri;
+use crate::i18n::t_args;
+use crate::message::{REFRESH_TRIGGER, show_info};
+use crate::overview::vendor_of;
+use crate::settings::PluginSort;
+use crate::version::{compare_versions, is_newer};
+
+// One bundle ID installed in several folders - usually once per root, so the panel shows up twice
+#[derive(Debug, Clone, PartialEq)]
+pub struct Duplicate {
+    pub bundle_id: String,
+    pub keep: PathBuf,        // Newest version; on a tie the copy hosts load, from the later root
+    pub remove: Vec<PathBuf>, // Every other copy
+}
+
+// Rows of one vendor in the grouped plugin list, with subtotals for the section header
+#[derive(Debug, Clone, PartialEq)]
+pub struct VendorGroup<'a> {
+    pub vendor: String, // Empty when the bundle ID has no vendor part
+    pub disk_bytes: u64,
+    pub plugins: Vec<&'a Plugin>,
+}
+
+// Latest scan result shared by all components - None until the first scan completes
+pub static PLUGINS: GlobalSignal<Option<Vec<Plugin>>> = Signal::global(|| None);
+
+// Plugins moved out of their extensions root with "Disable" - hosts don't load them
+pub static DISABLED_PLUGINS: GlobalSignal<Vec<Plugin>> = Signal::global(Vec::new);
+
+// Folders in the extensions roots that aren't readable plugins, with the reason
+pub static BROKEN_EXTENSIONS: GlobalSignal<Vec<BrokenExtension>> = Signal::global(Vec::new);
+
+// Extensions roots that don't exist yet, offered for creation above the list until dismissed
+pub static MISSING_ROOTS: GlobalSignal<Vec<ExtensionRoot>> = Signal::global(Vec::new);
+pub static MISSING_ROOTS_DISMISSED: GlobalSignal<bool> = Signal::global(|| false);
+
+// Plugin row selected in the list, target of keyboard actions
+pub static SELECTED_PLUGIN: GlobalSignal<Option<PathBuf>> = Signal::global(|| None);
+
+// Adobe apps installed on this machine - detected again with every scan
+pub static HOST_APPS: GlobalSignal<Vec<HostApp>> = Signal::global(Vec::new);
+
+// Each installed plugin's HostList and required CEP runtime, keyed by plugin folder
+pub static PLUGIN_TARGETS: GlobalSignal<HashMap<PathBuf, PluginTargets>> = Signal::global(HashMap::new);
+
+// Panel icons as data: URIs, only for plugins whose manifest points at a usable image
+pub static PLUGIN_ICONS: GlobalSignal<HashMap<PathBuf, String>> = Signal::global(HashMap::new);
+
+// Cache and log entries of extensions that are gone - found again with every scan
+pub static ORPHANED_CACHE: GlobalSignal<Vec<OrphanedEntry>> = Signal::global(Vec::new);
+
+// Foreign extensions folder opened for read-only browsing - replaces the installed list while open
+pub static INVENTORY: GlobalSignal<Option<ForeignInventory>> = Signal::global(|| None);
+
+// True while PLUGINS still holds the previous session's list, until the first scan replaces it
+pub static SHOWING_CACHED: GlobalSignal<bool> = Signal::global(|| false);
+
+// The scan in progress, for the status bar's counter and Cancel button; None between scans
+pub static ACTIVE_SCAN: GlobalSignal<Option<ScanProgress>> = Signal::global(|| None);
+
+// Paths the app itself just changed, so the next scan doesn't report them as external changes
+static EXPECTED_CHANGES: GlobalSignal<HashSet<PathBuf>> = Signal::global(HashSet::new);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignInventory {
+    pub folder: PathBuf,
+    pub plugins: Vec<Plugin>,
+}
+
+// Everything a rescan reads from disk, gathered off the UI thread and then applied in one go
+struct ScanSnapshot {
+    plugins: Vec<Plugin>,
+    targets: HashMap<PathBuf, PluginTargets>,
+    icons: HashMap<PathBuf, String>,
+    host_apps: Vec<HostApp>,
+    disabled: Vec<Plugin>,
+    broken: Vec<BrokenExtension>,
+    orphans: Vec<OrphanedEntry>,
+    missing_roots: Vec<ExtensionRoot>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct ScanDiff {
+    pub added: Vec<Plugin>,
+    pub removed: Vec<Plugin>,
+    pub version_changed: Vec<(Plugin, Plugin)>, // (previous, current)
+}
+
+impl ScanDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.version_changed.is_empty()
+    }
+
+    // Human-readable summary, e.g. "2 added, 1 removed, 1 version changed"
+    pub fn summary(&self) -> String {
+        [
+            ("scan-added", self.added.len()),
+            ("scan-removed", self.removed.len()),
+            ("scan-version-changed", self.version_changed.len()),
+        ]
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(key, count)| t_args(key, &[("count", &count.to_string())]))
+        .collect::<Vec<_>>()
+        .join(", ")
+    }
+
+    fn without_paths(mut self, paths: &HashSet<PathBuf>) -> Self {
+        self.added.retain(|plugin| !paths.contains(&plugin.path));
+        self.removed.retain(|plugin| !paths.contains(&plugin.path));
+        self.version_changed.retain(|(_, plugin)| !paths.contains(&plugin.path));
+        self
+    }
+}
+
+// Rescan whenever the refresh trigger flips - call once from the root component
+pub fn use_plugin_scanner() {
+    // A full walk takes seconds on a big extensions folder; show what it found last time meanwhile
+    use_future(|| async {
+        let cached = run_blocking(load_cached_inventory).await;
+        if let Some(cached) = cached.filter(|_| PLUGINS.peek().is_none()) {
+            log::info!("Showing {} cached plugins until the scan finishes", cached.len());
+            *PLUGINS.write() = Some(cached);
+            *SHOWING_CACHED.write() = true;
+        }
+    });
+    let _ = use_resource(move || {
+        let _ = REFRESH_TRIGGER(); // Create dependency on global signal
+        async move {
+            // A rescan replaces the one still running; its result would be stale anyway
+            cancel_scan();
+            let progress = ScanProgress::default();
+            *ACTIVE_SCAN.write() = Some(progress.clone());
+
+            // Size walks of large extensions take a while - keep them off the UI runtime
+            let scan = progress.clone();
+            let result = run_blocking(move || read_snapshot(&scan)).await;
+            *ACTIVE_SCAN.write() = None; // A replaced scan's future is dropped before it gets here
+            match result {
+                Ok(Some(snapshot)) => apply_scan(snapshot),
+                Ok(None) => {
+                    let (processed, total) = progress.counts();
+                    log::info!("Plugin scan cancelled after {}/{} folders", processed, total);
+                    show_info("scan-cancelled", &[]);
+                }
+                Err(e) => log::error!("Failed to scan plugins: {}", e),
+            }
+        }
+    });
+}
+
+// Stops the running scan at its next folder; the list keeps showing the previous result
+pub fn cancel_scan() {
+    if let Some(progress) = ACTIVE_SCAN.peek().as_ref() {
+        progress.cancel.cancel();
+    }
+}
+
+// Scans any directory laid out like an extensions root, e.g. a backup or another machine's drive
+pub async fn open_inventory(folder: PathBuf) -> Result<usize, PluginError> {
+    let root = folder.clone();
+    let mut plugins = run_blocking(move || scan_extensions_root(&root)).await?;
+    for plugin in &mut plugins {
+        plugin.can_remove = false; // Read-only: nothing in a foreign folder is ever modified
+    }
+    plugins.sort_by_key(|plugin| plugin.name.to_lowercase());
+
+    let count = plugins.len();
+    *SELECTED_PLUGIN.write() = None;
+    *INVENTORY.write() = Some(ForeignInventory { folder, plugins });
+    Ok(count)
+}
+
+pub fn inventory_cache_path() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("zxp-manager").join("inventory.json"))
+}
+
+pub fn close_inventory() {
+    *SELECTED_PLUGIN.write() = None;
+    *INVENTORY.write() = None;
+}
+
+pub fn detect_orphaned_cache() {
+    *ORPHANED_CACHE.write() = find_orphaned_cache(&installed_extension_ids());
+}
+
+// Call before trigger_refresh() for paths changed by our own install/remove
+pub fn expect_change(path: PathBuf) {
+    EXPECTED_CHANGES.write().insert(path);
+}
+
+// Expects plugins in scan order, which is host load order; legacy copies are never loaded, so
+// they don't count
+pub fn find_duplicates(plugins: &[Plugin]) -> Vec<Duplicate> {
+    let plugins: Vec<&Plugin> = plugins.iter().filter(|plugin| plugin.plugin_type != PluginType::Legacy).collect();
+    let mut duplicates: Vec<Duplicate> = Vec::new();
+
+    for (index, plugin) in plugins.iter().enumerate() {
+        if duplicates.iter().any(|duplicate| duplicate.bundle_id == plugin.bundle_id) {
+            continue; // Group already built from its first copy
+        }
+        let copies: Vec<&Plugin> = plugins[index..].iter().copied().filter(|other| other.bundle_id == plugin.bundle_id).collect();
+        if copies.len() < 2 {
+            continue;
+        }
+        let keep = copies.iter().copied().reduce(|keep, copy| if is_newer(&keep.version, &copy.version) { keep } else { copy });
+        let keep = keep.map(|keep| keep.path.clone()).unwrap_or_default();
+        duplicates.push(Duplicate {
+            bundle_id: plugin.bundle_id.clone(),
+            remove: copies.iter().map(|copy| copy.path.clone()).filter(|path| *path != keep).collect(),
+            keep,
+        });
+    }
+    duplicates
+}
+
+// Combined on-disk size of the listed plugins, for the status bar
+pub fn total_disk_bytes(plugins: &[Plugin]) -> u64 {
+    plugins.iter().map(|plugin| plugin.disk_bytes).sum()
+}
+
+// Stable, so rows with the same name or date keep their scan order; plugins with no known date go last
+pub fn sorted_plugins(plugins: &[Plugin], sort: PluginSort) -> Vec<&Plugin> {
+    let mut sorted: Vec<&Plugin> = plugins.iter().collect();
+    match sort {
+        PluginSort::ScanOrder => {}
+        PluginSort::NameAscending => sorted.sort_by_cached_key(|plugin| plugin.name.to_lowercase()),
+        PluginSort::NameDescending => sorted.sort_by_cached_key(|plugin| std::cmp::Reverse(plugin.name.to_lowercase())),
+        PluginSort::NewestFirst => sorted.sort_by_key(|plugin| (plugin.installed_at.is_none(), std::cmp::Reverse(plugin.installed_at))),
+        PluginSort::OldestFirst => sorted.sort_by_key(|plugin| (plugin.installed_at.is_none(), plugin.installed_at)),
+        PluginSort::HighestVersion => sorted.sort_by(|a, b| compare_versions(&b.version, &a.version)),
+        PluginSort::LowestVersion => sorted.sort_by(|a, b| compare_versions(&a.version, &b.version)),
+    }
+    sorted
+}
+
+// Vendors alphabetically, plugins without one last; rows keep the order they came in
+pub fn group_by_vendor<'a>(plugins: Vec<&'a Plugin>) -> Vec<VendorGroup<'a>> {
+    let mut groups: Vec<VendorGroup<'a>> = Vec::new();
+    for plugin in plugins {
+        let vendor = vendor_of(&plugin.bundle_id);
+        match groups.iter_mut().find(|group| group.vendor == vendor) {
+            Some(group) => {
+                group.disk_bytes += plugin.disk_bytes;
+                group.plugins.push(plugin);
+            }
+            None => groups.push(VendorGroup { vendor, disk_bytes: plugin.disk_bytes, plugins: vec![plugin] }),
+        }
+    }
+    groups.sort_by(|a, b| (a.vendor.is_empty(), &a.vendor).cmp(&(b.vendor.is_empty(), &b.vendor)));
+    groups
+}
+
+pub fn diff_snapshots(previous: &[Plugin], current: &[Plugin]) -> ScanDiff {
+    let mut diff = ScanDiff::default();
+
+    for plugin in current {
+        match previous.iter().find(|old| old.path == plugin.path) {
+            None => diff.added.push(plugin.clone()),
+            Some(old) if old.version != plugin.version => {
+                diff.version_changed.push((old.clone(), plugin.clone()));
+            }
+            Some(_) => {}
+        }
+    }
+
+    for old in previous {
+        if !current.iter().any(|plugin| plugin.path == old.path) {
+            diff.removed.push(old.clone());
+        }
+    }
+
+    diff
+}
+
+// Helper functions
+// None when the scan was cancelled - a partial list would read as removed plugins
+fn read_snapshot(progress: &ScanProgress) -> Result<Option<ScanSnapshot>, PluginError> {
+    let mut plugins = scan_cep_plugins_with(progress)?;
+    if progress.cancel.is_cancelled() {
+        return Ok(None);
+    }
+    plugins.extend(scan_legacy_plugins());
+    let targets = plugins
+        .iter()
+        .map(|plugin| {
+            let manifest_path = plugin.path.join("CSXS").join("manifest.xml");
+            let targets = PluginTargets {
+                hosts: parse_host_list(&manifest_path).unwrap_or_default(),
+                required_runtime: parse_required_runtime(&manifest_path).ok().flatten(),
+                ui_kinds: parse_extension_entries(&manifest_path).unwrap_or_default().iter().map(ExtensionEntry::ui_kind).collect(),
+            };
+            (plugin.path.clone(), targets)
+        })
+        .collect();
+    let icons = plugins
+        .iter()
+        .filter_map(|plugin| icon_data_uri(&plugin.path).map(|icon| (plugin.path.clone(), icon)))
+        .collect();
+
+    Ok(ScanSnapshot {
+        targets,
+        icons,
+        host_apps: detect_host_apps(),
+        disabled: scan_disabled_plugins(),
+        broken: scan_broken_extensions(),
+        orphans: find_orphaned_cache(&installed_extension_ids()),
+        missing_roots: missing_extension_roots(),
+        plugins,
+    })
+    .inspect(|snapshot| save_cached_inventory(&snapshot.plugins))
+    .map(Some)
+}
+
+fn load_cached_inventory() -> Option<Vec<Plugin>> {
+    let path = inventory_cache_path()?;
+    let content = fs::read_to_string(&path).ok()?; // Nothing cached yet
+    serde_json::from_str(&content)
+        .inspect_err(|e| log::warn!("Failed to parse cached inventory {:?}: {}", path, e))
+        .ok()
+}
+
+fn save_cached_inventory(plugins: &[Plugin]) {
+    let Some(path) = inventory_cache_path() else {
+        return;
+    };
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| serde_json::to_string(plugins).map_err(std::io::Error::from))
+        .and_then(|content| fs::write(&path, content));
+
+    if let Err(e) = result {
+        log::error!("Failed to save cached inventory {:?}: {}", path, e);
+    }
+}
+
+fn apply_scan(snapshot: ScanSnapshot) {
+    let ScanSnapshot { plugins, targets, icons, host_apps, disabled, broken, orphans, missing_roots } = snapshot;
+    let expected = std::mem::take(&mut *EXPECTED_CHANGES.write());
+    // The first scan is the baseline - there is nothing to compare it with; a cached list is a
+    // placeholder from the last session, not something the user saw change
+    let baseline = PLUGINS.peek().is_none() || *SHOWING_CACHED.peek();
+
+    let diff = PLUGINS
+        .peek()
+        .as_ref()
+        .filter(|_| !baseline)
+        .map(|previous| diff_snapshots(previous, &plugins).without_paths(&expected));
+
+    if let Some(diff) = diff.filter(|diff| !diff.is_empty()) {
+        let summary = show_info("scan-changes-detected", &[("summary", &diff.summary())]);
+        log::info!("{}", summary);
+    }
+
+    *PLUGIN_TARGETS.write() = targets;
+    *PLUGIN_ICONS.write() = icons;
+    *HOST_APPS.write() = host_apps;
+    *DISABLED_PLUGINS.write() = disabled;
+    *BROKEN_EXTENSIONS.write() = broken;
+    *ORPHANED_CACHE.write() = orphans;
+    *MISSING_ROOTS.write() = missing_roots;
+
+    // Mentioned once per session, on the baseline scan - the Diagnostics view offers the cleanup
+    let (count, size) = {
+        let orphans = ORPHANED_CACHE.peek();
+        (orphans.len(), orphans.iter().map(|orphan| orphan.size).sum::<u64>())
+    };
+    if baseline && count > 0 {
+        show_info("toast-orphans-found", &[("count", &count.to_string()), ("size", &format_size(size))]);
+    }
+    *PLUGINS.write() = Some(plugins);
+    *SHOWING_CACHED.write() = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_detects_added_removed_and_changed() {
+        let previous = vec![Plugin::for_test("com.a", "1.0"), Plugin::for_test("com.b", "1.0"), Plugin::for_test("com.c", "1.0")];
+        let current = vec![Plugin::for_test("com.a", "1.0"), Plugin::for_test("com.b", "2.0"), Plugin::for_test("com.d", "1.0")];
+
+        let diff = diff_snapshots(&previous, &current);
+
+        assert_eq!(diff.added, vec![Plugin::for_test("com.d", "1.0")]);
+        assert_eq!(diff.removed, vec![Plugin::for_test("com.c", "1.0")]);
+        assert_eq!(diff.version_changed, vec![(Plugin::for_test("com.b", "1.0"), Plugin::for_test("com.b", "2.0"))]);
+        assert_eq!(diff.summary(), "1 added, 1 removed, 1 version changed");
+    }
+
+    #[test]
+    fn test_duplicates_keep_newest_then_later_root() {
+        let copy = |root: &str, id: &str, version: &str| Plugin { path: PathBuf::from(format!("/{}/{}", root, id)), ..Plugin::for_test(id, version) };
+        let plugins = vec![
+            copy("system", "com.a", "2.0"),
+            copy("system", "com.b", "1.0"),
+            copy("system", "com.c", "1.0"),
+            copy("user", "com.a", "1.5"),
+            copy("user", "com.b", "1.0.0"),
+            Plugin { plugin_type: PluginType::Legacy, ..copy("legacy", "com.c", "0.9") }, // Never loaded
+        ];
+
+        let duplicates = find_duplicates(&plugins);
+
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!(duplicates[0].keep, PathBuf::from("/system/com.a"));
+        assert_eq!(duplicates[0].remove, vec![PathBuf::from("/user/com.a")]);
+        assert_eq!(duplicates[1].keep, PathBuf::from("/user/com.b")); // Same version - the loaded copy stays
+        assert_eq!(duplicates[1].remove, vec![PathBuf::from("/system/com.b")]);
+    }
+
+    #[test]
+    fn test_expected_changes_are_not_reported() {
+        let diff = diff_snapshots(&[], &[Plugin::for_test("com.a", "1.0")]);
+        let expected = HashSet::from([PathBuf::from("/extensions/com.a")]);
+
+        assert!(diff.without_paths(&expected).is_empty());
+    }
+
+    #[test]
+    fn test_group_by_vendor_keeps_row_order() {
+        let plugins = [Plugin::for_test("com.zeta.b", "1.0"), Plugin::for_test("local", "1.0"), Plugin::for_test("com.acme.x", "1.0"), Plugin::for_test("com.zeta.a", "1.0")];
+        let groups = group_by_vendor(plugins.iter().collect());
+
+        let summary: Vec<(&str, Vec<&str>, u64)> = groups
+            .iter()
+            .map(|group| (group.vendor.as_str(), group.plugins.iter().map(|plugin| plugin.bundle_id.as_str()).collect(), group.disk_bytes))
+            .collect();
+        assert_eq!(
+            summary,
+            [("acme", vec!["com.acme.x"], 4096), ("zeta", vec!["com.zeta.b", "com.zeta.a"], 8192), ("", vec!["local"], 4096)]
+        );
+    }
+
+    #[test]
+    fn test_sorted_plugins_by_name_install_date_and_version() {
+        let dated = |id: &str, installed_at: Option<i64>| Plugin { installed_at, ..Plugin::for_test(id, "1.0") };
+        let plugins = vec![dated("com.b", Some(200)), dated("com.c", None), dated("Com.a", Some(100))];
+        let ids = |sort: PluginSort| sorted_plugins(&plugins, sort).iter().map(|plugin| plugin.bundle_id.as_str()).collect::<Vec<_>>();
+
+        assert_eq!(ids(PluginSort::ScanOrder), ["com.b", "com.c", "Com.a"]);
+        assert_eq!(ids(PluginSort::NameAscending), ["Com.a", "com.b", "com.c"]);
+        assert_eq!(ids(PluginSort::NameDescending), ["com.c", "com.b", "Com.a"]);
+        assert_eq!(ids(PluginSort::NewestFirst), ["com.b", "Com.a", "com.c"]);
+        assert_eq!(ids(PluginSort::OldestFirst), ["Com.a", "com.b", "com.c"]);
+
+        let versioned = vec![Plugin::for_test("com.x", "1.9"), Plugin::for_test("com.y", "1.10"), Plugin::for_test("com.z", "1.9.1")];
+        let by_version: Vec<&str> = sorted_plugins(&versioned, PluginSort::HighestVersion).iter().map(|plugin| plugin.bundle_id.as_str()).collect();
+        assert_eq!(by_version, ["com.y", "com.z", "com.x"]);
+    }
+}
@@ -0,0 +1,289 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fs;
+use std::path::Path;
+
+use crate::data_operations::{Plugin, PluginType};
+use crate::i18n::{t, t_args};
+use crate::version::compare_versions;
+
+// Admin-managed, outside the user's reach - the app only ever reads it
+pub const POLICY_PATH: &str = "/Library/Application Support/zxp-manager/policy.json";
+
+// Signature file ZXPSignCmd adds to signed packages (kept in the installed folder too)
+pub const SIGNATURES_FILE: &str = "META-INF/signatures.xml";
+
+// Bundle ID patterns match exactly or by prefix with a trailing `*`, e.g. "com.vendor.*".
+// Publishers are the signing certificate's common name, compared case-insensitively.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Policy {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+    pub allow_publishers: Vec<String>,
+    pub deny_publishers: Vec<String>,
+    pub required: Vec<RequiredPlugin>, // Plugins every managed machine should have
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequiredPlugin {
+    pub bundle_id: String,
+    #[serde(default)]
+    pub min_version: Option<String>,
+}
+
+// A required plugin that is missing or older than the policy asks for
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequirementGap {
+    pub requirement: RequiredPlugin,
+    pub installed_version: Option<String>, // None when not installed at all
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DenyReason {
+    DeniedId(String),        // Pattern from the deny list that matched
+    DeniedPublisher(String), // Signer from the deny list
+    NotAllowed,              // Allow lists exist and nothing on them matched
+}
+
+impl std::fmt::Display for DenyReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DenyReason::DeniedId(pattern) => write!(f, "{}", t_args("policy-denied-id", &[("pattern", pattern)])),
+            DenyReason::DeniedPublisher(publisher) => {
+                write!(f, "{}", t_args("policy-denied-publisher", &[("publisher", publisher)]))
+            }
+            DenyReason::NotAllowed => write!(f, "{}", t("policy-not-allowed")),
+        }
+    }
+}
+
+// An installed plugin the policy doesn't permit
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolicyViolation {
+    pub plugin: Plugin,
+    pub publisher: Option<String>,
+    pub reason: DenyReason,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyStatus {
+    Missing, // Unmanaged machine - everything is allowed
+    Loaded(Policy),
+    Invalid, // Present but unreadable - reported, but nothing is blocked
+}
+
+impl Policy {
+    // Native Adobe extensions ship with the hosts, so only deny lists apply to them
+    pub fn evaluate(&self, bundle_id: &str, publisher: Option<&str>, is_native: bool) -> Result<(), DenyReason> {
+        if let Some(pattern) = self.deny.iter().find(|pattern| matches_pattern(pattern, bundle_id)) {
+            return Err(DenyReason::DeniedId(pattern.clone()));
+        }
+        if let Some(publisher) = publisher
+            && self.deny_publishers.iter().any(|denied| denied.eq_ignore_ascii_case(publisher))
+        {
+            return Err(DenyReason::DeniedPublisher(publisher.to_string()));
+        }
+
+        let has_allow_list = !self.allow.is_empty() || !self.allow_publishers.is_empty();
+        if !has_allow_list || is_native {
+            return Ok(());
+        }
+
+        let id_allowed = self.allow.iter().any(|pattern| matches_pattern(pattern, bundle_id));
+        let publisher_allowed = publisher
+            .is_some_and(|publisher| self.allow_publishers.iter().any(|allowed| allowed.eq_ignore_ascii_case(publisher)));
+        if id_allowed || publisher_allowed { Ok(()) } else { Err(DenyReason::NotAllowed) }
+    }
+}
+
+pub fn find_violations(policy: &Policy, plugins: &[Plugin]) -> Vec<PolicyViolation> {
+    plugins
+        .iter()
+        .filter_map(|plugin| {
+            let publisher = folder_publisher(&plugin.path);
+            let is_native = plugin.plugin_type == PluginType::Native;
+            policy.evaluate(&plugin.bundle_id, publisher.as_deref(), is_native).err().map(|reason| PolicyViolation {
+                plugin: plugin.clone(),
+                publisher,
+                reason,
+            })
+        })
+        .collect()
+}
+
+pub fn find_requirement_gaps(policy: &Policy, plugins: &[Plugin]) -> Vec<RequirementGap> {
+    policy
+        .required
+        .iter()
+        .filter_map(|requirement| {
+            let installed: Vec<&Plugin> = plugins.iter().filter(|plugin| plugin.bundle_id == requirement.bundle_id).collect();
+            // Any installed copy recent enough satisfies the requirement
+            let satisfied = !installed.is_empty()
+                && requirement.min_version.as_ref().is_none_or(|min| {
+                    installed.iter().any(|plugin| compare_versions(&plugin.version, min) != Ordering::Less)
+                });
+            (!satisfied).then(|| RequirementGap {
+                requirement: requirement.clone(),
+                installed_version: installed.first().map(|plugin| plugin.version.clone()),
+            })
+        })
+        .collect()
+}
+
+pub fn load_policy() -> PolicyStatus {
+    let content = match fs::read_to_string(POLICY_PATH) {
+        Ok(content) => content,
+        Err(_) => return PolicyStatus::Missing,
+    };
+
+    match serde_json::from_str(&content) {
+        Ok(policy) => PolicyStatus::Loaded(policy),
+        Err(e) => {
+            log::error!("Ignoring invalid policy file {}: {}", POLICY_PATH, e);
+            PolicyStatus::Invalid
+        }
+    }
+}
+
+// Signer of an installed extension folder, if it was installed from a signed ZXP
+pub fn folder_publisher(plugin_path: &Path) -> Option<String> {
+    let xml = fs::read_to_string(plugin_path.join(SIGNATURES_FILE)).ok()?;
+    signer_common_name(&xml)
+}
+
+// The first <X509Certificate> is the signer's; its subject CN follows the issuer's in DER order
+pub fn signer_common_name(signatures_xml: &str) -> Option<String> {
+    let start = signatures_xml.find("<X509Certificate>")? + "<X509Certificate>".len();
+    let end = start + signatures_xml[start..].find("</X509Certificate>")?;
+    let der = decode_base64(&signatures_xml[start..end])?;
+    common_names(&der).pop()
+}
+
+// Helper functions
+fn matches_pattern(pattern: &str, bundle_id: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => bundle_id.starts_with(prefix),
+        None => pattern == bundle_id,
+    }
+}
+
+// Values of every commonName attribute (OID 2.5.4.3) in a DER certificate
+fn common_names(der: &[u8]) -> Vec<String> {
+    const CN_OID: [u8; 5] = [0x06, 0x03, 0x55, 0x04, 0x03];
+    const STRING_TAGS: [u8; 4] = [0x0C, 0x13, 0x14, 0x16]; // UTF8, Printable, Teletex, IA5
+
+    let mut names = Vec::new();
+    let mut index = 0;
+    while index + CN_OID.len() + 2 <= der.len() {
+        if der[index..].starts_with(&CN_OID) {
+            let tag = der[index + CN_OID.len()];
+            let length = der[index + CN_OID.len() + 1] as usize;
+            let value_start = index + CN_OID.len() + 2;
+            // Names are short - long-form lengths don't occur here
+            if STRING_TAGS.contains(&tag) && length < 0x80 && value_start + length <= der.len() {
+                names.push(String::from_utf8_lossy(&der[value_start..value_start + length]).to_string());
+            }
+            index = value_start;
+        } else {
+            index += 1;
+        }
+    }
+    names
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in text.bytes().filter(|c| !c.is_ascii_whitespace()) {
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deny_wins_over_allow() {
+        let policy = Policy {
+            allow: vec!["com.vendor.*".to_string()],
+            deny: vec!["com.vendor.legacy".to_string()],
+            deny_publishers: vec!["Shady Panels LLC".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(policy.evaluate("com.vendor.tools", None, false), Ok(()));
+        assert_eq!(policy.evaluate("com.vendor.legacy", None, false), Err(DenyReason::DeniedId("com.vendor.legacy".to_string())));
+        assert_eq!(
+            policy.evaluate("com.vendor.tools", Some("shady panels llc"), false),
+            Err(DenyReason::DeniedPublisher("shady panels llc".to_string()))
+        );
+        assert_eq!(policy.evaluate("com.other.panel", None, false), Err(DenyReason::NotAllowed));
+        assert_eq!(policy.evaluate("com.adobe.ccx.start", None, true), Ok(()));
+    }
+
+    #[test]
+    fn test_requirement_gaps() {
+        let required = |bundle_id: &str, min_version: Option<&str>| RequiredPlugin {
+            bundle_id: bundle_id.to_string(),
+            min_version: min_version.map(str::to_string),
+        };
+        let policy = Policy {
+            required: vec![required("com.vendor.tools", Some("2.0")), required("com.vendor.color", None), required("com.vendor.sync", None)],
+            ..Default::default()
+        };
+        let plugins = vec![Plugin::for_test("com.vendor.tools", "1.9.5"), Plugin::for_test("com.vendor.color", "0.1")];
+
+        let gaps = find_requirement_gaps(&policy, &plugins);
+        assert_eq!(gaps.len(), 2);
+        assert_eq!(gaps[0].installed_version.as_deref(), Some("1.9.5")); // Outdated
+        assert_eq!(gaps[1].installed_version, None); // Missing
+    }
+
+    #[test]
+    fn test_signer_common_name() {
+        // Issuer CN "Test CA", then subject CN "Vendor Inc", as in a real certificate
+        let der = [
+            &[0x30, 0x20][..],
+            &[0x06, 0x03, 0x55, 0x04, 0x03, 0x13, 0x07],
+            b"Test CA",
+            &[0x06, 0x03, 0x55, 0x04, 0x03, 0x0C, 0x0A],
+            b"Vendor Inc",
+        ]
+        .concat();
+        let encoded = encode_base64(&der);
+        let xml = format!("<Signature><X509Data><X509Certificate>\n{}\n</X509Certificate></X509Data></Signature>", encoded);
+
+        assert_eq!(signer_common_name(&xml).as_deref(), Some("Vendor Inc"));
+        assert_eq!(signer_common_name("<Signature/>"), None);
+    }
+
+    fn encode_base64(bytes: &[u8]) -> String {
+        const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        bytes
+            .chunks(3)
+            .flat_map(|chunk| {
+                let n = chunk.iter().fold(0u32, |n, &byte| (n << 8) | byte as u32) << (8 * (3 - chunk.len()));
+                (0..4).map(move |i| if i <= chunk.len() { ALPHABET[(n >> (18 - 6 * i)) as usize & 63] as char } else { '=' })
+            })
+            .collect()
+    }
+}
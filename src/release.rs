@@ -0,0 +1,200 @@
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::clock::LocalTime;
+use crate::data_operations::{PluginInfo, parse_manifest_xml};
+use crate::file_operations::{FileOperationError, export_plugin_as_zxp, install_zxp_into};
+use crate::temp_files::temp_path;
+use crate::validation::{ValidationReport, validate_zxp};
+
+// "Prepare release": takes an unpacked CEP project to a signed ZXP in one pass - check the manifest
+// and files, sign, install the result into a scratch folder to prove it unpacks, and write the
+// update-feed entry that announces the new version
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseStep {
+    Validate,
+    Sign,
+    TestInstall,
+    FeedEntry,
+}
+
+impl ReleaseStep {
+    pub const ALL: [ReleaseStep; 4] = [ReleaseStep::Validate, ReleaseStep::Sign, ReleaseStep::TestInstall, ReleaseStep::FeedEntry];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ReleaseStep::Validate => "release-step-validate",
+            ReleaseStep::Sign => "release-step-sign",
+            ReleaseStep::TestInstall => "release-step-test-install",
+            ReleaseStep::FeedEntry => "release-step-feed",
+        }
+    }
+}
+
+// Where a step ended up; the detail is already translated
+#[derive(Debug, Clone, PartialEq)]
+pub enum StepOutcome {
+    Running,
+    Done(String),
+    Failed(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReleaseRequest {
+    pub source: PathBuf,
+    pub certificate: PathBuf,
+    pub password: String,
+    pub tsa_url: Option<String>,
+    pub download_url: String, // Where the ZXP will be uploaded, used for the feed entry's URL
+}
+
+// One version in a release feed - the JSON a panel's own update check would read
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FeedEntry {
+    pub id: String,
+    pub name: String,
+    pub version: String,
+    pub url: String,
+    pub size: u64, // Bytes of the signed ZXP
+    pub released: String, // "2025-04-10"
+}
+
+// Writes a minimal manifest (and an index.html for it to open) into a folder that has none yet
+pub fn scaffold_project(folder: &Path, bundle_id: &str, name: &str) -> Result<PathBuf, FileOperationError> {
+    let manifest_path = folder.join("CSXS").join("manifest.xml");
+    if manifest_path.exists() {
+        return Err(FileOperationError::AlreadyInstalled(manifest_path));
+    }
+    let write = |path: &Path, content: String| {
+        path.parent().map_or(Ok(()), fs::create_dir_all).and_then(|_| fs::write(path, content)).map_err(|e| FileOperationError::extract(path, e))
+    };
+
+    write(&manifest_path, scaffold_manifest(bundle_id.trim(), name.trim()))?;
+    let index = folder.join("index.html");
+    if !index.exists() {
+        write(&index, format!("<!DOCTYPE html>\n<html>\n<body>\n  <h1>{}</h1>\n</body>\n</html>\n", escape_xml(name.trim())))?;
+    }
+    log::info!("Scaffolded {} in {:?}", bundle_id, folder);
+    Ok(manifest_path)
+}
+
+// "com.example.panel-1.2.0.zxp"
+pub fn release_file_name(info: &PluginInfo) -> String {
+    format!("{}-{}.zxp", info.bundle_id, info.version)
+}
+
+pub fn read_project(source: &Path) -> Result<PluginInfo, FileOperationError> {
+    parse_manifest_xml(&source.join("CSXS").join("manifest.xml")).map_err(|_| FileOperationError::InvalidExtension(source.to_path_buf()))
+}
+
+// Lints the project the way installs lint a ZXP, from an unsigned package in the temp folder
+pub fn validate_project(source: &Path) -> Result<ValidationReport, FileOperationError> {
+    let package = temp_path("release-check.zxp");
+    let result = export_plugin_as_zxp(source, &package).and_then(|_| validate_zxp(&package));
+    let _ = fs::remove_file(&package);
+    result
+}
+
+// Installs into a scratch extensions root; the package must unpack with the project's ID and version
+pub fn test_install(zxp_path: &Path, expected: &PluginInfo) -> Result<(), FileOperationError> {
+    let root = temp_path("release-install");
+    let result = install_zxp_into(zxp_path, &root);
+    let _ = fs::remove_dir_all(&root);
+
+    let installed = result?;
+    if installed.info.bundle_id != expected.bundle_id || installed.info.version != expected.version {
+        log::error!("Test install of {:?} unpacked {} {}", zxp_path, installed.info.bundle_id, installed.info.version);
+        return Err(FileOperationError::InvalidExtension(zxp_path.to_path_buf()));
+    }
+    Ok(())
+}
+
+// `download_url` is the folder the ZXP is uploaded to; an empty one leaves just the file name
+pub fn feed_entry(info: &PluginInfo, zxp_path: &Path, download_url: &str) -> Result<FeedEntry, FileOperationError> {
+    let size = fs::metadata(zxp_path).map_err(|_| FileOperationError::FileNotFound(zxp_path.to_path_buf()))?.len();
+    let file_name = zxp_path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    let base = download_url.trim().trim_end_matches('/');
+
+    Ok(FeedEntry {
+        id: info.bundle_id.clone(),
+        name: info.name.clone(),
+        version: info.version.clone(),
+        url: if base.is_empty() { file_name } else { format!("{}/{}", base, file_name) },
+        size,
+        released: LocalTime::now().date_label(),
+    })
+}
+
+// Saved next to the ZXP as "<file>.json"; returns where
+pub fn write_feed_entry(entry: &FeedEntry, zxp_path: &Path) -> Result<PathBuf, FileOperationError> {
+    let path = zxp_path.with_extension("json");
+    let mut json = serde_json::to_string_pretty(entry).unwrap_or_default();
+    json.push('\n');
+    fs::write(&path, json).map_err(|e| FileOperationError::archive(&path, e))?;
+    Ok(path)
+}
+
+// Helper functions
+fn scaffold_manifest(bundle_id: &str, name: &str) -> String {
+    let (id, name) = (escape_xml(bundle_id), escape_xml(name));
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<ExtensionManifest Version="11.0" ExtensionBundleId="{id}" ExtensionBundleVersion="1.0.0" ExtensionBundleName="{name}">
+  <ExtensionList>
+    <Extension Id="{id}.panel" Version="1.0.0"/>
+  </ExtensionList>
+  <ExecutionEnvironment>
+    <HostList>
+      <Host Name="PHXS" Version="[22.0,99.9]"/>
+    </HostList>
+    <LocaleList><Locale Code="All"/></LocaleList>
+    <RequiredRuntimeList><RequiredRuntime Name="CSXS" Version="11.0"/></RequiredRuntimeList>
+  </ExecutionEnvironment>
+  <DispatchInfoList>
+    <Extension Id="{id}.panel">
+      <DispatchInfo>
+        <Resources><MainPath>./index.html</MainPath></Resources>
+        <UI>
+          <Type>Panel</Type>
+          <Menu>{name}</Menu>
+          <Geometry><Size><Height>400</Height><Width>300</Width></Size></Geometry>
+        </UI>
+      </DispatchInfo>
+    </Extension>
+  </DispatchInfoList>
+</ExtensionManifest>
+"#
+    )
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaffolded_project_validates_and_installs() {
+        let folder = std::env::temp_dir().join(format!("zxpm-release-{}", std::process::id()));
+        scaffold_project(&folder, "com.example.fresh", "Fresh Panel").unwrap();
+        assert!(matches!(scaffold_project(&folder, "com.example.fresh", "Again"), Err(FileOperationError::AlreadyInstalled(_))));
+
+        let info = read_project(&folder).unwrap();
+        assert_eq!((info.name.as_str(), release_file_name(&info).as_str()), ("Fresh Panel", "com.example.fresh-1.0.0.zxp"));
+        let report = validate_project(&folder).unwrap();
+        assert_eq!(report.findings.iter().map(|finding| finding.message_key).collect::<Vec<_>>(), ["validation-unsigned"]);
+
+        let package = folder.with_extension("zxp");
+        export_plugin_as_zxp(&folder, &package).unwrap();
+        test_install(&package, &info).unwrap();
+        let entry = feed_entry(&info, &package, "https://example.com/releases/").unwrap();
+        assert_eq!(entry.url, format!("https://example.com/releases/{}", package.file_name().unwrap().to_string_lossy()));
+
+        let _ = fs::remove_file(&package);
+        fs::remove_dir_all(&folder).unwrap();
+    }
+}
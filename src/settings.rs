@@ -0,0 +1,307 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::file_operations;
+use crate::hot_folder::HotFolder;
+use crate::i18n::{self, Locale};
+use crate::logging::{self, LogLevel};
+use crate::message::{ToastDurations, ToastPosition, persist_history};
+use crate::scheduler::MaintenanceSchedule;
+
+// User preferences persisted as JSON in the platform config directory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Settings {
+    pub language: Locale,
+    pub maintenance: MaintenanceSchedule,
+    pub keep_history: bool, // Save the activity history across sessions
+    pub log_level: LogLevel,
+    pub recent_installs: Vec<PathBuf>, // Source .zxp files, most recent first
+    pub last_directories: BTreeMap<DialogKind, PathBuf>,
+    pub double_click: DoubleClickAction,
+    pub check_for_updates: bool, // Opt-in: ask GitHub for a newer release on startup
+    pub zxpsigncmd_path: Option<PathBuf>, // Located by the user when it isn't on PATH
+    pub toast_position: ToastPosition,
+    pub toast_durations: ToastDurations,
+    pub inspect_before_install: bool, // List what an install would write and wait for approval
+    pub scan_scripts: bool, // Look through JS/JSX for risky calls before installing
+    pub hidden_quick_actions: Vec<QuickAction>, // Row hover buttons the user turned off
+    pub show_bundle_id: bool, // Extra Bundle ID column in the plugin list
+    pub plugin_sort: PluginSort,
+    pub group_by_vendor: bool, // Plugin list in collapsible vendor sections
+    pub host_filter: Option<String>, // Host code; only plugins whose manifest lists it are shown
+    pub tag_filter: Option<String>, // Only plugins the user gave this tag
+    pub hot_folder: HotFolder, // Watched folder whose ZXPs install themselves
+    pub theme: Theme,
+    pub motion: MotionPreference,
+    pub keep_quarantine: bool, // macOS: leave com.apple.quarantine on installed files
+    pub tray_icon: bool, // Companion tray icon; closing the window hides it when on at launch
+    pub mute_system_notifications: bool, // No native notifications while the window is in the background
+    pub telemetry: bool, // Opt-in anonymous usage counts, see telemetry.rs
+}
+
+// Color scheme, applied as a data-theme attribute on the root container
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum Theme {
+    #[default]
+    Dark,
+    HighContrast, // Pure black and white with bright borders
+}
+
+impl Theme {
+    pub const ALL: [Theme; 2] = [Theme::Dark, Theme::HighContrast];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Theme::Dark => "dark",
+            Theme::HighContrast => "high-contrast",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Theme> {
+        Theme::ALL.into_iter().find(|theme| theme.code() == code)
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Theme::Dark => "theme-dark",
+            Theme::HighContrast => "theme-high-contrast",
+        }
+    }
+}
+
+// Whether animations and transitions play; System follows the OS "reduce motion" preference
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum MotionPreference {
+    #[default]
+    System,
+    Reduced,
+    Full,
+}
+
+impl MotionPreference {
+    pub const ALL: [MotionPreference; 3] = [MotionPreference::System, MotionPreference::Reduced, MotionPreference::Full];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            MotionPreference::System => "system",
+            MotionPreference::Reduced => "reduced",
+            MotionPreference::Full => "full",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<MotionPreference> {
+        MotionPreference::ALL.into_iter().find(|motion| motion.code() == code)
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            MotionPreference::System => "motion-system",
+            MotionPreference::Reduced => "motion-reduced",
+            MotionPreference::Full => "motion-full",
+        }
+    }
+}
+
+// What double-clicking a plugin row does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DoubleClickAction {
+    #[default]
+    OpenDetails,
+    RevealInFileManager,
+    OpenManifest,
+}
+
+impl DoubleClickAction {
+    pub const ALL: [DoubleClickAction; 3] =
+        [DoubleClickAction::OpenDetails, DoubleClickAction::RevealInFileManager, DoubleClickAction::OpenManifest];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            DoubleClickAction::OpenDetails => "details",
+            DoubleClickAction::RevealInFileManager => "reveal",
+            DoubleClickAction::OpenManifest => "manifest",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<DoubleClickAction> {
+        DoubleClickAction::ALL.into_iter().find(|action| action.code() == code)
+    }
+
+    // Same wording as the matching context menu items
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            DoubleClickAction::OpenDetails => "menu-details",
+            DoubleClickAction::RevealInFileManager if cfg!(target_os = "macos") => "menu-reveal-finder",
+            DoubleClickAction::RevealInFileManager => "menu-reveal-explorer",
+            DoubleClickAction::OpenManifest => "menu-open-manifest",
+        }
+    }
+}
+
+// Buttons shown when hovering a plugin row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuickAction {
+    Reveal,
+    Details,
+    Disable,
+    Remove,
+}
+
+impl QuickAction {
+    pub const ALL: [QuickAction; 4] = [QuickAction::Reveal, QuickAction::Details, QuickAction::Disable, QuickAction::Remove];
+
+    // Same wording as the matching context menu items
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            QuickAction::Reveal if cfg!(target_os = "macos") => "menu-reveal-finder",
+            QuickAction::Reveal => "menu-reveal-explorer",
+            QuickAction::Details => "menu-details",
+            QuickAction::Disable => "menu-disable",
+            QuickAction::Remove => "menu-remove",
+        }
+    }
+}
+
+// Order of the plugin list, picked by clicking a column header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum PluginSort {
+    #[default]
+    ScanOrder, // The order hosts load plugins in
+    NameAscending,
+    NameDescending,
+    NewestFirst,
+    OldestFirst,
+    HighestVersion,
+    LowestVersion,
+}
+
+impl PluginSort {
+    // A first click sorts by the column, the next one reverses it
+    pub fn toggle_name(self) -> PluginSort {
+        if self == PluginSort::NameAscending { PluginSort::NameDescending } else { PluginSort::NameAscending }
+    }
+
+    // Newest first is what people look for after something broke
+    pub fn toggle_installed(self) -> PluginSort {
+        if self == PluginSort::NewestFirst { PluginSort::OldestFirst } else { PluginSort::NewestFirst }
+    }
+
+    // Highest first, like the installed column
+    pub fn toggle_version(self) -> PluginSort {
+        if self == PluginSort::HighestVersion { PluginSort::LowestVersion } else { PluginSort::HighestVersion }
+    }
+}
+
+// File dialogs that reopen where the user last picked something
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum DialogKind {
+    InstallZxp,
+    OpenInventory,
+    ExportZxp,
+    ExportList,
+    PluginSet,
+    Signing, // Extension folders, certificates and signed packages
+    HotFolder,
+}
+
+// Entries kept in the install area's Recent list
+const RECENT_INSTALLS_LIMIT: usize = 8;
+
+// Global settings signal - loaded once from disk on first access
+pub static SETTINGS: GlobalSignal<Settings> = Signal::global(load_settings);
+
+pub fn settings_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("zxp-manager").join("settings.json"))
+}
+
+pub fn load_settings() -> Settings {
+    let Some(path) = settings_path() else {
+        return Settings::default();
+    };
+
+    let settings = match fs::read_to_string(&path) {
+        Ok(content) => serde_json::from_str(&content).unwrap_or_else(|e| {
+            log::warn!("Failed to parse settings {:?}: {}", path, e);
+            Settings::default()
+        }),
+        Err(_) => Settings::default(), // First launch - nothing saved yet
+    };
+
+    i18n::set_locale(settings.language);
+    logging::set_level(settings.log_level);
+    file_operations::set_clear_quarantine(!settings.keep_quarantine);
+    settings
+}
+
+pub fn save_settings(settings: &Settings) -> Result<(), std::io::Error> {
+    let path = settings_path().ok_or(std::io::ErrorKind::NotFound)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let content = serde_json::to_string_pretty(settings)?;
+    fs::write(&path, content)
+}
+
+// Apply a change to the global settings and persist it
+pub fn update_settings(change: impl FnOnce(&mut Settings)) {
+    let mut settings = SETTINGS.write();
+    change(&mut settings);
+
+    if let Err(e) = save_settings(&settings) {
+        log::error!("Failed to save settings: {}", e);
+    }
+}
+
+pub fn set_language(locale: Locale) {
+    i18n::set_locale(locale);
+    update_settings(|settings| settings.language = locale);
+}
+
+pub fn set_keep_history(keep: bool) {
+    update_settings(|settings| settings.keep_history = keep);
+    persist_history(keep);
+}
+
+pub fn set_log_level(level: LogLevel) {
+    logging::set_level(level);
+    update_settings(|settings| settings.log_level = level);
+}
+
+pub fn set_keep_quarantine(keep: bool) {
+    file_operations::set_clear_quarantine(!keep);
+    update_settings(|settings| settings.keep_quarantine = keep);
+}
+
+pub fn remember_recent_install(zxp_path: &Path) {
+    update_settings(|settings| {
+        settings.recent_installs.retain(|path| path != zxp_path);
+        settings.recent_installs.insert(0, zxp_path.to_path_buf());
+        settings.recent_installs.truncate(RECENT_INSTALLS_LIMIT);
+    });
+}
+
+pub fn clear_recent_installs() {
+    update_settings(|settings| settings.recent_installs.clear());
+}
+
+// Start directory for a dialog, if the remembered one still exists
+pub fn last_directory(kind: DialogKind) -> Option<PathBuf> {
+    SETTINGS.read().last_directories.get(&kind).filter(|dir| dir.is_dir()).cloned()
+}
+
+pub fn remember_directory(kind: DialogKind, chosen: &Path) {
+    // Reopen next to the chosen file or folder
+    let Some(dir) = chosen.parent().filter(|dir| !dir.as_os_str().is_empty()) else {
+        return;
+    };
+    if SETTINGS.read().last_directories.get(&kind).map(PathBuf::as_path) != Some(dir) {
+        update_settings(|settings| {
+            settings.last_directories.insert(kind, dir.to_path_buf());
+        });
+    }
+}
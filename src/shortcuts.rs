@@ -0,0 +1,183 @@
+use dioxus::prelude::*;
+
+use crate::actions::{
+    INSTALL_COMPARISON, INSTALL_PREVIEWS, ZXP_INSPECTION, close_install_comparison, close_zxp_inspection, dismiss_install_preview,
+    install_from_clipboard, install_from_picker, remove_selected_plugin,
+};
+use crate::command_palette::{COMMAND_PALETTE_OPEN, toggle_command_palette};
+use crate::message::trigger_refresh;
+use crate::navigation::{View, show_view};
+
+// Keybinding registry - the single source of truth for handling and displaying shortcuts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutAction {
+    OpenPicker,
+    Rescan,
+    RemoveSelected,
+    FocusSearch,
+    PasteInstall,
+    ShowShortcuts,
+    CommandPalette,
+}
+
+impl ShortcutAction {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            ShortcutAction::OpenPicker => "shortcut-open-picker",
+            ShortcutAction::Rescan => "shortcut-rescan",
+            ShortcutAction::RemoveSelected => "shortcut-remove-selected",
+            ShortcutAction::FocusSearch => "shortcut-focus-search",
+            ShortcutAction::PasteInstall => "shortcut-paste-install",
+            ShortcutAction::ShowShortcuts => "shortcut-show-shortcuts",
+            ShortcutAction::CommandPalette => "shortcut-command-palette",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShortcutKey {
+    Char(char),
+    Delete, // Matches both Delete and Backspace (the Mac "delete" key)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shortcut {
+    pub action: ShortcutAction,
+    pub key: ShortcutKey,
+    pub primary_modifier: bool, // Cmd on macOS, Ctrl elsewhere
+}
+
+pub const SHORTCUTS: [Shortcut; 7] = [
+    Shortcut { action: ShortcutAction::OpenPicker, key: ShortcutKey::Char('o'), primary_modifier: true },
+    Shortcut { action: ShortcutAction::Rescan, key: ShortcutKey::Char('r'), primary_modifier: true },
+    Shortcut { action: ShortcutAction::RemoveSelected, key: ShortcutKey::Delete, primary_modifier: false },
+    Shortcut { action: ShortcutAction::FocusSearch, key: ShortcutKey::Char('f'), primary_modifier: true },
+    Shortcut { action: ShortcutAction::PasteInstall, key: ShortcutKey::Char('v'), primary_modifier: true },
+    Shortcut { action: ShortcutAction::ShowShortcuts, key: ShortcutKey::Char('?'), primary_modifier: false },
+    Shortcut { action: ShortcutAction::CommandPalette, key: ShortcutKey::Char('k'), primary_modifier: true },
+];
+
+// Whether the cheat sheet overlay listing SHORTCUTS is open
+pub static SHORTCUT_SHEET_OPEN: GlobalSignal<bool> = Signal::global(|| false);
+
+// Clipboard and undo keys a focused text field handles itself
+const TEXT_EDITING_KEYS: [&str; 5] = ["a", "c", "v", "x", "z"];
+
+impl Shortcut {
+    pub fn for_action(action: ShortcutAction) -> Option<Shortcut> {
+        SHORTCUTS.into_iter().find(|shortcut| shortcut.action == action)
+    }
+
+    // Platform-style label, e.g. "⌘O" on macOS or "Ctrl+O" elsewhere
+    pub fn display(&self) -> String {
+        let key = match self.key {
+            ShortcutKey::Char(c) => c.to_ascii_uppercase().to_string(),
+            ShortcutKey::Delete => if cfg!(target_os = "macos") { "⌫" } else { "Del" }.to_string(),
+        };
+
+        match (self.primary_modifier, cfg!(target_os = "macos")) {
+            (false, _) => key,
+            (true, true) => format!("⌘{}", key),
+            (true, false) => format!("Ctrl+{}", key),
+        }
+    }
+
+    fn matches(&self, key: &Key, primary_pressed: bool) -> bool {
+        if self.primary_modifier != primary_pressed {
+            return false;
+        }
+        match (self.key, key) {
+            (ShortcutKey::Char(expected), Key::Character(pressed)) => pressed.eq_ignore_ascii_case(&expected.to_string()),
+            (ShortcutKey::Delete, Key::Delete | Key::Backspace) => true,
+            _ => false,
+        }
+    }
+}
+
+// Tooltip suffix for buttons that also have a shortcut, e.g. " (⌘O)"
+pub fn shortcut_hint(action: ShortcutAction) -> String {
+    Shortcut::for_action(action)
+        .map(|shortcut| format!(" ({})", shortcut.display()))
+        .unwrap_or_default()
+}
+
+pub fn is_primary_modifier(modifiers: Modifiers) -> bool {
+    if cfg!(target_os = "macos") {
+        modifiers.contains(Modifiers::META)
+    } else {
+        modifiers.contains(Modifiers::CONTROL)
+    }
+}
+
+// For onkeydown of text inputs: true when the key belongs to the field, not the global shortcuts
+pub fn is_text_editing_key(event: &KeyboardEvent) -> bool {
+    if !is_primary_modifier(event.modifiers()) {
+        return true; // Plain typing, including Delete
+    }
+    match event.key() {
+        Key::Character(pressed) => TEXT_EDITING_KEYS.iter().any(|key| pressed.eq_ignore_ascii_case(key)),
+        _ => false,
+    }
+}
+
+// Root keydown handler - attached to the app container in main.rs
+pub fn handle_shortcut(event: KeyboardEvent) {
+    let primary_pressed = is_primary_modifier(event.modifiers());
+    let key = event.key();
+
+    if key == Key::Escape && COMMAND_PALETTE_OPEN() {
+        *COMMAND_PALETTE_OPEN.write() = false;
+        return;
+    }
+    if key == Key::Escape && SHORTCUT_SHEET_OPEN() {
+        *SHORTCUT_SHEET_OPEN.write() = false;
+        return;
+    }
+    // The comparison sits on top of the preview or inspector it was opened from
+    if key == Key::Escape && INSTALL_COMPARISON.peek().is_some() {
+        close_install_comparison();
+        return;
+    }
+    if key == Key::Escape && ZXP_INSPECTION.peek().is_some() {
+        close_zxp_inspection();
+        return;
+    }
+    if key == Key::Escape && !INSTALL_PREVIEWS.peek().is_empty() {
+        dismiss_install_preview();
+        return;
+    }
+
+    let Some(shortcut) = SHORTCUTS.iter().find(|shortcut| shortcut.matches(&key, primary_pressed)) else {
+        return;
+    };
+
+    // Keep the webview's own bindings (e.g. reload on Cmd+R) from firing too
+    event.prevent_default();
+    log::debug!("Shortcut triggered: {:?}", shortcut.action);
+
+    match shortcut.action {
+        ShortcutAction::OpenPicker => install_from_picker(),
+        ShortcutAction::Rescan => trigger_refresh(),
+        ShortcutAction::RemoveSelected => remove_selected_plugin(),
+        ShortcutAction::FocusSearch => focus_search(),
+        ShortcutAction::PasteInstall => install_from_clipboard(),
+        ShortcutAction::ShowShortcuts => {
+            let open = SHORTCUT_SHEET_OPEN();
+            *SHORTCUT_SHEET_OPEN.write() = !open;
+        }
+        ShortcutAction::CommandPalette => toggle_command_palette(),
+    }
+}
+
+fn focus_search() {
+    show_view(View::Plugins);
+
+    // The search box may only mount on the next frame after switching views
+    let _ = document::eval(
+        r#"(function focusSearch(attempts) {
+            const input = document.getElementById("plugin-search");
+            if (input) { input.focus(); input.select(); }
+            else if (attempts > 0) { requestAnimationFrame(() => focusSearch(attempts - 1)); }
+        })(10);"#,
+    );
+}
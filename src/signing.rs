@@ -0,0 +1,152 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::file_operations::FileOperationError;
+
+// Adobe's signing tool - ZXP signatures are XML-DSig over every file, so we drive it rather than reimplement it
+const TOOL_NAMES: [&str; 3] = ["ZXPSignCmd", "ZXPSignCmd-64bit", "ZXPSignCmd.exe"];
+
+// RFC 3161 timestamp authority; a timestamp keeps the package valid after the certificate expires
+pub const DEFAULT_TSA_URL: &str = "http://timestamp.digicert.com";
+
+// Subject of a new self-signed certificate
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CertificateRequest {
+    pub country: String, // Two-letter code, e.g. UA
+    pub state: String,
+    pub organization: String,
+    pub common_name: String, // Shown as the publisher by installers
+    pub password: String,
+}
+
+impl CertificateRequest {
+    pub fn is_complete(&self) -> bool {
+        self.country.trim().len() == 2
+            && [&self.state, &self.organization, &self.common_name, &self.password]
+                .iter()
+                .all(|field| !field.trim().is_empty())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignRequest {
+    pub source: PathBuf, // Extension folder with CSXS/manifest.xml
+    pub certificate: PathBuf,
+    pub password: String,
+    pub output: PathBuf,
+    pub tsa_url: Option<String>,
+}
+
+// The configured tool if it still exists, else the first ZXPSignCmd on PATH
+pub fn find_zxpsigncmd(configured: Option<&Path>) -> Option<PathBuf> {
+    if let Some(path) = configured.filter(|path| path.is_file()) {
+        return Some(path.to_path_buf());
+    }
+    let search_path = env::var_os("PATH")?;
+    env::split_paths(&search_path)
+        .flat_map(|dir| TOOL_NAMES.iter().map(move |name| dir.join(name)))
+        .find(|candidate| candidate.is_file())
+}
+
+// Note: ZXPSignCmd only takes passwords as arguments, so they are briefly visible in the process list
+pub fn create_self_signed_certificate(tool: &Path, request: &CertificateRequest, output: &Path) -> Result<(), FileOperationError> {
+    let output_arg = output.to_string_lossy();
+    run_tool(
+        tool,
+        &[
+            "-selfSignedCert",
+            request.country.trim(),
+            request.state.trim(),
+            request.organization.trim(),
+            request.common_name.trim(),
+            &request.password,
+            &output_arg,
+        ],
+    )?;
+    log::info!("Created self-signed certificate {:?} for {}", output, request.common_name.trim());
+    Ok(())
+}
+
+// Packages and signs in one step, then verifies the result
+pub fn sign_extension(tool: &Path, request: &SignRequest) -> Result<(), FileOperationError> {
+    let manifest = request.source.join("CSXS").join("manifest.xml");
+    if !manifest.is_file() {
+        return Err(FileOperationError::FileNotFound(manifest));
+    }
+    // ZXPSignCmd refuses to overwrite, and a stale package would pass verification
+    if request.output.exists() {
+        std::fs::remove_file(&request.output).map_err(|e| FileOperationError::archive(&request.output, e))?;
+    }
+
+    let args = sign_arguments(request);
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    run_tool(tool, &args)?;
+
+    let output_arg = request.output.to_string_lossy();
+    run_tool(tool, &["-verify", &output_arg])?;
+    log::info!("Signed {:?} into {:?}", request.source, request.output);
+    Ok(())
+}
+
+// Helper functions
+fn sign_arguments(request: &SignRequest) -> Vec<String> {
+    let mut args = vec![
+        "-sign".to_string(),
+        request.source.to_string_lossy().to_string(),
+        request.output.to_string_lossy().to_string(),
+        request.certificate.to_string_lossy().to_string(),
+        request.password.clone(),
+    ];
+    if let Some(url) = request.tsa_url.as_deref().map(str::trim).filter(|url| !url.is_empty()) {
+        args.push("-tsa".to_string());
+        args.push(url.to_string());
+    }
+    args
+}
+
+fn run_tool(tool: &Path, args: &[&str]) -> Result<(), FileOperationError> {
+    let output = Command::new(tool).args(args).output().map_err(|e| {
+        log::error!("Failed to run {:?}: {}", tool, e);
+        FileOperationError::SigningToolMissing
+    })?;
+
+    // ZXPSignCmd exits 0 on some failures, so its output is checked too
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let failed = !output.status.success() || stdout.contains("Error") || stderr.contains("Error");
+    if failed {
+        let message = last_line(&stderr).or_else(|| last_line(&stdout)).unwrap_or("exit status").to_string();
+        log::error!("{:?} {} failed: {}", tool, args.first().unwrap_or(&""), message);
+        return Err(FileOperationError::SigningFailed(message));
+    }
+    Ok(())
+}
+
+fn last_line(text: &str) -> Option<&str> {
+    text.lines().map(str::trim).rfind(|line| !line.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_arguments() {
+        let mut request = SignRequest {
+            source: PathBuf::from("/work/panel"),
+            certificate: PathBuf::from("/work/cert.p12"),
+            password: "secret".to_string(),
+            output: PathBuf::from("/work/panel.zxp"),
+            tsa_url: Some(DEFAULT_TSA_URL.to_string()),
+        };
+        assert_eq!(
+            sign_arguments(&request),
+            ["-sign", "/work/panel", "/work/panel.zxp", "/work/cert.p12", "secret", "-tsa", DEFAULT_TSA_URL]
+        );
+
+        request.tsa_url = Some("  ".to_string());
+        assert_eq!(sign_arguments(&request).len(), 5);
+        assert_eq!(last_line("Signing...\nError - invalid password\n\n"), Some("Error - invalid password"));
+    }
+}
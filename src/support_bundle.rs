@@ -0,0 +1,164 @@
+use serde_json::json;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+use crate::cep_preferences::{CepPreferences, read_all_preferences};
+use crate::data_operations::Plugin;
+use crate::file_operations::FileOperationError;
+use crate::host_detection::HostApp;
+use crate::logging::log_dir;
+use crate::plugin_list::{ListFormat, render_plugin_list};
+use crate::update_check::CURRENT_VERSION;
+
+// One ZIP to attach to a bug report, ours or an extension vendor's: the app's logs, the plugin
+// list, CSXS preference flags, detected host apps and what the machine runs. Nothing is uploaded.
+
+// Gathered by the caller, so the bundle shows what the app itself saw
+pub struct BundleContents<'a> {
+    pub plugins: &'a [Plugin],
+    pub host_apps: &'a [HostApp],
+}
+
+pub fn write_support_bundle(destination: &Path, contents: &BundleContents) -> Result<usize, FileOperationError> {
+    let mut files = vec![
+        ("system.json".to_string(), system_info().into_bytes()),
+        ("plugins.json".to_string(), render_plugin_list(contents.plugins, ListFormat::Json).into_bytes()),
+        ("host-apps.json".to_string(), host_apps_json(contents.host_apps).into_bytes()),
+        ("cep-preferences.json".to_string(), preferences_json(&read_all_preferences()).into_bytes()),
+    ];
+    files.extend(log_files());
+    write_zip(destination, &files)?;
+    log::info!("Wrote support bundle {:?} with {} files", destination, files.len());
+    Ok(files.len())
+}
+
+// Helper functions
+fn write_zip(destination: &Path, files: &[(String, Vec<u8>)]) -> Result<(), FileOperationError> {
+    let archive_error = |e: zip::result::ZipError| FileOperationError::archive(destination, io::Error::other(e));
+    let file = fs::File::create(destination).map_err(|e| FileOperationError::archive(destination, e))?;
+    let mut writer = ZipWriter::new(file);
+    for (name, content) in files {
+        writer.start_file(name.as_str(), SimpleFileOptions::default()).map_err(archive_error)?;
+        writer.write_all(content).map_err(|e| FileOperationError::archive(destination, e))?;
+    }
+    writer.finish().map_err(archive_error)?;
+    Ok(())
+}
+
+// The current log and its rotated predecessors
+fn log_files() -> Vec<(String, Vec<u8>)> {
+    let Some(dir) = log_dir() else {
+        return Vec::new();
+    };
+    let mut files: Vec<(String, Vec<u8>)> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|extension| extension == "log"))
+        .filter_map(|entry| Some((format!("logs/{}", entry.file_name().to_string_lossy()), fs::read(entry.path()).ok()?)))
+        .collect();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    files
+}
+
+fn system_info() -> String {
+    let info = json!({
+        "app_version": CURRENT_VERSION,
+        "os": std::env::consts::OS,
+        "arch": std::env::consts::ARCH,
+        "os_version": os_version(),
+    });
+    serde_json::to_string_pretty(&info).unwrap_or_default()
+}
+
+// sw_vers on macOS, ver on Windows, os-release or uname elsewhere
+fn os_version() -> String {
+    let (program, args): (&str, &[&str]) = if cfg!(target_os = "macos") {
+        ("sw_vers", &["-productVersion"])
+    } else if cfg!(target_os = "windows") {
+        ("cmd", &["/C", "ver"])
+    } else if let Some(name) = fs::read_to_string("/etc/os-release").ok().as_deref().and_then(pretty_name) {
+        return name;
+    } else {
+        ("uname", &["-sr"])
+    };
+    Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default()
+}
+
+fn pretty_name(os_release: &str) -> Option<String> {
+    let value = os_release.lines().find_map(|line| line.strip_prefix("PRETTY_NAME="))?;
+    Some(value.trim_matches('"').to_string())
+}
+
+fn host_apps_json(apps: &[HostApp]) -> String {
+    let apps: Vec<_> = apps
+        .iter()
+        .map(|app| {
+            json!({
+                "code": app.code,
+                "name": app.name,
+                "version": app.version,
+                "cep_version": app.cep_version,
+                "path": app.path.display().to_string(),
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&apps).unwrap_or_default()
+}
+
+fn preferences_json(preferences: &[CepPreferences]) -> String {
+    let preferences: Vec<_> = preferences
+        .iter()
+        .map(|preferences| {
+            json!({
+                "csxs_version": preferences.version,
+                "player_debug_mode": preferences.debug_mode,
+                "log_level": preferences.log_level.map(|level| level.code()),
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&preferences).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    #[test]
+    fn test_support_bundle_lists_every_part() {
+        let destination = std::env::temp_dir().join(format!("zxpm-support-{}.zip", std::process::id()));
+        let app = HostApp {
+            code: "PHXS",
+            name: "Adobe Photoshop 2025".to_string(),
+            version: Some("26.1.0".to_string()),
+            cep_version: Some(12),
+            path: "/Applications/Adobe Photoshop 2025".into(),
+        };
+        let written = write_support_bundle(&destination, &BundleContents { plugins: &[], host_apps: &[app] }).unwrap();
+
+        let mut archive = ZipArchive::new(fs::File::open(&destination).unwrap()).unwrap();
+        assert_eq!(archive.len(), written);
+        let names: Vec<String> = archive.file_names().map(str::to_string).collect();
+        for name in ["system.json", "plugins.json", "host-apps.json", "cep-preferences.json"] {
+            assert!(names.iter().any(|entry| entry == name), "{} missing", name);
+        }
+        let mut hosts = String::new();
+        archive.by_name("host-apps.json").unwrap().read_to_string(&mut hosts).unwrap();
+        assert!(hosts.contains("\"code\": \"PHXS\""));
+        let _ = fs::remove_file(destination);
+
+        assert_eq!(pretty_name("NAME=Fedora\nPRETTY_NAME=\"Fedora Linux 41\"\n").as_deref(), Some("Fedora Linux 41"));
+    }
+}
@@ -0,0 +1,181 @@
+use dioxus::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::settings::SETTINGS;
+use crate::update_check::CURRENT_VERSION;
+
+// Opt-in usage counts (Settings::telemetry, off by default) to see which platforms and flows
+// matter. Only counters plus app version and OS/arch are sent - no plugin names, paths, bundle
+// IDs or machine identifiers. Counts wait in telemetry.json until the next launch sends them.
+
+const TELEMETRY_ENDPOINT: &str = "https://telemetry.zxp-manager.app/v1/usage";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TelemetryEvent {
+    AppLaunched,
+    InstallSucceeded,
+    InstallFailed,
+}
+
+// Events since the last report that reached the server
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct UsageCounts {
+    pub app_launched: u32,
+    pub install_succeeded: u32,
+    pub install_failed: u32,
+}
+
+impl UsageCounts {
+    pub fn add(&mut self, event: TelemetryEvent) {
+        let counter = match event {
+            TelemetryEvent::AppLaunched => &mut self.app_launched,
+            TelemetryEvent::InstallSucceeded => &mut self.install_succeeded,
+            TelemetryEvent::InstallFailed => &mut self.install_failed,
+        };
+        *counter = counter.saturating_add(1);
+    }
+
+    // What is left after a report was sent; events recorded meanwhile stay for the next one
+    pub fn without(&self, sent: &UsageCounts) -> UsageCounts {
+        UsageCounts {
+            app_launched: self.app_launched.saturating_sub(sent.app_launched),
+            install_succeeded: self.install_succeeded.saturating_sub(sent.install_succeeded),
+            install_failed: self.install_failed.saturating_sub(sent.install_failed),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == UsageCounts::default()
+    }
+}
+
+// The complete request body - the settings preview shows exactly this
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UsageReport {
+    pub app_version: &'static str,
+    pub os: &'static str,
+    pub arch: &'static str,
+    pub events: UsageCounts,
+}
+
+pub fn usage_report(events: UsageCounts) -> UsageReport {
+    UsageReport { app_version: CURRENT_VERSION, os: std::env::consts::OS, arch: std::env::consts::ARCH, events }
+}
+
+// Nothing is counted while telemetry is off
+pub fn record(event: TelemetryEvent) {
+    if !SETTINGS.peek().telemetry {
+        return;
+    }
+    let mut counts = load_counts();
+    counts.add(event);
+    save_counts(&counts);
+}
+
+// The next report as it would be sent, with this launch counted
+pub fn preview_report() -> String {
+    let mut counts = load_counts();
+    if counts.app_launched == 0 {
+        counts.add(TelemetryEvent::AppLaunched);
+    }
+    serde_json::to_string_pretty(&usage_report(counts)).unwrap_or_default()
+}
+
+// Turning telemetry off drops what was counted and not sent yet
+pub fn discard_pending_usage() {
+    save_counts(&UsageCounts::default());
+}
+
+// Counts the launch and sends the pending report once per launch, only when the user opted in
+pub fn use_telemetry() {
+    use_future(|| async move {
+        if !SETTINGS.peek().telemetry {
+            return;
+        }
+        record(TelemetryEvent::AppLaunched);
+
+        let sent = load_counts();
+        match tokio::task::spawn_blocking(move || send_report(&usage_report(sent))).await {
+            Ok(Ok(())) => {
+                log::info!("Usage report sent: {:?}", sent);
+                save_counts(&load_counts().without(&sent));
+            }
+            Ok(Err(e)) => log::warn!("Sending the usage report failed: {}", e), // Kept for the next launch
+            Err(e) => log::warn!("Usage report task failed: {}", e),
+        }
+    });
+}
+
+// Helper functions
+fn telemetry_path() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("zxp-manager").join("telemetry.json"))
+}
+
+fn load_counts() -> UsageCounts {
+    telemetry_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_counts(counts: &UsageCounts) {
+    let Some(path) = telemetry_path() else {
+        return;
+    };
+    if counts.is_empty() {
+        if path.exists()
+            && let Err(e) = fs::remove_file(&path)
+        {
+            log::error!("Failed to delete usage counts {:?}: {}", path, e);
+        }
+        return;
+    }
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| serde_json::to_string(counts).map_err(std::io::Error::from))
+        .and_then(|content| fs::write(&path, content));
+    if let Err(e) = result {
+        log::error!("Failed to save usage counts {:?}: {}", path, e);
+    }
+}
+
+fn send_report(report: &UsageReport) -> Result<(), ureq::Error> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(REQUEST_TIMEOUT))
+        .user_agent(format!("zxp-manager/{}", CURRENT_VERSION))
+        .build()
+        .into();
+    agent.post(TELEMETRY_ENDPOINT).send_json(report)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usage_report_holds_only_aggregate_counts() {
+        let mut counts = UsageCounts::default();
+        counts.add(TelemetryEvent::AppLaunched);
+        counts.add(TelemetryEvent::InstallSucceeded);
+        counts.add(TelemetryEvent::InstallSucceeded);
+
+        let sent = counts;
+        counts.add(TelemetryEvent::InstallFailed); // Recorded while the report was on its way
+        assert_eq!(counts.without(&sent), UsageCounts { install_failed: 1, ..UsageCounts::default() });
+        assert!(sent.without(&sent).is_empty());
+
+        let json: serde_json::Value = serde_json::to_value(usage_report(sent)).unwrap();
+        let mut keys: Vec<&str> = json.as_object().unwrap().keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["app_version", "arch", "events", "os"]);
+        assert_eq!(json["events"]["install_succeeded"], 2);
+    }
+}
@@ -0,0 +1,126 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime};
+
+// Scratch files live under <temp>/zxp-manager/run-<pid>, one folder per running app, so two
+// instances never share a name and whatever a crashed run left behind is easy to find again
+
+const APP_TEMP_FOLDER: &str = "zxp-manager";
+const RUN_PREFIX: &str = "run-";
+
+// Debug launch sandboxes outlive the run that made them - the host app may still be using one
+pub const DEBUG_SANDBOX_FOLDER: &str = "debug";
+
+// Where a process can't be asked whether it is still alive, a run folder this old counts as stale
+const STALE_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+// Names older versions wrote straight into the shared temp folder
+const LEGACY_LEFTOVERS: [&str; 1] = ["temp_manifest.xml"];
+const LEGACY_PREFIXES: [&str; 3] = ["zxpm-staging-", "zxpm-release-check-", "zxpm-release-install-"];
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+pub fn app_temp_root() -> PathBuf {
+    std::env::temp_dir().join(APP_TEMP_FOLDER)
+}
+
+pub fn run_dir() -> PathBuf {
+    app_temp_root().join(format!("{}{}", RUN_PREFIX, std::process::id()))
+}
+
+// A fresh path in this run's folder, e.g. run-4242/3-release-check.zxp; the folder is created,
+// the file or folder itself is left to the caller
+pub fn temp_path(name: &str) -> PathBuf {
+    let dir = run_dir();
+    if let Err(e) = fs::create_dir_all(&dir) {
+        log::warn!("Failed to create temp folder {:?}: {}", dir, e);
+    }
+    dir.join(format!("{}-{}", NEXT_ID.fetch_add(1, Ordering::Relaxed), name))
+}
+
+// Startup sweep: run folders of processes that are gone, old debug sandboxes and what older
+// versions left in the shared temp folder. Returns how many entries were removed.
+pub fn sweep_stale_files() -> usize {
+    let root = app_temp_root();
+    let mut stale: Vec<PathBuf> = Vec::new();
+
+    for entry in fs::read_dir(&root).into_iter().flatten().flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if let Some(pid) = name.strip_prefix(RUN_PREFIX).and_then(|pid| pid.parse::<u32>().ok())
+            && pid != std::process::id()
+            && !process_alive(pid, &entry.path())
+        {
+            stale.push(entry.path());
+        }
+    }
+    for entry in fs::read_dir(root.join(DEBUG_SANDBOX_FOLDER)).into_iter().flatten().flatten() {
+        if older_than(&entry.path(), STALE_AGE) {
+            stale.push(entry.path());
+        }
+    }
+    for entry in fs::read_dir(std::env::temp_dir()).into_iter().flatten().flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if LEGACY_LEFTOVERS.contains(&name.as_str()) || LEGACY_PREFIXES.iter().any(|prefix| name.starts_with(prefix)) {
+            stale.push(entry.path());
+        }
+    }
+
+    let removed = stale.iter().filter(|path| remove_entry(path)).count();
+    if removed > 0 {
+        log::info!("Removed {} leftover temp entries", removed);
+    }
+    removed
+}
+
+// Helper functions
+#[cfg(unix)]
+fn process_alive(pid: u32, _run_dir: &Path) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // Signal 0 only checks; EPERM means the process exists but belongs to someone else
+    unsafe { libc::kill(pid, 0) == 0 || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM) }
+}
+
+#[cfg(not(unix))]
+fn process_alive(_pid: u32, run_dir: &Path) -> bool {
+    !older_than(run_dir, STALE_AGE)
+}
+
+fn older_than(path: &Path, age: Duration) -> bool {
+    fs::symlink_metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .is_ok_and(|modified| SystemTime::now().duration_since(modified).is_ok_and(|elapsed| elapsed > age))
+}
+
+fn remove_entry(path: &Path) -> bool {
+    let is_dir = fs::symlink_metadata(path).is_ok_and(|metadata| metadata.is_dir());
+    let result = if is_dir { fs::remove_dir_all(path) } else { fs::remove_file(path) };
+    result.inspect_err(|e| log::warn!("Failed to remove leftover {:?}: {}", path, e)).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_temp_paths_are_unique_and_in_run_dir() {
+        let (first, second) = (temp_path("check.zxp"), temp_path("check.zxp"));
+        assert_ne!(first, second);
+        assert!(first.starts_with(run_dir()) && first.to_string_lossy().ends_with("-check.zxp"));
+    }
+
+    #[test]
+    #[cfg(unix)] // Elsewhere run folders only go stale with age
+    fn test_sweep_removes_dead_runs_only() {
+        // Far above any real pid limit, so never a live process
+        let dead = app_temp_root().join(format!("{}{}", RUN_PREFIX, u32::MAX - 1));
+        fs::create_dir_all(&dead).unwrap();
+        fs::create_dir_all(run_dir()).unwrap();
+
+        sweep_stale_files();
+        assert!(!dead.exists());
+        assert!(run_dir().exists());
+    }
+}
@@ -0,0 +1,172 @@
+use dioxus::desktop::tao::event::{Event, WindowEvent};
+use dioxus::desktop::trayicon::menu::{IsMenuItem, Menu, MenuItem, PredefinedMenuItem};
+use dioxus::desktop::trayicon::{Icon, TrayIcon, TrayIconBuilder};
+use dioxus::desktop::{use_tray_menu_event_handler, use_wry_event_handler, window};
+use dioxus::prelude::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::actions::install_from_picker;
+use crate::i18n::{t, t_args};
+use crate::message::{HISTORY, trigger_refresh};
+use crate::plugin_store::PLUGINS;
+use crate::settings::SETTINGS;
+
+// Optional companion mode: a tray (menu bar) icon whose menu installs and rescans without the
+// full window. With it on at launch, closing the window only hides it.
+
+const MENU_INSTALL: &str = "tray-install";
+const MENU_RESCAN: &str = "tray-rescan";
+const MENU_SHOW: &str = "tray-show";
+const MENU_QUIT: &str = "tray-quit";
+
+// Newest history entries listed in the menu, and how much of each fits
+const SHOWN_ACTIVITY: usize = 3;
+const ACTIVITY_LABEL_CHARS: usize = 60;
+
+const ICON_SIZE: u32 = 32;
+
+// Whether closing the window should hide it - decided once, before the window opens
+pub fn hides_on_close() -> bool {
+    crate::settings::load_settings().tray_icon
+}
+
+// Creates, refreshes and removes the tray icon as the setting, plugin list and history change
+pub fn use_tray_companion() {
+    let tray = use_hook(|| Rc::new(RefCell::new(None::<TrayIcon>)));
+
+    use_effect({
+        let tray = tray.clone();
+        move || {
+            let enabled = SETTINGS.read().tray_icon;
+            let _ = SETTINGS.read().language; // Relabel when the UI language changes
+            let count = PLUGINS.read().as_ref().map(Vec::len);
+            let activity: Vec<String> =
+                HISTORY.read().iter().rev().take(SHOWN_ACTIVITY).map(|entry| short_label(&entry.content)).collect();
+
+            let mut tray = tray.borrow_mut();
+            if !enabled {
+                *tray = None; // Dropping the icon removes it
+                return;
+            }
+            let menu = build_menu(count, &activity);
+            match tray.as_ref() {
+                Some(icon) => icon.set_menu(Some(Box::new(menu))),
+                None => {
+                    *tray = TrayIconBuilder::new()
+                        .with_menu(Box::new(menu))
+                        .with_icon(tray_icon_image())
+                        .with_tooltip("ZXP Manager")
+                        .build()
+                        .inspect_err(|e| log::error!("Failed to create the tray icon: {}", e))
+                        .ok();
+                }
+            }
+        }
+    });
+
+    use_tray_menu_event_handler(|event| match event.id.0.as_str() {
+        MENU_INSTALL => {
+            if SETTINGS.peek().inspect_before_install {
+                show_window(); // The preview waits for approval in the window
+            }
+            install_from_picker();
+        }
+        MENU_RESCAN => trigger_refresh(),
+        MENU_SHOW => show_window(),
+        MENU_QUIT => quit(),
+        _ => {}
+    });
+
+    // Launched with the tray but turned off since: closing has nowhere to hide to
+    use_wry_event_handler(|event, _| {
+        if let Event::WindowEvent { event: WindowEvent::CloseRequested, .. } = event
+            && !SETTINGS.peek().tray_icon
+        {
+            quit();
+        }
+    });
+}
+
+// Helper functions
+fn build_menu(count: Option<usize>, activity: &[String]) -> Menu {
+    let count_label = match count {
+        Some(count) => t_args("tray-plugin-count", &[("count", &count.to_string())]),
+        None => t("tray-scanning"),
+    };
+    let menu = Menu::new();
+    let mut items: Vec<Box<dyn IsMenuItem>> = vec![
+        Box::new(MenuItem::new(count_label, false, None)),
+        Box::new(PredefinedMenuItem::separator()),
+        Box::new(MenuItem::with_id(MENU_INSTALL, t("tray-install"), true, None)),
+        Box::new(MenuItem::with_id(MENU_RESCAN, t("tray-rescan"), true, None)),
+    ];
+    if !activity.is_empty() {
+        items.push(Box::new(PredefinedMenuItem::separator()));
+        items.extend(activity.iter().map(|label| Box::new(MenuItem::new(label, false, None)) as Box<dyn IsMenuItem>));
+    }
+    items.push(Box::new(PredefinedMenuItem::separator()));
+    items.push(Box::new(MenuItem::with_id(MENU_SHOW, t("tray-show"), true, None)));
+    items.push(Box::new(MenuItem::with_id(MENU_QUIT, t("tray-quit"), true, None)));
+
+    let refs: Vec<&dyn IsMenuItem> = items.iter().map(|item| item.as_ref()).collect();
+    if let Err(e) = menu.append_items(&refs) {
+        log::error!("Failed to build the tray menu: {}", e);
+    }
+    menu
+}
+
+fn short_label(text: &str) -> String {
+    let first_line = text.lines().next().unwrap_or_default();
+    if first_line.chars().count() <= ACTIVITY_LABEL_CHARS {
+        return first_line.to_string();
+    }
+    let cut: String = first_line.chars().take(ACTIVITY_LABEL_CHARS - 3).collect();
+    format!("{}...", cut.trim_end())
+}
+
+fn show_window() {
+    let desktop = window();
+    desktop.window.set_visible(true);
+    desktop.window.set_focus();
+}
+
+// The window may only be hidden, so closing it wouldn't end the app
+fn quit() {
+    log::info!("Quitting from the tray");
+    std::process::exit(0);
+}
+
+// A rounded square with a "Z", drawn here so no image decoder is needed
+fn tray_icon_image() -> Icon {
+    let size = ICON_SIZE as i32;
+    let mut rgba = Vec::with_capacity((ICON_SIZE * ICON_SIZE * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let (dx, dy) = ((x - size / 2).abs() - 10, (y - size / 2).abs() - 10);
+            let inside = dx <= 0 || dy <= 0 || dx * dx + dy * dy <= 36;
+            let bar = (9..23).contains(&x) && ((8..11).contains(&y) || (21..24).contains(&y));
+            let diagonal = (11..21).contains(&y) && (x + y - 31).abs() <= 2;
+            let pixel: [u8; 4] = match (inside, bar || diagonal) {
+                (false, _) => [0, 0, 0, 0],
+                (true, true) => [255, 255, 255, 255],
+                (true, false) => [59, 130, 246, 255],
+            };
+            rgba.extend_from_slice(&pixel);
+        }
+    }
+    Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE).expect("tray icon size matches its pixels")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_activity_labels_are_shortened() {
+        assert_eq!(short_label("Installed Panel 1.2"), "Installed Panel 1.2");
+        assert_eq!(short_label("first line\nsecond"), "first line");
+        let long = short_label(&"x".repeat(100));
+        assert!(long.ends_with("...") && long.chars().count() == ACTIVITY_LABEL_CHARS);
+    }
+}
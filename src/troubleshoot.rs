@@ -0,0 +1,312 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::data_operations::{
+    Plugin, PluginType, SYSTEM_EXTENSIONS_PATH, can_remove_plugin, format_size, parse_host_list, parse_manifest_xml,
+    parse_required_runtime,
+};
+use crate::cep_preferences::{CepFlag, read_flag, write_flag};
+use crate::diagnostics::DiagnosticsReport;
+use crate::elevation::{run_elevated, shell_quote};
+use crate::file_operations::{FileOperationError, available_space, can_write_in, cep_cache_path, clear_cep_cache, remove_plugin};
+use crate::host_detection::{
+    HostApp, PluginTargets, host_display_name, is_targeted, runtime_shortfall, targets_only_missing_hosts,
+};
+use crate::policy::{PolicyStatus, SIGNATURES_FILE, load_policy};
+
+// CEP runtimes whose PlayerDebugMode flag we check when no host tells us which one it uses
+const DEBUG_MODE_VERSIONS: [u32; 4] = [9, 10, 11, 12];
+
+// Below this the install check warns - a typical panel is a few MB, bundles with Node modules far more
+const LOW_SPACE_WARNING: u64 = 200 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Troubleshooter {
+    PanelNotAppearing,
+    InstallPermission,
+}
+
+impl Troubleshooter {
+    pub const ALL: [Troubleshooter; 2] = [Troubleshooter::PanelNotAppearing, Troubleshooter::InstallPermission];
+
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Troubleshooter::PanelNotAppearing => "troubleshoot-panel-title",
+            Troubleshooter::InstallPermission => "troubleshoot-install-title",
+        }
+    }
+
+    pub fn intro_key(&self) -> &'static str {
+        match self {
+            Troubleshooter::PanelNotAppearing => "troubleshoot-panel-intro",
+            Troubleshooter::InstallPermission => "troubleshoot-install-intro",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warning, // Might be the cause, nothing is definitely broken
+    Fail,
+}
+
+// One-click remedies a failed step can offer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Fix {
+    EnableDebugMode(Vec<u32>), // CSXS versions to set PlayerDebugMode=1 for
+    ClearCepCache,
+    RemoveCopy(PathBuf), // Duplicate that loads instead of the plugin being diagnosed
+    TakeOwnership(PathBuf),
+}
+
+impl Fix {
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Fix::EnableDebugMode(_) => "fix-enable-debug-mode",
+            Fix::ClearCepCache => "fix-clear-cache",
+            Fix::RemoveCopy(_) => "fix-remove-copy",
+            Fix::TakeOwnership(_) => "fix-take-ownership",
+        }
+    }
+}
+
+// Text is kept as keys and arguments so results follow a language switch without re-running
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheckStep {
+    pub title_key: &'static str,
+    pub status: CheckStatus,
+    pub detail_key: &'static str,
+    pub args: Vec<(&'static str, String)>,
+    pub fix: Option<Fix>,
+}
+
+impl CheckStep {
+    fn new(title_key: &'static str, status: CheckStatus, detail_key: &'static str) -> CheckStep {
+        CheckStep { title_key, status, detail_key, args: Vec::new(), fix: None }
+    }
+
+    fn arg(mut self, name: &'static str, value: impl Into<String>) -> CheckStep {
+        self.args.push((name, value.into()));
+        self
+    }
+
+    fn with_fix(mut self, fix: Fix) -> CheckStep {
+        self.fix = Some(fix);
+        self
+    }
+}
+
+// "Panel not appearing in host app" - everything between a correct install and a menu entry that opens
+pub fn run_panel_checks(plugin: &Plugin, report: &DiagnosticsReport, apps: &[HostApp]) -> Vec<CheckStep> {
+    let manifest_path = plugin.path.join("CSXS").join("manifest.xml");
+    if let Err(e) = parse_manifest_xml(&manifest_path) {
+        return vec![
+            CheckStep::new("check-manifest", CheckStatus::Fail, "check-manifest-fail").arg("error", e.to_string()),
+        ];
+    }
+
+    let targets = PluginTargets {
+        hosts: parse_host_list(&manifest_path).unwrap_or_default(),
+        required_runtime: parse_required_runtime(&manifest_path).ok().flatten(),
+        ui_kinds: Vec::new(), // Not checked here
+    };
+    let mut steps = vec![CheckStep::new("check-manifest", CheckStatus::Pass, "check-manifest-pass")];
+    steps.push(host_step(&targets, apps));
+    steps.push(runtime_step(&targets, apps));
+    steps.push(signature_step(plugin, &targets, apps));
+    steps.push(duplicate_step(plugin, report));
+    steps.push(menu_step(plugin, report));
+    steps.push(cache_step());
+    steps
+}
+
+// "Install fails with permission error" - installs extract into the all-users extensions folder
+pub fn run_install_checks(plugins: &[Plugin]) -> Vec<CheckStep> {
+    let root = Path::new(SYSTEM_EXTENSIONS_PATH);
+    let mut steps = vec![writable_step(root)];
+
+    let foreign: Vec<&Plugin> = plugins
+        .iter()
+        .filter(|plugin| plugin.plugin_type == PluginType::Installed && !plugin.can_remove)
+        .collect();
+    steps.push(match foreign.first() {
+        None => CheckStep::new("check-plugin-owners", CheckStatus::Pass, "check-plugin-owners-pass"),
+        Some(first) => CheckStep::new("check-plugin-owners", CheckStatus::Warning, "check-plugin-owners-warn")
+            .arg("count", foreign.len().to_string())
+            .arg("path", first.path.display().to_string())
+            .with_fix(Fix::TakeOwnership(root.to_path_buf())),
+    });
+
+    steps.push(match available_space(root) {
+        Some(available) if available < LOW_SPACE_WARNING => {
+            CheckStep::new("check-free-space", CheckStatus::Warning, "check-free-space-warn").arg("available", format_size(available))
+        }
+        Some(available) => {
+            CheckStep::new("check-free-space", CheckStatus::Pass, "check-free-space-pass").arg("available", format_size(available))
+        }
+        None => CheckStep::new("check-free-space", CheckStatus::Warning, "check-free-space-unknown"),
+    });
+
+    steps.push(match load_policy() {
+        PolicyStatus::Missing => CheckStep::new("check-policy", CheckStatus::Pass, "check-policy-missing"),
+        PolicyStatus::Loaded(_) => CheckStep::new("check-policy", CheckStatus::Warning, "check-policy-loaded"),
+        PolicyStatus::Invalid => CheckStep::new("check-policy", CheckStatus::Warning, "check-policy-invalid"),
+    });
+    steps
+}
+
+// Returns the translation key of the success message
+pub fn apply_fix(fix: &Fix) -> Result<&'static str, FileOperationError> {
+    match fix {
+        Fix::EnableDebugMode(versions) => {
+            for version in versions {
+                write_flag(*version, CepFlag::PlayerDebugMode, "1")?;
+            }
+            log::info!("Enabled PlayerDebugMode for CSXS {:?}", versions);
+            Ok("fix-debug-mode-done")
+        }
+        Fix::ClearCepCache => clear_cep_cache().map(|_| "fix-cache-done"),
+        Fix::RemoveCopy(path) => remove_plugin(path).map(|_| "fix-copy-removed"),
+        Fix::TakeOwnership(path) => take_ownership(path).map(|_| "fix-ownership-done"),
+    }
+}
+
+// Helper functions
+fn host_step(targets: &PluginTargets, apps: &[HostApp]) -> CheckStep {
+    if targets.hosts.is_empty() {
+        return CheckStep::new("check-hosts", CheckStatus::Warning, "check-hosts-none-listed");
+    }
+    if targets_only_missing_hosts(&targets.hosts, apps) {
+        let names: Vec<&str> = targets.hosts.iter().map(|host| host_display_name(&host.name)).collect();
+        return CheckStep::new("check-hosts", CheckStatus::Fail, "check-hosts-missing").arg("hosts", names.join(", "));
+    }
+    CheckStep::new("check-hosts", CheckStatus::Pass, "check-hosts-pass")
+}
+
+fn runtime_step(targets: &PluginTargets, apps: &[HostApp]) -> CheckStep {
+    match runtime_shortfall(targets, apps) {
+        Some(shortfall) => CheckStep::new("check-runtime", CheckStatus::Fail, "badge-runtime-hint")
+            .arg("required", shortfall.required.to_string())
+            .arg("host", host_display_name(shortfall.host))
+            .arg("available", shortfall.available.to_string()),
+        None => CheckStep::new("check-runtime", CheckStatus::Pass, "check-runtime-pass"),
+    }
+}
+
+// Hosts refuse unsigned extensions unless PlayerDebugMode is on for their CEP version
+fn signature_step(plugin: &Plugin, targets: &PluginTargets, apps: &[HostApp]) -> CheckStep {
+    if plugin.path.join(SIGNATURES_FILE).exists() {
+        return CheckStep::new("check-signature", CheckStatus::Pass, "check-signature-signed");
+    }
+
+    let mut versions: Vec<u32> = apps
+        .iter()
+        .filter(|app| targets.hosts.is_empty() || is_targeted(targets, app))
+        .filter_map(|app| app.cep_version)
+        .collect();
+    if versions.is_empty() {
+        versions = DEBUG_MODE_VERSIONS.to_vec();
+    }
+    versions.sort();
+    versions.dedup();
+
+    let disabled: Vec<u32> = versions.into_iter().filter(|version| !debug_mode_enabled(*version)).collect();
+    if disabled.is_empty() {
+        return CheckStep::new("check-signature", CheckStatus::Pass, "check-signature-debug-on");
+    }
+    CheckStep::new("check-signature", CheckStatus::Fail, "check-signature-debug-off").with_fix(Fix::EnableDebugMode(disabled))
+}
+
+fn duplicate_step(plugin: &Plugin, report: &DiagnosticsReport) -> CheckStep {
+    let shadowing = report
+        .collisions()
+        .find(|resolution| resolution.shadowed.iter().any(|copy| copy.folder == plugin.path) && resolution.winner.folder != plugin.path);
+
+    match shadowing {
+        Some(resolution) => {
+            let winner = &resolution.winner.folder;
+            let step = CheckStep::new("check-duplicates", CheckStatus::Fail, "check-duplicates-shadowed")
+                .arg("path", winner.display().to_string());
+            if can_remove_plugin(winner) { step.with_fix(Fix::RemoveCopy(winner.clone())) } else { step }
+        }
+        None => CheckStep::new("check-duplicates", CheckStatus::Pass, "check-duplicates-pass"),
+    }
+}
+
+fn menu_step(plugin: &Plugin, report: &DiagnosticsReport) -> CheckStep {
+    let hidden = report.hidden.iter().filter(|copy| copy.folder == plugin.path).count();
+    let loaded = report
+        .resolutions
+        .iter()
+        .filter(|resolution| resolution.winner.folder == plugin.path)
+        .count();
+
+    if loaded > 0 && hidden == loaded {
+        CheckStep::new("check-menu", CheckStatus::Warning, "check-menu-hidden")
+    } else {
+        CheckStep::new("check-menu", CheckStatus::Pass, "check-menu-pass")
+    }
+}
+
+// A stale cache can keep serving an old build of a panel after an update
+fn cache_step() -> CheckStep {
+    let entries = cep_cache_path()
+        .and_then(|path| fs::read_dir(path).ok())
+        .map(|entries| entries.count())
+        .unwrap_or(0);
+
+    if entries == 0 {
+        CheckStep::new("check-cache", CheckStatus::Pass, "check-cache-empty")
+    } else {
+        CheckStep::new("check-cache", CheckStatus::Warning, "check-cache-present")
+            .arg("count", entries.to_string())
+            .with_fix(Fix::ClearCepCache)
+    }
+}
+
+fn writable_step(root: &Path) -> CheckStep {
+    if !root.is_dir() {
+        return CheckStep::new("check-install-folder", CheckStatus::Fail, "check-install-folder-missing")
+            .arg("path", root.display().to_string())
+            .with_fix(Fix::TakeOwnership(root.to_path_buf()));
+    }
+    if can_write_in(root) {
+        CheckStep::new("check-install-folder", CheckStatus::Pass, "check-install-folder-pass")
+    } else {
+        CheckStep::new("check-install-folder", CheckStatus::Fail, "check-install-folder-readonly")
+            .arg("path", root.display().to_string())
+            .with_fix(Fix::TakeOwnership(root.to_path_buf()))
+    }
+}
+
+fn debug_mode_enabled(version: u32) -> bool {
+    read_flag(version, CepFlag::PlayerDebugMode).as_deref() == Some("1")
+}
+
+// The all-users folder belongs to root, so this goes through the system password prompt
+fn take_ownership(path: &Path) -> Result<(), FileOperationError> {
+    if !cfg!(target_os = "macos") {
+        return Err(FileOperationError::PermissionDenied(path.to_path_buf()));
+    }
+
+    let uid = unsafe { libc::getuid() };
+    let quoted = shell_quote(path);
+    run_elevated(&format!("mkdir -p {quoted} && chown -R {uid} {quoted}"), || Ok(String::new()), path)?;
+    log::info!("Took ownership of {:?}", path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broken_manifest_stops_panel_checks() {
+        let plugin = Plugin::for_test("com.example.broken", "1.0");
+
+        let steps = run_panel_checks(&plugin, &DiagnosticsReport::default(), &[]);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].status, CheckStatus::Fail);
+    }
+}
@@ -0,0 +1,65 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+use std::time::Duration;
+
+use crate::i18n::t_args;
+use crate::notifications::notify_when_away;
+use crate::settings::SETTINGS;
+use crate::version::is_newer;
+
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const LATEST_RELEASE_API: &str = "https://api.github.com/repos/andriimahda/zxp-manager/releases/latest";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvailableUpdate {
+    pub version: String,
+    pub url: String, // Release page with the downloads
+}
+
+// Set once the startup check finds a newer release
+pub static AVAILABLE_UPDATE: GlobalSignal<Option<AvailableUpdate>> = Signal::global(|| None);
+
+// Fields used from GitHub's release JSON
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    html_url: String,
+}
+
+// Checks GitHub releases once per launch, only when the user opted in
+pub fn use_update_check() {
+    use_future(|| async move {
+        if !SETTINGS.peek().check_for_updates {
+            return;
+        }
+
+        match tokio::task::spawn_blocking(fetch_latest_release).await {
+            Ok(Ok(release)) if is_newer(&release.tag_name, CURRENT_VERSION) => {
+                log::info!("Update available: {} (running {})", release.tag_name, CURRENT_VERSION);
+                let version = release.tag_name.trim_start_matches('v').to_string();
+                notify_when_away(&t_args("notification-update-available", &[("version", &version)]));
+                *AVAILABLE_UPDATE.write() = Some(AvailableUpdate { version, url: release.html_url });
+            }
+            Ok(Ok(_)) => log::info!("ZXP Manager {} is up to date", CURRENT_VERSION),
+            Ok(Err(e)) => log::warn!("Update check failed: {}", e), // Offline is normal - stay quiet in the UI
+            Err(e) => log::warn!("Update check task failed: {}", e),
+        }
+    });
+}
+
+fn fetch_latest_release() -> Result<Release, ureq::Error> {
+    let agent: ureq::Agent = ureq::Agent::config_builder()
+        .timeout_global(Some(REQUEST_TIMEOUT))
+        .user_agent(format!("zxp-manager/{}", CURRENT_VERSION)) // GitHub rejects requests without one
+        .build()
+        .into();
+
+    agent
+        .get(LATEST_RELEASE_API)
+        .header("Accept", "application/vnd.github+json")
+        .call()?
+        .body_mut()
+        .read_json()
+}
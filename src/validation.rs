@@ -0,0 +1,295 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{Cursor, Read};
+use std::path::Path;
+use zip::ZipArchive;
+
+use crate::data_operations::{decode_manifest, parse_extension_entries_content, parse_manifest_str};
+use crate::file_operations::FileOperationError;
+
+const MANIFEST_ENTRY: &str = "CSXS/manifest.xml";
+const SIGNATURES_ENTRY: &str = "META-INF/signatures.xml";
+const MAX_MANIFEST_SIZE: u64 = 1024 * 1024;
+
+// Entries expanding more than this ratio, and past this size, look like a zip bomb
+const SUSPICIOUS_RATIO: u64 = 100;
+const SUSPICIOUS_SIZE: u64 = 50 * 1024 * 1024;
+
+// Files a panel has no reason to ship - CEP runs HTML and JavaScript
+const EXECUTABLE_EXTENSIONS: [&str; 12] = ["exe", "dll", "msi", "bat", "cmd", "vbs", "ps1", "sh", "command", "pkg", "dmg", "scpt"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,   // The extension will not load or the archive is unsafe
+    Warning, // Worth a look, but installs and usually works
+}
+
+// One lint result; the message is a locale key plus its arguments, so it renders in the UI language
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message_key: &'static str,
+    pub args: Vec<(&'static str, String)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ValidationReport {
+    pub bundle_id: Option<String>, // None when the manifest is missing or broken
+    pub findings: Vec<Finding>,
+}
+
+impl ValidationReport {
+    pub fn is_clean(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    pub fn count(&self, severity: Severity) -> usize {
+        self.findings.iter().filter(|finding| finding.severity == severity).count()
+    }
+
+    fn add(&mut self, severity: Severity, message_key: &'static str, args: Vec<(&'static str, String)>) {
+        self.findings.push(Finding { severity, message_key, args });
+    }
+}
+
+// Reads the whole ZXP into memory and lints it - nothing is extracted
+pub fn validate_zxp(zxp_path: &Path) -> Result<ValidationReport, FileOperationError> {
+    let bytes = fs::read(zxp_path).map_err(|_| FileOperationError::FileNotFound(zxp_path.to_path_buf()))?;
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).map_err(|_| FileOperationError::InvalidZip(zxp_path.to_path_buf()))?;
+    Ok(validate_archive(&mut archive))
+}
+
+pub fn validate_archive(archive: &mut ZipArchive<Cursor<Vec<u8>>>) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let mut names = HashSet::new();
+
+    for index in 0..archive.len() {
+        let Ok(entry) = archive.by_index_raw(index) else {
+            report.add(Severity::Error, "validation-unreadable-entry", vec![("index", index.to_string())]);
+            continue;
+        };
+        let name = entry.name().to_string();
+        check_entry(&mut report, &name, entry.unix_mode(), entry.size(), entry.compressed_size());
+        names.insert(name.trim_end_matches('/').to_string());
+    }
+
+    let manifest = read_entry(archive, MANIFEST_ENTRY);
+    check_contents(&mut report, &names, manifest);
+    report
+}
+
+// The same lint for an unpacked extension folder; names are paths relative to the folder
+pub fn validate_folder(folder: &Path) -> Result<ValidationReport, FileOperationError> {
+    if !folder.is_dir() {
+        return Err(FileOperationError::FileNotFound(folder.to_path_buf()));
+    }
+    let mut report = ValidationReport::default();
+    let mut names = HashSet::new();
+    collect_folder_entries(&mut report, &mut names, folder, "");
+
+    let manifest = fs::read(folder.join(MANIFEST_ENTRY)).ok().filter(|bytes| bytes.len() as u64 <= MAX_MANIFEST_SIZE);
+    check_contents(&mut report, &names, manifest);
+    Ok(report)
+}
+
+// Helper functions
+// Signature and manifest checks shared by archives and folders
+fn check_contents(report: &mut ValidationReport, names: &HashSet<String>, manifest: Option<Vec<u8>>) {
+    if !names.contains(SIGNATURES_ENTRY) {
+        report.add(Severity::Warning, "validation-unsigned", Vec::new());
+    }
+
+    let Some(bytes) = manifest else {
+        report.add(Severity::Error, "validation-manifest-missing", Vec::new());
+        return;
+    };
+    let Some(manifest) = decode_manifest(&bytes) else {
+        report.add(Severity::Error, "validation-manifest-invalid", Vec::new());
+        return;
+    };
+    let (Ok(info), Ok(entries)) = (parse_manifest_str(&manifest), parse_extension_entries_content(&manifest)) else {
+        report.add(Severity::Error, "validation-manifest-invalid", Vec::new());
+        return;
+    };
+    report.bundle_id = Some(info.bundle_id);
+
+    if entries.is_empty() {
+        report.add(Severity::Error, "validation-no-extensions", Vec::new());
+    }
+    for entry in &entries {
+        let extension = || ("extension", entry.id.clone());
+        match &entry.main_path {
+            Some(path) => check_manifest_path(report, names, path, Severity::Error, entry.id.clone()),
+            None => report.add(Severity::Error, "validation-main-undeclared", vec![extension()]),
+        }
+        if let Some(path) = &entry.script_path {
+            check_manifest_path(report, names, path, Severity::Warning, entry.id.clone());
+        }
+        for (_, path) in &entry.icons {
+            check_manifest_path(report, names, path, Severity::Warning, entry.id.clone());
+        }
+    }
+}
+
+// Symlinks are reported, not followed - a link can point anywhere on disk
+fn collect_folder_entries(report: &mut ValidationReport, names: &mut HashSet<String>, dir: &Path, prefix: &str) {
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let Ok(metadata) = fs::symlink_metadata(entry.path()) else {
+            continue;
+        };
+        let name = format!("{}{}", prefix, entry.file_name().to_string_lossy());
+        let unix_mode = metadata.file_type().is_symlink().then_some(0o120000);
+        check_entry(report, &name, unix_mode, metadata.len(), metadata.len());
+        if metadata.is_dir() {
+            collect_folder_entries(report, names, &entry.path(), &format!("{}/", name));
+        }
+        names.insert(name);
+    }
+}
+
+fn check_entry(report: &mut ValidationReport, name: &str, unix_mode: Option<u32>, size: u64, compressed_size: u64) {
+    let path = || vec![("path", name.to_string())];
+
+    if is_absolute(name) {
+        report.add(Severity::Error, "validation-absolute-entry", path());
+    } else if name.split(['/', '\\']).any(|part| part == "..") {
+        report.add(Severity::Error, "validation-traversal-entry", path());
+    }
+    if unix_mode.is_some_and(|mode| mode & 0o170000 == 0o120000) {
+        report.add(Severity::Warning, "validation-symlink", path());
+    }
+    if is_executable(name) {
+        report.add(Severity::Warning, "validation-executable", path());
+    }
+    if size > SUSPICIOUS_SIZE && size > compressed_size.saturating_mul(SUSPICIOUS_RATIO) {
+        report.add(Severity::Warning, "validation-compression", path());
+    }
+}
+
+// MainPath, ScriptPath and icons are relative to the extension root and must be inside the archive
+fn check_manifest_path(report: &mut ValidationReport, names: &HashSet<String>, path: &str, missing: Severity, extension: String) {
+    if path.contains("://") {
+        return; // Remote content isn't in the archive to check
+    }
+    let args = vec![("extension", extension), ("path", path.to_string())];
+    if is_absolute(path.trim()) {
+        report.add(Severity::Error, "validation-absolute-path", args);
+        return;
+    }
+    match archive_path(path) {
+        Some(resolved) if names.contains(&resolved) => {}
+        Some(_) => report.add(missing, "validation-path-missing", args),
+        None => report.add(Severity::Error, "validation-path-outside", args),
+    }
+}
+
+// "./client/index.html?debug" -> "client/index.html"; None when the path climbs out of the extension
+fn archive_path(manifest_path: &str) -> Option<String> {
+    let path = manifest_path.trim().split(['?', '#']).next().unwrap_or_default().replace('\\', "/");
+    let mut parts: Vec<&str> = Vec::new();
+    for part in path.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            part => parts.push(part),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+fn is_absolute(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    path.starts_with(['/', '\\']) || (bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':')
+}
+
+fn is_executable(name: &str) -> bool {
+    let name = name.trim_end_matches('/');
+    let in_app_bundle = name.split('/').any(|part| part.ends_with(".app"));
+    let extension = name.rsplit_once('.').map(|(_, extension)| extension.to_ascii_lowercase());
+    in_app_bundle || extension.is_some_and(|extension| EXECUTABLE_EXTENSIONS.contains(&extension.as_str()))
+}
+
+fn read_entry(archive: &mut ZipArchive<Cursor<Vec<u8>>>, name: &str) -> Option<Vec<u8>> {
+    let entry = archive.by_name(name).ok()?;
+    if entry.size() > MAX_MANIFEST_SIZE {
+        return None;
+    }
+    let mut content = Vec::new();
+    entry.take(MAX_MANIFEST_SIZE).read_to_end(&mut content).ok()?;
+    Some(content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::ZipWriter;
+    use zip::write::SimpleFileOptions;
+
+    const MANIFEST: &str = r#"<ExtensionManifest ExtensionBundleId="com.example.lint" ExtensionBundleVersion="1.0.0">
+  <ExtensionList><Extension Id="com.example.lint.panel" Version="1.0.0"/></ExtensionList>
+  <DispatchInfoList>
+    <Extension Id="com.example.lint.panel">
+      <DispatchInfo>
+        <Resources><MainPath>./client/index.html?debug=1</MainPath><ScriptPath>../host.jsx</ScriptPath></Resources>
+        <UI><Icons><Icon Type="Normal">./icons/panel.png</Icon></Icons></UI>
+      </DispatchInfo>
+    </Extension>
+  </DispatchInfoList>
+</ExtensionManifest>"#;
+
+    fn archive(entries: &[(&str, &str)]) -> ZipArchive<Cursor<Vec<u8>>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in entries {
+            writer.start_file(*name, SimpleFileOptions::default()).unwrap();
+            writer.write_all(content.as_bytes()).unwrap();
+        }
+        ZipArchive::new(writer.finish().unwrap()).unwrap()
+    }
+
+    fn keys(report: &ValidationReport) -> Vec<&'static str> {
+        report.findings.iter().map(|finding| finding.message_key).collect()
+    }
+
+    #[test]
+    fn test_validate_archive_findings() {
+        let report = validate_archive(&mut archive(&[
+            (MANIFEST_ENTRY, MANIFEST),
+            (SIGNATURES_ENTRY, "<signatures/>"),
+            ("client/index.html", "<html></html>"),
+            ("tools/install.sh", "#!/bin/sh"),
+        ]));
+        assert_eq!(report.bundle_id.as_deref(), Some("com.example.lint"));
+        assert_eq!(keys(&report), ["validation-executable", "validation-path-outside", "validation-path-missing"]);
+        assert_eq!(report.count(Severity::Error), 1); // The script escapes the extension folder
+        assert_eq!(report.count(Severity::Warning), 2);
+
+        let report = validate_archive(&mut archive(&[("client/index.html", "")]));
+        assert_eq!(keys(&report), ["validation-unsigned", "validation-manifest-missing"]);
+    }
+
+    #[test]
+    fn test_validate_folder_matches_archive_checks() {
+        let folder = crate::temp_files::temp_path("lint");
+        fs::create_dir_all(folder.join("CSXS")).unwrap();
+        fs::create_dir_all(folder.join("client")).unwrap();
+        fs::write(folder.join(MANIFEST_ENTRY), MANIFEST).unwrap();
+        fs::write(folder.join("client/index.html"), "<html></html>").unwrap();
+
+        let report = validate_folder(&folder).unwrap();
+        let _ = fs::remove_dir_all(&folder);
+        assert_eq!(report.bundle_id.as_deref(), Some("com.example.lint"));
+        assert_eq!(keys(&report), ["validation-unsigned", "validation-path-outside", "validation-path-missing"]);
+        assert!(validate_folder(&folder).is_err());
+    }
+
+    #[test]
+    fn test_archive_path() {
+        assert_eq!(archive_path("./client/index.html#top").as_deref(), Some("client/index.html"));
+        assert_eq!(archive_path("client\\..\\index.html").as_deref(), Some("index.html"));
+        assert_eq!(archive_path("../host.jsx"), None);
+        assert!(is_absolute("/etc/passwd") && is_absolute("C:\\panel") && !is_absolute("client/c:d"));
+    }
+}
@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+
+// Version strings from manifests and release tags are loosely formatted - compare them leniently
+
+// Dotted numeric release parts, then an optional pre-release tag that sorts below the release
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    parts: Vec<u64>,
+    pre_release: Option<Vec<Identifier>>,
+}
+
+// One dot-separated piece of a pre-release tag; numbers sort below words, as in SemVer
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Identifier {
+    Number(u64),
+    Word(String),
+}
+
+impl Version {
+    // Accepts 1.2.3, 1.2.3.4, v1.2, 1.2.0-beta.1, 2.0rc1 and 1.2.3 build 45. A purely numeric dash
+    // suffix (1.2.3-456) is a vendor build number and counts as one more release part. Build
+    // metadata after "+" or a space is ignored.
+    pub fn parse(version: &str) -> Option<Version> {
+        let version = version.trim().trim_start_matches(['v', 'V']);
+        let version = version.split([' ', '+']).next()?;
+        let (core, tag) = match version.split_once('-') {
+            Some((core, tag)) => (core, Some(tag)),
+            None => {
+                // Letters glued to the number, e.g. 2.0rc1 or 1.2.3b
+                let split = version.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(version.len());
+                let (core, tag) = version.split_at(split);
+                (core, Some(tag).filter(|tag| !tag.is_empty()))
+            }
+        };
+
+        let mut parts: Vec<u64> = core.split('.').map(|part| part.parse().ok()).collect::<Option<_>>()?;
+        let pre_release = match tag {
+            Some(build) if build.chars().all(|c| c.is_ascii_digit()) && !build.is_empty() => {
+                parts.push(build.parse().ok()?);
+                None
+            }
+            Some(tag) => Some(parse_tag(tag)?),
+            None => None,
+        };
+        // 1.2 and 1.2.0 are the same release
+        while parts.len() > 1 && parts.last() == Some(&0) {
+            parts.pop();
+        }
+        Some(Version { parts, pre_release })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Version) -> Ordering {
+        self.parts.cmp(&other.parts).then_with(|| match (&self.pre_release, &other.pre_release) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Greater, // 1.2.0 is newer than 1.2.0-beta
+            (Some(_), None) => Ordering::Less,
+            (Some(own), Some(theirs)) => own.cmp(theirs),
+        })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Version) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Total order for sorting: unreadable versions ("nightly", "") go below every readable one and
+// compare as text among themselves
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    match (Version::parse(a), Version::parse(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Greater,
+        (None, Some(_)) => Ordering::Less,
+        (None, None) => a.trim().to_lowercase().cmp(&b.trim().to_lowercase()),
+    }
+}
+
+// False when either version can't be read - an unknown version is never an upgrade
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    match (Version::parse(candidate), Version::parse(current)) {
+        (Some(candidate), Some(current)) => candidate > current,
+        _ => false,
+    }
+}
+
+// Helper functions
+// "beta.2" -> [beta, 2]; "rc1" -> [rc, 1], so rc10 sorts above rc9
+fn parse_tag(tag: &str) -> Option<Vec<Identifier>> {
+    let mut identifiers = Vec::new();
+    for piece in tag.split(['.', '-']) {
+        if piece.is_empty() {
+            return None;
+        }
+        let mut rest = piece;
+        while !rest.is_empty() {
+            let numeric = rest.starts_with(|c: char| c.is_ascii_digit());
+            let end = rest.find(|c: char| c.is_ascii_digit() != numeric).unwrap_or(rest.len());
+            let (run, tail) = rest.split_at(end);
+            identifiers.push(if numeric { Identifier::Number(run.parse().ok()?) } else { Identifier::Word(run.to_lowercase()) });
+            rest = tail;
+        }
+    }
+    Some(identifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_comparison() {
+        assert!(is_newer("v1.2.0", "1.1.9"));
+        assert!(is_newer("1.10.0", "1.9.3"));
+        assert!(!is_newer("v1.2", "1.2.0"));
+        assert!(!is_newer("1.2.0-beta.1", "1.2.0"));
+        assert!(!is_newer("nightly", "1.0.0"));
+
+        assert!(is_newer("1.2.3.4", "1.2.3"));
+        assert!(is_newer("1.2.0-beta.10", "1.2.0-beta.9"));
+        assert!(is_newer("1.2.0-rc.1", "1.2.0-beta.2"));
+        assert!(is_newer("2.0rc10", "2.0rc9"));
+        assert!(is_newer("2.0", "2.0b3"));
+        assert!(is_newer("1.2.3-457", "1.2.3-456"));
+        assert_eq!(compare_versions("1.2.3 build 45", "1.2.3+46"), Ordering::Equal);
+        assert_eq!(compare_versions("1.02", "1.2"), Ordering::Equal);
+
+        let mut versions = vec!["1.10", "nightly", "1.9", "1.9.0-beta", ""];
+        versions.sort_by(|a, b| compare_versions(a, b));
+        assert_eq!(versions, ["", "nightly", "1.9.0-beta", "1.9", "1.10"]);
+    }
+}